@@ -4,6 +4,8 @@
 //! then calling the [`CmdRunner`] directly.
 //! For simple calls like [`AdminRequest::ListDnas`] this is probably easier
 //! but if you want more control use [`CmdRunner::command`].
+use std::collections::BTreeSet;
+use std::collections::HashMap;
 use std::path::Path;
 use std::path::PathBuf;
 use std::{collections::HashSet, convert::TryInto};
@@ -11,17 +13,42 @@ use std::{collections::HashSet, convert::TryInto};
 use anyhow::anyhow;
 use anyhow::bail;
 use anyhow::ensure;
+use holochain_conductor_api::AdminEvent;
+use holochain_conductor_api::AdminEventTopic;
 use holochain_conductor_api::AdminInterfaceConfig;
 use holochain_conductor_api::AdminRequest;
 use holochain_conductor_api::AdminResponse;
+use holochain_conductor_api::AgentInfoAddResult;
+use holochain_conductor_api::AgentInfoRejection;
+use holochain_conductor_api::AppStatusFilter;
+use holochain_conductor_api::CapGrantAccess;
+use holochain_conductor_api::CapGrantInfo;
+use holochain_conductor_api::CellReindexReport;
+use holochain_conductor_api::DnaSummary;
+use holochain_conductor_api::InstalledAppInfo;
 use holochain_conductor_api::InterfaceDriver;
+use holochain_conductor_api::JsonDump;
+use holochain_conductor_api::NetworkStats;
+use holochain_conductor_api::ZomeFnCallStats;
 use holochain_p2p::kitsune_p2p;
 use holochain_p2p::kitsune_p2p::agent_store::AgentInfoSigned;
+use holochain_types::prelude::AppBundle;
+use holochain_types::prelude::AppManifest;
+use holochain_types::prelude::CellNick;
+use holochain_types::prelude::CreateCloneCellPayload;
+use holochain_types::prelude::DeleteCloneCellPayload;
+use holochain_types::prelude::DnaGamut;
+use holochain_types::prelude::EntryType;
 use holochain_types::prelude::InstallAppDnaPayload;
 use holochain_types::prelude::InstallAppPayload;
 use holochain_types::prelude::InstalledCell;
+use holochain_types::prelude::MembraneProof;
 use holochain_types::prelude::RegisterDnaPayload;
+use holochain_types::prelude::SerializedBytes;
+use holochain_types::prelude::Timestamp;
+use holochain_types::prelude::UnsafeBytes;
 use holochain_types::prelude::YamlProperties;
+use holochain_types::prelude::HOLO_HASH_UNTYPED_LEN;
 use holochain_types::prelude::{AgentPubKey, AppBundleSource};
 use holochain_types::prelude::{CellId, InstallAppBundlePayload};
 use holochain_types::prelude::{DnaHash, InstalledApp};
@@ -30,10 +57,16 @@ use std::convert::TryFrom;
 
 use crate::cmds::Existing;
 use crate::expect_match;
+use crate::expect_unit_match;
 use crate::ports::get_admin_ports;
 use crate::run::run_async;
+use crate::CallError;
 use crate::CmdRunner;
+use crate::ConnectOptions;
+use serde::Deserialize;
+use serde::Serialize;
 use structopt::StructOpt;
+use tracing::Instrument;
 
 #[doc(hidden)]
 #[derive(Debug, StructOpt)]
@@ -45,11 +78,85 @@ pub struct Call {
     pub running: Vec<u16>,
     #[structopt(flatten)]
     pub existing: Existing,
+    #[structopt(long, conflicts_with_all = &["running", "existing_paths", "all", "last", "indices"])]
+    /// Call the sandboxes listed in a discovery file
+    /// written by `create --discovery-file`.
+    /// The sandboxes saved in the current directory are ignored.
+    /// Admin ports recorded in the file are used when there are any.
+    pub from: Option<PathBuf>,
+    #[structopt(long, default_value = "5000")]
+    /// Milliseconds to wait for each attempt to connect
+    /// to a conductor's admin interface.
+    pub connect_timeout: u64,
+    #[structopt(long, default_value = "0")]
+    /// How many times to retry connecting to a conductor
+    /// with backoff before starting a new conductor.
+    /// Useful when conductors are still starting up.
+    pub connect_retries: usize,
+    #[structopt(long, default_value = "120000")]
+    /// Milliseconds to wait for a response to each
+    /// admin request before giving up.
+    pub request_timeout: u64,
+    #[structopt(short, long, default_value = "text", possible_values = &["text", "json"])]
+    /// Print the results as human readable `text`
+    /// or machine readable `json`.
+    /// When calling more than one conductor each JSON result is
+    /// wrapped as `{"admin_port": <port>, "result": <result>}`.
+    pub output: Output,
     #[structopt(subcommand)]
     /// The admin request you want to make.
     pub call: AdminRequestCli,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// The format call results are printed in.
+pub enum Output {
+    /// Human readable messages.
+    Text,
+    /// One line of JSON per conductor.
+    Json,
+}
+
+impl std::str::FromStr for Output {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Output::Text),
+            "json" => Ok(Output::Json),
+            _ => bail!("Unknown output format {}, expected `text` or `json`", s),
+        }
+    }
+}
+
+#[derive(Debug, StructOpt, Clone)]
+/// Subscribes to admin events from conductors
+/// and prints them as they arrive until Ctrl-C.
+pub struct Watch {
+    #[structopt(short, long, conflicts_with_all = &["existing_paths", "indices"], value_delimiter = ",")]
+    /// Ports to running conductor admin interfaces.
+    /// If this is empty existing sandboxes will be used.
+    /// Cannot be combined with existing sandboxes.
+    pub running: Vec<u16>,
+    #[structopt(flatten)]
+    pub existing: Existing,
+    #[structopt(
+        short,
+        long,
+        value_delimiter = ",",
+        default_value = "cells,apps",
+        parse(try_from_str = parse_event_topic)
+    )]
+    /// The topics of the events to print.
+    /// Can be any of `cells`, `apps` and `reindex`.
+    /// For example `hc sandbox watch -t=apps`.
+    pub topics: Vec<AdminEventTopic>,
+    #[structopt(short, long, default_value = "text", possible_values = &["text", "json"])]
+    /// Print the events as human readable `text`
+    /// or machine readable `json`.
+    pub output: Output,
+}
+
 // Docs have different use for structopt
 // so documenting everything doesn't make sense.
 #[allow(missing_docs)]
@@ -62,22 +169,92 @@ pub enum AdminRequestCli {
     InstallAppBundle(InstallAppBundle),
     /// Calls AdminRequest::ListAppInterfaces.
     ListAppWs,
-    /// Calls AdminRequest::ListDnas.
-    ListDnas,
+    ListDnas(ListDnas),
     /// Calls AdminRequest::GenerateAgentPubKey.
     NewAgent,
-    /// Calls AdminRequest::ListCellIds.
-    ListCells,
+    ListCells(ListCells),
     /// Calls AdminRequest::ListActiveApps.
     ListActiveApps,
+    ListApps(ListApps),
     ActivateApp(ActivateApp),
     DeactivateApp(DeactivateApp),
+    EnableApp(EnableApp),
+    DisableApp(DisableApp),
+    UninstallApp(UninstallApp),
+    CreateClone(CreateClone),
+    DeleteClone(DeleteClone),
     DumpState(DumpState),
-    /// Calls AdminRequest::AddAgentInfo.
-    /// [Unimplemented].
-    AddAgents,
+    DumpFullState(DumpFullState),
+    AddAgents(AddAgents),
     ListAgents(ListAgents),
+    ExportAgents(ExportAgents),
+    /// Calls AdminRequest::Ping and reports
+    /// how long the conductor took to respond.
+    Ping,
+    /// Calls AdminRequest::NetworkStats and reports
+    /// whether the conductor is connected:
+    /// its transport activity and addresses, whether the
+    /// bootstrap service is reachable and the peers in each space.
+    NetworkStats,
+    TriggerGossip(TriggerGossip),
+    ZomeCallStats(ZomeCallStats),
+    ReindexCell(ReindexCell),
+    ListCapabilityGrants(ListCapabilityGrants),
+}
+
+impl AdminRequestCli {
+    /// The name of the subcommand as it is typed on the command line.
+    fn name(&self) -> &'static str {
+        match self {
+            AdminRequestCli::AddAdminWs(_) => "add-admin-ws",
+            AdminRequestCli::AddAppWs(_) => "add-app-ws",
+            AdminRequestCli::RegisterDna(_) => "register-dna",
+            AdminRequestCli::InstallApp(_) => "install-app",
+            AdminRequestCli::InstallAppBundle(_) => "install-app-bundle",
+            AdminRequestCli::ListAppWs => "list-app-ws",
+            AdminRequestCli::ListDnas(_) => "list-dnas",
+            AdminRequestCli::NewAgent => "new-agent",
+            AdminRequestCli::ListCells(_) => "list-cells",
+            AdminRequestCli::ListActiveApps => "list-active-apps",
+            AdminRequestCli::ListApps(_) => "list-apps",
+            AdminRequestCli::ActivateApp(_) => "activate-app",
+            AdminRequestCli::DeactivateApp(_) => "deactivate-app",
+            AdminRequestCli::EnableApp(_) => "enable-app",
+            AdminRequestCli::DisableApp(_) => "disable-app",
+            AdminRequestCli::UninstallApp(_) => "uninstall-app",
+            AdminRequestCli::CreateClone(_) => "create-clone",
+            AdminRequestCli::DeleteClone(_) => "delete-clone",
+            AdminRequestCli::DumpState(_) => "dump-state",
+            AdminRequestCli::DumpFullState(_) => "dump-full-state",
+            AdminRequestCli::AddAgents(_) => "add-agents",
+            AdminRequestCli::ListAgents(_) => "list-agents",
+            AdminRequestCli::ExportAgents(_) => "export-agents",
+            AdminRequestCli::Ping => "ping",
+            AdminRequestCli::NetworkStats => "network-stats",
+            AdminRequestCli::TriggerGossip(_) => "trigger-gossip",
+            AdminRequestCli::ZomeCallStats(_) => "zome-call-stats",
+            AdminRequestCli::ReindexCell(_) => "reindex-cell",
+            AdminRequestCli::ListCapabilityGrants(_) => "list-capability-grants",
+        }
+    }
+
+    /// The app this call targets if it targets one.
+    fn app_id(&self) -> Option<&str> {
+        match self {
+            AdminRequestCli::InstallApp(args) => Some(&args.app_id),
+            AdminRequestCli::InstallAppBundle(args) => args.app_id.as_deref(),
+            AdminRequestCli::ActivateApp(args) => Some(&args.app_id),
+            AdminRequestCli::DeactivateApp(args) => Some(&args.app_id),
+            AdminRequestCli::EnableApp(args) => Some(&args.app_id),
+            AdminRequestCli::DisableApp(args) => Some(&args.app_id),
+            AdminRequestCli::UninstallApp(args) => Some(&args.app_id),
+            AdminRequestCli::CreateClone(args) => Some(&args.app_id),
+            AdminRequestCli::DeleteClone(args) => Some(&args.app_id),
+            _ => None,
+        }
+    }
 }
+
 #[derive(Debug, StructOpt, Clone)]
 /// Calls AdminRequest::AddAdminInterfaces
 /// and adds another admin interface.
@@ -94,6 +271,14 @@ pub struct AddAppWs {
     /// Optional port number.
     /// Defaults to assigned by OS.
     pub port: Option<u16>,
+    #[structopt(long)]
+    /// Write the bound app port and the admin port
+    /// as JSON to this file once the interface is attached.
+    /// e.g. `{"app_port":8888,"admin_port":9000}`
+    /// Useful for process managers that want to wait
+    /// for the interface to be ready.
+    /// Can only be used when calling a single conductor.
+    pub ready_file: Option<PathBuf>,
 }
 
 #[derive(Debug, StructOpt, Clone)]
@@ -118,9 +303,8 @@ pub struct RegisterDna {
 /// Calls AdminRequest::InstallApp
 /// and installs a new app.
 ///
-/// Setting properties and membrane proofs is not
-/// yet supported.
-/// CellNicks are set to `my-app-0`, `my-app-1` etc.
+/// CellNicks default to `my-app-0`, `my-app-1` etc.
+/// and can be overridden with `--nick`.
 pub struct InstallApp {
     #[structopt(short, long, default_value = "test-app")]
     /// Sets the InstalledAppId.
@@ -130,6 +314,30 @@ pub struct InstallApp {
     /// Agent key is Base64 (same format that is used in logs).
     /// e.g. `uhCAk71wNXTv7lstvi4PfUr_JDvxLucF9WzUgWPNIEZIoPGMF4b_o`
     pub agent_key: Option<AgentPubKey>,
+    #[structopt(short, long, conflicts_with = "dry_run")]
+    /// Path to a yaml file of properties to set on every dna in this app.
+    /// Each dna is registered as a derived dna with these properties
+    /// before the app is installed.
+    pub properties: Option<PathBuf>,
+    #[structopt(short, long, number_of_values = 1, parse(try_from_str = parse_membrane_proof))]
+    /// Membrane proof for a cell in the form `<cell-nick>=<path>`.
+    /// The file is read as raw bytes.
+    /// Repeat this option for each cell that requires a proof.
+    /// e.g. `--membrane-proof test-app-0=./proof.bin`
+    pub membrane_proof: Vec<(CellNick, PathBuf)>,
+    #[structopt(short, long, number_of_values = 1, parse(try_from_str = parse_nick))]
+    /// Set the CellNick for a dna in the form `<index>=<nick>`
+    /// where the index is the position of the dna in this app.
+    /// Dnas without a nick use the default `{app-id}-{index}`.
+    /// e.g. `--nick 0=chat --nick 1=profiles`
+    pub nick: Vec<(usize, CellNick)>,
+    #[structopt(long)]
+    /// Validate the app and print what would be installed
+    /// without installing it.
+    /// Every dna must already be registered on the conductor.
+    /// Can't be used with `--properties` because the derived
+    /// dnas would have to be registered to know their hashes.
+    pub dry_run: bool,
     #[structopt(required = true, min_values = 1, parse(try_from_str = parse_dna_hash))]
     /// The dna hashes to use in this app.
     pub dnas: Vec<DnaHash>,
@@ -139,9 +347,10 @@ pub struct InstallApp {
 /// Calls AdminRequest::InstallAppBundle
 /// and installs a new app.
 ///
-/// Setting properties and membrane proofs is not
-/// yet supported.
-/// CellNicks are set to `my-app-0`, `my-app-1` etc.
+/// The bundle's manifest is validated before installing
+/// and every bundled dna it references must be in the bundle.
+/// Properties are set by the manifest.
+/// CellNicks are the slot ids in the manifest.
 pub struct InstallAppBundle {
     #[structopt(short, long)]
     /// Sets the InstalledAppId.
@@ -159,6 +368,59 @@ pub struct InstallAppBundle {
 
     /// Optional UID override for every DNA in this app
     pub uid: Option<Uid>,
+
+    #[structopt(short, long, number_of_values = 1, parse(try_from_str = parse_membrane_proof))]
+    /// Membrane proof for a slot in the form `<slot-id>=<path>`.
+    /// The file is read as raw bytes.
+    /// Repeat this option for each slot that requires a proof.
+    /// e.g. `--membrane-proof chat=./proof.bin`
+    pub membrane_proof: Vec<(CellNick, PathBuf)>,
+
+    #[structopt(long, number_of_values = 1, parse(try_from_str = parse_expect_hash))]
+    /// Pin the hash of a slot's dna in the form `<slot-id>=<dna-hash>`.
+    /// The dna files in the bundle are hashed with the uid override
+    /// and every pin is checked before anything is installed.
+    /// The install is aborted if any of them don't match.
+    /// e.g. `--expect-hash chat=uhC0k...`
+    pub expect_hash: Vec<(CellNick, DnaHash)>,
+}
+
+#[derive(Debug, StructOpt, Clone)]
+/// Calls AdminRequest::ListDnas
+/// and lists the hashes of the registered dnas.
+/// Filtering or `--verbose` calls AdminRequest::ListDnaSummaries instead.
+pub struct ListDnas {
+    #[structopt(long)]
+    /// Only list dnas with this name.
+    pub by_name: Option<String>,
+    #[structopt(long, alias = "by-uuid")]
+    /// Only list dnas with this uid.
+    pub by_uid: Option<String>,
+    #[structopt(short, long)]
+    /// Show the name, uid and zomes of each dna
+    /// instead of just the hash.
+    pub verbose: bool,
+}
+
+#[derive(Debug, StructOpt, Clone)]
+/// Calls AdminRequest::ListCellIds
+/// and lists the ids of the running cells.
+/// `--by-app` calls AdminRequest::ListApps instead.
+pub struct ListCells {
+    #[structopt(long)]
+    /// Group the cell ids under the app that
+    /// installed them along with their cell nick.
+    pub by_app: bool,
+}
+
+#[derive(Debug, StructOpt, Clone)]
+/// Calls AdminRequest::ListApps
+/// and lists the installed apps and their cells
+/// grouped by status.
+pub struct ListApps {
+    #[structopt(short, long, parse(try_from_str = parse_status_filter), possible_values = &["active", "inactive"])]
+    /// Only list apps with this status.
+    pub status: Option<AppStatusFilter>,
 }
 
 #[derive(Debug, StructOpt, Clone)]
@@ -177,24 +439,142 @@ pub struct DeactivateApp {
     pub app_id: String,
 }
 
+#[derive(Debug, StructOpt, Clone)]
+/// Calls AdminRequest::EnableApp
+/// and activates the installed app,
+/// clearing any reason it was disabled.
+pub struct EnableApp {
+    /// The InstalledAppId to enable.
+    pub app_id: String,
+}
+
+#[derive(Debug, StructOpt, Clone)]
+/// Calls AdminRequest::DisableApp
+/// and deactivates the installed app,
+/// recording why it was disabled.
+pub struct DisableApp {
+    /// The InstalledAppId to disable.
+    pub app_id: String,
+    #[structopt(short, long)]
+    /// Why the app is being disabled.
+    /// This is shown by `list-apps`.
+    pub reason: String,
+}
+
+#[derive(Debug, StructOpt, Clone)]
+/// Calls AdminRequest::UninstallApp
+/// and removes the installed app along with
+/// any cells that no other app uses.
+pub struct UninstallApp {
+    /// The InstalledAppId to uninstall.
+    pub app_id: String,
+}
+
+#[derive(Debug, StructOpt, Clone)]
+/// Calls AdminRequest::CreateCloneCell
+/// and adds a clone of a slot's cell to the app.
+/// The clone uses the same agent as the slot
+/// and a new uid.
+pub struct CreateClone {
+    /// The InstalledAppId to add the clone to.
+    pub app_id: String,
+    /// The slot id (CellNick) of the cell to clone.
+    pub slot_id: CellNick,
+    #[structopt(short, long)]
+    /// Path to a yaml file of properties to set on the clone.
+    pub properties: Option<PathBuf>,
+}
+
+#[derive(Debug, StructOpt, Clone)]
+/// Calls AdminRequest::DeleteCloneCell
+/// and removes a cloned cell from the app.
+/// The cell it was cloned from and any other
+/// clones in the slot are left in place.
+pub struct DeleteClone {
+    /// The InstalledAppId the clone belongs to.
+    pub app_id: String,
+    /// The slot id (CellNick) the clone was created in.
+    pub slot_id: CellNick,
+    #[structopt(parse(try_from_str = parse_dna_hash), required_unless = "cell")]
+    /// The dna hash half of the clone's cell id.
+    pub dna: Option<DnaHash>,
+    #[structopt(parse(try_from_str = parse_agent_key), required_unless = "cell")]
+    /// The agent half of the clone's cell id.
+    pub agent_key: Option<AgentPubKey>,
+    #[structopt(long, parse(try_from_str = parse_cell_id), conflicts_with_all = &["dna", "agent_key"])]
+    /// The clone's cell id in the form `<dna>:<agent>`.
+    /// Can be used instead of passing the two halves separately.
+    pub cell: Option<CellId>,
+}
+
 #[derive(Debug, StructOpt, Clone)]
 /// Calls AdminRequest::DumpState
 /// and dumps the current cell's state.
-/// TODO: Add pretty print.
-/// TODO: Default to dumping all cell state.
+/// Use `dump-full-state` to dump every cell.
 pub struct DumpState {
-    #[structopt(parse(try_from_str = parse_dna_hash))]
+    #[structopt(parse(try_from_str = parse_dna_hash), required_unless = "cell")]
     /// The dna hash half of the cell id to dump.
-    pub dna: DnaHash,
-    #[structopt(parse(try_from_str = parse_agent_key))]
+    pub dna: Option<DnaHash>,
+    #[structopt(parse(try_from_str = parse_agent_key), required_unless = "cell")]
     /// The agent half of the cell id to dump.
-    pub agent_key: AgentPubKey,
+    pub agent_key: Option<AgentPubKey>,
+    #[structopt(long, parse(try_from_str = parse_cell_id), conflicts_with_all = &["dna", "agent_key"])]
+    /// The cell id to dump in the form `<dna>:<agent>`.
+    /// Can be used instead of passing the two halves separately.
+    pub cell: Option<CellId>,
+    #[structopt(long)]
+    /// Print the dump as indented JSON with the source chain
+    /// in sequence order and hashes in Base64.
+    pub pretty: bool,
+    #[structopt(long)]
+    /// Also dump the ops in the validation limbo,
+    /// integration limbo and integrated ops stores.
+    pub include_queues: bool,
+    #[structopt(long, default_value = "100")]
+    /// The most ops to dump from each queue
+    /// when `--include-queues` is set.
+    pub max_queue_entries: u32,
+    #[structopt(long, parse(try_from_str = parse_rfc3339))]
+    /// Only dump source chain headers made at or after
+    /// this RFC 3339 time, e.g. `2021-03-01T00:00:00Z`.
+    pub since: Option<chrono::DateTime<chrono::Utc>>,
+    #[structopt(long, parse(try_from_str = parse_rfc3339))]
+    /// Only dump source chain headers made at or before
+    /// this RFC 3339 time.
+    pub until: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+#[derive(Debug, StructOpt, Clone)]
+/// Calls AdminRequest::ListCellIds and then
+/// AdminRequest::DumpState for each cell
+/// and dumps the state of all cells.
+pub struct DumpFullState {
+    #[structopt(short, long)]
+    /// Only dump the cell at this index.
+    /// Cells are sorted by cell id so the
+    /// index is stable across runs.
+    pub cell_index: Option<usize>,
+}
+
+#[derive(Debug, StructOpt, Clone)]
+/// Calls AdminRequest::AddAgentInfo
+/// and adds the agent info from the files to
+/// this conductor's peer store.
+/// Agent info that is already in the peer store
+/// is skipped.
+pub struct AddAgents {
+    /// Paths to files of serialized agent info.
+    /// Each file can contain a JSON array of agent info
+    /// or one JSON agent info per line.
+    /// Reads from stdin if no paths are given or a path is `-`.
+    pub paths: Vec<PathBuf>,
 }
 
 #[derive(Debug, StructOpt, Clone)]
 /// Calls AdminRequest::RequestAgentInfo
 /// and pretty prints the agent info on
 /// this conductor.
+/// Agent info is sorted by space and then by when it was signed.
 pub struct ListAgents {
     #[structopt(short, long, parse(try_from_str = parse_agent_key), requires = "dna")]
     /// Optionally request agent info for a particular cell id.
@@ -202,38 +582,158 @@ pub struct ListAgents {
     #[structopt(short, long, parse(try_from_str = parse_dna_hash), requires = "agent_key")]
     /// Optionally request agent info for a particular cell id.
     pub dna: Option<DnaHash>,
+    #[structopt(long, parse(try_from_str = parse_cell_id), conflicts_with_all = &["dna", "agent_key"])]
+    /// Optionally request agent info for a particular
+    /// cell id in the form `<dna>:<agent>`.
+    pub cell: Option<CellId>,
+    #[structopt(long, conflicts_with = "expired")]
+    /// Only show agent info that expires within this many minutes.
+    /// Agent info that has already expired is included.
+    pub expires_within: Option<i64>,
+    #[structopt(long)]
+    /// Only show agent info that has already expired.
+    pub expired: bool,
+    #[structopt(long)]
+    /// Show every signed agent info instead of only
+    /// the most recently signed info for each agent.
+    pub all_signatures: bool,
+}
+
+#[derive(Debug, StructOpt, Clone)]
+/// Calls AdminRequest::RequestAgentInfo
+/// and exports the agent info on this conductor
+/// in a format that `add-agents` can import.
+/// Each line is one serialized agent info along
+/// with its space and agent hashes.
+pub struct ExportAgents {
+    #[structopt(short, long, parse(try_from_str = parse_agent_key), requires = "dna")]
+    /// Optionally export agent info for a particular cell id.
+    pub agent_key: Option<AgentPubKey>,
+    #[structopt(short, long, parse(try_from_str = parse_dna_hash), requires = "agent_key")]
+    /// Optionally export agent info for a particular cell id.
+    pub dna: Option<DnaHash>,
+    #[structopt(long, parse(try_from_str = parse_cell_id), conflicts_with_all = &["dna", "agent_key"])]
+    /// Optionally export agent info for a particular
+    /// cell id in the form `<dna>:<agent>`.
+    pub cell: Option<CellId>,
+    #[structopt(short, long)]
+    /// Write the agent info to this file.
+    /// Writes to stdout if no file is given or the file is `-`.
+    pub file: Option<PathBuf>,
+}
+
+#[derive(Debug, StructOpt, Clone)]
+/// Calls AdminRequest::TriggerGossip
+/// and runs the workflows that process the ops
+/// a cell has received through gossip now.
+pub struct TriggerGossip {
+    #[structopt(parse(try_from_str = parse_cell_id))]
+    /// The cell id to trigger in the form `<dna>:<agent>`.
+    pub cell: CellId,
+    #[structopt(short, long)]
+    /// Also call AdminRequest::TriggerPublish
+    /// to publish the cell's authored ops now.
+    pub publish: bool,
+}
+
+#[derive(Debug, StructOpt, Clone)]
+/// Calls AdminRequest::ZomeCallStats
+/// and lists how many times each zome function
+/// of a cell has been called and how long the calls took.
+pub struct ZomeCallStats {
+    #[structopt(parse(try_from_str = parse_cell_id))]
+    /// The cell id to get the stats for in the form `<dna>:<agent>`.
+    pub cell: CellId,
+}
+
+#[derive(Debug, StructOpt, Clone)]
+/// Calls AdminRequest::ReindexCell
+/// and rebuilds the element and metadata stores
+/// of a cell from the ops it has integrated.
+/// Safe to run while the cell is running.
+/// Progress is printed as the conductor reports it.
+pub struct ReindexCell {
+    #[structopt(parse(try_from_str = parse_cell_id))]
+    /// The cell id to reindex in the form `<dna>:<agent>`.
+    pub cell: CellId,
+}
+
+#[derive(Debug, StructOpt, Clone)]
+/// Calls AdminRequest::ListCapabilityGrants
+/// and lists the capability grants on a cell's source chain,
+/// showing which agents can call which functions.
+pub struct ListCapabilityGrants {
+    #[structopt(parse(try_from_str = parse_cell_id))]
+    /// The cell id to list the grants of in the form `<dna>:<agent>`.
+    pub cell: CellId,
+    #[structopt(long)]
+    /// Also list grants that have been updated or deleted.
+    pub include_revoked: bool,
 }
 
 #[doc(hidden)]
 pub async fn call(holochain_path: &Path, req: Call) -> anyhow::Result<()> {
+    let span = tracing::info_span!(
+        "call",
+        subcommand = req.call.name(),
+        app_id = ?req.call.app_id()
+    );
+    call_all(holochain_path, req).instrument(span).await
+}
+
+async fn call_all(holochain_path: &Path, req: Call) -> anyhow::Result<()> {
     let Call {
         existing,
         running,
+        from,
+        connect_timeout,
+        connect_retries,
+        request_timeout,
+        output,
         call,
     } = req;
-    let cmds = if running.is_empty() {
-        let paths = if existing.is_empty() {
-            crate::save::load(std::env::current_dir()?)?
-        } else {
-            existing.load()?
+    let connect = ConnectOptions {
+        timeout: std::time::Duration::from_millis(connect_timeout),
+        retries: connect_retries,
+    };
+    let mut cmds = if running.is_empty() {
+        let (paths, recorded_ports) = match from {
+            Some(file) => {
+                let discovery = crate::save::load_discovery(&file)?;
+                let recorded_ports: Vec<_> =
+                    discovery.sandboxes.iter().map(|s| s.admin_port).collect();
+                (discovery.paths(), recorded_ports)
+            }
+            None if existing.is_empty() => {
+                (crate::save::load(std::env::current_dir()?)?, Vec::new())
+            }
+            None => (existing.load()?, Vec::new()),
         };
-        let ports = get_admin_ports(paths.clone()).await?;
+        let mut ports = get_admin_ports(paths.clone()).await?;
+        // Ports bound when the discovery file was written win
+        // over any saved in the current directory.
+        for (port, recorded) in ports.iter_mut().zip(recorded_ports) {
+            if let Some(recorded) = recorded {
+                *port = recorded;
+            }
+        }
         let mut cmds = Vec::with_capacity(ports.len());
         for (port, path) in ports.into_iter().zip(paths.into_iter()) {
-            match CmdRunner::try_new(port).await {
-                Ok(cmd) => cmds.push((cmd, None)),
+            match CmdRunner::try_new_with_retry(port, connect).await {
+                Ok(cmd) => cmds.push((port, cmd, None)),
                 Err(e) => {
                     if let holochain_websocket::WebsocketError::Io(e) = &e {
                         if let std::io::ErrorKind::ConnectionRefused = e.kind() {
                             let (port, holochain) = run_async(holochain_path, path, None).await?;
-                            cmds.push((CmdRunner::new(port).await, Some(holochain)));
+                            cmds.push((port, CmdRunner::new(port).await, Some(holochain)));
                             continue;
                         }
                     }
-                    bail!(
+                    return Err(CallError::Connection(format!(
                         "Failed to connect to running conductor or start one {:?}",
                         e
-                    )
+                    ))
+                    .into());
                 }
             }
         }
@@ -241,112 +741,557 @@ pub async fn call(holochain_path: &Path, req: Call) -> anyhow::Result<()> {
     } else {
         let mut cmds = Vec::with_capacity(running.len());
         for port in running {
-            cmds.push((CmdRunner::new(port).await, None));
+            let cmd = CmdRunner::try_new_with_retry(port, connect)
+                .await
+                .map_err(|e| {
+                    CallError::Connection(format!(
+                        "Failed to connect to admin port {}: {:?}",
+                        port, e
+                    ))
+                })?;
+            cmds.push((port, cmd, None));
         }
         cmds
     };
-    for mut cmd in cmds {
-        call_inner(&mut cmd.0, call.clone()).await?;
+
+    if let AdminRequestCli::AddAppWs(AddAppWs {
+        ready_file: Some(_),
+        ..
+    }) = &call
+    {
+        ensure!(
+            cmds.len() == 1,
+            "--ready-file can only be used when calling a single conductor but there are {}",
+            cmds.len()
+        );
+    }
+
+    let request_timeout = std::time::Duration::from_millis(request_timeout);
+    for (_, cmd, _) in cmds.iter_mut() {
+        cmd.set_request_timeout(request_timeout);
+    }
+
+    // Only label the output when there's more than one conductor.
+    let label = cmds.len() > 1;
+    let num_calls = cmds.len();
+    let calls = cmds.into_iter().map(|(port, mut cmd, holochain)| {
+        let call = call.clone();
+        let span = tracing::info_span!(
+            "call_conductor",
+            subcommand = call.name(),
+            admin_port = port,
+            app_id = ?call.app_id()
+        );
+        async move {
+            let mut printer = CallOutput::default();
+            let result = call_inner(&mut cmd, port, call, output, &mut printer).await;
+            if let Err(e) = &result {
+                tracing::error!(error = ?e, "Call failed");
+            }
+            // Keep any conductor we started alive until the call is done.
+            drop(holochain);
+            (port, printer, result)
+        }
+        .instrument(span)
+    });
+    let mut failed = 0;
+    for (port, mut printer, result) in futures::future::join_all(calls).await {
+        let label = if label { Some(port) } else { None };
+        if let Err(e) = result {
+            failed += 1;
+            match output {
+                Output::Json => {
+                    let error = match e.downcast_ref::<CallError>() {
+                        Some(e) => serde_json::to_value(e)?,
+                        None => serde_json::json!({ "kind": "other", "data": format!("{:?}", e) }),
+                    };
+                    // Errors always carry the admin port so they aren't labelled again.
+                    printer.raw(serde_json::to_string(
+                        &serde_json::json!({ "admin_port": port, "error": error }),
+                    )?);
+                }
+                Output::Text => printer.msg(format!("Call failed: {:?}", e)),
+            }
+        }
+        printer.print(label);
+    }
+    ensure!(
+        failed == 0,
+        "{} of {} conductor calls failed",
+        failed,
+        num_calls
+    );
+    Ok(())
+}
+
+/// Subscribe to admin events from running conductors and print
+/// them as they arrive until Ctrl-C or every conductor disconnects.
+/// Unlike [`call`] this never starts a conductor.
+pub async fn watch(args: Watch) -> anyhow::Result<()> {
+    use futures::StreamExt;
+    let Watch {
+        running,
+        existing,
+        topics,
+        output,
+    } = args;
+    let ports = if running.is_empty() {
+        let paths = if existing.is_empty() {
+            crate::save::load(std::env::current_dir()?)?
+        } else {
+            existing.load()?
+        };
+        get_admin_ports(paths).await?
+    } else {
+        running
+    };
+    ensure!(!ports.is_empty(), "There are no conductors to watch");
+
+    let mut streams = Vec::with_capacity(ports.len());
+    for port in ports {
+        let cmd = CmdRunner::try_new(port).await.map_err(|e| {
+            CallError::Connection(format!("Failed to connect to admin port {}: {:?}", port, e))
+        })?;
+        let events = cmd.subscribe(topics.clone()).await?;
+        streams.push(events.map(move |event| (port, event)));
+    }
+    if output == Output::Text {
+        msg!(
+            "Watching {} conductor(s) for {:?} events, press Ctrl-C to stop",
+            streams.len(),
+            topics
+        );
+    }
+    let mut events = futures::stream::select_all(streams);
+    loop {
+        tokio::select! {
+            event = events.next() => match event {
+                Some((port, event)) => {
+                    let mut printer = CallOutput::default();
+                    print_output(&mut printer, output, &event, |event| format!("{:?}", event))?;
+                    printer.print(Some(port));
+                }
+                None => {
+                    if output == Output::Text {
+                        msg!("All conductors disconnected");
+                    }
+                    break;
+                }
+            },
+            _ = tokio::signal::ctrl_c() => break,
+        }
     }
     Ok(())
 }
 
-async fn call_inner(cmd: &mut CmdRunner, call: AdminRequestCli) -> anyhow::Result<()> {
+async fn call_inner(
+    cmd: &mut CmdRunner,
+    admin_port: u16,
+    call: AdminRequestCli,
+    output: Output,
+    printer: &mut CallOutput,
+) -> anyhow::Result<()> {
     match call {
         AdminRequestCli::AddAdminWs(args) => {
             let port = add_admin_interface(cmd, args).await?;
-            msg!("Added Admin port {}", port);
+            print_output(printer, output, &port, |port| {
+                format!("Added Admin port {}", port)
+            })?;
         }
         AdminRequestCli::AddAppWs(args) => {
+            let ready_file = args.ready_file.clone();
             let port = attach_app_interface(cmd, args).await?;
-            msg!("Added App port {}", port);
+            if let Some(path) = ready_file {
+                write_ready_file(&path, port, admin_port)?;
+            }
+            print_output(printer, output, &port, |port| {
+                format!("Added App port {}", port)
+            })?;
         }
         AdminRequestCli::ListAppWs => {
             let ports = list_app_ws(cmd).await?;
-            msg!("Attached App Interfaces {:?}", ports);
+            print_output(printer, output, &ports, |ports| {
+                format!("Attached App Interfaces {:?}", ports)
+            })?;
         }
         AdminRequestCli::RegisterDna(args) => {
             let dnas = register_dna(cmd, args).await?;
-            msg!("Registered Dna: {:?}", dnas);
+            print_output(printer, output, &dnas, |dnas| {
+                format!("Registered Dna: {:?}", dnas)
+            })?;
+        }
+        AdminRequestCli::InstallApp(args) if args.dry_run => {
+            let plan = dry_run_install_app(cmd, args).await?;
+            print_output(printer, output, &plan, |plan| {
+                let cells: Vec<_> = plan
+                    .cells
+                    .iter()
+                    .map(|(nick, hash)| format!("\n  {}: {}", nick, hash))
+                    .collect();
+                let agent_key = match &plan.agent_key {
+                    Some(agent_key) => agent_key.to_string(),
+                    None => "generated on install".to_string(),
+                };
+                format!(
+                    "Dry run: would install App: {} for agent {} with cells:{}",
+                    plan.installed_app_id,
+                    agent_key,
+                    cells.concat()
+                )
+            })?;
         }
         AdminRequestCli::InstallApp(args) => {
             let app_id = args.app_id.clone();
             let cells = install_app(cmd, args).await?;
-            msg!("Installed App: {} with cells {:?}", app_id, cells);
+            print_output(printer, output, &cells, |cells| {
+                let mut hashes: Vec<_> = cells
+                    .iter()
+                    .map(|c| format!("\n  {}: {}", c.as_nick(), c.as_id().dna_hash()))
+                    .collect();
+                hashes.sort();
+                format!(
+                    "Installed App: {} with cells {:?}\nDna hashes:{}",
+                    app_id,
+                    cells,
+                    hashes.concat()
+                )
+            })?;
         }
         AdminRequestCli::InstallAppBundle(args) => {
             let app = install_app_bundle(cmd, args).await?;
-            let cells: Vec<_> = app.all_cells().collect();
-            msg!(
-                "Installed App: {} with cells {:?}",
-                app.installed_app_id(),
-                cells
-            );
+            print_output(printer, output, &app, |app| {
+                let cells: Vec<_> = app.all_cells().collect();
+                let mut hashes: Vec<_> = app
+                    .slots()
+                    .iter()
+                    .map(|(slot_id, slot)| format!("\n  {}: {}", slot_id, slot.dna_hash()))
+                    .collect();
+                hashes.sort();
+                format!(
+                    "Installed App: {} with cells {:?}\nDna hashes:{}",
+                    app.installed_app_id(),
+                    cells,
+                    hashes.concat()
+                )
+            })?;
+        }
+        AdminRequestCli::ListDnas(args) => {
+            if args.by_name.is_none() && args.by_uid.is_none() && !args.verbose {
+                let dnas = list_dnas(cmd).await?;
+                print_output(printer, output, &dnas, |dnas| format!("Dnas: {:?}", dnas))?;
+            } else {
+                let summaries = list_dna_summaries(cmd, &args).await?;
+                if args.verbose {
+                    print_output(printer, output, &summaries, |summaries| {
+                        format!("Dnas:\n{}", format_dna_summaries(summaries))
+                    })?;
+                } else {
+                    let dnas: Vec<_> = summaries.into_iter().map(|s| s.hash).collect();
+                    print_output(printer, output, &dnas, |dnas| format!("Dnas: {:?}", dnas))?;
+                }
+            }
+        }
+        AdminRequestCli::Ping => {
+            let latency = ping(cmd).await?;
+            print_output(
+                printer,
+                output,
+                &serde_json::json!({ "latency_ms": latency.as_secs_f64() * 1000.0 }),
+                |_| format!("Pong in {:?}", latency),
+            )?;
+        }
+        AdminRequestCli::NetworkStats => {
+            let stats = network_stats(cmd).await?;
+            print_output(printer, output, &stats, pretty_network_stats)?;
         }
-        AdminRequestCli::ListDnas => {
-            let dnas = list_dnas(cmd).await?;
-            msg!("Dnas: {:?}", dnas);
+        AdminRequestCli::TriggerGossip(args) => {
+            let publish = args.publish;
+            let cell_id = trigger_gossip(cmd, args).await?;
+            print_output(
+                printer,
+                output,
+                &serde_json::json!({ "cell_id": cell_id, "published": publish }),
+                |_| {
+                    if publish {
+                        format!("Triggered gossip and publish for cell: {:?}", cell_id)
+                    } else {
+                        format!("Triggered gossip for cell: {:?}", cell_id)
+                    }
+                },
+            )?;
+        }
+        AdminRequestCli::ZomeCallStats(args) => {
+            let stats = zome_call_stats(cmd, args).await?;
+            print_output(printer, output, &stats, |stats| {
+                format_zome_call_stats(stats)
+            })?;
+        }
+        AdminRequestCli::ReindexCell(args) => {
+            if let Output::Text = output {
+                printer.msg(format!("Reindexing cell: {:?}", args.cell));
+            }
+            let report = reindex_cell(cmd, args, |done, total| {
+                // Printed as it happens because the output
+                // is only printed once the call is done
+                if let Output::Text = output {
+                    msg!(
+                        "[admin port {}] Reindexed {}/{} ops",
+                        admin_port,
+                        done,
+                        total
+                    );
+                }
+            })
+            .await?;
+            print_output(printer, output, &report, |report| {
+                format!(
+                    "Reindexed {} valid and {} rejected ops. \
+                    Skipped {} abandoned ops and {} ops with missing data.",
+                    report.valid, report.rejected, report.abandoned, report.missing_data
+                )
+            })?;
+        }
+        AdminRequestCli::ListCapabilityGrants(args) => {
+            let grants = list_capability_grants(cmd, args).await?;
+            print_output(printer, output, &grants, |grants| format_cap_grants(grants))?;
         }
         AdminRequestCli::NewAgent => {
             let agent = generate_agent_pub_key(cmd).await?;
-            msg!("Added agent {}", agent);
+            print_output(printer, output, &agent, |agent| {
+                format!("Added agent {}", agent)
+            })?;
         }
-        AdminRequestCli::ListCells => {
-            let cells = list_cell_ids(cmd).await?;
-            msg!("Cell Ids: {:?}", cells);
+        AdminRequestCli::ListCells(args) => {
+            if args.by_app {
+                let apps = list_cells_by_app(cmd).await?;
+                print_output(printer, output, &apps, |apps| format_cells_by_app(apps))?;
+            } else {
+                let cells = list_cell_ids(cmd).await?;
+                print_output(printer, output, &cells, |cells| {
+                    format!("Cell Ids: {:?}", cells)
+                })?;
+            }
         }
         AdminRequestCli::ListActiveApps => {
             let apps = list_active_apps(cmd).await?;
-            msg!("Active Apps: {:?}", apps);
+            print_output(printer, output, &apps, |apps| {
+                format!("Active Apps: {:?}", apps)
+            })?;
+        }
+        AdminRequestCli::ListApps(args) => {
+            let apps = list_apps(cmd, args).await?;
+            print_output(printer, output, &apps, |apps| {
+                format!("Apps:\n{}", format_apps(apps))
+            })?;
         }
         AdminRequestCli::ActivateApp(args) => {
             let app_id = args.app_id.clone();
             activate_app(cmd, args).await?;
-            msg!("Activated app: {:?}", app_id);
+            print_output(printer, output, &app_id, |app_id| {
+                format!("Activated app: {:?}", app_id)
+            })?;
         }
         AdminRequestCli::DeactivateApp(args) => {
             let app_id = args.app_id.clone();
             deactivate_app(cmd, args).await?;
-            msg!("Deactivated app: {:?}", app_id);
+            print_output(printer, output, &app_id, |app_id| {
+                format!("Deactivated app: {:?}", app_id)
+            })?;
+        }
+        AdminRequestCli::EnableApp(args) => {
+            let app_id = args.app_id.clone();
+            enable_app(cmd, args).await?;
+            print_output(printer, output, &app_id, |app_id| {
+                format!("Enabled app: {:?}", app_id)
+            })?;
+        }
+        AdminRequestCli::DisableApp(args) => {
+            let app_id = args.app_id.clone();
+            disable_app(cmd, args).await?;
+            print_output(printer, output, &app_id, |app_id| {
+                format!("Disabled app: {:?}", app_id)
+            })?;
+        }
+        AdminRequestCli::UninstallApp(args) => {
+            let app_id = args.app_id.clone();
+            let (removed_cells, kept_cells) = uninstall_app(cmd, args).await?;
+            match output {
+                Output::Text => printer.msg(format!(
+                    "Uninstalled app: {:?}\nRemoved cells: {:?}\nKept cells still used by other apps: {:?}",
+                    app_id, removed_cells, kept_cells
+                )),
+                Output::Json => printer.json(&serde_json::json!({
+                    "app_id": app_id,
+                    "removed_cells": removed_cells,
+                    "kept_cells": kept_cells,
+                }))?,
+            }
+        }
+        AdminRequestCli::CreateClone(args) => {
+            let cell_id = create_clone(cmd, args).await?;
+            print_output(printer, output, &cell_id, |cell_id| {
+                format!("Created clone cell: {:?}", cell_id)
+            })?;
+        }
+        AdminRequestCli::DeleteClone(args) => {
+            let cell_id = args.cell_id()?;
+            delete_clone(cmd, args).await?;
+            print_output(printer, output, &cell_id, |cell_id| {
+                format!("Deleted clone cell: {:?}", cell_id)
+            })?;
         }
         AdminRequestCli::DumpState(args) => {
             let state = dump_state(cmd, args).await?;
-            msg!("DUMP STATE \n{}", state);
+            match output {
+                Output::Text => printer.msg(format!("DUMP STATE \n{}", state)),
+                // The state is already JSON.
+                Output::Json => printer.json_str(state),
+            }
+        }
+        AdminRequestCli::DumpFullState(args) => match output {
+            Output::Text => {
+                let state = dump_full_state(cmd, args).await?;
+                printer.msg(format!("DUMP FULL STATE \n{}", state));
+            }
+            Output::Json => {
+                let states: Vec<_> = dump_all_states(cmd, args)
+                    .await?
+                    .into_iter()
+                    .map(|(index, cell_id, state)| match state {
+                        Ok(state) => serde_json::json!({
+                            "index": index,
+                            "cell_id": cell_id,
+                            "state": serde_json::from_str::<serde_json::Value>(&state)
+                                .unwrap_or(serde_json::Value::String(state)),
+                        }),
+                        Err(e) => serde_json::json!({
+                            "index": index,
+                            "cell_id": cell_id,
+                            "error": format!("{:?}", e),
+                        }),
+                    })
+                    .collect();
+                printer.json(&states)?;
+            }
+        },
+        AdminRequestCli::AddAgents(args) => {
+            let summary = add_agents(cmd, args).await?;
+            print_output(printer, output, &summary, |s| {
+                let mut out = format!(
+                    "Added {} agent info, rejected {} as duplicates and {} as invalid",
+                    s.added,
+                    s.duplicates,
+                    s.rejected.len()
+                );
+                for r in &s.rejected {
+                    out.push_str(&format!(
+                        "\n  agent {} in space {}: {}",
+                        r.agent.as_deref().unwrap_or("unknown"),
+                        r.space.as_deref().unwrap_or("unknown"),
+                        r.reason
+                    ));
+                }
+                out
+            })?;
+        }
+        AdminRequestCli::ExportAgents(args) => {
+            let file = args.file.clone();
+            let agent_infos = export_agents(cmd, args).await?;
+            match file {
+                Some(path) if path != Path::new("-") => {
+                    std::fs::write(&path, &agent_infos)?;
+                    let exported = agent_infos.lines().count();
+                    print_output(
+                        printer,
+                        output,
+                        &serde_json::json!({
+                            "exported": exported,
+                            "file": path,
+                        }),
+                        |_| format!("Exported {} agent info to {}", exported, path.display()),
+                    )?;
+                }
+                _ => {
+                    // Each line is an agent info as JSON.
+                    for line in agent_infos.lines() {
+                        match output {
+                            Output::Text => printer.raw(line.to_string()),
+                            Output::Json => printer.json_str(line.to_string()),
+                        }
+                    }
+                }
+            }
         }
-        AdminRequestCli::AddAgents => todo!("Adding agent info via cli is coming soon"),
         AdminRequestCli::ListAgents(args) => {
             use std::fmt::Write;
-            let agent_infos = request_agent_info(cmd, args).await?;
-            for info in agent_infos {
-                let mut out = String::new();
-                let cell_info = list_cell_ids(cmd).await?;
-                let agents = cell_info
-                    .iter()
-                    .map(|c| c.agent_pubkey().clone())
-                    .map(|a| (a.clone(), holochain_p2p::agent_holo_to_kit(a)))
-                    .collect::<Vec<_>>();
+            let expires_within = args.expires_within;
+            let expired = args.expired;
+            let all_signatures = args.all_signatures;
+            let now = chrono::Utc::now();
+            let mut agent_infos = Vec::new();
+            for info in request_agent_info(cmd, args).await? {
+                // One bad info in the peer store shouldn't hide the rest.
+                let kit_info = match read_agent_info(&info) {
+                    Ok(kit_info) => kit_info,
+                    Err(e) => {
+                        tracing::warn!(error = %e, "Skipping agent info");
+                        if let Output::Text = output {
+                            printer.msg(format!("Skipping {}\n", e));
+                        }
+                        continue;
+                    }
+                };
+                agent_infos.push((info, kit_info));
+            }
+            select_agent_infos(
+                &mut agent_infos,
+                all_signatures,
+                now,
+                expires_within,
+                expired,
+            );
+            if let Output::Json = output {
+                let agent_infos: Vec<_> = agent_infos.into_iter().map(|(info, _)| info).collect();
+                printer.json(&agent_infos)?;
+                return Ok(());
+            }
 
-                let dnas = cell_info
-                    .iter()
-                    .map(|c| c.dna_hash().clone())
-                    .map(|d| (d.clone(), holochain_p2p::space_holo_to_kit(d)))
-                    .collect::<Vec<_>>();
-
-                let info: kitsune_p2p::agent_store::AgentInfo = (&info).try_into().unwrap();
-                let this_agent = agents.iter().find(|a| *info.as_agent_ref() == a.1);
-                let this_dna = dnas.iter().find(|d| *info.as_space_ref() == d.1).unwrap();
-                if let Some(this_agent) = this_agent {
-                    writeln!(out, "This Agent {:?} is {:?}", this_agent.0, this_agent.1)?;
+            let cell_info = list_cell_ids(cmd).await?;
+            let agents = cell_info
+                .iter()
+                .map(|c| c.agent_pubkey().clone())
+                .map(|a| (a.clone(), holochain_p2p::agent_holo_to_kit(a)))
+                .collect::<Vec<_>>();
+
+            let dnas = cell_info
+                .iter()
+                .map(|c| c.dna_hash().clone())
+                .map(|d| (d.clone(), holochain_p2p::space_holo_to_kit(d)))
+                .collect::<Vec<_>>();
+
+            for (_, info) in agent_infos {
+                let mut out = String::new();
+                // The peer store also has infos for agents we don't host.
+                match find_local(
+                    &agents,
+                    info.as_agent_ref(),
+                    AgentInfoLookupError::UnknownAgent,
+                ) {
+                    Ok(agent) => {
+                        writeln!(out, "This Agent {:?} is {:?}", agent, info.as_agent_ref())?
+                    }
+                    Err(e) => writeln!(out, "{}: {:?}", e, info.as_agent_ref())?,
+                }
+                match find_local(
+                    &dnas,
+                    info.as_space_ref(),
+                    AgentInfoLookupError::UnknownSpace,
+                ) {
+                    Ok(dna) => writeln!(out, "This DNA {:?} is {:?}", dna, info.as_space_ref())?,
+                    Err(e) => writeln!(out, "{}: {:?}", e, info.as_space_ref())?,
                 }
-                writeln!(out, "This DNA {:?} is {:?}", this_dna.0, this_dna.1)?;
 
-                use chrono::{DateTime, Duration, NaiveDateTime, Utc};
-                let duration = Duration::milliseconds(info.signed_at_ms() as i64);
-                let s = duration.num_seconds() as i64;
-                let n = duration.clone().to_std().unwrap().subsec_nanos();
-                let dt = DateTime::<Utc>::from_utc(NaiveDateTime::from_timestamp(s, n), Utc);
-                let exp = dt + Duration::milliseconds(info.expires_after_ms() as i64);
-                let now = Utc::now();
+                let (dt, exp) = agent_info_times(&info)?;
 
                 writeln!(out, "signed at {}", dt)?;
                 writeln!(
@@ -358,13 +1303,84 @@ async fn call_inner(cmd: &mut CmdRunner, call: AdminRequestCli) -> anyhow::Resul
                 writeln!(out, "space: {:?}", info.as_space_ref())?;
                 writeln!(out, "agent: {:?}", info.as_agent_ref())?;
                 writeln!(out, "urls: {:?}", info.as_urls_ref())?;
-                msg!("{}\n", out);
+                printer.msg(format!("{}\n", out));
             }
         }
     }
     Ok(())
 }
 
+/// Record the result of a call in the chosen [`Output`] format.
+/// The `text` closure is only run for [`Output::Text`].
+fn print_output<T: Serialize>(
+    printer: &mut CallOutput,
+    output: Output,
+    value: &T,
+    text: impl FnOnce(&T) -> String,
+) -> anyhow::Result<()> {
+    match output {
+        Output::Text => printer.msg(text(value)),
+        Output::Json => printer.json(value)?,
+    }
+    Ok(())
+}
+
+/// The output of a call to a single conductor.
+/// This is collected instead of printed directly so
+/// calls to many conductors can run concurrently
+/// without interleaving their output.
+#[derive(Default)]
+struct CallOutput(Vec<OutputLine>);
+
+enum OutputLine {
+    /// Printed with the `hc-sandbox:` prefix.
+    Msg(String),
+    /// Printed as is.
+    Raw(String),
+    /// A JSON value printed as is, or wrapped in an object
+    /// with the conductor's admin port when labelled.
+    Json(String),
+}
+
+impl CallOutput {
+    fn msg(&mut self, s: String) {
+        self.0.push(OutputLine::Msg(s));
+    }
+
+    fn raw(&mut self, s: String) {
+        self.0.push(OutputLine::Raw(s));
+    }
+
+    fn json<T: Serialize>(&mut self, value: &T) -> anyhow::Result<()> {
+        self.json_str(serde_json::to_string(value)?);
+        Ok(())
+    }
+
+    /// Add a string that is already JSON.
+    fn json_str(&mut self, s: String) {
+        self.0.push(OutputLine::Json(s));
+    }
+
+    /// Print the output, labelling each message and JSON
+    /// value with the conductor's admin port if one is given.
+    fn print(self, label: Option<u16>) {
+        for line in self.0 {
+            match (line, label) {
+                (OutputLine::Msg(s), Some(port)) => msg!("[admin port {}] {}", port, s),
+                (OutputLine::Msg(s), None) => msg!("{}", s),
+                (OutputLine::Json(s), Some(port)) => println!("{}", labelled_json(port, &s)),
+                (OutputLine::Raw(s), _) | (OutputLine::Json(s), None) => println!("{}", s),
+            }
+        }
+    }
+}
+
+/// Wrap a JSON value in an object with the admin
+/// port of the conductor it came from.
+fn labelled_json(port: u16, json: &str) -> String {
+    format!(r#"{{"admin_port":{},"result":{}}}"#, port, json)
+}
+
 /// Calls [`AdminRequest::AddAdminInterfaces`] and adds another admin interface.
 pub async fn add_admin_interface(cmd: &mut CmdRunner, args: AddAdminWs) -> anyhow::Result<u16> {
     let port = args.port.unwrap_or(0);
@@ -375,13 +1391,12 @@ pub async fn add_admin_interface(cmd: &mut CmdRunner, args: AddAdminWs) -> anyho
             },
         ]))
         .await?;
-    ensure!(
-        matches!(resp, AdminResponse::AdminInterfacesAdded),
-        "Failed to add admin interface, got: {:?}",
-        resp
-    );
-    // TODO: return chosen port when 0 is used
-    Ok(port)
+    let ports =
+        expect_match!(resp => AdminResponse::AdminInterfacesAdded, "Failed to add admin interface");
+    ports
+        .into_iter()
+        .next()
+        .ok_or_else(|| anyhow!("Failed to add admin interface {}, no port was bound", port))
 }
 
 /// Calls [`AdminRequest::RegisterDna`] and registers dna.
@@ -417,28 +1432,21 @@ pub async fn register_dna(cmd: &mut CmdRunner, args: RegisterDna) -> anyhow::Res
 }
 
 /// Calls [`AdminRequest::InstallApp`] and installs a new app.
-/// Creates an app per dna with the app id of `{app-id}-{dna-index}`
-/// e.g. `my-cool-app-3`.
+/// Creates a cell per dna with the cell nick set by `--nick`
+/// or defaulting to `{app-id}-{dna-index}` e.g. `my-cool-app-3`.
 pub async fn install_app(
     cmd: &mut CmdRunner,
     args: InstallApp,
 ) -> anyhow::Result<HashSet<InstalledCell>> {
-    let InstallApp {
-        app_id,
-        agent_key,
-        dnas,
-    } = args;
+    ensure!(!args.dry_run, "Use dry_run_install_app for a dry run");
+    let app_id = args.app_id.clone();
+    let agent_key = args.agent_key.clone();
+    let dnas = install_app_dnas(cmd, args).await?;
     let agent_key = match agent_key {
         Some(agent) => agent,
         None => generate_agent_pub_key(cmd).await?,
     };
 
-    let dnas = dnas
-        .into_iter()
-        .enumerate()
-        .map(|(i, hash)| InstallAppDnaPayload::hash_only(hash, format!("{}-{}", app_id, i)))
-        .collect();
-
     let app = InstallAppPayload {
         installed_app_id: app_id,
         agent_key,
@@ -462,30 +1470,262 @@ pub async fn install_app(
         .collect())
 }
 
-/// Calls [`AdminRequest::InstallApp`] and installs a new app.
-pub async fn install_app_bundle(
+/// What [`dry_run_install_app`] found would be installed.
+#[derive(Debug, Serialize)]
+pub struct InstallAppDryRun {
+    /// The app id that would be installed.
+    pub installed_app_id: String,
+    /// The agent for the app if one was given.
+    pub agent_key: Option<AgentPubKey>,
+    /// The cell nick and dna hash of each cell in the app.
+    pub cells: Vec<(CellNick, DnaHash)>,
+}
+
+/// Runs the same checks as [`install_app`] and reports what would
+/// be installed without calling [`AdminRequest::InstallApp`].
+/// Only [`AdminRequest::ListDnas`] is called on the conductor.
+pub async fn dry_run_install_app(
     cmd: &mut CmdRunner,
-    args: InstallAppBundle,
-) -> anyhow::Result<InstalledApp> {
-    let InstallAppBundle {
-        app_id,
+    args: InstallApp,
+) -> anyhow::Result<InstallAppDryRun> {
+    ensure!(
+        args.properties.is_none(),
+        "Properties can't be set on a dry run"
+    );
+    let registered: HashSet<_> = list_dnas(cmd).await?.into_iter().collect();
+    for hash in &args.dnas {
+        ensure!(
+            registered.contains(hash),
+            "Dna {} is not registered on this conductor",
+            hash
+        );
+    }
+    let installed_app_id = args.app_id.clone();
+    let agent_key = args.agent_key.clone();
+    let cells = install_app_dnas(cmd, args)
+        .await?
+        .into_iter()
+        .map(|dna| (dna.nick, dna.hash))
+        .collect();
+    Ok(InstallAppDryRun {
+        installed_app_id,
         agent_key,
-        path,
-        uid,
+        cells,
+    })
+}
+
+/// Build the payload for each dna in an [`InstallApp`],
+/// checking the nicks, membrane proofs and expected hashes.
+/// Dnas are registered with the properties if any are given.
+async fn install_app_dnas(
+    cmd: &mut CmdRunner,
+    args: InstallApp,
+) -> anyhow::Result<Vec<InstallAppDnaPayload>> {
+    let InstallApp {
+        app_id,
+        agent_key: _,
+        properties,
+        membrane_proof,
+        nick,
+        dry_run: _,
+        dnas,
     } = args;
 
-    let bundle = AppBundleSource::Path(path).resolve().await?;
+    let mut membrane_proofs = HashMap::with_capacity(membrane_proof.len());
+    for (nick, path) in membrane_proof {
+        let proof = SerializedBytes::from(UnsafeBytes::from(std::fs::read(&path)?));
+        ensure!(
+            membrane_proofs.insert(nick.clone(), proof).is_none(),
+            "Membrane proof was given more than once for cell nick {}",
+            nick
+        );
+    }
 
-    let agent_key = match agent_key {
-        Some(agent) => agent,
+    let nicks = cell_nicks(&app_id, dnas.len(), nick)?;
+
+    let mut dna_payloads = Vec::with_capacity(dnas.len());
+    for (hash, nick) in dnas.into_iter().zip(nicks) {
+        // Properties can only be set by registering a derived dna.
+        let hash = match &properties {
+            Some(properties) => {
+                register_dna(
+                    cmd,
+                    RegisterDna {
+                        uid: None,
+                        properties: Some(properties.clone()),
+                        path: None,
+                        hash: Some(hash),
+                    },
+                )
+                .await?
+            }
+            None => hash,
+        };
+        let membrane_proof = membrane_proofs.remove(&nick);
+        dna_payloads.push(InstallAppDnaPayload {
+            hash,
+            nick,
+            membrane_proof,
+        });
+    }
+    if !membrane_proofs.is_empty() {
+        bail!(
+            "Membrane proofs were given for cell nicks that are not in this app: {:?}",
+            membrane_proofs.keys().collect::<Vec<_>>()
+        );
+    }
+    Ok(dna_payloads)
+}
+
+/// Check every pinned hash against the hash its slot's dna resolved to.
+/// All the mismatches are reported together.
+fn check_expected_hashes(
+    expected: &[(CellNick, DnaHash)],
+    hashes: &HashMap<CellNick, DnaHash>,
+) -> anyhow::Result<()> {
+    let mut pinned = HashSet::with_capacity(expected.len());
+    let mut mismatches = Vec::new();
+    for (slot_id, expected) in expected {
+        ensure!(
+            pinned.insert(slot_id),
+            "More than one expected hash given for slot {}",
+            slot_id
+        );
+        let found = hashes.get(slot_id).ok_or_else(|| {
+            anyhow!(
+                "Expected hash {} was given for slot {} which is not in this app",
+                expected,
+                slot_id
+            )
+        })?;
+        if found != expected {
+            mismatches.push(format!(
+                "\n  {}: expected {} but found {}",
+                slot_id, expected, found
+            ));
+        }
+    }
+    ensure!(
+        mismatches.is_empty(),
+        "Dna hash mismatch:{}",
+        mismatches.concat()
+    );
+    Ok(())
+}
+
+/// Hash the dna of each slot from the bundled dna files,
+/// applying the uid override the same way the conductor does on install.
+async fn bundle_dna_hashes(
+    path: &Path,
+    uid: Option<Uid>,
+    agent_key: AgentPubKey,
+) -> anyhow::Result<HashMap<CellNick, DnaHash>> {
+    let bundle = AppBundleSource::Path(path.to_path_buf())
+        .resolve()
+        .await
+        .map_err(|e| anyhow!("Failed to read app bundle {}: {}", path.display(), e))?;
+    let bundle = match uid {
+        Some(uid) => {
+            let mut manifest = bundle.manifest().to_owned();
+            manifest.set_uid(uid);
+            AppBundle::from(bundle.into_inner().update_manifest(manifest)?)
+        }
+        None => bundle,
+    };
+    #[allow(deprecated)]
+    let gamut = DnaGamut::placeholder();
+    let resolution = bundle
+        .resolve_cells(agent_key, gamut, HashMap::new())
+        .await?;
+    Ok(resolution
+        .slots
+        .into_iter()
+        .map(|(slot_id, slot)| (slot_id, slot.dna_hash().clone()))
+        .collect())
+}
+
+/// Build the cell nick for each of the `num_dnas` dnas.
+/// Any dna without a custom nick falls back to `{app-id}-{dna-index}`.
+fn cell_nicks(
+    app_id: &str,
+    num_dnas: usize,
+    custom: Vec<(usize, CellNick)>,
+) -> anyhow::Result<Vec<CellNick>> {
+    let mut nicks: Vec<Option<CellNick>> = vec![None; num_dnas];
+    for (i, nick) in custom {
+        let slot = nicks.get_mut(i).ok_or_else(|| {
+            anyhow!(
+                "Nick {} was given for dna index {} but there are only {} dnas",
+                nick,
+                i,
+                num_dnas
+            )
+        })?;
+        ensure!(
+            slot.is_none(),
+            "More than one nick given for dna index {}",
+            i
+        );
+        *slot = Some(nick);
+    }
+    let nicks: Vec<_> = nicks
+        .into_iter()
+        .enumerate()
+        .map(|(i, nick)| nick.unwrap_or_else(|| format!("{}-{}", app_id, i)))
+        .collect();
+    let mut seen = HashSet::with_capacity(nicks.len());
+    for nick in &nicks {
+        ensure!(seen.insert(nick), "Duplicate cell nick {}", nick);
+    }
+    Ok(nicks)
+}
+
+/// Calls [`AdminRequest::InstallApp`] and installs a new app.
+pub async fn install_app_bundle(
+    cmd: &mut CmdRunner,
+    args: InstallAppBundle,
+) -> anyhow::Result<InstalledApp> {
+    let InstallAppBundle {
+        app_id,
+        agent_key,
+        path,
+        uid,
+        membrane_proof,
+        expect_hash,
+    } = args;
+
+    let bundle = AppBundleSource::Path(path.clone())
+        .resolve()
+        .await
+        .map_err(|e| anyhow!("Failed to read app bundle {}: {}", path.display(), e))?;
+
+    let mut membrane_proofs = HashMap::with_capacity(membrane_proof.len());
+    for (slot_id, path) in membrane_proof {
+        let proof = SerializedBytes::from(UnsafeBytes::from(std::fs::read(&path)?));
+        ensure!(
+            membrane_proofs.insert(slot_id.clone(), proof).is_none(),
+            "Membrane proof was given more than once for slot {}",
+            slot_id
+        );
+    }
+    check_app_bundle(&bundle, &membrane_proofs)
+        .map_err(|e| anyhow!("Invalid app bundle {}: {}", path.display(), e))?;
+
+    let agent_key = match agent_key {
+        Some(agent) => agent,
         None => generate_agent_pub_key(cmd).await?,
     };
 
+    if !expect_hash.is_empty() {
+        let hashes = bundle_dna_hashes(&path, uid.clone(), agent_key.clone()).await?;
+        check_expected_hashes(&expect_hash, &hashes)?;
+    }
+
     let payload = InstallAppBundlePayload {
         installed_app_id: app_id,
         agent_key,
         source: AppBundleSource::Bundle(bundle),
-        membrane_proofs: Default::default(),
+        membrane_proofs,
         uid,
     };
 
@@ -503,6 +1743,42 @@ pub async fn install_app_bundle(
     Ok(installed_app)
 }
 
+/// Check the bundle's manifest is valid, every bundled dna it references
+/// is in the bundle and every membrane proof is for a slot in the manifest.
+fn check_app_bundle(
+    bundle: &AppBundle,
+    membrane_proofs: &HashMap<CellNick, MembraneProof>,
+) -> anyhow::Result<()> {
+    use mr_bundle::Manifest;
+    let manifest = bundle.manifest();
+    let slot_ids: HashSet<_> = match manifest {
+        AppManifest::V1(m) => m.slots.iter().map(|slot| &slot.id).collect(),
+    };
+    manifest
+        .clone()
+        .validate()
+        .map_err(|e| anyhow!("Invalid manifest: {}", e))?;
+    for location in manifest.locations() {
+        if let mr_bundle::Location::Bundled(dna_path) = location {
+            ensure!(
+                bundle.bundled_resources().contains_key(&dna_path),
+                "Manifest references dna {} which is missing from the bundle",
+                dna_path.display()
+            );
+        }
+    }
+    let unknown: Vec<_> = membrane_proofs
+        .keys()
+        .filter(|slot_id| !slot_ids.contains(slot_id))
+        .collect();
+    ensure!(
+        unknown.is_empty(),
+        "Membrane proofs were given for slots that are not in this app: {:?}",
+        unknown
+    );
+    Ok(())
+}
+
 /// Calls [`AdminRequest::ListAppInterfaces`].
 pub async fn list_app_ws(cmd: &mut CmdRunner) -> anyhow::Result<Vec<u16>> {
     let resp = cmd.command(AdminRequest::ListAppInterfaces).await?;
@@ -515,6 +1791,282 @@ pub async fn list_dnas(cmd: &mut CmdRunner) -> anyhow::Result<Vec<DnaHash>> {
     Ok(expect_match!(resp => AdminResponse::DnasListed, "Failed to list dnas"))
 }
 
+/// Calls [`AdminRequest::ListDnaSummaries`] and keeps
+/// the dnas matching the filters in [`ListDnas`].
+pub async fn list_dna_summaries(
+    cmd: &mut CmdRunner,
+    args: &ListDnas,
+) -> anyhow::Result<Vec<DnaSummary>> {
+    let resp = cmd.command(AdminRequest::ListDnaSummaries).await?;
+    let summaries =
+        expect_match!(resp => AdminResponse::DnaSummariesListed, "Failed to list dna summaries");
+    Ok(filter_dna_summaries(summaries, args))
+}
+
+fn filter_dna_summaries(summaries: Vec<DnaSummary>, args: &ListDnas) -> Vec<DnaSummary> {
+    summaries
+        .into_iter()
+        .filter(|s| args.by_name.as_ref().map_or(true, |name| *name == s.name))
+        .filter(|s| args.by_uid.as_ref().map_or(true, |uid| *uid == s.uid))
+        .collect()
+}
+
+fn format_dna_summaries(summaries: &[DnaSummary]) -> String {
+    use std::fmt::Write;
+    let mut out = String::new();
+    for DnaSummary {
+        hash,
+        name,
+        uid,
+        zome_names,
+    } in summaries
+    {
+        let zome_names: Vec<_> = zome_names.iter().map(|z| z.to_string()).collect();
+        writeln!(out, "{}", hash).ok();
+        writeln!(out, "  name: {}", name).ok();
+        writeln!(out, "  uid: {}", uid).ok();
+        writeln!(out, "  zomes: {}", zome_names.join(", ")).ok();
+    }
+    out
+}
+
+/// Calls [`AdminRequest::Ping`] and returns the round-trip time.
+pub async fn ping(cmd: &mut CmdRunner) -> anyhow::Result<std::time::Duration> {
+    let start = std::time::Instant::now();
+    let resp = cmd.command(AdminRequest::Ping).await?;
+    expect_unit_match!(resp => AdminResponse::Pong, "Failed to ping conductor");
+    Ok(start.elapsed())
+}
+
+/// Calls [`AdminRequest::NetworkStats`].
+pub async fn network_stats(cmd: &mut CmdRunner) -> anyhow::Result<NetworkStats> {
+    let resp = cmd.command(AdminRequest::NetworkStats).await?;
+    Ok(expect_match!(resp => AdminResponse::NetworkStatsRequested, "Failed to get network stats"))
+}
+
+/// Calls [`AdminRequest::TriggerGossip`] and,
+/// if `publish` is set, [`AdminRequest::TriggerPublish`]
+/// for the cell. Returns the triggered cell id.
+pub async fn trigger_gossip(cmd: &mut CmdRunner, args: TriggerGossip) -> anyhow::Result<CellId> {
+    let resp = cmd
+        .command(AdminRequest::TriggerGossip {
+            cell_id: Box::new(args.cell.clone()),
+        })
+        .await?;
+    expect_unit_match!(resp => AdminResponse::GossipTriggered, "Failed to trigger gossip");
+    if args.publish {
+        let resp = cmd
+            .command(AdminRequest::TriggerPublish {
+                cell_id: Box::new(args.cell.clone()),
+            })
+            .await?;
+        expect_unit_match!(resp => AdminResponse::PublishTriggered, "Failed to trigger publish");
+    }
+    Ok(args.cell)
+}
+
+/// Calls [`AdminRequest::ZomeCallStats`].
+pub async fn zome_call_stats(
+    cmd: &mut CmdRunner,
+    args: ZomeCallStats,
+) -> anyhow::Result<Vec<ZomeFnCallStats>> {
+    let resp = cmd
+        .command(AdminRequest::ZomeCallStats {
+            cell_id: Box::new(args.cell),
+        })
+        .await?;
+    Ok(
+        expect_match!(resp => AdminResponse::ZomeCallStatsRequested, "Failed to get zome call stats"),
+    )
+}
+
+/// Calls [`AdminRequest::ReindexCell`] and calls `progress` with the
+/// ops done so far and the total as the conductor reports them.
+/// The progress comes from a subscription to [`AdminEventTopic::Reindex`]
+/// events on a second connection to the runner's admin port.
+pub async fn reindex_cell(
+    cmd: &mut CmdRunner,
+    args: ReindexCell,
+    mut progress: impl FnMut(usize, usize),
+) -> anyhow::Result<CellReindexReport> {
+    use futures::StreamExt;
+    let cell_id = args.cell;
+    let events = cmd
+        .subscribe(vec![AdminEventTopic::Reindex])
+        .await?
+        .filter_map({
+            let cell_id = cell_id.clone();
+            move |event| {
+                futures::future::ready(match event {
+                    AdminEvent::CellReindexProgress {
+                        cell_id: c,
+                        done,
+                        total,
+                    } if c == cell_id => Some((done, total)),
+                    _ => None,
+                })
+            }
+        });
+    futures::pin_mut!(events);
+    let request = cmd.command(AdminRequest::ReindexCell {
+        cell_id: Box::new(cell_id),
+    });
+    futures::pin_mut!(request);
+    let resp = loop {
+        tokio::select! {
+            resp = &mut request => break resp?,
+            Some((done, total)) = events.next() => progress(done, total),
+        }
+    };
+    Ok(expect_match!(resp => AdminResponse::CellReindexed, "Failed to reindex cell"))
+}
+
+/// Calls [`AdminRequest::ListCapabilityGrants`].
+pub async fn list_capability_grants(
+    cmd: &mut CmdRunner,
+    args: ListCapabilityGrants,
+) -> anyhow::Result<Vec<CapGrantInfo>> {
+    let resp = cmd
+        .command(AdminRequest::ListCapabilityGrants {
+            cell_id: Box::new(args.cell),
+            include_revoked: args.include_revoked,
+        })
+        .await?;
+    Ok(
+        expect_match!(resp => AdminResponse::CapabilityGrantsListed, "Failed to list capability grants"),
+    )
+}
+
+/// Format each capability grant as a block of lines
+/// like `list-agents` does for agent infos.
+fn format_cap_grants(grants: &[CapGrantInfo]) -> String {
+    use std::fmt::Write;
+    if grants.is_empty() {
+        return "No capability grants\n".to_string();
+    }
+    let mut out = String::new();
+    for grant in grants {
+        writeln!(out, "grant: {:?}", grant.header_hash).ok();
+        writeln!(out, "tag: {:?}", grant.tag).ok();
+        match &grant.access {
+            CapGrantAccess::Unrestricted => writeln!(out, "access: unrestricted"),
+            CapGrantAccess::Transferable => writeln!(out, "access: transferable"),
+            CapGrantAccess::Assigned(assignees) => {
+                writeln!(out, "access: assigned to {:?}", assignees)
+            }
+        }
+        .ok();
+        let functions: Vec<_> = grant
+            .functions
+            .iter()
+            .map(|(zome, f)| format!("{}/{}", zome.0, f.0))
+            .collect();
+        writeln!(out, "functions: {}", functions.join(", ")).ok();
+        if grant.revoked {
+            writeln!(out, "revoked").ok();
+        }
+        writeln!(out).ok();
+    }
+    out
+}
+
+/// Format zome call stats as a table
+/// with a row for each function.
+fn format_zome_call_stats(stats: &[ZomeFnCallStats]) -> String {
+    use std::fmt::Write;
+    if stats.is_empty() {
+        return "No zome calls\n".to_string();
+    }
+    let mut out = String::new();
+    writeln!(
+        out,
+        "{:<20} {:<20} {:>8} {:>8} {:>12} {:>12}",
+        "ZOME", "FN", "CALLS", "ERRORS", "MEAN", "MAX"
+    )
+    .ok();
+    for s in stats {
+        writeln!(
+            out,
+            "{:<20} {:<20} {:>8} {:>8} {:>12} {:>12}",
+            s.zome_name.0,
+            s.fn_name.0,
+            s.calls,
+            s.errors,
+            format!("{:?}", s.mean().unwrap_or_default()),
+            format!("{:?}", std::time::Duration::from_micros(s.max_micros)),
+        )
+        .ok();
+    }
+    out
+}
+
+/// Render the [`NetworkStats`] as a short human readable report.
+pub fn pretty_network_stats(stats: &NetworkStats) -> String {
+    use std::fmt::Write;
+    let NetworkStats {
+        transport,
+        transport_addresses,
+        bootstrap_reachable,
+        spaces,
+    } = stats;
+    let mut out = String::new();
+    writeln!(out, "Transport addresses:").ok();
+    for address in transport_addresses {
+        writeln!(out, "  {}", address).ok();
+    }
+    let bootstrap = match bootstrap_reachable {
+        Some(true) => "reachable",
+        Some(false) => "unreachable",
+        None => "not configured",
+    };
+    writeln!(out, "Bootstrap service: {}", bootstrap).ok();
+    writeln!(out, "Open connections: {}", transport.open_connections).ok();
+    writeln!(
+        out,
+        "Bytes sent: {}, received: {}",
+        transport.bytes_sent, transport.bytes_received
+    )
+    .ok();
+    writeln!(
+        out,
+        "Gossip: {} rounds, {} rounds and {} bytes sent in the last {}s",
+        transport.gossip_rounds,
+        transport.recent_gossip_rounds,
+        transport.recent_gossip_bytes_sent,
+        kitsune_p2p::metrics::RECENT_GOSSIP_WINDOW.as_secs()
+    )
+    .ok();
+    match transport.recent_request_rtt_us {
+        Some(rtt) => writeln!(
+            out,
+            "Requests: {} answered in the last {}s, mean round trip {:.1}ms",
+            transport.recent_requests,
+            kitsune_p2p::metrics::RECENT_GOSSIP_WINDOW.as_secs(),
+            rtt as f64 / 1000.0
+        ),
+        None => writeln!(
+            out,
+            "Requests: none answered in the last {}s",
+            kitsune_p2p::metrics::RECENT_GOSSIP_WINDOW.as_secs()
+        ),
+    }
+    .ok();
+    if spaces.is_empty() {
+        write!(out, "Spaces: none").ok();
+    } else {
+        write!(out, "Spaces:").ok();
+    }
+    for space in spaces {
+        write!(
+            out,
+            "\n  {}: {} local agents, {} peers",
+            space.dna_hash, space.local_agents, space.peers
+        )
+        .ok();
+    }
+    out
+}
+
 /// Calls [`AdminRequest::GenerateAgentPubKey`].
 pub async fn generate_agent_pub_key(cmd: &mut CmdRunner) -> anyhow::Result<AgentPubKey> {
     let resp = cmd.command(AdminRequest::GenerateAgentPubKey).await?;
@@ -529,12 +2081,128 @@ pub async fn list_cell_ids(cmd: &mut CmdRunner) -> anyhow::Result<Vec<CellId>> {
     Ok(expect_match!(resp => AdminResponse::CellIdsListed, "Failed to list cell ids"))
 }
 
+/// The cells installed by a single app.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AppCells {
+    /// The app that installed the cells.
+    pub installed_app_id: String,
+    /// The cell nick and id of each cell in the app.
+    pub cells: Vec<(CellNick, CellId)>,
+    /// The cell nick of the slot and id of each cell cloned at runtime.
+    #[serde(default)]
+    pub clone_cells: Vec<(CellNick, CellId)>,
+}
+
+/// Calls [`AdminRequest::ListApps`] and groups the
+/// cell ids of every installed app by the app id,
+/// including any clone cells.
+pub async fn list_cells_by_app(cmd: &mut CmdRunner) -> anyhow::Result<Vec<AppCells>> {
+    let apps = list_apps(cmd, ListApps { status: None }).await?;
+    Ok(group_cells_by_app(apps))
+}
+
+impl From<&InstalledApp> for AppCells {
+    fn from(app: &InstalledApp) -> Self {
+        Self {
+            installed_app_id: app.installed_app_id().clone(),
+            cells: app
+                .provisioned_cells()
+                .map(|(nick, id)| (nick.clone(), id.clone()))
+                .collect(),
+            clone_cells: app
+                .cloned_cells_by_slot()
+                .map(|(nick, id)| (nick.clone(), id.clone()))
+                .collect(),
+        }
+    }
+}
+
+fn group_cells_by_app(apps: Vec<InstalledAppInfo>) -> Vec<AppCells> {
+    let by_nick = |cells: Vec<InstalledCell>| {
+        cells
+            .into_iter()
+            .map(|cell| {
+                let (id, nick) = cell.into_inner();
+                (nick, id)
+            })
+            .collect()
+    };
+    apps.into_iter()
+        .map(|app| AppCells {
+            installed_app_id: app.installed_app_id,
+            cells: by_nick(app.cell_data),
+            clone_cells: by_nick(app.clone_cells),
+        })
+        .collect()
+}
+
+/// Format cells as a row for each cell
+/// under the app that installed it.
+fn format_cells_by_app(apps: &[AppCells]) -> String {
+    use std::fmt::Write;
+    let mut out = String::new();
+    for app in apps {
+        writeln!(out, "{}", app.installed_app_id).ok();
+        for (nick, id) in &app.cells {
+            writeln!(out, "  {:<20} {}", nick, id).ok();
+        }
+        for (nick, id) in &app.clone_cells {
+            writeln!(out, "  {:<20} {} (clone)", nick, id).ok();
+        }
+    }
+    if out.is_empty() {
+        out.push_str("No apps installed\n");
+    }
+    out
+}
+
 /// Calls [`AdminRequest::ListActiveApps`].
 pub async fn list_active_apps(cmd: &mut CmdRunner) -> anyhow::Result<Vec<String>> {
     let resp = cmd.command(AdminRequest::ListActiveApps).await?;
     Ok(expect_match!(resp => AdminResponse::ActiveAppsListed, "Failed to list active apps"))
 }
 
+/// Calls [`AdminRequest::ListApps`].
+pub async fn list_apps(
+    cmd: &mut CmdRunner,
+    args: ListApps,
+) -> anyhow::Result<Vec<InstalledAppInfo>> {
+    let resp = cmd
+        .command(AdminRequest::ListApps {
+            status_filter: args.status,
+        })
+        .await?;
+    Ok(expect_match!(resp => AdminResponse::AppsListed, "Failed to list apps"))
+}
+
+/// Format apps as a table grouped by status
+/// with a row for each cell.
+fn format_apps(apps: &[InstalledAppInfo]) -> String {
+    use std::fmt::Write;
+    let mut out = String::new();
+    for (title, active) in &[("ACTIVE", true), ("INACTIVE", false)] {
+        let group: Vec<_> = apps.iter().filter(|a| a.active == *active).collect();
+        if group.is_empty() {
+            continue;
+        }
+        writeln!(out, "{} ({})", title, group.len()).ok();
+        for app in group {
+            match &app.disabled_reason {
+                Some(reason) => writeln!(out, "  {} (disabled: {})", app.installed_app_id, reason),
+                None => writeln!(out, "  {}", app.installed_app_id),
+            }
+            .ok();
+            for cell in &app.cell_data {
+                writeln!(out, "    {:<20} {}", cell.as_nick(), cell.as_id()).ok();
+            }
+        }
+    }
+    if out.is_empty() {
+        out.push_str("No apps installed\n");
+    }
+    out
+}
+
 /// Calls [`AdminRequest::ActivateApp`] and activates the installed app.
 pub async fn activate_app(cmd: &mut CmdRunner, args: ActivateApp) -> anyhow::Result<()> {
     let resp = cmd
@@ -542,11 +2210,7 @@ pub async fn activate_app(cmd: &mut CmdRunner, args: ActivateApp) -> anyhow::Res
             installed_app_id: args.app_id,
         })
         .await?;
-    ensure!(
-        matches!(resp, AdminResponse::AppActivated),
-        "Failed to activate app, got: {:?}",
-        resp
-    );
+    expect_unit_match!(resp => AdminResponse::AppActivated, "Failed to activate app");
     Ok(())
 }
 
@@ -557,11 +2221,109 @@ pub async fn deactivate_app(cmd: &mut CmdRunner, args: DeactivateApp) -> anyhow:
             installed_app_id: args.app_id,
         })
         .await?;
-    ensure!(
-        matches!(resp, AdminResponse::AppDeactivated),
-        "Failed to deactivate app, got: {:?}",
-        resp
-    );
+    expect_unit_match!(resp => AdminResponse::AppDeactivated, "Failed to deactivate app");
+    Ok(())
+}
+
+/// Calls [`AdminRequest::EnableApp`] and enables the installed app.
+pub async fn enable_app(cmd: &mut CmdRunner, args: EnableApp) -> anyhow::Result<()> {
+    let resp = cmd
+        .command(AdminRequest::EnableApp {
+            installed_app_id: args.app_id,
+        })
+        .await?;
+    expect_unit_match!(resp => AdminResponse::AppEnabled, "Failed to enable app");
+    Ok(())
+}
+
+/// Calls [`AdminRequest::DisableApp`] and disables the installed app.
+pub async fn disable_app(cmd: &mut CmdRunner, args: DisableApp) -> anyhow::Result<()> {
+    let resp = cmd
+        .command(AdminRequest::DisableApp {
+            installed_app_id: args.app_id,
+            reason: args.reason,
+        })
+        .await?;
+    expect_unit_match!(resp => AdminResponse::AppDisabled, "Failed to disable app");
+    Ok(())
+}
+
+/// Calls [`AdminRequest::UninstallApp`] and uninstalls the app.
+/// Returns the cells that were removed followed by
+/// the cells that were kept because other apps still use them.
+pub async fn uninstall_app(
+    cmd: &mut CmdRunner,
+    args: UninstallApp,
+) -> anyhow::Result<(Vec<CellId>, Vec<CellId>)> {
+    let resp = cmd
+        .command(AdminRequest::UninstallApp {
+            installed_app_id: args.app_id,
+        })
+        .await?;
+    match resp {
+        AdminResponse::AppUninstalled {
+            removed_cells,
+            kept_cells,
+        } => Ok((removed_cells, kept_cells)),
+        other => Err(CallError::unexpected(
+            "Failed to uninstall app",
+            "AdminResponse::AppUninstalled",
+            other,
+        )
+        .into()),
+    }
+}
+
+/// Calls [`AdminRequest::CreateCloneCell`] and returns the new clone's [`CellId`].
+/// The dna and agent to clone are looked up from the app's cell in the slot.
+pub async fn create_clone(cmd: &mut CmdRunner, args: CreateClone) -> anyhow::Result<CellId> {
+    let CreateClone {
+        app_id,
+        slot_id,
+        properties,
+    } = args;
+    let apps = list_apps(cmd, ListApps { status: None }).await?;
+    let app = apps
+        .into_iter()
+        .find(|app| app.installed_app_id == app_id)
+        .ok_or_else(|| anyhow!("App {} is not installed", app_id))?;
+    let base = app
+        .cell_data
+        .into_iter()
+        .find(|cell| *cell.as_nick() == slot_id)
+        .ok_or_else(|| anyhow!("App {} has no slot {}", app_id, slot_id))?;
+    let properties = match properties {
+        Some(path) => Some(YamlProperties::new(serde_yaml::from_str(
+            &std::fs::read_to_string(path)?,
+        )?)),
+        None => None,
+    };
+    let payload = CreateCloneCellPayload {
+        properties,
+        dna_hash: base.as_id().dna_hash().clone(),
+        agent_key: base.as_id().agent_pubkey().clone(),
+        installed_app_id: app_id,
+        slot_id,
+        membrane_proof: None,
+    };
+    let resp = cmd
+        .command(AdminRequest::CreateCloneCell(Box::new(payload)))
+        .await?;
+    Ok(expect_match!(resp => AdminResponse::CloneCellCreated, "Failed to create clone cell"))
+}
+
+/// Calls [`AdminRequest::DeleteCloneCell`] and removes the clone from its app.
+pub async fn delete_clone(cmd: &mut CmdRunner, args: DeleteClone) -> anyhow::Result<()> {
+    let cell_id = args.cell_id()?;
+    let payload = DeleteCloneCellPayload {
+        installed_app_id: args.app_id,
+        slot_id: args.slot_id,
+        cell_id,
+    };
+    let resp = cmd
+        .command(AdminRequest::DeleteCloneCell(Box::new(payload)))
+        .await?;
+    expect_unit_match!(resp => AdminResponse::CloneCellDeleted, "Failed to delete clone cell");
     Ok(())
 }
 
@@ -573,50 +2335,624 @@ pub async fn attach_app_interface(cmd: &mut CmdRunner, args: AddAppWs) -> anyhow
     tracing::debug!(?resp);
     match resp {
         AdminResponse::AppInterfaceAttached { port } => Ok(port),
-        _ => Err(anyhow!(
-            "Failed to attach app interface {:?}, got: {:?}",
-            args.port,
-            resp
-        )),
+        other => Err(CallError::unexpected(
+            &format!("Failed to attach app interface {:?}", args.port),
+            "AdminResponse::AppInterfaceAttached",
+            other,
+        )
+        .into()),
     }
 }
 
+/// Write the app and admin ports to a JSON file.
+/// The file is written to a temporary file first and then renamed
+/// so a reader never sees a partially written file.
+pub fn write_ready_file(path: &Path, app_port: u16, admin_port: u16) -> anyhow::Result<()> {
+    let contents = serde_json::to_vec(&serde_json::json!({
+        "app_port": app_port,
+        "admin_port": admin_port,
+    }))?;
+    let mut tmp_name = path
+        .file_name()
+        .ok_or_else(|| anyhow!("Ready file {} is not a file path", path.display()))?
+        .to_os_string();
+    tmp_name.push(".tmp");
+    let tmp = path.with_file_name(tmp_name);
+    std::fs::write(&tmp, contents)?;
+    std::fs::rename(&tmp, path)?;
+    Ok(())
+}
+
 /// Calls [`AdminRequest::DumpState`] and dumps the current cell's state.
-// TODO: Add pretty print.
-// TODO: Default to dumping all cell state.
+/// If `pretty` is set the dump is rendered with [`pretty_state_dump`].
 pub async fn dump_state(cmd: &mut CmdRunner, args: DumpState) -> anyhow::Result<String> {
+    let pretty = args.pretty;
+    let (since, until) = (args.since, args.until);
+    if let (Some(since), Some(until)) = (since, until) {
+        if since > until {
+            bail!("--since {} is after --until {}", since, until);
+        }
+    }
+    let queue_entries = if args.include_queues {
+        Some(args.max_queue_entries)
+    } else {
+        None
+    };
     let resp = cmd
         .command(AdminRequest::DumpState {
-            cell_id: Box::new(args.into()),
+            cell_id: Box::new(args.try_into()?),
+            queue_entries,
         })
         .await?;
-    Ok(expect_match!(resp => AdminResponse::StateDumped, "Failed to dump state"))
+    let mut state = expect_match!(resp => AdminResponse::StateDumped, "Failed to dump state");
+    if since.is_some() || until.is_some() {
+        state = filter_state_dump(&state, since, until)?;
+    }
+    if pretty {
+        pretty_state_dump(&state)
+    } else {
+        Ok(state)
+    }
+}
+
+/// Remove the source chain headers made outside of `since` and `until`
+/// from the JSON returned by [`AdminRequest::DumpState`] and add a line
+/// to its summary saying how many were removed.
+fn filter_state_dump(
+    state: &str,
+    since: Option<chrono::DateTime<chrono::Utc>>,
+    until: Option<chrono::DateTime<chrono::Utc>>,
+) -> anyhow::Result<String> {
+    use std::fmt::Write;
+    let (mut dump, mut summary): (JsonDump, String) = serde_json::from_str(state)?;
+    let since = since.map(Timestamp::from);
+    let until = until.map(Timestamp::from);
+    let elements = &mut dump.source_chain_dump.elements;
+    let before = elements.len();
+    elements.retain(|e| match e {
+        Some(e) => in_time_window(e.header.timestamp(), since, until),
+        // Keep missing elements so they are still reported.
+        None => true,
+    });
+    let filtered = before - elements.len();
+    writeln!(
+        summary,
+        "Headers outside the time window filtered out: {}",
+        filtered
+    )?;
+    Ok(serde_json::to_string(&(dump, summary))?)
+}
+
+/// Is `t` at or after `since` and at or before `until`.
+/// A missing bound doesn't limit that side of the window.
+fn in_time_window(t: Timestamp, since: Option<Timestamp>, until: Option<Timestamp>) -> bool {
+    since.map_or(true, |since| t >= since) && until.map_or(true, |until| t <= until)
+}
+
+/// Render the JSON returned by [`AdminRequest::DumpState`] as indented JSON
+/// that is easier to read.
+/// The source chain is listed in sequence order with each header's entry type
+/// labelled and all hashes shown in the same Base64 form used in logs.
+pub fn pretty_state_dump(state: &str) -> anyhow::Result<String> {
+    let (dump, summary): (JsonDump, String) = serde_json::from_str(state)?;
+    let JsonDump {
+        peer_dump,
+        source_chain_dump,
+        integration_dump,
+        queue_dump,
+    } = dump;
+    let missing_elements = source_chain_dump
+        .elements
+        .iter()
+        .filter(|e| e.is_none())
+        .count();
+    let mut elements: Vec<_> = source_chain_dump.elements.into_iter().flatten().collect();
+    elements.sort_by_key(|e| e.header.header_seq());
+    let source_chain: Vec<_> = elements
+        .into_iter()
+        .map(|e| {
+            serde_json::json!({
+                "seq": e.header.header_seq(),
+                "header_type": format!("{:?}", e.header.header_type()),
+                "header_address": e.header_address.to_string(),
+                "prev_header": e.header.prev_header().map(|h| h.to_string()),
+                "author": e.header.author().to_string(),
+                "timestamp": e.header.timestamp().to_string(),
+                "entry_type": e.header.entry_type().map(entry_type_label),
+                "entry_hash": e.header.entry_hash().map(|h| h.to_string()),
+                "has_entry": e.entry.is_some(),
+            })
+        })
+        .collect();
+    let peers: Vec<_> = peer_dump
+        .peers
+        .iter()
+        .map(|p| display_kitsune_agent(&p.kitsune_agent))
+        .collect();
+    let queues = queue_dump.map(|q| {
+        let validation_limbo: Vec<_> = q
+            .validation_limbo
+            .iter()
+            .map(|(hash, v)| {
+                serde_json::json!({
+                    "op_hash": hash.to_string(),
+                    "op": format!("{:?}", v.op),
+                    "status": format!("{:?}", v.status),
+                    "num_tries": v.num_tries,
+                })
+            })
+            .collect();
+        let integration_limbo: Vec<_> = q
+            .integration_limbo
+            .iter()
+            .map(|(hash, v)| {
+                serde_json::json!({
+                    "op_hash": hash.to_string(),
+                    "op": format!("{:?}", v.op),
+                    "validation_status": format!("{:?}", v.validation_status),
+                })
+            })
+            .collect();
+        let integrated: Vec<_> = q
+            .integrated
+            .iter()
+            .map(|(hash, v)| {
+                serde_json::json!({
+                    "op_hash": hash.to_string(),
+                    "op": format!("{:?}", v.op),
+                    "validation_status": format!("{:?}", v.validation_status),
+                    "when_integrated": v.when_integrated.to_string(),
+                })
+            })
+            .collect();
+        serde_json::json!({
+            "validation_limbo": validation_limbo,
+            "integration_limbo": integration_limbo,
+            "integrated": integrated,
+        })
+    });
+    let out = serde_json::json!({
+        "summary": summary.lines().collect::<Vec<_>>(),
+        "source_chain": source_chain,
+        "missing_elements": missing_elements,
+        "published_ops_count": source_chain_dump.published_ops_count,
+        "integration": integration_dump,
+        "queues": queues,
+        "this_agent": peer_dump.this_agent.map(|(a, _)| a.to_string()),
+        "this_dna": peer_dump.this_dna.map(|(d, _)| d.to_string()),
+        "peers": peers,
+    });
+    Ok(serde_json::to_string_pretty(&out)?)
+}
+
+/// Render a kitsune agent as the [`AgentPubKey`] it was made from,
+/// falling back to the debug form if it isn't a valid hash.
+fn display_kitsune_agent(agent: &kitsune_p2p::KitsuneAgent) -> String {
+    if agent.0.len() == HOLO_HASH_UNTYPED_LEN {
+        AgentPubKey::from_raw_36(agent.0.clone()).to_string()
+    } else {
+        format!("{:?}", agent)
+    }
+}
+
+fn entry_type_label(entry_type: &EntryType) -> String {
+    match entry_type {
+        EntryType::AgentPubKey => "AgentPubKey".to_string(),
+        EntryType::App(app) => format!(
+            "App(zome_id: {}, entry_def_index: {}, {:?})",
+            app.zome_id().index(),
+            app.id().index(),
+            app.visibility()
+        ),
+        EntryType::CapClaim => "CapClaim".to_string(),
+        EntryType::CapGrant => "CapGrant".to_string(),
+    }
+}
+
+/// Calls [`AdminRequest::ListCellIds`] and then [`AdminRequest::DumpState`]
+/// for each cell, concatenating the dumps with a header line per cell.
+/// Cells are sorted by id so the output can be diffed across runs.
+/// If a cell fails to dump the error is written in place of its state
+/// and the remaining cells are still dumped.
+pub async fn dump_full_state(cmd: &mut CmdRunner, args: DumpFullState) -> anyhow::Result<String> {
+    use std::fmt::Write;
+    let mut out = String::new();
+    for (i, cell_id, state) in dump_all_states(cmd, args).await? {
+        writeln!(out, "### CELL {}: {} ###", i, cell_id)?;
+        match state {
+            Ok(state) => writeln!(out, "{}", state)?,
+            Err(e) => writeln!(out, "Failed to dump state: {:?}", e)?,
+        }
+    }
+    Ok(out)
+}
+
+/// Dump the state of every cell (or just the one at `cell_index`)
+/// returning each cell's index, id and dump result.
+/// Cells are sorted by id so the indices are stable across runs.
+pub async fn dump_all_states(
+    cmd: &mut CmdRunner,
+    args: DumpFullState,
+) -> anyhow::Result<Vec<(usize, CellId, anyhow::Result<String>)>> {
+    let mut cells = list_cell_ids(cmd).await?;
+    cells.sort_by_cached_key(|c| c.to_string());
+    let cells: Vec<_> = match args.cell_index {
+        Some(i) => {
+            let cell = cells.get(i).cloned().ok_or_else(|| {
+                anyhow!(
+                    "Cell index {} is out of range, there are {} cells",
+                    i,
+                    cells.len()
+                )
+            })?;
+            vec![(i, cell)]
+        }
+        None => cells.into_iter().enumerate().collect(),
+    };
+    let mut out = Vec::with_capacity(cells.len());
+    for (i, cell_id) in cells {
+        let state = dump_state(cmd, cell_id.clone().into()).await;
+        out.push((i, cell_id, state));
+    }
+    Ok(out)
 }
 
 /// Calls [`AdminRequest::AddAgentInfo`] with and adds the list of agent info.
-pub async fn add_agent_info(cmd: &mut CmdRunner, args: Vec<AgentInfoSigned>) -> anyhow::Result<()> {
+/// Returns whether each agent info was added in the same order.
+pub async fn add_agent_info(
+    cmd: &mut CmdRunner,
+    args: Vec<AgentInfoSigned>,
+) -> anyhow::Result<Vec<AgentInfoAddResult>> {
     let resp = cmd
         .command(AdminRequest::AddAgentInfo { agent_infos: args })
         .await?;
-    ensure!(
-        matches!(resp, AdminResponse::AgentInfoAdded),
-        "Failed to add agent info, got: {:?}",
-        resp
-    );
-    Ok(())
+    Ok(expect_match!(resp => AdminResponse::AgentInfoAdded, "Failed to add agent info"))
+}
+
+/// The outcome of [`add_agents`].
+#[derive(Debug, Default, Serialize)]
+pub struct AddAgentsSummary {
+    /// How many agent info were added.
+    pub added: usize,
+    /// How many agent info were skipped because
+    /// they were already in the peer store.
+    pub duplicates: usize,
+    /// The agent info the conductor rejected.
+    pub rejected: Vec<RejectedAgentInfo>,
+}
+
+/// An agent info the conductor would not add to its peer store.
+#[derive(Debug, Serialize)]
+pub struct RejectedAgentInfo {
+    /// The agent the info is for if it could be read.
+    pub agent: Option<String>,
+    /// The space the info is for if it could be read.
+    pub space: Option<String>,
+    /// Why it was rejected.
+    pub reason: AgentInfoRejection,
+}
+
+impl RejectedAgentInfo {
+    fn new(info: &AgentInfoSigned, reason: AgentInfoRejection) -> Self {
+        let info: Option<kitsune_p2p::agent_store::AgentInfo> = info.try_into().ok();
+        Self {
+            agent: info.as_ref().map(|i| format!("{:?}", i.as_agent_ref())),
+            space: info.as_ref().map(|i| format!("{:?}", i.as_space_ref())),
+            reason,
+        }
+    }
 }
 
+/// Reads the agent info from the [`AddAgents`] paths and calls [`add_agent_info`]
+/// with any that are not already in this conductor's peer store.
+/// Invalid agent info is reported in the summary and
+/// doesn't stop the rest from being added.
+pub async fn add_agents(cmd: &mut CmdRunner, args: AddAgents) -> anyhow::Result<AddAgentsSummary> {
+    let mut paths = args.paths;
+    if paths.is_empty() {
+        paths.push(PathBuf::from("-"));
+    }
+    let mut agent_infos = Vec::new();
+    for path in paths {
+        let s = if path == Path::new("-") {
+            use std::io::Read;
+            let mut s = String::new();
+            std::io::stdin().read_to_string(&mut s)?;
+            s
+        } else {
+            std::fs::read_to_string(&path)?
+        };
+        agent_infos.extend(parse_agent_infos(&s)?);
+    }
+
+    let mut existing: BTreeSet<_> = request_agent_info(
+        cmd,
+        ListAgents {
+            agent_key: None,
+            dna: None,
+            cell: None,
+            expires_within: None,
+            expired: false,
+            all_signatures: true,
+        },
+    )
+    .await?
+    .into_iter()
+    .collect();
+    let total = agent_infos.len();
+    let new_infos: Vec<_> = agent_infos
+        .into_iter()
+        .filter(|info| existing.insert(info.clone()))
+        .collect();
+    let mut summary = AddAgentsSummary {
+        duplicates: total - new_infos.len(),
+        ..Default::default()
+    };
+    if new_infos.is_empty() {
+        return Ok(summary);
+    }
+    let results = add_agent_info(cmd, new_infos.clone()).await?;
+    for (info, result) in new_infos.iter().zip(results) {
+        match result {
+            AgentInfoAddResult::Accepted => summary.added += 1,
+            AgentInfoAddResult::Rejected(reason) => {
+                summary.rejected.push(RejectedAgentInfo::new(info, reason))
+            }
+        }
+    }
+    Ok(summary)
+}
+
+/// Parse agent info from either a JSON array or
+/// newline delimited JSON.
+fn parse_agent_infos(s: &str) -> anyhow::Result<Vec<AgentInfoSigned>> {
+    if s.trim_start().starts_with('[') {
+        Ok(serde_json::from_str(s)?)
+    } else {
+        s.lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| Ok(serde_json::from_str(line)?))
+            .collect()
+    }
+}
+
+/// How many agent info to request at a time.
+const AGENT_INFO_PAGE_SIZE: u32 = 100;
+
 /// Calls [`AdminRequest::RequestAgentInfo`] and pretty prints the agent info on this conductor.
+/// The agent info is requested a page at a time so a large
+/// peer store doesn't need to fit in a single response.
 pub async fn request_agent_info(
     cmd: &mut CmdRunner,
     args: ListAgents,
 ) -> anyhow::Result<Vec<AgentInfoSigned>> {
-    let resp = cmd
-        .command(AdminRequest::RequestAgentInfo {
-            cell_id: args.into(),
+    let cell_id: Option<CellId> = args.into();
+    let mut agent_infos = Vec::new();
+    let mut cursor = None;
+    loop {
+        let resp = cmd
+            .command(AdminRequest::RequestAgentInfo {
+                cell_id: cell_id.clone(),
+                limit: Some(AGENT_INFO_PAGE_SIZE),
+                cursor,
+            })
+            .await?;
+        let page = expect_match!(resp => AdminResponse::AgentInfoRequested, "Failed to request agent info");
+        agent_infos.extend(page.agent_infos);
+        cursor = page.next_cursor;
+        if cursor.is_none() {
+            break;
+        }
+    }
+    Ok(agent_infos)
+}
+
+/// An agent info as written by [`export_agents`].
+/// The hashes are only there to make the file easy to search.
+/// They are ignored on import because the signed agent info
+/// is the source of truth.
+#[derive(Debug, Serialize)]
+struct ExportedAgentInfo<'a> {
+    space_hash: String,
+    agent_hash: String,
+    #[serde(flatten)]
+    info: &'a AgentInfoSigned,
+}
+
+/// Calls [`AdminRequest::RequestAgentInfo`] and serializes the agent info
+/// on this conductor with one agent info per line.
+/// The output can be read by [`add_agents`].
+pub async fn export_agents(cmd: &mut CmdRunner, args: ExportAgents) -> anyhow::Result<String> {
+    let agent_infos = request_agent_info(
+        cmd,
+        ListAgents {
+            agent_key: args.agent_key,
+            dna: args.dna,
+            cell: args.cell,
+            expires_within: None,
+            expired: false,
+            all_signatures: true,
+        },
+    )
+    .await?;
+    let mut agent_infos = agent_infos
+        .into_iter()
+        .map(|info| {
+            let kit_info: kitsune_p2p::agent_store::AgentInfo = (&info)
+                .try_into()
+                .map_err(|e| anyhow!("Failed to read agent info: {:?}", e))?;
+            Ok((info, kit_info))
         })
-        .await?;
-    Ok(expect_match!(resp => AdminResponse::AgentInfoRequested, "Failed to request agent info"))
+        .collect::<anyhow::Result<Vec<_>>>()?;
+    sort_agent_infos(&mut agent_infos, true);
+    serialize_agent_infos(&agent_infos)
+}
+
+/// Serialize agent info with one [`ExportedAgentInfo`] per line.
+fn serialize_agent_infos(
+    agent_infos: &[(AgentInfoSigned, kitsune_p2p::agent_store::AgentInfo)],
+) -> anyhow::Result<String> {
+    let mut out = String::new();
+    for (info, kit_info) in agent_infos {
+        let space = &kit_info.as_space_ref().0;
+        let agent = &kit_info.as_agent_ref().0;
+        ensure!(
+            space.len() == HOLO_HASH_UNTYPED_LEN && agent.len() == HOLO_HASH_UNTYPED_LEN,
+            "Agent info has an invalid space or agent: {:?}",
+            kit_info
+        );
+        let exported = ExportedAgentInfo {
+            space_hash: DnaHash::from_raw_36(space.clone()).to_string(),
+            agent_hash: AgentPubKey::from_raw_36(agent.clone()).to_string(),
+            info,
+        };
+        out.push_str(&serde_json::to_string(&exported)?);
+        out.push('\n');
+    }
+    Ok(out)
+}
+
+/// Why an agent info from the peer store couldn't be listed
+/// against the cells on this conductor.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum AgentInfoLookupError {
+    /// The signed info couldn't be decoded.
+    Unreadable(String),
+    /// The agent isn't one of this conductor's cells.
+    UnknownAgent,
+    /// The space isn't the dna of one of this conductor's cells.
+    UnknownSpace,
+}
+
+impl std::fmt::Display for AgentInfoLookupError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AgentInfoLookupError::Unreadable(e) => write!(f, "unreadable agent info: {}", e),
+            AgentInfoLookupError::UnknownAgent => write!(f, "unknown agent"),
+            AgentInfoLookupError::UnknownSpace => write!(f, "unknown space"),
+        }
+    }
+}
+
+impl std::error::Error for AgentInfoLookupError {}
+
+/// Find the local hash for a kitsune agent or space
+/// in the pairs of `(local, kitsune)` hashes.
+fn find_local<'a, L, K: PartialEq>(
+    local: &'a [(L, K)],
+    kitsune: &K,
+    missing: AgentInfoLookupError,
+) -> Result<&'a L, AgentInfoLookupError> {
+    local
+        .iter()
+        .find(|(_, k)| k == kitsune)
+        .map(|(l, _)| l)
+        .ok_or(missing)
+}
+
+/// Decode a signed agent info and check its times can be listed.
+fn read_agent_info(
+    info: &AgentInfoSigned,
+) -> Result<kitsune_p2p::agent_store::AgentInfo, AgentInfoLookupError> {
+    let kit_info: kitsune_p2p::agent_store::AgentInfo = info
+        .try_into()
+        .map_err(|e| AgentInfoLookupError::Unreadable(format!("{:?}", e)))?;
+    agent_info_times(&kit_info)?;
+    Ok(kit_info)
+}
+
+/// When the agent info was signed and when it expires.
+/// Fails if either time is out of range.
+fn agent_info_times(
+    info: &kitsune_p2p::agent_store::AgentInfo,
+) -> Result<(chrono::DateTime<chrono::Utc>, chrono::DateTime<chrono::Utc>), AgentInfoLookupError> {
+    use chrono::{DateTime, Duration, NaiveDateTime, Utc};
+    let out_of_range = || {
+        AgentInfoLookupError::Unreadable(format!(
+            "signed at {}ms expiring after {}ms is out of range",
+            info.signed_at_ms(),
+            info.expires_after_ms()
+        ))
+    };
+    let signed_at_ms = i64::try_from(info.signed_at_ms()).map_err(|_| out_of_range())?;
+    let expires_after_ms = i64::try_from(info.expires_after_ms()).map_err(|_| out_of_range())?;
+    let dt = NaiveDateTime::from_timestamp_opt(
+        signed_at_ms / 1000,
+        (signed_at_ms % 1000) as u32 * 1_000_000,
+    )
+    .ok_or_else(out_of_range)?;
+    let dt = DateTime::<Utc>::from_utc(dt, Utc);
+    let exp = dt
+        .checked_add_signed(Duration::milliseconds(expires_after_ms))
+        .ok_or_else(out_of_range)?;
+    Ok((dt, exp))
+}
+
+/// Sort agent info by space and then by when it was signed.
+/// Unless `all_signatures` is set only the most recently
+/// signed info for each agent in each space is kept.
+fn sort_agent_infos<T>(
+    agent_infos: &mut Vec<(T, kitsune_p2p::agent_store::AgentInfo)>,
+    all_signatures: bool,
+) {
+    if !all_signatures {
+        // Put the most recent info for each agent first so dedup keeps it.
+        agent_infos.sort_by(|(_, a), (_, b)| {
+            (
+                a.as_space_ref(),
+                a.as_agent_ref(),
+                std::cmp::Reverse(a.signed_at_ms()),
+            )
+                .cmp(&(
+                    b.as_space_ref(),
+                    b.as_agent_ref(),
+                    std::cmp::Reverse(b.signed_at_ms()),
+                ))
+        });
+        agent_infos.dedup_by(|(_, a), (_, b)| {
+            a.as_space_ref() == b.as_space_ref() && a.as_agent_ref() == b.as_agent_ref()
+        });
+    }
+    agent_infos.sort_by(|(_, a), (_, b)| {
+        (a.as_space_ref(), a.signed_at_ms()).cmp(&(b.as_space_ref(), b.signed_at_ms()))
+    });
+}
+
+/// Sort and dedupe agent info with [`sort_agent_infos`] and then
+/// keep the infos that pass the expiry filters.
+/// Deduping first means an agent's stale info can't be
+/// listed in place of the latest one.
+fn select_agent_infos<T>(
+    agent_infos: &mut Vec<(T, kitsune_p2p::agent_store::AgentInfo)>,
+    all_signatures: bool,
+    now: chrono::DateTime<chrono::Utc>,
+    expires_within: Option<i64>,
+    expired: bool,
+) {
+    sort_agent_infos(agent_infos, all_signatures);
+    // Infos with out of range times were skipped when they were read.
+    agent_infos.retain(|(_, info)| {
+        agent_info_times(info).map_or(false, |(_, exp)| {
+            expiry_matches(exp, now, expires_within, expired)
+        })
+    });
+}
+
+/// Should agent info expiring at `exp` be listed.
+/// With no filters everything is listed.
+fn expiry_matches(
+    exp: chrono::DateTime<chrono::Utc>,
+    now: chrono::DateTime<chrono::Utc>,
+    expires_within: Option<i64>,
+    expired: bool,
+) -> bool {
+    if expired {
+        return exp <= now;
+    }
+    match expires_within {
+        Some(mins) => exp - now <= chrono::Duration::minutes(mins),
+        None => true,
+    }
 }
 
 fn parse_agent_key(arg: &str) -> anyhow::Result<AgentPubKey> {
@@ -627,16 +2963,127 @@ fn parse_dna_hash(arg: &str) -> anyhow::Result<DnaHash> {
     DnaHash::try_from(arg).map_err(|e| anyhow::anyhow!("{:?}", e))
 }
 
+fn parse_rfc3339(arg: &str) -> anyhow::Result<chrono::DateTime<chrono::Utc>> {
+    Ok(chrono::DateTime::parse_from_rfc3339(arg)
+        .map_err(|e| anyhow!("Expected an RFC 3339 time like 2021-03-01T00:00:00Z: {}", e))?
+        .with_timezone(&chrono::Utc))
+}
+
+/// Parse a cell id in the form `<dna>:<agent>`.
+fn parse_cell_id(arg: &str) -> anyhow::Result<CellId> {
+    let mut split = arg.splitn(2, ':');
+    match (split.next(), split.next()) {
+        (Some(dna), Some(agent)) if !dna.is_empty() && !agent.is_empty() => Ok(CellId::new(
+            parse_dna_hash(dna.trim())?,
+            parse_agent_key(agent.trim())?,
+        )),
+        _ => bail!("Cell id must be in the form `<dna>:<agent>`, got: {}", arg),
+    }
+}
+
+/// The cell id from either the `--cell` argument
+/// or the separate dna and agent arguments.
+fn cell_id_from_args(
+    cell: Option<CellId>,
+    dna: Option<DnaHash>,
+    agent_key: Option<AgentPubKey>,
+) -> Option<CellId> {
+    cell.or_else(|| Some(CellId::new(dna?, agent_key?)))
+}
+
+fn parse_event_topic(arg: &str) -> anyhow::Result<AdminEventTopic> {
+    match arg {
+        "cells" => Ok(AdminEventTopic::Cells),
+        "apps" => Ok(AdminEventTopic::Apps),
+        "reindex" => Ok(AdminEventTopic::Reindex),
+        _ => bail!(
+            "Unknown event topic {}, expected `cells`, `apps` or `reindex`",
+            arg
+        ),
+    }
+}
+
+fn parse_status_filter(arg: &str) -> anyhow::Result<AppStatusFilter> {
+    match arg {
+        "active" => Ok(AppStatusFilter::Active),
+        "inactive" => Ok(AppStatusFilter::Inactive),
+        _ => bail!(
+            "Unknown app status {}, expected `active` or `inactive`",
+            arg
+        ),
+    }
+}
+
+fn parse_nick(arg: &str) -> anyhow::Result<(usize, CellNick)> {
+    let mut split = arg.splitn(2, '=');
+    match (split.next(), split.next()) {
+        (Some(index), Some(nick)) if !nick.is_empty() => Ok((index.parse()?, nick.to_string())),
+        _ => bail!("Nick must be in the form `<index>=<nick>`, got: {}", arg),
+    }
+}
+
+fn parse_expect_hash(arg: &str) -> anyhow::Result<(CellNick, DnaHash)> {
+    let mut split = arg.splitn(2, '=');
+    match (split.next(), split.next()) {
+        (Some(slot_id), Some(hash)) if !slot_id.is_empty() && !hash.is_empty() => {
+            Ok((slot_id.to_string(), parse_dna_hash(hash)?))
+        }
+        _ => bail!(
+            "Expected hash must be in the form `<slot-id>=<dna-hash>`, got: {}",
+            arg
+        ),
+    }
+}
+
+fn parse_membrane_proof(arg: &str) -> anyhow::Result<(CellNick, PathBuf)> {
+    let mut split = arg.splitn(2, '=');
+    match (split.next(), split.next()) {
+        (Some(nick), Some(path)) if !nick.is_empty() && !path.is_empty() => {
+            Ok((nick.to_string(), PathBuf::from(path)))
+        }
+        _ => bail!(
+            "Membrane proof must be in the form `<cell-nick>=<path>`, got: {}",
+            arg
+        ),
+    }
+}
+
 impl From<CellId> for DumpState {
     fn from(cell_id: CellId) -> Self {
         let (dna, agent_key) = cell_id.into_dna_and_agent();
-        Self { agent_key, dna }
+        Self {
+            agent_key: Some(agent_key),
+            dna: Some(dna),
+            cell: None,
+            pretty: false,
+            include_queues: false,
+            max_queue_entries: 0,
+            since: None,
+            until: None,
+        }
     }
 }
 
-impl From<DumpState> for CellId {
-    fn from(ds: DumpState) -> Self {
-        CellId::new(ds.dna, ds.agent_key)
+impl DumpState {
+    /// The cell id to dump.
+    pub fn cell_id(&self) -> anyhow::Result<CellId> {
+        cell_id_from_args(self.cell.clone(), self.dna.clone(), self.agent_key.clone())
+            .ok_or_else(|| anyhow!("dump-state needs `<dna> <agent>` or `--cell <dna>:<agent>`"))
+    }
+}
+
+impl TryFrom<DumpState> for CellId {
+    type Error = anyhow::Error;
+    fn try_from(ds: DumpState) -> anyhow::Result<Self> {
+        ds.cell_id()
+    }
+}
+
+impl DeleteClone {
+    /// The cell id of the clone to delete.
+    pub fn cell_id(&self) -> anyhow::Result<CellId> {
+        cell_id_from_args(self.cell.clone(), self.dna.clone(), self.agent_key.clone())
+            .ok_or_else(|| anyhow!("delete-clone needs `<dna> <agent>` or `--cell <dna>:<agent>`"))
     }
 }
 
@@ -645,8 +3092,677 @@ impl From<ListAgents> for Option<CellId> {
         let ListAgents {
             agent_key: a,
             dna: d,
+            cell: c,
+            ..
         } = la;
-        d.and_then(|d| a.map(|a| (d, a)))
-            .map(|(d, a)| CellId::new(d, a))
+        cell_id_from_args(c, d, a)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use holochain_conductor_api::ExternalApiWireError;
+    use holochain_conductor_api::SpaceStats;
+    use holochain_types::prelude::Header;
+    use holochain_types::prelude::HeaderHash;
+    use holochain_types::prelude::InitZomesComplete;
+    use holochain_types::prelude::Signature;
+
+    #[test]
+    fn network_stats_report_each_space() {
+        let dna_hash = DnaHash::from_raw_32(vec![1; 32]);
+        let stats = NetworkStats {
+            transport: Default::default(),
+            transport_addresses: vec![url2::Url2::parse("kitsune-quic://127.0.0.1:5000")],
+            bootstrap_reachable: Some(false),
+            spaces: vec![SpaceStats {
+                dna_hash: dna_hash.clone(),
+                local_agents: 1,
+                peers: 3,
+            }],
+        };
+        let report = pretty_network_stats(&stats);
+        assert!(report.contains("  kitsune-quic://127.0.0.1:5000\n"));
+        assert!(report.contains("Bootstrap service: unreachable\n"));
+        assert!(report.ends_with(&format!("\n  {}: 1 local agents, 3 peers", dna_hash)));
+        assert!(report.contains("Requests: none answered in the last 60s\n"));
+
+        let mut stats = stats;
+        stats.transport.recent_requests = 2;
+        stats.transport.recent_request_rtt_us = Some(1500);
+        assert!(pretty_network_stats(&stats)
+            .contains("Requests: 2 answered in the last 60s, mean round trip 1.5ms\n"));
+    }
+
+    #[test]
+    fn expected_hashes_are_checked_per_slot() {
+        let hash = DnaHash::from_raw_32(vec![1; 32]);
+        let other_hash = DnaHash::from_raw_32(vec![2; 32]);
+        let arg = format!("chat={}", hash);
+        assert_eq!(
+            parse_expect_hash(&arg).unwrap(),
+            ("chat".to_string(), hash.clone())
+        );
+        assert!(parse_expect_hash("chat").is_err());
+        assert!(parse_expect_hash(&format!("={}", hash)).is_err());
+        assert!(parse_expect_hash("chat=not-a-hash").is_err());
+
+        let hashes: HashMap<_, _> = vec![
+            ("chat".to_string(), hash.clone()),
+            ("profiles".to_string(), other_hash.clone()),
+        ]
+        .into_iter()
+        .collect();
+        let pin = |slot_id: &str, hash: &DnaHash| (slot_id.to_string(), hash.clone());
+        assert!(check_expected_hashes(&[pin("chat", &hash)], &hashes).is_ok());
+        // Every mismatch is reported.
+        let err =
+            check_expected_hashes(&[pin("chat", &other_hash), pin("profiles", &hash)], &hashes)
+                .unwrap_err()
+                .to_string();
+        assert!(err.contains(&format!("chat: expected {} but found {}", other_hash, hash)));
+        assert!(err.contains(&format!(
+            "profiles: expected {} but found {}",
+            hash, other_hash
+        )));
+        assert!(check_expected_hashes(&[pin("missing", &hash)], &hashes).is_err());
+        assert!(check_expected_hashes(&[pin("chat", &hash), pin("chat", &hash)], &hashes).is_err());
+    }
+
+    #[test]
+    fn span_fields_match_the_command_line() {
+        for name in &["list-dnas", "network-stats", "ping"] {
+            let call = AdminRequestCli::from_iter_safe(&["call", name]).unwrap();
+            assert_eq!(call.name(), *name);
+            assert_eq!(call.app_id(), None);
+        }
+        let call = AdminRequestCli::from_iter_safe(&["call", "activate-app", "my-app"]).unwrap();
+        assert_eq!(call.name(), "activate-app");
+        assert_eq!(call.app_id(), Some("my-app"));
+    }
+
+    #[test]
+    fn dry_run_cannot_set_properties() {
+        let hash = DnaHash::from_raw_32(vec![1; 32]).to_string();
+        let args = InstallApp::from_iter_safe(&["install-app", "--dry-run", &hash]).unwrap();
+        assert!(args.dry_run);
+        assert!(InstallApp::from_iter_safe(&[
+            "install-app",
+            "--dry-run",
+            "--properties",
+            "properties.yaml",
+            &hash,
+        ])
+        .is_err());
+    }
+
+    #[test]
+    fn cell_nicks_fall_back_to_default() {
+        let nicks = cell_nicks("app", 3, vec![(1, "chat".to_string())]).unwrap();
+        assert_eq!(nicks, vec!["app-0", "chat", "app-2"]);
+    }
+
+    #[test]
+    fn cell_nicks_reject_duplicates() {
+        assert!(cell_nicks("app", 2, vec![(0, "app-1".to_string())]).is_err());
+        assert!(cell_nicks(
+            "app",
+            2,
+            vec![(0, "chat".to_string()), (1, "chat".to_string())]
+        )
+        .is_err());
+        assert!(cell_nicks(
+            "app",
+            2,
+            vec![(0, "chat".to_string()), (0, "profiles".to_string())]
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn cell_nicks_reject_out_of_range() {
+        assert!(cell_nicks("app", 1, vec![(1, "chat".to_string())]).is_err());
+    }
+
+    #[test]
+    fn expiry_filters() {
+        let now = chrono::Utc::now();
+        let soon = now + chrono::Duration::minutes(5);
+        let later = now + chrono::Duration::minutes(60);
+        let past = now - chrono::Duration::minutes(5);
+
+        for exp in &[soon, later, past] {
+            assert!(expiry_matches(*exp, now, None, false));
+        }
+
+        assert!(expiry_matches(soon, now, Some(10), false));
+        assert!(expiry_matches(past, now, Some(10), false));
+        assert!(!expiry_matches(later, now, Some(10), false));
+
+        assert!(expiry_matches(past, now, None, true));
+        assert!(!expiry_matches(soon, now, None, true));
+        assert!(!expiry_matches(later, now, None, true));
+    }
+
+    #[test]
+    fn agent_infos_sorted_and_deduped() {
+        use kitsune_p2p::agent_store::AgentInfo;
+        use kitsune_p2p::KitsuneAgent;
+        use kitsune_p2p::KitsuneSpace;
+        let info = |space: u8, agent: u8, signed_at_ms: u64| {
+            (
+                (space, agent, signed_at_ms),
+                AgentInfo::new(
+                    KitsuneSpace(vec![space; HOLO_HASH_UNTYPED_LEN]),
+                    KitsuneAgent(vec![agent; HOLO_HASH_UNTYPED_LEN]),
+                    Vec::new(),
+                    signed_at_ms,
+                    1000,
+                ),
+            )
+        };
+        let infos = vec![
+            info(2, 1, 10),
+            info(1, 1, 30),
+            info(1, 2, 20),
+            info(1, 1, 40),
+            info(2, 1, 5),
+        ];
+
+        let mut all = infos.clone();
+        sort_agent_infos(&mut all, true);
+        let all: Vec<_> = all.into_iter().map(|(k, _)| k).collect();
+        assert_eq!(
+            all,
+            vec![(1, 2, 20), (1, 1, 30), (1, 1, 40), (2, 1, 5), (2, 1, 10)]
+        );
+
+        let mut latest = infos;
+        sort_agent_infos(&mut latest, false);
+        let latest: Vec<_> = latest.into_iter().map(|(k, _)| k).collect();
+        assert_eq!(latest, vec![(1, 2, 20), (1, 1, 40), (2, 1, 10)]);
+    }
+
+    #[test]
+    fn stale_agent_infos_are_deduped_before_expiry_filter() {
+        use kitsune_p2p::agent_store::AgentInfo;
+        use kitsune_p2p::KitsuneAgent;
+        use kitsune_p2p::KitsuneSpace;
+        let info = |agent: u8, signed_at_ms: u64| {
+            (
+                (agent, signed_at_ms),
+                AgentInfo::new(
+                    KitsuneSpace(vec![1; HOLO_HASH_UNTYPED_LEN]),
+                    KitsuneAgent(vec![agent; HOLO_HASH_UNTYPED_LEN]),
+                    Vec::new(),
+                    signed_at_ms,
+                    1000,
+                ),
+            )
+        };
+        // Agent 1 has an expired info and a newer one that is still valid.
+        // Agent 2 only has an expired info.
+        let infos = vec![info(1, 1000), info(1, 5000), info(2, 2000)];
+        let now = chrono::DateTime::<chrono::Utc>::from_utc(
+            chrono::NaiveDateTime::from_timestamp(5, 500_000_000),
+            chrono::Utc,
+        );
+
+        let mut expired = infos.clone();
+        select_agent_infos(&mut expired, false, now, None, true);
+        let expired: Vec<_> = expired.into_iter().map(|(k, _)| k).collect();
+        assert_eq!(expired, vec![(2, 2000)]);
+
+        let mut all_expired = infos.clone();
+        select_agent_infos(&mut all_expired, true, now, None, true);
+        let all_expired: Vec<_> = all_expired.into_iter().map(|(k, _)| k).collect();
+        assert_eq!(all_expired, vec![(1, 1000), (2, 2000)]);
+
+        let mut valid = infos;
+        select_agent_infos(&mut valid, false, now, Some(1), false);
+        let valid: Vec<_> = valid.into_iter().map(|(k, _)| k).collect();
+        assert_eq!(valid, vec![(2, 2000), (1, 5000)]);
+    }
+
+    #[test]
+    fn dump_headers_are_filtered_by_time() {
+        let since = parse_rfc3339("2021-03-01T00:00:00Z").unwrap();
+        let until = parse_rfc3339("2021-03-02T00:00:00+00:00").unwrap();
+        assert!(parse_rfc3339("yesterday").is_err());
+        let (since, until) = (Timestamp::from(since), Timestamp::from(until));
+        let before = Timestamp(since.0 - 1, 0);
+        let during = Timestamp(since.0 + 60, 0);
+        let after = Timestamp(until.0, 1);
+
+        assert!(in_time_window(during, Some(since), Some(until)));
+        assert!(in_time_window(since, Some(since), Some(until)));
+        assert!(in_time_window(until, Some(since), Some(until)));
+        assert!(!in_time_window(before, Some(since), Some(until)));
+        assert!(!in_time_window(after, Some(since), Some(until)));
+        assert!(in_time_window(before, None, Some(until)));
+        assert!(in_time_window(after, Some(since), None));
+    }
+
+    #[test]
+    fn kitsune_agents_display_as_agent_keys() {
+        use kitsune_p2p::KitsuneAgent;
+
+        let agent = AgentPubKey::from_raw_32(vec![7; 32]);
+        let kitsune_agent = KitsuneAgent(agent.get_raw_36().to_vec());
+        assert_eq!(display_kitsune_agent(&kitsune_agent), agent.to_string());
+
+        let short = KitsuneAgent(vec![7; 4]);
+        assert_eq!(display_kitsune_agent(&short), format!("{:?}", short));
+    }
+
+    #[test]
+    fn labelled_json_keeps_the_value() {
+        let line = labelled_json(4444, r#"{"app_id":"app","cells":[1,2]}"#);
+        let value: serde_json::Value = serde_json::from_str(&line).unwrap();
+        assert_eq!(
+            value,
+            serde_json::json!({
+                "admin_port": 4444,
+                "result": { "app_id": "app", "cells": [1, 2] },
+            })
+        );
+    }
+
+    #[test]
+    fn dump_state_is_filtered_by_time() {
+        let author = AgentPubKey::from_raw_32(vec![1; 32]);
+        let prev_header = HeaderHash::from_raw_32(vec![2; 32]);
+        let element = |secs| {
+            serde_json::json!({
+                "signature": Signature([0; 64]),
+                "header_address": prev_header,
+                "header": Header::InitZomesComplete(InitZomesComplete {
+                    author: author.clone(),
+                    timestamp: Timestamp(secs, 0),
+                    header_seq: 3,
+                    prev_header: prev_header.clone(),
+                }),
+                "entry": null,
+            })
+        };
+        let dump = serde_json::json!({
+            "peer_dump": {
+                "this_agent_info": null,
+                "this_dna": null,
+                "this_agent": null,
+                "peers": [],
+            },
+            "source_chain_dump": {
+                "elements": [element(100), null, element(200), element(300)],
+                "published_ops_count": 0,
+            },
+            "integration_dump": {
+                "validation_limbo": 0,
+                "integration_limbo": 0,
+                "integrated": 0,
+            },
+        });
+        let state = serde_json::to_string(&(dump, "Summary\n")).unwrap();
+        let at = |secs| {
+            chrono::DateTime::from_utc(chrono::NaiveDateTime::from_timestamp(secs, 0), chrono::Utc)
+        };
+
+        let filtered = filter_state_dump(&state, Some(at(150)), Some(at(250))).unwrap();
+        assert!(!filtered.contains('\n'));
+        let (dump, summary): (JsonDump, String) = serde_json::from_str(&filtered).unwrap();
+        let timestamps: Vec<_> = dump
+            .source_chain_dump
+            .elements
+            .iter()
+            .map(|e| e.as_ref().map(|e| e.header.timestamp().0))
+            .collect();
+        assert_eq!(timestamps, vec![None, Some(200)]);
+        assert_eq!(
+            summary,
+            "Summary\nHeaders outside the time window filtered out: 2\n"
+        );
+    }
+
+    #[test]
+    fn unknown_agents_and_spaces_are_not_found() {
+        let local = vec![("a", 1), ("b", 2)];
+        assert_eq!(
+            find_local(&local, &2, AgentInfoLookupError::UnknownAgent),
+            Ok(&"b")
+        );
+        let e = find_local(&local, &3, AgentInfoLookupError::UnknownSpace).unwrap_err();
+        assert_eq!(e, AgentInfoLookupError::UnknownSpace);
+        assert_eq!(e.to_string(), "unknown space");
+    }
+
+    #[test]
+    fn out_of_range_agent_info_times_are_unreadable() {
+        use kitsune_p2p::agent_store::AgentInfo;
+        use kitsune_p2p::KitsuneAgent;
+        use kitsune_p2p::KitsuneSpace;
+        let info = |signed_at_ms, expires_after_ms| {
+            AgentInfo::new(
+                KitsuneSpace(vec![1; HOLO_HASH_UNTYPED_LEN]),
+                KitsuneAgent(vec![1; HOLO_HASH_UNTYPED_LEN]),
+                Vec::new(),
+                signed_at_ms,
+                expires_after_ms,
+            )
+        };
+        let (dt, exp) = agent_info_times(&info(1500, 1000)).unwrap();
+        assert_eq!(dt.timestamp_millis(), 1500);
+        assert_eq!(exp.timestamp_millis(), 2500);
+
+        for bad in &[
+            info(i64::MAX as u64 + 1, 1000),
+            info(u64::MAX, 1000),
+            info(i64::MAX as u64, 1000),
+            info(0, u64::MAX),
+        ] {
+            let e = agent_info_times(bad).unwrap_err();
+            assert!(matches!(e, AgentInfoLookupError::Unreadable(_)));
+            assert!(e.to_string().starts_with("unreadable agent info"));
+        }
+    }
+
+    #[test]
+    fn exported_agent_infos_can_be_imported() {
+        use kitsune_p2p::agent_store::AgentInfo;
+        use kitsune_p2p::KitsuneAgent;
+        use kitsune_p2p::KitsuneSignature;
+        use kitsune_p2p::KitsuneSpace;
+        let infos: Vec<_> = (1..4)
+            .map(|i| {
+                let signed = AgentInfoSigned::try_new(
+                    KitsuneAgent(vec![i; HOLO_HASH_UNTYPED_LEN]),
+                    KitsuneSignature(vec![i; 64]),
+                    vec![i; 10],
+                )
+                .unwrap();
+                let info = AgentInfo::new(
+                    KitsuneSpace(vec![0; HOLO_HASH_UNTYPED_LEN]),
+                    KitsuneAgent(vec![i; HOLO_HASH_UNTYPED_LEN]),
+                    Vec::new(),
+                    0,
+                    1000,
+                );
+                (signed, info)
+            })
+            .collect();
+
+        let exported = serialize_agent_infos(&infos).unwrap();
+        assert_eq!(exported.lines().count(), 3);
+        let agent = AgentPubKey::from_raw_36(vec![1; HOLO_HASH_UNTYPED_LEN]).to_string();
+        assert!(exported.lines().next().unwrap().contains(&agent));
+
+        let imported = parse_agent_infos(&exported).unwrap();
+        let expected: Vec<_> = infos.into_iter().map(|(signed, _)| signed).collect();
+        assert_eq!(imported, expected);
+    }
+
+    #[test]
+    fn call_errors_distinguish_error_responses() {
+        let e = CallError::unexpected(
+            "Failed to list dnas",
+            "AdminResponse::DnasListed",
+            AdminResponse::Error(ExternalApiWireError::InternalError("boom".into())),
+        );
+        assert!(matches!(
+            e,
+            CallError::ConductorError {
+                error: ExternalApiWireError::InternalError(_),
+                ..
+            }
+        ));
+        let e = CallError::unexpected(
+            "Failed to list dnas",
+            "AdminResponse::DnasListed",
+            AdminResponse::Pong,
+        );
+        assert!(matches!(e, CallError::UnexpectedResponse { .. }));
+        let json = serde_json::to_value(&e).unwrap();
+        assert_eq!(json["kind"], "unexpected_response");
+        assert_eq!(json["data"]["expected"], "AdminResponse::DnasListed");
+    }
+
+    #[test]
+    fn ready_file_is_replaced() {
+        let dir = std::env::temp_dir().join(nanoid::nanoid!());
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("ready.json");
+        write_ready_file(&path, 8888, 9000).unwrap();
+        write_ready_file(&path, 8889, 9000).unwrap();
+        let ready: serde_json::Value =
+            serde_json::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+        assert_eq!(
+            ready,
+            serde_json::json!({"app_port": 8889, "admin_port": 9000})
+        );
+        assert!(!dir.join("ready.json.tmp").exists());
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn dna_summaries_are_filtered_by_name_and_uid() {
+        let summary = |n: u8, name: &str, uid: &str| DnaSummary {
+            hash: DnaHash::from_raw_32(vec![n; 32]),
+            name: name.to_string(),
+            uid: uid.to_string(),
+            zome_names: vec!["foo".into()],
+        };
+        let summaries = vec![
+            summary(1, "chat", "a"),
+            summary(2, "chat", "b"),
+            summary(3, "wiki", "a"),
+        ];
+        let filter = |args: &[&str]| {
+            let args = ListDnas::from_iter_safe(args).unwrap();
+            filter_dna_summaries(summaries.clone(), &args)
+        };
+        assert_eq!(filter(&["list-dnas"]), summaries);
+        assert_eq!(
+            filter(&["list-dnas", "--by-name", "chat"]),
+            summaries[..2].to_vec()
+        );
+        assert_eq!(
+            filter(&["list-dnas", "--by-uuid", "a", "--by-name", "chat"]),
+            summaries[..1].to_vec()
+        );
+        assert_eq!(filter(&["list-dnas", "--by-uid", "c"]), vec![]);
+    }
+
+    #[test]
+    fn cell_id_can_be_passed_combined_or_split() {
+        let dna = DnaHash::from_raw_32(vec![1; 32]);
+        let agent = AgentPubKey::from_raw_32(vec![2; 32]);
+        let cell_id = CellId::new(dna.clone(), agent.clone());
+        let (dna, agent) = (dna.to_string(), agent.to_string());
+        let combined = format!("{}:{}", dna, agent);
+        assert_eq!(parse_cell_id(&combined).unwrap(), cell_id);
+        assert!(parse_cell_id(&dna).is_err());
+        assert!(parse_cell_id(&format!("{}:", dna)).is_err());
+
+        let split = DumpState::from_iter_safe(&["dump-state", &dna, &agent]).unwrap();
+        assert_eq!(split.cell_id().unwrap(), cell_id);
+        let combined_arg = DumpState::from_iter_safe(&["dump-state", "--cell", &combined]).unwrap();
+        assert_eq!(combined_arg.cell_id().unwrap(), cell_id);
+        assert!(DumpState::from_iter_safe(&["dump-state"]).is_err());
+        assert!(
+            DumpState::from_iter_safe(&["dump-state", &dna, &agent, "--cell", &combined]).is_err()
+        );
+
+        let list = ListAgents::from_iter_safe(&["list-agents", "--cell", &combined]).unwrap();
+        assert_eq!(Option::<CellId>::from(list), Some(cell_id.clone()));
+
+        let trigger =
+            TriggerGossip::from_iter_safe(&["trigger-gossip", &combined, "--publish"]).unwrap();
+        assert_eq!(trigger.cell, cell_id);
+        assert!(trigger.publish);
+        assert!(TriggerGossip::from_iter_safe(&["trigger-gossip", &dna, &agent]).is_err());
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn slow_call_times_out() {
+        use crate::RequestTimeout;
+        use futures::StreamExt;
+        use holochain_websocket::WebsocketConfig;
+        use holochain_websocket::WebsocketListener;
+
+        // A conductor that accepts requests but never responds.
+        let mut server = WebsocketListener::bind(
+            url2::Url2::parse("ws://127.0.0.1:0"),
+            std::sync::Arc::new(WebsocketConfig::default()),
+        )
+        .await
+        .unwrap();
+        let port = server.local_addr().port().unwrap();
+        tokio::task::spawn(async move {
+            while let Some(Ok((_send, mut recv))) = server.next().await {
+                tokio::task::spawn(async move {
+                    // Hold on to the requests so they are never answered.
+                    let mut requests = Vec::new();
+                    while let Some(request) = recv.next().await {
+                        requests.push(request);
+                    }
+                });
+            }
+        });
+
+        let call = Call::from_iter_safe(&["call", "--request-timeout", "50", "list-dnas"]).unwrap();
+        let timeout = std::time::Duration::from_millis(call.request_timeout);
+        let mut cmd = CmdRunner::try_new(port).await.unwrap();
+
+        let e = cmd
+            .command_with_timeout(AdminRequest::ListDnas, timeout)
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            e.downcast_ref::<CallError>(),
+            Some(CallError::Timeout(RequestTimeout(t))) if *t == timeout
+        ));
+
+        // Plain commands are bounded by the runner's request timeout.
+        cmd.set_request_timeout(timeout);
+        let e = cmd.command(AdminRequest::ListDnas).await.unwrap_err();
+        assert!(matches!(
+            e.downcast_ref::<CallError>(),
+            Some(CallError::Timeout(_))
+        ));
+    }
+
+    #[test]
+    fn call_from_discovery_file_replaces_other_targets() {
+        let call = Call::from_iter_safe(&["call", "--from", "hc.json", "list-dnas"]).unwrap();
+        assert_eq!(call.from, Some(PathBuf::from("hc.json")));
+        assert!(
+            Call::from_iter_safe(&["call", "--from", "hc.json", "-r=9000", "list-dnas"]).is_err()
+        );
+        assert!(Call::from_iter_safe(&["call", "--from", "hc.json", "-a", "list-dnas"]).is_err());
+    }
+
+    #[test]
+    fn zome_call_stats_are_formatted_per_fn() {
+        assert_eq!(format_zome_call_stats(&[]), "No zome calls\n");
+        let mut stats = ZomeFnCallStats::new("zome".into(), "f".into());
+        stats.record(std::time::Duration::from_millis(2), false);
+        stats.record(std::time::Duration::from_millis(4), true);
+        let text = format_zome_call_stats(&[stats]);
+        let mut lines = text.lines();
+        assert!(lines.next().unwrap().starts_with("ZOME"));
+        let row: Vec<_> = lines.next().unwrap().split_whitespace().collect();
+        assert_eq!(row, vec!["zome", "f", "2", "1", "3ms", "4ms"]);
+        assert_eq!(lines.next(), None);
+    }
+
+    #[test]
+    fn cap_grants_are_formatted_per_grant() {
+        assert_eq!(format_cap_grants(&[]), "No capability grants\n");
+        let mut functions = std::collections::BTreeSet::new();
+        functions.insert(("zome".into(), "f".into()));
+        functions.insert(("zome".into(), "g".into()));
+        let grant = CapGrantInfo {
+            header_hash: HeaderHash::from_raw_32(vec![1; 32]),
+            tag: "tag".into(),
+            access: CapGrantAccess::Transferable,
+            functions,
+            revoked: true,
+        };
+        let text = format_cap_grants(&[grant.clone()]);
+        let lines: Vec<_> = text.lines().collect();
+        assert_eq!(
+            lines,
+            vec![
+                format!("grant: {:?}", grant.header_hash).as_str(),
+                "tag: \"tag\"",
+                "access: transferable",
+                "functions: zome/f, zome/g",
+                "revoked",
+                "",
+            ]
+        );
+    }
+
+    #[test]
+    fn watch_topics_default_to_all() {
+        let watch = Watch::from_iter_safe(&["watch"]).unwrap();
+        assert_eq!(
+            watch.topics,
+            vec![AdminEventTopic::Cells, AdminEventTopic::Apps]
+        );
+        let watch = Watch::from_iter_safe(&["watch", "-t=apps"]).unwrap();
+        assert_eq!(watch.topics, vec![AdminEventTopic::Apps]);
+        let watch = Watch::from_iter_safe(&["watch", "-t=cells,reindex"]).unwrap();
+        assert_eq!(
+            watch.topics,
+            vec![AdminEventTopic::Cells, AdminEventTopic::Reindex]
+        );
+        assert!(Watch::from_iter_safe(&["watch", "-t=gossip"]).is_err());
+    }
+
+    #[test]
+    fn cells_are_grouped_under_their_app() {
+        let cell_id = CellId::new(
+            DnaHash::from_raw_32(vec![1; 32]),
+            AgentPubKey::from_raw_32(vec![2; 32]),
+        );
+        let clone_id = CellId::new(
+            DnaHash::from_raw_32(vec![3; 32]),
+            AgentPubKey::from_raw_32(vec![2; 32]),
+        );
+        let apps = vec![InstalledAppInfo {
+            installed_app_id: "app".to_string(),
+            cell_data: vec![InstalledCell::new(cell_id.clone(), "chat".to_string())],
+            clone_cells: vec![InstalledCell::new(clone_id.clone(), "chat".to_string())],
+            active: true,
+            disabled_reason: None,
+        }];
+        let grouped = group_cells_by_app(apps);
+        assert_eq!(grouped.len(), 1);
+        assert_eq!(grouped[0].installed_app_id, "app");
+        assert_eq!(
+            grouped[0].cells,
+            vec![("chat".to_string(), cell_id.clone())]
+        );
+        assert_eq!(
+            grouped[0].clone_cells,
+            vec![("chat".to_string(), clone_id.clone())]
+        );
+        let text = format_cells_by_app(&grouped);
+        assert_eq!(
+            text,
+            format!(
+                "app\n  {:<20} {}\n  {:<20} {} (clone)\n",
+                "chat", cell_id, "chat", clone_id
+            )
+        );
+        assert_eq!(format_cells_by_app(&[]), "No apps installed\n");
+
+        assert!(!ListCells::from_iter_safe(&["list-cells"]).unwrap().by_app);
+        assert!(
+            ListCells::from_iter_safe(&["list-cells", "--by-app"])
+                .unwrap()
+                .by_app
+        );
     }
 }
@@ -1,5 +1,6 @@
 //! Definitions of StructOpt options for use in the CLI
 
+use crate::calls::AppCells;
 use crate::cmds::*;
 use holochain_types::prelude::InstalledAppId;
 use std::path::Path;
@@ -68,6 +69,9 @@ pub enum HcSandboxSubcommand {
     /// Make a call to a conductor's admin interface.
     Call(crate::calls::Call),
 
+    /// Print events from running conductors' admin interfaces as they happen.
+    Watch(crate::calls::Watch),
+
     /// List sandboxes found in `$(pwd)/.hc`.
     List {
         /// Show more verbose information.
@@ -93,6 +97,12 @@ pub struct Run {
     #[structopt(short, long, value_delimiter = ",")]
     ports: Vec<u16>,
 
+    /// Record the admin ports the conductors bound in this
+    /// discovery file once they are ready.
+    /// Sandboxes that aren't listed in the file are added to it.
+    #[structopt(long)]
+    discovery_file: Option<PathBuf>,
+
     /// (flattened)
     #[structopt(flatten)]
     existing: Existing,
@@ -108,7 +118,9 @@ impl HcSandbox {
                 run,
                 happ,
             } => {
-                let paths = generate(&self.holochain_path, happ, create, app_id).await?;
+                let discovery_file = create.discovery_file.clone();
+                let sandboxes = generate(&self.holochain_path, happ, create, app_id).await?;
+                let paths: Vec<_> = sandboxes.iter().map(|(p, _)| p.clone()).collect();
                 for (port, path) in self
                     .force_admin_ports
                     .clone()
@@ -117,12 +129,21 @@ impl HcSandbox {
                 {
                     crate::force_admin_port(path, port)?;
                 }
+                let discovery = discovery_file.map(|file| {
+                    let discovery = crate::save::Discovery::new(
+                        sandboxes.into_iter().map(|(path, app)| (path, vec![app])),
+                    );
+                    (file, discovery)
+                });
+                if let Some((file, discovery)) = &discovery {
+                    crate::save::save_discovery(file, discovery)?;
+                }
                 if let Some(ports) = run {
                     let holochain_path = self.holochain_path.clone();
                     let force_admin_ports = self.force_admin_ports.clone();
                     tokio::task::spawn(async move {
                         if let Err(e) =
-                            run_n(&holochain_path, paths, ports, force_admin_ports).await
+                            run_n(&holochain_path, paths, ports, force_admin_ports, discovery).await
                         {
                             tracing::error!(failed_to_run = ?e);
                         }
@@ -131,15 +152,29 @@ impl HcSandbox {
                     crate::save::release_ports(std::env::current_dir()?).await?;
                 }
             }
-            HcSandboxSubcommand::Run(Run { ports, existing }) => {
+            HcSandboxSubcommand::Run(Run {
+                ports,
+                discovery_file,
+                existing,
+            }) => {
                 let paths = existing.load()?;
                 if paths.is_empty() {
                     return Ok(());
                 }
+                let discovery = match discovery_file {
+                    Some(file) if file.exists() => {
+                        let discovery = crate::save::load_discovery(&file)?;
+                        Some((file, discovery))
+                    }
+                    Some(file) => Some((file, crate::save::Discovery::default())),
+                    None => None,
+                };
                 let holochain_path = self.holochain_path.clone();
                 let force_admin_ports = self.force_admin_ports.clone();
                 tokio::task::spawn(async move {
-                    if let Err(e) = run_n(&holochain_path, paths, ports, force_admin_ports).await {
+                    if let Err(e) =
+                        run_n(&holochain_path, paths, ports, force_admin_ports, discovery).await
+                    {
                         tracing::error!(failed_to_run = ?e);
                     }
                 });
@@ -149,17 +184,22 @@ impl HcSandbox {
             HcSandboxSubcommand::Call(call) => {
                 crate::calls::call(&self.holochain_path, call).await?
             }
+            HcSandboxSubcommand::Watch(watch) => crate::calls::watch(watch).await?,
             // HcSandboxSubcommand::Task => todo!("Running custom tasks is coming soon"),
             HcSandboxSubcommand::List { verbose } => {
                 crate::save::list(std::env::current_dir()?, verbose)?
             }
             HcSandboxSubcommand::Clean => crate::save::clean(std::env::current_dir()?, Vec::new())?,
-            HcSandboxSubcommand::Create(Create {
-                num_sandboxes,
-                network,
-                root,
-                directories,
-            }) => {
+            HcSandboxSubcommand::Create(create) => {
+                create.verify_network().await?;
+                let network = create.kitsune_config()?;
+                let Create {
+                    num_sandboxes,
+                    root,
+                    directories,
+                    discovery_file,
+                    ..
+                } = create;
                 let mut paths = Vec::with_capacity(num_sandboxes);
                 msg!(
                     "Creating {} conductor sandboxes with same settings",
@@ -167,13 +207,18 @@ impl HcSandbox {
                 );
                 for i in 0..num_sandboxes {
                     let path = crate::generate::generate(
-                        network.clone().map(|n| n.into_inner().into()),
+                        network.clone(),
                         root.clone(),
                         directories.get(i).cloned(),
                     )?;
                     paths.push(path);
                 }
                 crate::save::save(std::env::current_dir()?, paths.clone())?;
+                if let Some(file) = discovery_file {
+                    let discovery =
+                        crate::save::Discovery::new(paths.iter().map(|p| (p.clone(), Vec::new())));
+                    crate::save::save_discovery(&file, &discovery)?;
+                }
                 msg!("Created {:?}", paths);
             }
         }
@@ -187,27 +232,74 @@ async fn run_n(
     paths: Vec<PathBuf>,
     app_ports: Vec<u16>,
     force_admin_ports: Vec<u16>,
+    discovery: Option<(PathBuf, crate::save::Discovery)>,
 ) -> anyhow::Result<()> {
-    let run_holochain = |holochain_path: PathBuf, path: PathBuf, ports, force_admin_port| async move {
-        crate::run::run(&holochain_path, path, ports, force_admin_port).await?;
-        Result::<_, anyhow::Error>::Ok(())
+    let start_holochain = |holochain_path: PathBuf, sandboxes: Vec<(PathBuf, _, _)>| async move {
+        let paths: Vec<_> = sandboxes.iter().map(|(path, _, _)| path.clone()).collect();
+        let (ports, holochain) = crate::run::start_shared(&holochain_path, sandboxes).await?;
+        Result::<_, anyhow::Error>::Ok((ports, paths, holochain))
     };
     let mut force_admin_ports = force_admin_ports.into_iter();
     let mut app_ports = app_ports.into_iter();
-    let jhs = paths
+    // Sandboxes on the same shared mem network can only reach
+    // each other when they run in the same holochain process.
+    let mut processes: Vec<(Option<String>, Vec<_>)> = Vec::new();
+    for ((path, force_admin_port), app_port) in paths
         .into_iter()
         .zip(std::iter::repeat_with(|| force_admin_ports.next()))
         .zip(std::iter::repeat_with(|| app_ports.next()))
-        .map(|((path, force_admin_port), app_port)| {
-            let f = run_holochain(
-                holochain_path.to_path_buf(),
-                path,
-                app_port.map(|p| vec![p]).unwrap_or_default(),
-                force_admin_port,
-            );
-            tokio::task::spawn(f)
-        });
-    futures::future::try_join_all(jhs).await?;
+    {
+        let sandbox = (
+            path.clone(),
+            app_port.map(|p| vec![p]).unwrap_or_default(),
+            force_admin_port,
+        );
+        match crate::run::mem_shared_network(path)? {
+            Some(name) => match processes
+                .iter_mut()
+                .find(|(network, _)| network.as_ref() == Some(&name))
+            {
+                Some((_, sandboxes)) => sandboxes.push(sandbox),
+                None => processes.push((Some(name), vec![sandbox])),
+            },
+            None => processes.push((None, vec![sandbox])),
+        }
+    }
+    let jhs = processes.into_iter().map(|(_, sandboxes)| {
+        let f = start_holochain(holochain_path.to_path_buf(), sandboxes);
+        tokio::task::spawn(f)
+    });
+    let conductors = futures::future::try_join_all(jhs)
+        .await?
+        .into_iter()
+        .collect::<anyhow::Result<Vec<_>>>()?;
+    let admin_ports: Vec<_> = conductors
+        .iter()
+        .flat_map(|(ports, _, _)| ports.iter().copied())
+        .collect();
+    msg!(
+        "{} conductor(s) ready on admin ports {:?}",
+        admin_ports.len(),
+        admin_ports
+    );
+    // Only write the discovery file once the conductors
+    // have reported the admin ports they actually bound.
+    if let Some((file, mut discovery)) = discovery {
+        discovery.set_admin_ports(
+            conductors
+                .iter()
+                .flat_map(|(ports, paths, _)| paths.iter().cloned().zip(ports.iter().copied())),
+        );
+        crate::save::save_discovery(&file, &discovery)?;
+    }
+    futures::future::join_all(
+        conductors
+            .into_iter()
+            .map(
+                |(_, paths, holochain)| async move { crate::run::wait(&paths[0], holochain).await },
+            ),
+    )
+    .await;
     Ok(())
 }
 
@@ -216,9 +308,10 @@ async fn generate(
     happ: Option<PathBuf>,
     create: Create,
     app_id: InstalledAppId,
-) -> anyhow::Result<Vec<PathBuf>> {
+) -> anyhow::Result<Vec<(PathBuf, AppCells)>> {
     let happ = crate::bundles::parse_happ(happ)?;
-    let paths = crate::sandbox::default_n(holochain_path, create, happ, app_id).await?;
-    crate::save::save(std::env::current_dir()?, paths.clone())?;
-    Ok(paths)
+    let sandboxes = crate::sandbox::default_n(holochain_path, create, happ, app_id).await?;
+    let paths = sandboxes.iter().map(|(p, _)| p.clone()).collect();
+    crate::save::save(std::env::current_dir()?, paths)?;
+    Ok(sandboxes)
 }
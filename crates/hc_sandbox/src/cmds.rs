@@ -1,7 +1,12 @@
+use std::path::Path;
 use std::path::PathBuf;
 
+use anyhow::bail;
+use anyhow::Context;
 use holochain_p2p::kitsune_p2p::KitsuneP2pConfig;
+use holochain_p2p::kitsune_p2p::ProxyConfig;
 use holochain_p2p::kitsune_p2p::TransportConfig;
+use holochain_p2p::kitsune_p2p::MEM_BOOTSTRAP_SCHEME;
 use structopt::StructOpt;
 use url2::Url2;
 
@@ -13,7 +18,9 @@ use url2::Url2;
 // - keystore
 pub struct Create {
     /// Number of conductor sandboxes to create.
-    #[structopt(short, long, default_value = "1")]
+    /// Every sandbox gets the same network settings so
+    /// they all share the same bootstrap service.
+    #[structopt(short, long, alias = "num-conductors", default_value = "1")]
     pub num_sandboxes: usize,
 
     #[structopt(subcommand)]
@@ -33,6 +40,23 @@ pub struct Create {
     /// For example `hc gen -r path/to/my/chains -n 3 -d=first,second,third`
     /// will create three sandboxes with directories named "first", "second", and "third".
     pub directories: Vec<PathBuf>,
+    #[structopt(long)]
+    /// Load the network settings from a TOML file
+    /// containing a `KitsuneP2pConfig`.
+    /// Files with a `.json` extension are read as JSON instead.
+    /// Any settings passed to the `network` subcommand
+    /// override the matching fields from this file.
+    ///
+    /// For example `hc gen --network-config network.toml network -b https://bootstrap.holo.host`
+    /// uses the file but swaps in a different bootstrap service.
+    pub network_config: Option<PathBuf>,
+    #[structopt(long)]
+    /// Write the created sandboxes, their admin ports and any
+    /// installed apps and cells to this JSON file.
+    /// Pass it to `call --from` to call exactly these sandboxes.
+    /// Admin ports are filled in once the conductors are running
+    /// with `generate --run` or `run --discovery-file`.
+    pub discovery_file: Option<PathBuf>,
 }
 
 #[derive(Debug, StructOpt, Clone)]
@@ -52,12 +76,139 @@ impl NetworkCmd {
 pub struct Network {
     #[structopt(subcommand)]
     /// Set the type of network.
-    pub transport: NetworkType,
+    /// Defaults to the transports from `--network-config`
+    /// or the memory transport if there is no config file.
+    pub transport: Option<NetworkType>,
     #[structopt(short, long, parse(from_str = Url2::parse))]
     /// Optionally set a bootstrap service URL.
     /// A bootstrap service can used for peers to discover each other without
     /// prior knowledge of each other.
     pub bootstrap: Option<Url2>,
+    #[structopt(long, requires = "bootstrap")]
+    /// Check that the bootstrap service is reachable
+    /// before creating the sandbox and fail if it isn't.
+    pub verify_bootstrap: bool,
+    #[structopt(long)]
+    /// Override the delay between gossip loop iterations.
+    /// Default: the kitsune tuning param default.
+    pub gossip_loop_iteration_delay_ms: Option<u32>,
+    #[structopt(long)]
+    /// Override the default timeout for rpc single.
+    /// Default: the kitsune tuning param default.
+    pub default_rpc_single_timeout_ms: Option<u32>,
+    #[structopt(long)]
+    /// Override the default timeout for rpc multi.
+    /// Default: the kitsune tuning param default.
+    pub default_rpc_multi_timeout_ms: Option<u32>,
+    #[structopt(long)]
+    /// Override the default timeout for remote notify.
+    /// Default: the kitsune tuning param default.
+    pub default_notify_timeout_ms: Option<u32>,
+    #[structopt(long, parse(from_str = Url2::parse))]
+    /// Periodically publish the conductor's transport metrics
+    /// (open connections, bytes sent / received and gossip rounds)
+    /// as JSON to this URL.
+    /// The same metrics can be fetched on demand with the `NetworkStats` admin call.
+    pub metrics_endpoint: Option<Url2>,
+    #[structopt(long, conflicts_with = "bootstrap")]
+    /// Let conductors using the memory transport find each other
+    /// through a network shared by every conductor created with
+    /// this name, instead of a bootstrap service.
+    /// `hc run` starts the sandboxes on the same shared network
+    /// in a single holochain process so they can reach each other.
+    pub mem_shared_network: Option<String>,
+}
+
+impl Network {
+    /// How long to wait for the bootstrap service to respond when verifying it.
+    const VERIFY_BOOTSTRAP_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(5);
+
+    /// If `verify_bootstrap` is set then check the bootstrap
+    /// service responds to a request, otherwise do nothing.
+    pub async fn verify_bootstrap(&self) -> anyhow::Result<()> {
+        let url = match (&self.bootstrap, self.verify_bootstrap) {
+            (Some(url), true) => url,
+            _ => return Ok(()),
+        };
+        let client = reqwest::Client::builder()
+            .timeout(Self::VERIFY_BOOTSTRAP_TIMEOUT)
+            .build()?;
+        // Any response at all means the service is reachable.
+        if let Err(e) = client.head(url.as_str()).send().await {
+            bail!(
+                "Failed to reach the bootstrap service at {}: {}\n\
+                Check the url is correct or remove `--verify-bootstrap` to skip this check.",
+                url,
+                e
+            );
+        }
+        Ok(())
+    }
+
+    /// Override the fields of an existing config with
+    /// the settings that were set on this network.
+    pub fn apply_to(self, kit: &mut KitsuneP2pConfig) {
+        let Network {
+            transport,
+            bootstrap,
+            verify_bootstrap: _,
+            gossip_loop_iteration_delay_ms,
+            default_rpc_single_timeout_ms,
+            default_rpc_multi_timeout_ms,
+            default_notify_timeout_ms,
+            metrics_endpoint,
+            mem_shared_network,
+        } = self;
+        if bootstrap.is_some() {
+            kit.bootstrap_service = bootstrap;
+        }
+        if let Some(name) = mem_shared_network {
+            kit.bootstrap_service =
+                Some(Url2::parse(format!("{}://{}", MEM_BOOTSTRAP_SCHEME, name)));
+        }
+        if metrics_endpoint.is_some() {
+            kit.metrics_endpoint = metrics_endpoint;
+        }
+
+        // Only override the tuning params that were set.
+        let tuning_params = std::sync::Arc::make_mut(&mut kit.tuning_params);
+        if let Some(ms) = gossip_loop_iteration_delay_ms {
+            tuning_params.gossip_loop_iteration_delay_ms = ms;
+        }
+        if let Some(ms) = default_rpc_single_timeout_ms {
+            tuning_params.default_rpc_single_timeout_ms = ms;
+        }
+        if let Some(ms) = default_rpc_multi_timeout_ms {
+            tuning_params.default_rpc_multi_timeout_ms = ms;
+        }
+        if let Some(ms) = default_notify_timeout_ms {
+            tuning_params.default_notify_timeout_ms = ms;
+        }
+
+        match transport {
+            None => (),
+            Some(NetworkType::Mem) => kit.transport_pool = Vec::new(),
+            Some(NetworkType::Quic(Quic {
+                bind_to,
+                override_host,
+                override_port,
+                proxy,
+            })) => {
+                let transport = TransportConfig::Quic {
+                    bind_to,
+                    override_host,
+                    override_port,
+                };
+                kit.transport_pool = match proxy {
+                    None => vec![transport],
+                    Some(proxy_url) => vec![TransportConfig::Proxy {
+                        sub_transport: Box::new(transport),
+                        proxy_config: ProxyConfig::RemoteProxyClient { proxy_url },
+                    }],
+                };
+            }
+        }
+    }
 }
 
 #[derive(Debug, StructOpt, Clone)]
@@ -72,6 +223,7 @@ pub enum NetworkType {
 pub struct Quic {
     #[structopt(short, long, parse(from_str = Url2::parse))]
     /// To which network interface / port should we bind?
+    /// Kitsune binds a single transport so this can only be given once.
     /// Default: "kitsune-quic://0.0.0.0:0".
     pub bind_to: Option<Url2>,
     #[structopt(short, long)]
@@ -166,50 +318,82 @@ Run `hc g --help` for more options."
 
 impl From<Network> for KitsuneP2pConfig {
     fn from(n: Network) -> Self {
-        let Network {
-            transport,
-            bootstrap,
-        } = n;
         let mut kit = KitsuneP2pConfig::default();
-        kit.bootstrap_service = bootstrap;
+        n.apply_to(&mut kit);
+        kit
+    }
+}
 
-        match transport {
-            NetworkType::Mem => (),
-            NetworkType::Quic(Quic {
-                bind_to,
-                override_host,
-                override_port,
-                proxy: None,
-            }) => {
-                kit.transport_pool = vec![TransportConfig::Quic {
-                    bind_to,
-                    override_host,
-                    override_port,
-                }];
-            }
-            NetworkType::Quic(Quic {
-                bind_to,
-                override_host,
-                override_port,
-                proxy: Some(proxy_url),
-            }) => {
-                let transport = TransportConfig::Quic {
-                    bind_to,
-                    override_host,
-                    override_port,
-                };
-                kit.transport_pool = vec![TransportConfig::Proxy {
-                    sub_transport: Box::new(transport),
-                    proxy_config: holochain_p2p::kitsune_p2p::ProxyConfig::RemoteProxyClient {
-                        proxy_url,
-                    },
-                }]
-            }
+impl Create {
+    /// Check the network config is usable before creating sandboxes.
+    pub async fn verify_network(&self) -> anyhow::Result<()> {
+        if self.num_sandboxes > 1
+            && self.uses_mem_transport()?
+            && !self.uses_mem_shared_network()?
+        {
+            msg!(
+                "Warning: The memory transport only connects conductors within the same process.
+The {} conductors will not be able to reach each other.
+Use `network --mem-shared-network <name> mem` or `network quic` with a bootstrap service `-b`
+to create connected conductors.",
+                self.num_sandboxes
+            );
         }
-        kit
+        match &self.network {
+            Some(NetworkCmd::Network(network)) => network.verify_bootstrap().await,
+            None => Ok(()),
+        }
+    }
+
+    /// Is the network set to only use the memory transport.
+    /// Without any network config the conductor defaults to it.
+    fn uses_mem_transport(&self) -> anyhow::Result<bool> {
+        Ok(self
+            .kitsune_config()?
+            .map_or(true, |kit| kit.transport_pool.is_empty()))
+    }
+
+    /// Is the network set to find peers through a shared mem network.
+    fn uses_mem_shared_network(&self) -> anyhow::Result<bool> {
+        Ok(self
+            .kitsune_config()?
+            .and_then(|kit| kit.bootstrap_service)
+            .map_or(false, |url| url.scheme() == MEM_BOOTSTRAP_SCHEME))
+    }
+
+    /// The network config for the new sandboxes.
+    /// Loads the `--network-config` file if there is one
+    /// and then applies any settings from the `network` subcommand on top.
+    pub fn kitsune_config(&self) -> anyhow::Result<Option<KitsuneP2pConfig>> {
+        let file = match &self.network_config {
+            Some(path) => Some(load_network_config(path)?),
+            None => None,
+        };
+        let network = self.network.clone().map(NetworkCmd::into_inner);
+        Ok(match (file, network) {
+            (Some(mut kit), Some(network)) => {
+                network.apply_to(&mut kit);
+                Some(kit)
+            }
+            (Some(kit), None) => Some(kit),
+            (None, network) => network.map(Into::into),
+        })
     }
 }
 
+/// Read a [`KitsuneP2pConfig`] from a TOML file,
+/// or from JSON if the file has a `.json` extension.
+fn load_network_config(path: &Path) -> anyhow::Result<KitsuneP2pConfig> {
+    let contents = std::fs::read_to_string(path)
+        .with_context(|| format!("Failed to read network config {}", path.display()))?;
+    let kit = match path.extension().and_then(|ext| ext.to_str()) {
+        Some("json") => serde_json::from_str(&contents).map_err(anyhow::Error::from),
+        _ => toml::from_str(&contents).map_err(anyhow::Error::from),
+    }
+    .with_context(|| format!("Failed to parse network config {}", path.display()))?;
+    Ok(kit)
+}
+
 impl Default for Create {
     fn default() -> Self {
         Self {
@@ -217,6 +401,229 @@ impl Default for Create {
             network: None,
             root: None,
             directories: Vec::with_capacity(0),
+            network_config: None,
+            discovery_file: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn quic(bind_to: Option<&str>) -> TransportConfig {
+        TransportConfig::Quic {
+            bind_to: bind_to.map(Url2::parse),
+            override_host: None,
+            override_port: None,
+        }
+    }
+
+    fn proxied(transport: TransportConfig, proxy_url: &str) -> TransportConfig {
+        TransportConfig::Proxy {
+            sub_transport: Box::new(transport),
+            proxy_config: ProxyConfig::RemoteProxyClient {
+                proxy_url: Url2::parse(proxy_url),
+            },
         }
     }
+
+    fn network(bind_to: Option<&str>, proxy: Option<&str>) -> KitsuneP2pConfig {
+        Network {
+            transport: Some(NetworkType::Quic(Quic {
+                bind_to: bind_to.map(Url2::parse),
+                override_host: None,
+                override_port: None,
+                proxy: proxy.map(Url2::parse),
+            })),
+            bootstrap: None,
+            verify_bootstrap: false,
+            gossip_loop_iteration_delay_ms: None,
+            default_rpc_single_timeout_ms: None,
+            default_rpc_multi_timeout_ms: None,
+            default_notify_timeout_ms: None,
+            metrics_endpoint: None,
+            mem_shared_network: None,
+        }
+        .into()
+    }
+
+    #[test]
+    fn quic_defaults_to_single_bind() {
+        let kit = network(None, None);
+        assert_eq!(kit.transport_pool, vec![quic(None)]);
+        assert_eq!(kit.tuning_params, KitsuneP2pConfig::default().tuning_params);
+    }
+
+    #[test]
+    fn quic_binds_the_address() {
+        let v6 = "kitsune-quic://[::]:0";
+        let kit = network(Some(v6), None);
+        assert_eq!(kit.transport_pool, vec![quic(Some(v6))]);
+    }
+
+    #[test]
+    fn quic_rejects_multiple_binds() {
+        let quic = Quic::from_iter_safe(&[
+            "quic",
+            "--bind-to",
+            "kitsune-quic://0.0.0.0:0",
+            "--bind-to",
+            "kitsune-quic://[::]:0",
+        ]);
+        assert!(quic.is_err());
+    }
+
+    #[test]
+    fn proxy_wraps_the_transport() {
+        let proxy = "kitsune-proxy://primary.proxy";
+        let kit = network(None, Some(proxy));
+        assert_eq!(kit.transport_pool, vec![proxied(quic(None), proxy)]);
+    }
+
+    #[test]
+    fn flags_override_network_config_file() {
+        let mut file_kit = network(Some("kitsune-quic://0.0.0.0:0"), None);
+        file_kit.bootstrap_service = Some(Url2::parse("https://file.bootstrap"));
+        let path = std::env::temp_dir().join(format!("network_config_{}.json", nanoid::nanoid!()));
+        std::fs::write(&path, serde_json::to_string(&file_kit).unwrap()).unwrap();
+
+        let mut create = Create {
+            network_config: Some(path),
+            ..Default::default()
+        };
+        assert_eq!(create.kitsune_config().unwrap(), Some(file_kit.clone()));
+
+        create.network = Some(NetworkCmd::Network(Network {
+            transport: None,
+            bootstrap: Some(Url2::parse("https://flag.bootstrap")),
+            verify_bootstrap: false,
+            gossip_loop_iteration_delay_ms: Some(42),
+            default_rpc_single_timeout_ms: None,
+            default_rpc_multi_timeout_ms: None,
+            default_notify_timeout_ms: None,
+            metrics_endpoint: None,
+            mem_shared_network: None,
+        }));
+        let kit = create.kitsune_config().unwrap().unwrap();
+        // The transports come from the file.
+        assert_eq!(kit.transport_pool, file_kit.transport_pool);
+        // The flags replace just the fields that were set.
+        assert_eq!(
+            kit.bootstrap_service,
+            Some(Url2::parse("https://flag.bootstrap"))
+        );
+        assert_eq!(kit.tuning_params.gossip_loop_iteration_delay_ms, 42);
+        assert_eq!(
+            kit.tuning_params.default_rpc_single_timeout_ms,
+            file_kit.tuning_params.default_rpc_single_timeout_ms
+        );
+        std::fs::remove_file(create.network_config.unwrap()).unwrap();
+    }
+
+    #[test]
+    fn toml_network_config_keeps_its_bootstrap_service() {
+        let path = std::env::temp_dir().join(format!("network_config_{}.toml", nanoid::nanoid!()));
+        std::fs::write(
+            &path,
+            r#"
+bootstrap_service = "https://file.bootstrap"
+network_type = "quic_bootstrap"
+
+[[transport_pool]]
+type = "quic"
+bind_to = "kitsune-quic://0.0.0.0:0"
+"#,
+        )
+        .unwrap();
+
+        // Flags that don't set a bootstrap leave the one from the file.
+        let create = Create {
+            network_config: Some(path),
+            network: Some(NetworkCmd::Network(Network {
+                transport: None,
+                bootstrap: None,
+                verify_bootstrap: false,
+                gossip_loop_iteration_delay_ms: Some(42),
+                default_rpc_single_timeout_ms: None,
+                default_rpc_multi_timeout_ms: None,
+                default_notify_timeout_ms: None,
+                metrics_endpoint: None,
+                mem_shared_network: None,
+            })),
+            ..Default::default()
+        };
+        let kit = create.kitsune_config().unwrap().unwrap();
+        assert_eq!(
+            kit.bootstrap_service,
+            Some(Url2::parse("https://file.bootstrap"))
+        );
+        assert_eq!(
+            kit.transport_pool,
+            vec![quic(Some("kitsune-quic://0.0.0.0:0"))]
+        );
+        assert_eq!(kit.tuning_params.gossip_loop_iteration_delay_ms, 42);
+        std::fs::remove_file(create.network_config.unwrap()).unwrap();
+    }
+
+    #[test]
+    fn mem_transport_is_detected() {
+        let mut create = Create::default();
+        assert!(create.uses_mem_transport().unwrap());
+        create.network = Some(NetworkCmd::Network(Network {
+            transport: Some(NetworkType::Mem),
+            bootstrap: None,
+            verify_bootstrap: false,
+            gossip_loop_iteration_delay_ms: None,
+            default_rpc_single_timeout_ms: None,
+            default_rpc_multi_timeout_ms: None,
+            default_notify_timeout_ms: None,
+            metrics_endpoint: None,
+            mem_shared_network: None,
+        }));
+        assert!(create.uses_mem_transport().unwrap());
+    }
+
+    #[test]
+    fn metrics_endpoint_is_set() {
+        let network = Network::from_iter_safe(&[
+            "network",
+            "--metrics-endpoint",
+            "http://localhost:9000/metrics",
+        ])
+        .unwrap();
+        let kit: KitsuneP2pConfig = network.into();
+        assert_eq!(
+            kit.metrics_endpoint,
+            Some(Url2::parse("http://localhost:9000/metrics"))
+        );
+    }
+
+    #[test]
+    fn mem_shared_network_is_the_bootstrap_service() {
+        let network =
+            Network::from_iter_safe(&["network", "--mem-shared-network", "demo", "mem"]).unwrap();
+        let kit: KitsuneP2pConfig = network.into();
+        assert!(kit.transport_pool.is_empty());
+        assert_eq!(
+            kit.bootstrap_service,
+            Some(Url2::parse("kitsune-mem-bootstrap://demo"))
+        );
+        // A shared network replaces the bootstrap service.
+        assert!(Network::from_iter_safe(&[
+            "network",
+            "--mem-shared-network",
+            "demo",
+            "-b",
+            "https://bootstrap.holo.host",
+            "mem",
+        ])
+        .is_err());
+    }
+
+    #[test]
+    fn num_conductors_is_an_alias() {
+        let create = Create::from_iter_safe(&["create", "--num-conductors", "3"]).unwrap();
+        assert_eq!(create.num_sandboxes, 3);
+    }
 }
@@ -110,10 +110,14 @@
 
 #![allow(deprecated)]
 
+use std::convert::TryFrom;
 use std::path::Path;
 use std::path::PathBuf;
 
+use holochain_conductor_api::ExternalApiWireError;
+use holochain_conductor_api::{AdminEvent, AdminEventTopic};
 use holochain_conductor_api::{AdminRequest, AdminResponse};
+use holochain_websocket::WebsocketReceiver;
 use holochain_websocket::WebsocketResult;
 use holochain_websocket::WebsocketSender;
 use ports::get_admin_api;
@@ -147,10 +151,15 @@ mod ports;
 /// An active connection to a running conductor.
 pub struct CmdRunner {
     client: WebsocketSender,
+    /// The admin port this runner is connected to.
+    port: u16,
+    request_timeout: std::time::Duration,
 }
 
 impl CmdRunner {
     const HOLOCHAIN_PATH: &'static str = "holochain";
+    /// How long [`CmdRunner::command`] waits for a response by default.
+    pub const DEFAULT_REQUEST_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(120);
     /// Create a new connection for calling admin interface commands.
     /// Panics if admin port fails to connect.
     pub async fn new(port: u16) -> Self {
@@ -161,8 +170,48 @@ impl CmdRunner {
 
     /// Create a new connection for calling admin interface commands.
     pub async fn try_new(port: u16) -> WebsocketResult<Self> {
-        let client = get_admin_api(port).await?;
-        Ok(Self { client })
+        Ok(Self::connect(port).await?.0)
+    }
+
+    /// Connect to the admin port, also returning the
+    /// receiver for signals sent to this connection.
+    async fn connect(port: u16) -> WebsocketResult<(Self, WebsocketReceiver)> {
+        let (client, events) = get_admin_api(port).await?;
+        let cmd = Self {
+            client,
+            port,
+            request_timeout: Self::DEFAULT_REQUEST_TIMEOUT,
+        };
+        Ok((cmd, events))
+    }
+
+    /// Create a new connection for calling admin interface commands.
+    /// Each attempt is bounded by the [`ConnectOptions`] timeout and failed
+    /// attempts are retried with exponential backoff.
+    /// This is useful when the conductor may still be starting up.
+    pub async fn try_new_with_retry(port: u16, options: ConnectOptions) -> WebsocketResult<Self> {
+        let mut delay = ConnectOptions::INITIAL_BACKOFF;
+        let mut attempt = 0;
+        loop {
+            let result = match tokio::time::timeout(options.timeout, Self::try_new(port)).await {
+                Ok(result) => result,
+                Err(_) => Err(std::io::Error::new(
+                    std::io::ErrorKind::TimedOut,
+                    format!("Timed out connecting to admin port {}", port),
+                )
+                .into()),
+            };
+            match result {
+                Ok(cmd) => return Ok(cmd),
+                Err(e) if attempt < options.retries => {
+                    tracing::debug!(port, attempt, ?e, "Failed to connect, retrying");
+                    tokio::time::sleep(delay).await;
+                    delay = (delay * 2).min(ConnectOptions::MAX_BACKOFF);
+                    attempt += 1;
+                }
+                Err(e) => return Err(e),
+            }
+        }
     }
 
     /// Create a command runner from a sandbox path.
@@ -185,9 +234,180 @@ impl CmdRunner {
     }
 
     /// Make an Admin request to this conductor.
+    /// Fails with [`CallError::Timeout`] if there's no response
+    /// within this runner's request timeout.
     pub async fn command(&mut self, cmd: AdminRequest) -> anyhow::Result<AdminResponse> {
-        let response: Result<AdminResponse, _> = self.client.request(cmd).await;
-        Ok(response?)
+        let timeout = self.request_timeout;
+        self.command_with_timeout(cmd, timeout).await
+    }
+
+    /// Make an Admin request to this conductor and wait at most
+    /// `timeout` for the response.
+    /// Fails with [`CallError::Timeout`] if the timeout is reached.
+    pub async fn command_with_timeout(
+        &mut self,
+        cmd: AdminRequest,
+        timeout: std::time::Duration,
+    ) -> anyhow::Result<AdminResponse> {
+        let response: Result<AdminResponse, _> =
+            tokio::time::timeout(timeout, self.client.request(cmd))
+                .await
+                .map_err(|_| CallError::Timeout(RequestTimeout(timeout)))?;
+        Ok(response.map_err(|e| CallError::Connection(format!("{:?}", e)))?)
+    }
+
+    /// Set how long [`CmdRunner::command`] waits for a response.
+    pub fn set_request_timeout(&mut self, timeout: std::time::Duration) {
+        self.request_timeout = timeout;
+    }
+
+    /// Calls [`AdminRequest::Subscribe`] and returns a stream of the
+    /// [`AdminEvent`]s for these topics as they arrive.
+    /// The events come in on a new connection to the same admin port
+    /// that stays open while the stream is in use, so commands can still
+    /// be made on this runner.
+    pub async fn subscribe(
+        &self,
+        topics: Vec<AdminEventTopic>,
+    ) -> anyhow::Result<futures::stream::BoxStream<'static, AdminEvent>> {
+        use futures::StreamExt;
+        let port = self.port;
+        let (mut subscriber, events) = Self::connect(port).await.map_err(|e| {
+            CallError::Connection(format!(
+                "Failed to connect to admin port {} for events: {:?}",
+                port, e
+            ))
+        })?;
+        subscriber.set_request_timeout(self.request_timeout);
+        let resp = subscriber
+            .command(AdminRequest::Subscribe { topics })
+            .await?;
+        match resp {
+            AdminResponse::Subscribed => (),
+            other => {
+                return Err(CallError::unexpected(
+                    "Failed to subscribe to admin events",
+                    "AdminResponse::Subscribed",
+                    other,
+                )
+                .into())
+            }
+        }
+        Ok(events
+            .filter_map(move |(bytes, respond)| {
+                // Hold the subscriber so its connection stays open.
+                let _ = &subscriber;
+                let event = if respond.is_request() {
+                    None
+                } else {
+                    AdminEvent::try_from(bytes)
+                        .map_err(|e| tracing::warn!(?e, "Failed to decode admin event"))
+                        .ok()
+                };
+                futures::future::ready(event)
+            })
+            .boxed())
+    }
+}
+
+/// An admin request didn't get a response in time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+pub struct RequestTimeout(pub std::time::Duration);
+
+impl std::fmt::Display for RequestTimeout {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Admin request timed out after {:?}", self.0)
+    }
+}
+
+impl std::error::Error for RequestTimeout {}
+
+/// Why an admin call failed.
+/// Calls return this inside their [`anyhow::Error`]
+/// so it can be recovered with `downcast_ref`.
+#[derive(Debug, Clone, serde::Serialize)]
+#[serde(tag = "kind", content = "data", rename_all = "snake_case")]
+pub enum CallError {
+    /// The conductor responded with [`AdminResponse::Error`].
+    ConductorError {
+        /// What the call was trying to do.
+        context: String,
+        /// The error the conductor returned.
+        error: ExternalApiWireError,
+    },
+    /// The request couldn't be sent or the response couldn't be read.
+    Connection(String),
+    /// The conductor didn't respond in time.
+    Timeout(RequestTimeout),
+    /// The conductor responded with a different response than expected.
+    UnexpectedResponse {
+        /// What the call was trying to do.
+        context: String,
+        /// The expected response variant.
+        expected: String,
+        /// The response that was returned.
+        got: String,
+    },
+}
+
+impl CallError {
+    /// Create the error for a response that wasn't the expected variant.
+    /// Error responses from the conductor are kept separate
+    /// from other unexpected responses.
+    pub fn unexpected(context: &str, expected: &str, response: AdminResponse) -> Self {
+        match response {
+            AdminResponse::Error(error) => CallError::ConductorError {
+                context: context.to_string(),
+                error,
+            },
+            got => CallError::UnexpectedResponse {
+                context: context.to_string(),
+                expected: expected.to_string(),
+                got: format!("{:?}", got),
+            },
+        }
+    }
+}
+
+impl std::fmt::Display for CallError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CallError::ConductorError { context, error } => {
+                write!(f, "{}: Conductor returned an error {:?}", context, error)
+            }
+            CallError::Connection(e) => write!(f, "Failed to reach the conductor: {}", e),
+            CallError::Timeout(t) => write!(f, "{}", t),
+            CallError::UnexpectedResponse {
+                context,
+                expected,
+                got,
+            } => write!(f, "{}: Expected {} but got {}", context, expected, got),
+        }
+    }
+}
+
+impl std::error::Error for CallError {}
+
+/// Options for connecting to a conductor's admin interface.
+#[derive(Debug, Clone, Copy)]
+pub struct ConnectOptions {
+    /// How long to wait for each connection attempt.
+    pub timeout: std::time::Duration,
+    /// How many times to retry after the first attempt fails.
+    pub retries: usize,
+}
+
+impl ConnectOptions {
+    const INITIAL_BACKOFF: std::time::Duration = std::time::Duration::from_millis(100);
+    const MAX_BACKOFF: std::time::Duration = std::time::Duration::from_secs(2);
+}
+
+impl Default for ConnectOptions {
+    fn default() -> Self {
+        Self {
+            timeout: std::time::Duration::from_secs(5),
+            retries: 0,
+        }
     }
 }
 
@@ -206,15 +426,33 @@ macro_rules! expect_variant {
 }
 
 #[macro_export]
-/// Expect that an enum matches a variant and return an error if it doesn't.
+/// Expect that an [`AdminResponse`] matches a variant and
+/// return a [`CallError`] if it doesn't.
 macro_rules! expect_match {
     ($var:expr => $variant:path, $error_msg:expr) => {
         match $var {
             $variant(v) => v,
-            _ => anyhow::bail!("{}: Expected {} but got {:?}", $error_msg, stringify!($variant), $var),
+            other => {
+                return Err($crate::CallError::unexpected($error_msg, stringify!($variant), other).into())
+            }
         }
     };
     ($var:expr => $variant:path) => {
-        expect_variant!($var => $variant, "")
+        expect_match!($var => $variant, "")
+    };
+}
+
+#[macro_export]
+/// Like [`expect_match`] for [`AdminResponse`] variants without any data.
+macro_rules! expect_unit_match {
+    ($var:expr => $variant:path, $error_msg:expr) => {
+        match $var {
+            $variant => (),
+            other => {
+                return Err(
+                    $crate::CallError::unexpected($error_msg, stringify!($variant), other).into(),
+                )
+            }
+        }
     };
 }
@@ -43,9 +43,11 @@ pub async fn get_admin_ports(paths: Vec<PathBuf>) -> anyhow::Result<Vec<u16>> {
     Ok(ports)
 }
 
-pub(crate) async fn get_admin_api(port: u16) -> WebsocketResult<WebsocketSender> {
+pub(crate) async fn get_admin_api(
+    port: u16,
+) -> WebsocketResult<(WebsocketSender, WebsocketReceiver)> {
     tracing::debug!(port);
-    websocket_client_by_port(port).await.map(|p| p.0)
+    websocket_client_by_port(port).await
 }
 
 async fn websocket_client_by_port(
@@ -2,10 +2,11 @@
 use std::path::Path;
 use std::{path::PathBuf, process::Stdio};
 
+use holochain_p2p::kitsune_p2p::MEM_BOOTSTRAP_SCHEME;
 use tokio::io::AsyncBufReadExt;
 use tokio::io::BufReader;
 use tokio::process::{Child, Command};
-use tokio::sync::oneshot;
+use tokio::sync::mpsc;
 
 use crate::calls::attach_app_interface;
 use crate::calls::AddAppWs;
@@ -29,27 +30,76 @@ pub async fn run(
     app_ports: Vec<u16>,
     force_admin_port: Option<u16>,
 ) -> anyhow::Result<()> {
-    let (port, mut holochain) =
-        run_async(holochain_path, sandbox_path.clone(), force_admin_port).await?;
-    msg!("Running conductor on admin port {}", port);
-    for app_port in app_ports {
-        msg!("Attaching app port {}", app_port);
-        let mut cmd = CmdRunner::try_new(port).await?;
-        let port = attach_app_interface(
-            &mut cmd,
-            AddAppWs {
-                port: Some(app_port),
-            },
-        )
-        .await?;
-        msg!("App port attached at {}", port);
+    let (_, holochain) = start(
+        holochain_path,
+        sandbox_path.clone(),
+        app_ports,
+        force_admin_port,
+    )
+    .await?;
+    wait(&sandbox_path, holochain).await;
+    Ok(())
+}
+
+/// Start a conductor like [`run`] and return its admin port once it is
+/// ready, without waiting for it to finish.
+/// Use [`wait`] to wait for it.
+pub async fn start(
+    holochain_path: &Path,
+    sandbox_path: PathBuf,
+    app_ports: Vec<u16>,
+    force_admin_port: Option<u16>,
+) -> anyhow::Result<(u16, Child)> {
+    let (ports, holochain) = start_shared(
+        holochain_path,
+        vec![(sandbox_path, app_ports, force_admin_port)],
+    )
+    .await?;
+    Ok((ports[0], holochain))
+}
+
+/// Start a conductor for each sandbox like [`start`] but all
+/// in a single holochain process, which is the only way conductors
+/// using the memory transport can reach each other.
+/// Each sandbox is given with its app ports and optional forced admin port.
+/// Returns the admin ports in the same order as the sandboxes.
+pub async fn start_shared(
+    holochain_path: &Path,
+    sandboxes: Vec<(PathBuf, Vec<u16>, Option<u16>)>,
+) -> anyhow::Result<(Vec<u16>, Child)> {
+    let (ports, holochain) = run_async_shared(
+        holochain_path,
+        sandboxes
+            .iter()
+            .map(|(sandbox_path, _, force_admin_port)| (sandbox_path.clone(), *force_admin_port))
+            .collect(),
+    )
+    .await?;
+    for ((sandbox_path, app_ports, _), port) in sandboxes.into_iter().zip(ports.iter().copied()) {
+        msg!("Running conductor on admin port {}", port);
+        for app_port in app_ports {
+            msg!("Attaching app port {}", app_port);
+            let mut cmd = CmdRunner::try_new(port).await?;
+            let port = attach_app_interface(
+                &mut cmd,
+                AddAppWs {
+                    port: Some(app_port),
+                    ready_file: None,
+                },
+            )
+            .await?;
+            msg!("App port attached at {}", port);
+        }
+        crate::save::lock_live(std::env::current_dir()?, &sandbox_path, port).await?;
     }
-    crate::save::lock_live(std::env::current_dir()?, &sandbox_path, port).await?;
     msg!("Connected successfully to a running holochain");
-    let e = format!("Failed to run holochain at {}", sandbox_path.display());
+    Ok((ports, holochain))
+}
 
+/// Wait for a conductor started with [`start`] to finish.
+pub async fn wait(sandbox_path: &Path, mut holochain: Child) {
+    let e = format!("Failed to run holochain at {}", sandbox_path.display());
     holochain.wait().await.expect(&e);
-    Ok(())
 }
 
 /// Run a conductor in the background.
@@ -64,44 +114,78 @@ pub async fn run_async(
     sandbox_path: PathBuf,
     force_admin_port: Option<u16>,
 ) -> anyhow::Result<(u16, Child)> {
-    let mut config = match read_config(sandbox_path.clone())? {
-        Some(c) => c,
-        None => create_config(sandbox_path.clone()),
-    };
-    match force_admin_port {
-        Some(port) => {
-            set_admin_port(&mut config, port);
+    let (ports, child) =
+        run_async_shared(holochain_path, vec![(sandbox_path, force_admin_port)]).await?;
+    Ok((ports[0], child))
+}
+
+/// Run a conductor for each sandbox in a single holochain
+/// process in the background, like [`run_async`].
+/// Returns the admin ports in the same order as the sandboxes.
+pub async fn run_async_shared(
+    holochain_path: &Path,
+    sandboxes: Vec<(PathBuf, Option<u16>)>,
+) -> anyhow::Result<(Vec<u16>, Child)> {
+    let num_conductors = sandboxes.len();
+    let mut config_paths = Vec::with_capacity(num_conductors);
+    for (sandbox_path, force_admin_port) in sandboxes {
+        let mut config = match read_config(sandbox_path.clone())? {
+            Some(c) => c,
+            None => create_config(sandbox_path.clone()),
+        };
+        match force_admin_port {
+            Some(port) => {
+                set_admin_port(&mut config, port);
+            }
+            None => random_admin_port(&mut config),
         }
-        None => random_admin_port(&mut config),
+        config_paths.push(write_config(sandbox_path, &config));
     }
-    let config_path = write_config(sandbox_path.clone(), &config);
-    let (tx_config, rx_config) = oneshot::channel();
-    let mut child = start_holochain(holochain_path, config_path, tx_config).await;
+    let (tx_ports, mut rx_ports) = mpsc::unbounded_channel();
+    let mut child = start_holochain(holochain_path, config_paths, tx_ports).await;
     check_started(&mut child).await;
-    let port = rx_config
-        .await
-        .expect("Failed to get admin port from conductor");
-    Ok((port, child))
+    let mut ports = Vec::with_capacity(num_conductors);
+    while ports.len() < num_conductors {
+        let port = rx_ports
+            .recv()
+            .await
+            .expect("Failed to get admin port from conductor");
+        ports.push(port);
+    }
+    Ok((ports, child))
+}
+
+/// The name of the shared mem network the sandbox's conductor
+/// finds peers through, if it uses one.
+/// Sandboxes on the same shared mem network need to
+/// be run together with [`start_shared`].
+pub fn mem_shared_network(sandbox_path: PathBuf) -> anyhow::Result<Option<String>> {
+    Ok(read_config(sandbox_path)?
+        .and_then(|config| config.network)
+        .and_then(|network| network.bootstrap_service)
+        .filter(|url| url.scheme() == MEM_BOOTSTRAP_SCHEME)
+        .map(|url| url.host_str().unwrap_or_default().to_string()))
 }
 
 async fn start_holochain(
     holochain_path: &Path,
-    config_path: PathBuf,
-    tx_config: oneshot::Sender<u16>,
+    config_paths: Vec<PathBuf>,
+    tx_ports: mpsc::UnboundedSender<u16>,
 ) -> Child {
     tracing::info!("\n\n----\nstarting holochain\n----\n\n");
     let mut cmd = Command::new(holochain_path);
     cmd.arg("--structured")
         // .env("RUST_LOG", "trace")
-        .arg("--config-path")
-        .arg(config_path)
         .stdout(Stdio::piped())
         .stderr(Stdio::piped())
         .kill_on_drop(true);
+    for config_path in config_paths {
+        cmd.arg("--config-path").arg(config_path);
+    }
 
     let mut holochain = cmd.spawn().expect("Failed to spawn holochain");
     // TODO: Allow redirecting output per conductor.
-    spawn_output(&mut holochain, tx_config);
+    spawn_output(&mut holochain, tx_ports);
     holochain
 }
 
@@ -114,22 +198,27 @@ async fn check_started(holochain: &mut Child) {
     }
 }
 
-fn spawn_output(holochain: &mut Child, config: oneshot::Sender<u16>) {
+/// Sends the first admin port from the setup each conductor prints.
+fn spawn_output(holochain: &mut Child, ports: mpsc::UnboundedSender<u16>) {
     let stdout = holochain.stdout.take();
     let stderr = holochain.stderr.take();
     tokio::task::spawn(async move {
         let mut needs_setup = true;
-        let mut config = Some(config);
+        let mut port_sent = false;
         if let Some(stdout) = stdout {
             let mut reader = BufReader::new(stdout).lines();
             while let Ok(Some(line)) = reader.next_line().await {
+                // Each conductor in the process prints its own setup.
+                if line == "###HOLOCHAIN_SETUP###" {
+                    port_sent = false;
+                }
                 if needs_setup {
                     match check_sandbox(&line, &mut needs_setup) {
                         (true, Some(port)) => {
-                            if let Some(config) = config.take() {
-                                config
-                                    .send(port)
-                                    .expect("Failed to send admin port from config");
+                            if !port_sent {
+                                port_sent = true;
+                                // Nothing is listening once every port has been read.
+                                ports.send(port).ok();
                             }
                             continue;
                         }
@@ -170,3 +259,29 @@ fn check_sandbox(line: &str, needs_setup: &mut bool) -> (bool, Option<u16>) {
     }
     (false, None)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cmds::Network;
+    use structopt::StructOpt;
+
+    #[test]
+    fn mem_shared_network_is_read_from_config() {
+        let path = std::env::temp_dir().join(format!("sandbox_{}", nanoid::nanoid!()));
+        std::fs::create_dir(&path).unwrap();
+        let mut config = create_config(path.clone());
+        write_config(path.clone(), &config);
+        assert_eq!(mem_shared_network(path.clone()).unwrap(), None);
+
+        let network =
+            Network::from_iter_safe(&["network", "--mem-shared-network", "demo", "mem"]).unwrap();
+        config.network = Some(network.into());
+        write_config(path.clone(), &config);
+        assert_eq!(
+            mem_shared_network(path.clone()).unwrap(),
+            Some("demo".to_string())
+        );
+        std::fs::remove_dir_all(path).unwrap();
+    }
+}
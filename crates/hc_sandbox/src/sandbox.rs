@@ -4,6 +4,7 @@ use std::path::PathBuf;
 
 use holochain_types::prelude::InstalledAppId;
 
+use crate::calls::AppCells;
 use crate::calls::InstallAppBundle;
 use crate::cmds::*;
 use crate::run::run_async;
@@ -12,15 +13,17 @@ use crate::CmdRunner;
 /// Generates a new sandbox with a default [`ConductorConfig`]
 /// and optional network.
 /// Then installs the dnas with a new app per dna.
+/// Returns the sandbox path and the cells of the installed app.
 pub async fn default_with_network(
     holochain_path: &Path,
     create: Create,
     directory: Option<PathBuf>,
     happ: PathBuf,
     app_id: InstalledAppId,
-) -> anyhow::Result<PathBuf> {
-    let Create { network, root, .. } = create;
-    let path = crate::generate::generate(network.map(|n| n.into_inner().into()), root, directory)?;
+) -> anyhow::Result<(PathBuf, AppCells)> {
+    let network = create.kitsune_config()?;
+    let Create { root, .. } = create;
+    let path = crate::generate::generate(network, root, directory)?;
     let conductor = run_async(holochain_path, path.clone(), None).await?;
     let mut cmd = CmdRunner::new(conductor.0).await;
     let install_bundle = InstallAppBundle {
@@ -28,9 +31,11 @@ pub async fn default_with_network(
         agent_key: None,
         path: happ,
         uid: None,
+        membrane_proof: Vec::new(),
+        expect_hash: Vec::new(),
     };
-    crate::calls::install_app_bundle(&mut cmd, install_bundle).await?;
-    Ok(path)
+    let app = crate::calls::install_app_bundle(&mut cmd, install_bundle).await?;
+    Ok((path, AppCells::from(&app)))
 }
 
 /// Same as [`default_with_network`] but creates n copies
@@ -40,15 +45,16 @@ pub async fn default_n(
     create: Create,
     happ: PathBuf,
     app_id: InstalledAppId,
-) -> anyhow::Result<Vec<PathBuf>> {
+) -> anyhow::Result<Vec<(PathBuf, AppCells)>> {
+    create.verify_network().await?;
     let num_sandboxes = create.num_sandboxes;
     msg!(
         "Creating {} conductor sandboxes with same settings",
         num_sandboxes
     );
-    let mut paths = Vec::with_capacity(num_sandboxes);
+    let mut sandboxes = Vec::with_capacity(num_sandboxes);
     for i in 0..num_sandboxes {
-        let p = default_with_network(
+        let sandbox = default_with_network(
             holochain_path,
             create.clone(),
             create.directories.get(i).cloned(),
@@ -56,8 +62,11 @@ pub async fn default_n(
             app_id.clone(),
         )
         .await?;
-        paths.push(p);
+        sandboxes.push(sandbox);
     }
-    msg!("Created {:?}", paths);
-    Ok(paths)
+    msg!(
+        "Created {:?}",
+        sandboxes.iter().map(|(p, _)| p).collect::<Vec<_>>()
+    );
+    Ok(sandboxes)
 }
@@ -5,6 +5,12 @@
 use std::path::Path;
 use std::path::PathBuf;
 
+use anyhow::ensure;
+use anyhow::Context;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::calls::AppCells;
 use crate::config;
 use crate::config::CONDUCTOR_CONFIG;
 
@@ -86,6 +92,97 @@ pub fn load(mut hc_dir: PathBuf) -> anyhow::Result<Vec<PathBuf>> {
     Ok(paths)
 }
 
+/// The sandboxes made by a single `create` or `generate`.
+/// This is written to the `--discovery-file` so a later
+/// `call --from` can target exactly these sandboxes
+/// instead of whatever is saved in the current directory.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Discovery {
+    /// The sandboxes in the order they were created.
+    pub sandboxes: Vec<DiscoveredSandbox>,
+}
+
+/// A sandbox listed in a [`Discovery`] file.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DiscoveredSandbox {
+    /// The sandbox directory.
+    pub path: PathBuf,
+    /// The admin port the sandbox's conductor bound when it was last run.
+    /// `None` if it hasn't been run since the file was written.
+    pub admin_port: Option<u16>,
+    /// The apps installed when the sandbox was created.
+    pub apps: Vec<AppCells>,
+}
+
+impl Discovery {
+    /// List sandboxes that aren't running yet.
+    /// Their admin ports are only known once
+    /// the conductors report the ports they bound,
+    /// see [`Discovery::set_admin_ports`].
+    pub fn new<I>(sandboxes: I) -> Self
+    where
+        I: IntoIterator<Item = (PathBuf, Vec<AppCells>)>,
+    {
+        let sandboxes = sandboxes
+            .into_iter()
+            .map(|(path, apps)| DiscoveredSandbox {
+                path,
+                admin_port: None,
+                apps,
+            })
+            .collect();
+        Self { sandboxes }
+    }
+
+    /// Record the admin ports bound by running conductors.
+    /// Sandboxes that aren't listed yet are added without any apps.
+    pub fn set_admin_ports<I>(&mut self, ports: I)
+    where
+        I: IntoIterator<Item = (PathBuf, u16)>,
+    {
+        for (path, port) in ports {
+            match self.sandboxes.iter_mut().find(|s| s.path == path) {
+                Some(sandbox) => sandbox.admin_port = Some(port),
+                None => self.sandboxes.push(DiscoveredSandbox {
+                    path,
+                    admin_port: Some(port),
+                    apps: Vec::new(),
+                }),
+            }
+        }
+    }
+
+    /// The sandbox directories.
+    pub fn paths(&self) -> Vec<PathBuf> {
+        self.sandboxes.iter().map(|s| s.path.clone()).collect()
+    }
+}
+
+/// Write the discovery file as JSON, replacing any existing file.
+pub fn save_discovery(path: &Path, discovery: &Discovery) -> anyhow::Result<()> {
+    std::fs::write(path, serde_json::to_string_pretty(discovery)?)
+        .with_context(|| format!("Failed to write discovery file {}", path.display()))?;
+    Ok(())
+}
+
+/// Load a discovery file.
+/// Fails if any of the sandboxes in it no longer exist.
+pub fn load_discovery(path: &Path) -> anyhow::Result<Discovery> {
+    let json = std::fs::read_to_string(path)
+        .with_context(|| format!("Failed to read discovery file {}", path.display()))?;
+    let discovery: Discovery = serde_json::from_str(&json)
+        .with_context(|| format!("Failed to parse discovery file {}", path.display()))?;
+    for sandbox in &discovery.sandboxes {
+        ensure!(
+            sandbox.path.join(CONDUCTOR_CONFIG).exists(),
+            "Sandbox {} from discovery file {} no longer exists",
+            sandbox.path.display(),
+            path.display()
+        );
+    }
+    Ok(discovery)
+}
+
 /// Print out the sandboxes contained in the `.hc` file.
 pub fn list(hc_dir: PathBuf, verbose: usize) -> anyhow::Result<()> {
     let out = load(hc_dir)?.into_iter().enumerate().try_fold(
@@ -200,3 +297,46 @@ pub async fn release_ports(hc_dir: PathBuf) -> anyhow::Result<()> {
     }
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use holochain_types::prelude::*;
+
+    #[test]
+    fn discovery_file_round_trips_and_checks_sandboxes() {
+        let dir = std::env::temp_dir().join(format!("hc_discovery_{}", nanoid::nanoid!()));
+        let sandbox = dir.join("sandbox");
+        std::fs::create_dir_all(&sandbox).unwrap();
+        let cell_id = CellId::new(
+            DnaHash::from_raw_32(vec![1; 32]),
+            AgentPubKey::from_raw_32(vec![2; 32]),
+        );
+        let apps = vec![AppCells {
+            installed_app_id: "app".to_string(),
+            cells: vec![("chat".to_string(), cell_id)],
+            clone_cells: Vec::new(),
+        }];
+        let mut discovery = Discovery::new(vec![(sandbox.clone(), apps)]);
+        // The sandbox hasn't been run so there is no admin port yet.
+        assert_eq!(discovery.sandboxes[0].admin_port, None);
+        let other = dir.join("other");
+        discovery.set_admin_ports(vec![(sandbox.clone(), 9000), (other.clone(), 9001)]);
+        assert_eq!(discovery.sandboxes[0].admin_port, Some(9000));
+        assert_eq!(discovery.sandboxes[1].path, other);
+        assert_eq!(discovery.sandboxes[1].admin_port, Some(9001));
+        assert!(discovery.sandboxes[1].apps.is_empty());
+        discovery.sandboxes.pop();
+        assert_eq!(discovery.paths(), vec![sandbox.clone()]);
+
+        let file = dir.join("discovery.json");
+        save_discovery(&file, &discovery).unwrap();
+        // The sandbox has no conductor config so it's stale.
+        assert!(load_discovery(&file).is_err());
+        std::fs::write(sandbox.join(CONDUCTOR_CONFIG), "").unwrap();
+        assert_eq!(load_discovery(&file).unwrap(), discovery);
+        assert!(load_discovery(&dir.join("missing.json")).is_err());
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
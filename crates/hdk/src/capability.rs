@@ -103,7 +103,7 @@ pub fn create_cap_claim(cap_claim_entry: CapClaimEntry) -> ExternResult<HeaderHa
 /// extern at one time, or may apply in addition to a stricter grant. In this case, revoking a
 /// stricter grant, or failing to revoke all Unrestricted grants will leave the function open.
 ///
-/// @todo administration functions to query active grants
+/// Use [ `query_cap_grants` ] to list the grants that are currently active.
 ///
 /// There is an apparent "chicken or the egg" situation where [ `CapGrant` ] are required for remote
 /// agents to call externs, so how does an agent request a grant in the first place?
@@ -143,6 +143,46 @@ pub fn delete_cap_grant(hash: HeaderHash) -> ExternResult<HeaderHash> {
     delete(hash)
 }
 
+/// List the capability grants that are currently active on the local source chain.
+///
+/// Wraps the [ `query` ] HDK function to find every [ `CapGrant` ] create and update that has not
+/// been deleted or replaced by a later update, as per [ `delete_cap_grant` ] and
+/// [ `update_cap_grant` ]. Each grant is returned with the [ `HeaderHash` ] that can be passed to
+/// those functions to revoke or roll it.
+///
+/// Grants are returned in chain order, including any grants committed earlier in the same call.
+///
+/// The implicit capability the authoring agent has on its own externs is not a grant on the chain
+/// so it is never listed.
+pub fn query_cap_grants() -> ExternResult<Vec<(HeaderHash, CapGrantEntry)>> {
+    let grants = query(
+        ChainQueryFilter::new()
+            .entry_type(EntryType::CapGrant)
+            .include_entries(true),
+    )?;
+    let deletes = query(ChainQueryFilter::new().header_type(HeaderType::Delete))?;
+    let revoked: HashSet<HeaderHash> = deletes
+        .iter()
+        .chain(grants.iter())
+        .filter_map(|element| match element.header() {
+            Header::Delete(delete) => Some(delete.deletes_address.clone()),
+            Header::Update(update) => Some(update.original_header_address.clone()),
+            _ => None,
+        })
+        .collect();
+    Ok(grants
+        .into_iter()
+        .filter(|element| !revoked.contains(element.header_address()))
+        .filter_map(|element| {
+            let header_hash = element.header_address().clone();
+            element
+                .entry()
+                .to_grant_option()
+                .map(|grant| (header_hash, grant))
+        })
+        .collect())
+}
+
 /// Generate secrets for capability grants.
 ///
 /// Wraps the [ `random_bytes` ] HDK function with appropriate parameters set.
@@ -27,3 +27,26 @@ pub fn get_agent_activity(
 pub fn query(filter: ChainQueryFilter) -> ExternResult<Vec<Element>> {
     HDK.with(|h| h.borrow().query(filter))
 }
+
+/// Close the source chain so the agent can migrate to the DNA with the given hash.
+///
+/// Commits a [ `CloseChain` ] header that declares the new DNA as the migration path.
+/// The `migrate_agent` callbacks of every zome are run with [ `MigrateAgent::Close` ] first
+/// and if any of them fail nothing is committed and the error is returned.
+/// Nothing else can be committed to the chain once it is closed.
+///
+/// Moving data to the new chain is up to the happ, e.g. by calling the old cell from the new one.
+pub fn close_chain(new_dna_hash: DnaHash) -> ExternResult<HeaderHash> {
+    HDK.with(|h| h.borrow().close_chain(new_dna_hash))
+}
+
+/// Open the source chain of an agent migrating from the DNA with the given hash.
+///
+/// Commits an [ `OpenChain` ] header that declares the previous DNA as the migration path.
+/// The `migrate_agent` callbacks of every zome are run with [ `MigrateAgent::Open` ] first
+/// and if any of them fail nothing is committed and the error is returned.
+/// The chain can only be opened straight after genesis and zome initialization,
+/// before anything else is committed.
+pub fn open_chain(prev_dna_hash: DnaHash) -> ExternResult<HeaderHash> {
+    HDK.with(|h| h.borrow().open_chain(prev_dna_hash))
+}
@@ -205,6 +205,11 @@ where
 ///       contacts on their current network partition, there could always be an older live entry
 ///       on another partition, and of course the oldest live entry could be deleted and no longer
 ///       be live.
+///
+/// Note: [ `GetOptions::latest` ] and [ `GetOptions::content` ] answer from local data when the
+///       agent is an authority for the hash or authored it. Use [ `GetOptions::network` ] to
+///       always ask the network when the local copy might be stale, or
+///       [ `GetOptions::network_only` ] to ignore local data entirely.
 pub fn get<H>(hash: H, options: GetOptions) -> ExternResult<Option<Element>>
 where
     AnyDhtHash: From<H>,
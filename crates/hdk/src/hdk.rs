@@ -23,6 +23,8 @@ pub trait HdkT: Send + Sync {
         get_agent_activity_input: GetAgentActivityInput,
     ) -> ExternResult<AgentActivity>;
     fn query(&self, filter: ChainQueryFilter) -> ExternResult<Vec<Element>>;
+    fn close_chain(&self, new_dna_hash: DnaHash) -> ExternResult<HeaderHash>;
+    fn open_chain(&self, prev_dna_hash: DnaHash) -> ExternResult<HeaderHash>;
     // Ed25519
     fn sign(&self, sign: Sign) -> ExternResult<Signature>;
     fn sign_ephemeral(&self, sign_ephemeral: SignEphemeral) -> ExternResult<EphemeralSignatures>;
@@ -94,6 +96,12 @@ impl HdkT for ErrHdk {
     fn query(&self, _: ChainQueryFilter) -> ExternResult<Vec<Element>> {
         Self::err()
     }
+    fn close_chain(&self, _: DnaHash) -> ExternResult<HeaderHash> {
+        Self::err()
+    }
+    fn open_chain(&self, _: DnaHash) -> ExternResult<HeaderHash> {
+        Self::err()
+    }
     fn sign(&self, _: Sign) -> ExternResult<Signature> {
         Self::err()
     }
@@ -227,6 +235,12 @@ impl HdkT for HostHdk {
     fn query(&self, filter: ChainQueryFilter) -> ExternResult<Vec<Element>> {
         host_call::<ChainQueryFilter, Vec<Element>>(__query, filter)
     }
+    fn close_chain(&self, new_dna_hash: DnaHash) -> ExternResult<HeaderHash> {
+        host_call::<DnaHash, HeaderHash>(__close_chain, new_dna_hash)
+    }
+    fn open_chain(&self, prev_dna_hash: DnaHash) -> ExternResult<HeaderHash> {
+        host_call::<DnaHash, HeaderHash>(__open_chain, prev_dna_hash)
+    }
 
     fn sign(&self, sign: Sign) -> ExternResult<Signature> {
         host_call::<Sign, Signature>(__sign, sign)
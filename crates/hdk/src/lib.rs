@@ -152,6 +152,7 @@
 //!   - Close runs when an agent is deprecating an old source chain in favour of a new one
 //!   - All zomes in a DNA migrate at the same time
 //!   - Any failure fails the migration
+//!   - Runs when `close_chain` or `open_chain` is called, before the header is committed
 //! - `fn post_commit(headers: Vec<HeaderHash>) -> ExternResult<PostCommitCallbackResult>`:
 //!   - Allows the guest a final veto to entry commits or to perform side effects in response
 //!   - Executes after the wasm call that originated the commits so not bound by the original atomic transaction
@@ -397,6 +398,9 @@ pub use paste;
 /// Agent activity is retrieved from a dedicated neighbourhood centered around the agent.
 /// The agent's neighbourhood also maintains a passive security net that guards against attempted chain forks and/or rollbacks.
 /// The same query DSL for local chain queries is used to filter remote agent activity headers.
+///
+/// An agent can migrate to a new DNA by closing their chain with `close_chain` and opening a chain in the new DNA with `open_chain`.
+/// Both run the `migrate_agent` callbacks first so any zome can refuse the migration.
 pub mod chain;
 
 /// Create and verify signatures for serializable Rust structures and raw binary data.
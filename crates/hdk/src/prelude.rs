@@ -3,8 +3,11 @@ pub use crate::capability::create_cap_claim;
 pub use crate::capability::create_cap_grant;
 pub use crate::capability::delete_cap_grant;
 pub use crate::capability::generate_cap_secret;
+pub use crate::capability::query_cap_grants;
 pub use crate::capability::update_cap_grant;
+pub use crate::chain::close_chain;
 pub use crate::chain::get_agent_activity;
+pub use crate::chain::open_chain;
 pub use crate::chain::query;
 pub use crate::ed25519::sign;
 pub use crate::ed25519::sign_ephemeral;
@@ -62,6 +65,7 @@ pub use hdk_derive::hdk_extern;
 pub use holo_hash;
 pub use holo_hash::AgentPubKey;
 pub use holo_hash::AnyDhtHash;
+pub use holo_hash::DnaHash;
 pub use holo_hash::EntryHash;
 pub use holo_hash::EntryHashes;
 pub use holo_hash::HasHash;
@@ -117,6 +121,8 @@ macro_rules! holochain_externs {
             __delete_link,
             __update,
             __delete,
+            __close_chain,
+            __open_chain,
             __schedule,
             __sleep,
             __x_salsa20_poly1305_encrypt,
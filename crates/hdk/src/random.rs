@@ -9,6 +9,8 @@ use crate::prelude::*;
 /// It's not possible to generate random bytes from inside the wasm guest so the data is provided
 /// by the wasm host which implies operating system specific details re: randomness.
 ///
+/// The host returns an error if more than 1MiB of bytes are requested in a single call.
+///
 /// The bytes are cryptographically random in that they are unpredictable, to the quality of what
 /// host environment offers and the crypto implementation within holochain.
 ///
@@ -65,7 +65,7 @@ pub fn sys_time() -> ExternResult<core::time::Duration> {
     HDK.with(|h| h.borrow().sys_time(()))
 }
 
-/// @todo Not implemented
+/// @todo Not implemented, always returns an error.
 pub fn schedule(execute_after: std::time::Duration) -> ExternResult<()> {
     HDK.with(|h| h.borrow().schedule(execute_after))
 }
@@ -33,9 +33,12 @@ struct Opt {
     #[structopt(
         short = "c",
         long,
-        help = "Path to a YAML file containing conductor configuration"
+        number_of_values = 1,
+        help = "Path to a YAML file containing conductor configuration.
+    Can be repeated to run a conductor for each config in this process,
+    so conductors using the memory transport can reach each other"
     )]
-    config_path: Option<PathBuf>,
+    config_path: Vec<PathBuf>,
 
     #[structopt(
         short = "i",
@@ -63,8 +66,22 @@ async fn async_main() {
 
     kitsune_p2p_types::metrics::init_sys_info_poll();
 
-    let conductor =
-        conductor_handle_from_config_path(opt.config_path.clone(), opt.interactive).await;
+    let config_paths = if opt.config_path.is_empty() {
+        vec![None]
+    } else {
+        opt.config_path.into_iter().map(Some).collect()
+    };
+    if opt.interactive && config_paths.len() > 1 {
+        println!("Interactive mode only supports a single config path");
+        std::process::exit(ERROR_CODE);
+    }
+
+    // Initialize the conductors one at a time so their setup
+    // is printed in the same order as the config paths.
+    let mut conductors = Vec::with_capacity(config_paths.len());
+    for config_path in config_paths {
+        conductors.push(conductor_handle_from_config_path(config_path, opt.interactive).await);
+    }
 
     info!("Conductor successfully initialized.");
 
@@ -79,15 +96,20 @@ async fn async_main() {
     #[cfg(unix)]
     let _ = notify(true, &[NotifyState::Ready]);
 
-    // Await on the main JoinHandle, keeping the process alive until all
+    // Await on the main JoinHandles, keeping the process alive until all
     // Conductor activity has ceased
-    let result = conductor
-        .take_shutdown_handle()
-        .await
-        .expect("The shutdown handle has already been taken.")
-        .await;
-
-    handle_shutdown(result);
+    let mut shutdown_handles = Vec::with_capacity(conductors.len());
+    for conductor in &conductors {
+        shutdown_handles.push(
+            conductor
+                .take_shutdown_handle()
+                .await
+                .expect("The shutdown handle has already been taken."),
+        );
+    }
+    for result in futures::future::join_all(shutdown_handles).await {
+        handle_shutdown(result);
+    }
 
     // TODO: on SIGINT/SIGKILL, kill the conductor:
     // conductor.kill().await
@@ -56,6 +56,34 @@ impl RealAdminInterfaceApi {
     pub(crate) fn new(conductor_handle: ConductorHandle) -> Self {
         RealAdminInterfaceApi { conductor_handle }
     }
+
+    /// Activate an app and create its cells.
+    /// Returns the error response if this app's cells failed to be created.
+    async fn enable_app(
+        &self,
+        installed_app_id: InstalledAppId,
+    ) -> ConductorApiResult<Option<AdminResponse>> {
+        // Activate app
+        self.conductor_handle
+            .activate_app(installed_app_id.clone())
+            .await?;
+
+        // Create cells
+        let errors = self.conductor_handle.clone().setup_cells().await?;
+
+        // Check if this app was created successfully
+        Ok(errors
+            .into_iter()
+            // We only care about this app for the activate command
+            .find(|cell_error| match cell_error {
+                CreateAppError::Failed {
+                    installed_app_id: error_app_id,
+                    ..
+                } => error_app_id == &installed_app_id,
+            })
+            // There was an error in this app so return it
+            .map(|this_app_error| AdminResponse::Error(this_app_error.into())))
+    }
 }
 
 #[async_trait::async_trait]
@@ -67,11 +95,12 @@ impl AdminInterfaceApi for RealAdminInterfaceApi {
         use AdminRequest::*;
         match request {
             AddAdminInterfaces(configs) => {
-                self.conductor_handle
+                let ports = self
+                    .conductor_handle
                     .clone()
                     .add_admin_interfaces(configs)
                     .await?;
-                Ok(AdminResponse::AdminInterfacesAdded)
+                Ok(AdminResponse::AdminInterfacesAdded(ports))
             }
             RegisterDna(payload) => {
                 trace!(register_dna_payload = ?payload);
@@ -128,13 +157,20 @@ impl AdminInterfaceApi for RealAdminInterfaceApi {
                 Ok(AdminResponse::DnaRegistered(hash))
             }
             CreateCloneCell(payload) => {
-                let cell_id = payload.cell_id();
-                self.conductor_handle
+                let cell_id = self
+                    .conductor_handle
                     .clone()
                     .create_clone_cell(*payload)
                     .await?;
                 Ok(AdminResponse::CloneCellCreated(cell_id))
             }
+            DeleteCloneCell(payload) => {
+                self.conductor_handle
+                    .clone()
+                    .destroy_clone_cell(*payload)
+                    .await?;
+                Ok(AdminResponse::CloneCellDeleted)
+            }
             InstallApp(payload) => {
                 trace!(?payload.dnas);
                 let InstallAppPayload {
@@ -194,6 +230,22 @@ impl AdminInterfaceApi for RealAdminInterfaceApi {
                 let dna_list = self.conductor_handle.list_dnas().await?;
                 Ok(AdminResponse::DnasListed(dna_list))
             }
+            ListDnaSummaries => {
+                let mut summaries = Vec::new();
+                for hash in self.conductor_handle.list_dnas().await? {
+                    // Skip any dna that was removed since it was listed.
+                    if let Some(dna) = self.conductor_handle.get_dna(&hash).await {
+                        let dna_def = dna.dna_def();
+                        summaries.push(DnaSummary {
+                            hash,
+                            name: dna_def.name.clone(),
+                            uid: dna_def.uid.clone(),
+                            zome_names: dna_def.zomes.iter().map(|(n, _)| n.clone()).collect(),
+                        });
+                    }
+                }
+                Ok(AdminResponse::DnaSummariesListed(summaries))
+            }
             GenerateAgentPubKey => {
                 let agent_pub_key = self
                     .conductor_handle
@@ -211,37 +263,43 @@ impl AdminInterfaceApi for RealAdminInterfaceApi {
                 let app_ids = self.conductor_handle.list_active_apps().await?;
                 Ok(AdminResponse::ActiveAppsListed(app_ids))
             }
-            ActivateApp { installed_app_id } => {
-                // Activate app
-                self.conductor_handle
-                    .activate_app(installed_app_id.clone())
-                    .await?;
-
-                // Create cells
-                let errors = self.conductor_handle.clone().setup_cells().await?;
-
-                // Check if this app was created successfully
-                errors
-                    .into_iter()
-                    // We only care about this app for the activate command
-                    .find(|cell_error| match cell_error {
-                        CreateAppError::Failed {
-                            installed_app_id: error_app_id,
-                            ..
-                        } => error_app_id == &installed_app_id,
-                    })
-                    // There was an error in this app so return it
-                    .map(|this_app_error| Ok(AdminResponse::Error(this_app_error.into())))
-                    // No error, return success
-                    .unwrap_or(Ok(AdminResponse::AppActivated))
+            ListApps { status_filter } => {
+                let apps = self.conductor_handle.list_apps(status_filter).await?;
+                Ok(AdminResponse::AppsListed(apps))
             }
+            ActivateApp { installed_app_id } => Ok(self
+                .enable_app(installed_app_id)
+                .await?
+                .unwrap_or(AdminResponse::AppActivated)),
+            EnableApp { installed_app_id } => Ok(self
+                .enable_app(installed_app_id)
+                .await?
+                .unwrap_or(AdminResponse::AppEnabled)),
             DeactivateApp { installed_app_id } => {
-                // Activate app
                 self.conductor_handle
                     .deactivate_app(installed_app_id.clone())
                     .await?;
                 Ok(AdminResponse::AppDeactivated)
             }
+            DisableApp {
+                installed_app_id,
+                reason,
+            } => {
+                self.conductor_handle
+                    .disable_app(installed_app_id, Some(reason))
+                    .await?;
+                Ok(AdminResponse::AppDisabled)
+            }
+            UninstallApp { installed_app_id } => {
+                let (removed_cells, kept_cells) = self
+                    .conductor_handle
+                    .uninstall_app(installed_app_id)
+                    .await?;
+                Ok(AdminResponse::AppUninstalled {
+                    removed_cells,
+                    kept_cells,
+                })
+            }
             AttachAppInterface { port } => {
                 let port = port.unwrap_or(0);
                 let port = self
@@ -255,17 +313,64 @@ impl AdminInterfaceApi for RealAdminInterfaceApi {
                 let interfaces = self.conductor_handle.list_app_interfaces().await?;
                 Ok(AdminResponse::AppInterfacesListed(interfaces))
             }
-            DumpState { cell_id } => {
-                let state = self.conductor_handle.dump_cell_state(&cell_id).await?;
+            DumpState {
+                cell_id,
+                queue_entries,
+            } => {
+                let state = self
+                    .conductor_handle
+                    .dump_cell_state(&cell_id, queue_entries.map(|n| n as usize))
+                    .await?;
                 Ok(AdminResponse::StateDumped(state))
             }
             AddAgentInfo { agent_infos } => {
-                self.conductor_handle.add_agent_infos(agent_infos).await?;
-                Ok(AdminResponse::AgentInfoAdded)
+                let results = self.conductor_handle.add_agent_infos(agent_infos).await?;
+                Ok(AdminResponse::AgentInfoAdded(results))
             }
-            RequestAgentInfo { cell_id } => {
-                let r = self.conductor_handle.get_agent_infos(cell_id).await?;
-                Ok(AdminResponse::AgentInfoRequested(r))
+            RequestAgentInfo {
+                cell_id,
+                limit,
+                cursor,
+            } => {
+                let page = self
+                    .conductor_handle
+                    .get_agent_infos(cell_id, limit, cursor)
+                    .await?;
+                Ok(AdminResponse::AgentInfoRequested(page))
+            }
+            Ping => Ok(AdminResponse::Pong),
+            // The websocket connection tracks the topics itself
+            // as they only apply to that connection.
+            Subscribe { .. } => Ok(AdminResponse::Subscribed),
+            NetworkStats => {
+                let stats = self.conductor_handle.network_stats().await?;
+                Ok(AdminResponse::NetworkStatsRequested(stats))
+            }
+            TriggerGossip { cell_id } => {
+                self.conductor_handle.trigger_gossip(&cell_id).await?;
+                Ok(AdminResponse::GossipTriggered)
+            }
+            TriggerPublish { cell_id } => {
+                self.conductor_handle.trigger_publish(&cell_id).await?;
+                Ok(AdminResponse::PublishTriggered)
+            }
+            ZomeCallStats { cell_id } => {
+                let stats = self.conductor_handle.zome_call_stats(&cell_id).await?;
+                Ok(AdminResponse::ZomeCallStatsRequested(stats))
+            }
+            ReindexCell { cell_id } => {
+                let report = self.conductor_handle.reindex_cell(&cell_id).await?;
+                Ok(AdminResponse::CellReindexed(report))
+            }
+            ListCapabilityGrants {
+                cell_id,
+                include_revoked,
+            } => {
+                let grants = self
+                    .conductor_handle
+                    .list_capability_grants(&cell_id, include_revoked)
+                    .await?;
+                Ok(AdminResponse::CapabilityGrantsListed(grants))
             }
         }
     }
@@ -377,6 +482,17 @@ mod test {
         let expects = vec![dna_hash.clone()];
         assert_matches!(dna_list, AdminResponse::DnasListed(a) if a == expects);
 
+        let summaries = admin_api
+            .handle_admin_request(AdminRequest::ListDnaSummaries)
+            .await;
+        let expects = vec![DnaSummary {
+            hash: dna_hash.clone(),
+            name: "test".to_string(),
+            uid: uid.to_string(),
+            zome_names: vec![TestWasm::Foo.into()],
+        }];
+        assert_matches!(summaries, AdminResponse::DnaSummariesListed(a) if a == expects);
+
         // register by hash
         let hash_payload = RegisterDnaPayload {
             uid: None,
@@ -547,7 +663,46 @@ mod test {
             .handle_admin_request(AdminRequest::ListCellIds)
             .await;
 
-        assert_matches!(res, AdminResponse::CellIdsListed(v) if v == vec![cell_id2]);
+        assert_matches!(res, AdminResponse::CellIdsListed(v) if v == vec![cell_id2.clone()]);
+
+        let res = admin_api
+            .handle_admin_request(AdminRequest::TriggerGossip {
+                cell_id: Box::new(cell_id2.clone()),
+            })
+            .await;
+        assert_matches!(res, AdminResponse::GossipTriggered);
+        let res = admin_api
+            .handle_admin_request(AdminRequest::TriggerPublish {
+                cell_id: Box::new(cell_id2.clone()),
+            })
+            .await;
+        assert_matches!(res, AdminResponse::PublishTriggered);
+        let res = admin_api
+            .handle_admin_request(AdminRequest::ZomeCallStats {
+                cell_id: Box::new(cell_id2.clone()),
+            })
+            .await;
+        assert_matches!(res, AdminResponse::ZomeCallStatsRequested(v) if v.is_empty());
+        let res = admin_api
+            .handle_admin_request(AdminRequest::ReindexCell {
+                cell_id: Box::new(cell_id2.clone()),
+            })
+            .await;
+        assert_matches!(res, AdminResponse::CellReindexed(r) if r.missing_data == 0);
+        let res = admin_api
+            .handle_admin_request(AdminRequest::ListCapabilityGrants {
+                cell_id: Box::new(cell_id2),
+                include_revoked: true,
+            })
+            .await;
+        assert_matches!(res, AdminResponse::CapabilityGrantsListed(v) if v.is_empty());
+        let missing_cell = CellId::new(dna_hash.clone(), fake_agent_pubkey_1());
+        let res = admin_api
+            .handle_admin_request(AdminRequest::TriggerGossip {
+                cell_id: Box::new(missing_cell),
+            })
+            .await;
+        assert_matches!(res, AdminResponse::Error(_));
 
         // now try to install the happ using the hash
         let _install_response = admin_api
@@ -566,6 +721,90 @@ mod test {
         assert_matches!(res, AdminResponse::ActiveAppsListed(v) if v.contains(&"test-by-path".to_string()) && v.contains(&"test-by-hash".to_string())
         );
 
+        let res = admin_api
+            .handle_admin_request(AdminRequest::DeactivateApp {
+                installed_app_id: "test-by-hash".to_string(),
+            })
+            .await;
+        assert_matches!(res, AdminResponse::AppDeactivated);
+
+        let res = admin_api
+            .handle_admin_request(AdminRequest::ListApps {
+                status_filter: None,
+            })
+            .await;
+        let apps = unwrap_to::unwrap_to!(res => AdminResponse::AppsListed).clone();
+        let apps: Vec<_> = apps
+            .into_iter()
+            .map(|app| (app.installed_app_id, app.active))
+            .collect();
+        assert_eq!(
+            apps,
+            vec![
+                ("test-by-hash".to_string(), false),
+                ("test-by-path".to_string(), true)
+            ]
+        );
+
+        let res = admin_api
+            .handle_admin_request(AdminRequest::ListApps {
+                status_filter: Some(AppStatusFilter::Active),
+            })
+            .await;
+        assert_matches!(res, AdminResponse::AppsListed(v) if v.len() == 1 && v[0].installed_app_id == "test-by-path" && v[0].cell_data.len() == 1);
+
+        let res = admin_api
+            .handle_admin_request(AdminRequest::ListApps {
+                status_filter: Some(AppStatusFilter::Inactive),
+            })
+            .await;
+        assert_matches!(res, AdminResponse::AppsListed(v) if v.len() == 1 && v[0].installed_app_id == "test-by-hash");
+
+        handle.shutdown().await;
+        tokio::time::timeout(std::time::Duration::from_secs(1), shutdown)
+            .await
+            .ok();
+        Ok(())
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn ping() -> Result<()> {
+        observability::test_run().ok();
+        let envs = test_environments();
+        let handle = Conductor::builder().test(&envs).await?;
+        let shutdown = handle.take_shutdown_handle().await.unwrap();
+        let admin_api = RealAdminInterfaceApi::new(handle.clone());
+
+        let res = admin_api.handle_admin_request(AdminRequest::Ping).await;
+        assert_matches!(res, AdminResponse::Pong);
+
+        handle.shutdown().await;
+        tokio::time::timeout(std::time::Duration::from_secs(1), shutdown)
+            .await
+            .ok();
+        Ok(())
+    }
+    #[tokio::test(flavor = "multi_thread")]
+    async fn network_stats() -> Result<()> {
+        observability::test_run().ok();
+        let envs = test_environments();
+        let handle = Conductor::builder().test(&envs).await?;
+        let shutdown = handle.take_shutdown_handle().await.unwrap();
+        let admin_api = RealAdminInterfaceApi::new(handle.clone());
+
+        let res = admin_api
+            .handle_admin_request(AdminRequest::NetworkStats)
+            .await;
+        let stats = match res {
+            AdminResponse::NetworkStatsRequested(stats) => stats,
+            r => panic!("Unexpected response {:?}", r),
+        };
+        // No cells have joined so there has been no gossip.
+        assert_eq!(stats.transport.gossip_rounds, 0);
+        assert!(stats.spaces.is_empty());
+        // The test network has no bootstrap service.
+        assert_eq!(stats.bootstrap_reachable, None);
+
         handle.shutdown().await;
         tokio::time::timeout(std::time::Duration::from_secs(1), shutdown)
             .await
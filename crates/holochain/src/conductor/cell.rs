@@ -16,6 +16,8 @@ use crate::conductor::handle::ConductorHandle;
 use crate::core::queue_consumer::spawn_queue_consumer_tasks;
 use crate::core::queue_consumer::InitialQueueTriggers;
 use crate::core::queue_consumer::QueueTriggers;
+use crate::core::queue_consumer::ValidationReceiptTuning;
+use crate::core::ribosome::error::RibosomeError;
 use crate::core::ribosome::guest_callback::init::InitResult;
 use crate::core::ribosome::real_ribosome::RealRibosome;
 use crate::core::ribosome::ZomeCallInvocation;
@@ -24,6 +26,7 @@ use crate::core::workflow::error::WorkflowError;
 use crate::core::workflow::genesis_workflow::genesis_workflow;
 use crate::core::workflow::incoming_dht_ops_workflow::incoming_dht_ops_workflow;
 use crate::core::workflow::initialize_zomes_workflow;
+use crate::core::workflow::integrate_dht_ops_workflow::reindex_integrated_ops;
 use crate::core::workflow::produce_dht_ops_workflow::dht_op_light::light_to_op;
 use crate::core::workflow::CallZomeWorkflowArgs;
 use crate::core::workflow::CallZomeWorkspace;
@@ -38,14 +41,19 @@ use futures::future::FutureExt;
 use hash_type::AnyDht;
 use holo_hash::*;
 use holochain_cascade::authority;
+use holochain_conductor_api::CapGrantInfo;
+use holochain_conductor_api::CellReindexReport;
+use holochain_conductor_api::ZomeFnCallStats;
 use holochain_lmdb::db::GetDb;
 use holochain_lmdb::env::EnvironmentRead;
 use holochain_lmdb::env::EnvironmentWrite;
 use holochain_lmdb::env::ReadManager;
+use holochain_p2p::HolochainP2pCellT;
 use holochain_serialized_bytes::SerializedBytes;
 use holochain_state::prelude::*;
 use holochain_types::prelude::*;
 use observability::OpenSpanExt;
+use std::collections::HashMap;
 use std::hash::Hash;
 use std::hash::Hasher;
 use tokio::sync;
@@ -55,6 +63,11 @@ use validation_package::ValidationPackageDb;
 
 mod validation_package;
 
+/// The most zome functions a cell keeps call stats for.
+/// Calls to functions beyond this aren't recorded so callers can't
+/// grow the stats without bound.
+const MAX_ZOME_CALL_STATS: usize = 1000;
+
 #[allow(missing_docs)]
 pub mod error;
 
@@ -101,6 +114,7 @@ where
     env: EnvironmentWrite,
     holochain_p2p_cell: P2pCell,
     queue_triggers: QueueTriggers,
+    zome_call_stats: parking_lot::Mutex<HashMap<(ZomeName, FunctionName), ZomeFnCallStats>>,
 }
 
 impl Cell {
@@ -119,6 +133,7 @@ impl Cell {
         holochain_p2p_cell: holochain_p2p::HolochainP2pCell,
         managed_task_add_sender: sync::mpsc::Sender<ManagedTaskAdd>,
         managed_task_stop_broadcaster: sync::broadcast::Sender<()>,
+        validation_receipt_tuning: ValidationReceiptTuning,
     ) -> CellResult<(Self, InitialQueueTriggers)> {
         let conductor_api = CellConductorApi::new(conductor_handle.clone(), id.clone());
 
@@ -135,6 +150,7 @@ impl Cell {
                 conductor_api.clone(),
                 managed_task_add_sender,
                 managed_task_stop_broadcaster,
+                validation_receipt_tuning,
             )
             .await;
 
@@ -145,6 +161,7 @@ impl Cell {
                     env,
                     holochain_p2p_cell,
                     queue_triggers,
+                    zome_call_stats: Default::default(),
                 },
                 initial_queue_triggers,
             ))
@@ -552,32 +569,40 @@ impl Cell {
         authority::handle_get_agent_activity(env.into(), agent, query, options).map_err(Into::into)
     }
 
-    /// a remote agent is sending us a validation receipt.
+    /// a remote agent is sending us a batch of validation receipts.
     #[tracing::instrument(skip(self))]
     async fn handle_validation_receipt(&self, receipt: SerializedBytes) -> CellResult<()> {
-        let receipt: SignedValidationReceipt = receipt.try_into()?;
+        // Older peers send a single receipt instead of a bundle.
+        let receipts = match ValidationReceiptBundle::try_from(receipt.clone()) {
+            Ok(ValidationReceiptBundle(receipts)) => receipts,
+            Err(_) => vec![SignedValidationReceipt::try_from(receipt)?],
+        };
 
-        // Add to authored
         let db = self.env.get_db(&*AUTHORED_DHT_OPS)?;
         let mut authored_dht_ops: AuthoredDhtOpsStore =
             KvBufFresh::new(self.env.clone().into(), db);
-        match authored_dht_ops.get(&receipt.receipt.dht_op_hash)? {
-            Some(mut auth) => {
-                auth.receipt_count += 1;
-                authored_dht_ops.put(receipt.receipt.dht_op_hash.clone(), auth)?;
-            }
-            None => {
-                warn!(
-                    "Got receipt {:?} but it's missing from authored db so throwing receipt away",
-                    receipt
-                );
-                return Err(CellError::OpMissingForReceipt(receipt.receipt.dht_op_hash));
+        let mut receipts_db = ValidationReceiptsBuf::new(&self.env)?;
+        let mut missing = None;
+        for receipt in receipts {
+            // Add to authored
+            match authored_dht_ops.get(&receipt.receipt.dht_op_hash)? {
+                Some(mut auth) => {
+                    auth.receipt_count += 1;
+                    authored_dht_ops.put(receipt.receipt.dht_op_hash.clone(), auth)?;
+                }
+                None => {
+                    warn!(
+                        "Got receipt {:?} but it's missing from authored db so throwing receipt away",
+                        receipt
+                    );
+                    missing.get_or_insert(receipt.receipt.dht_op_hash);
+                    continue;
+                }
             }
-        }
 
-        // Add to receipts db
-        let mut receipts_db = ValidationReceiptsBuf::new(&self.env)?;
-        receipts_db.add_if_unique(receipt)?;
+            // Add to receipts db
+            receipts_db.add_if_unique(receipt)?;
+        }
 
         // Write to db
         self.env.with_commit(|w| {
@@ -586,7 +611,10 @@ impl Cell {
             DatabaseResult::Ok(())
         })?;
 
-        Ok(())
+        match missing {
+            Some(dht_op_hash) => Err(CellError::OpMissingForReceipt(dht_op_hash)),
+            None => Ok(()),
+        }
     }
 
     #[instrument(skip(self, dht_arc, since, until))]
@@ -692,6 +720,46 @@ impl Cell {
         // Check if init has run if not run it
         self.check_or_run_zome_init().await?;
 
+        let start = std::time::Instant::now();
+        let zome_name = call.zome_name.clone();
+        let fn_name = call.fn_name.clone();
+        let result = self.call_zome_inner(call, workspace_lock).await;
+        self.record_zome_call(zome_name, fn_name, start.elapsed(), &result);
+        result
+    }
+
+    /// Add a call to the stats.
+    /// Calls to zomes or functions that don't exist aren't recorded
+    /// and only a successful response counts as a success.
+    fn record_zome_call(
+        &self,
+        zome_name: ZomeName,
+        fn_name: FunctionName,
+        elapsed: std::time::Duration,
+        result: &CellResult<ZomeCallResult>,
+    ) {
+        let failed = match result {
+            Ok(Ok(ZomeCallResponse::Ok(_))) => false,
+            Ok(Err(RibosomeError::ZomeNotExists(_)))
+            | Ok(Err(RibosomeError::ZomeFnNotExists(_, _))) => return,
+            _ => true,
+        };
+        let mut stats = self.zome_call_stats.lock();
+        let key = (zome_name, fn_name);
+        if !stats.contains_key(&key) && stats.len() >= MAX_ZOME_CALL_STATS {
+            return;
+        }
+        stats
+            .entry(key.clone())
+            .or_insert_with(|| ZomeFnCallStats::new(key.0, key.1))
+            .record(elapsed, failed);
+    }
+
+    async fn call_zome_inner(
+        &self,
+        call: ZomeCall,
+        workspace_lock: Option<CallZomeWorkspaceLock>,
+    ) -> CellResult<ZomeCallResult> {
         let arc = self.env();
         let keystore = arc.keystore().clone();
 
@@ -718,7 +786,7 @@ impl Cell {
             workspace_lock,
             self.holochain_p2p_cell.clone(),
             keystore,
-            arc.clone().into(),
+            arc.clone(),
             args,
             self.queue_triggers.produce_dht_ops.clone(),
         )
@@ -803,6 +871,79 @@ impl Cell {
         &self.env
     }
 
+    /// Run a round of network gossip for this cell's dna now, and the
+    /// workflows that process ops received through gossip or publish.
+    /// Sys validation is triggered and it triggers app validation and
+    /// integration in turn.
+    pub(crate) async fn trigger_gossip_workflows(&self) -> CellResult<()> {
+        self.holochain_p2p_cell.clone().trigger_gossip().await?;
+        self.queue_triggers.sys_validation.clone().trigger();
+        Ok(())
+    }
+
+    /// Run the publish workflow now.
+    /// Ops that were published recently are still skipped
+    /// until their publish interval has passed.
+    pub(crate) fn trigger_publish_workflow(&self) {
+        self.queue_triggers.publish_dht_ops.clone().trigger();
+    }
+
+    /// The calls to each zome function since the cell was created,
+    /// sorted by zome name and then function name.
+    pub(crate) fn zome_call_stats(&self) -> Vec<ZomeFnCallStats> {
+        let mut stats: Vec<_> = self.zome_call_stats.lock().values().cloned().collect();
+        stats.sort_by(|a, b| (&a.zome_name, &a.fn_name).cmp(&(&b.zome_name, &b.fn_name)));
+        stats
+    }
+
+    /// Integrate all the integrated ops again to rebuild the
+    /// element and metadata stores.
+    /// Integration waits until this is done and `progress` is called
+    /// with the ops done so far and the total as it goes.
+    /// Sys validation is triggered afterwards so any ops that were
+    /// waiting on rebuilt data can make their way through the workflows.
+    pub(crate) async fn reindex<F>(&self, progress: F) -> CellResult<CellReindexReport>
+    where
+        F: FnMut(usize, usize) + Send + 'static,
+    {
+        let guard = self
+            .queue_triggers
+            .integration_lock
+            .clone()
+            .lock_owned()
+            .await;
+        let env = self.env.clone();
+        let report = tokio::task::spawn_blocking(move || {
+            // The guard moves here so it's held until the reindex is
+            // done even if this call is dropped
+            let _guard = guard;
+            reindex_integrated_ops(env, progress)
+        })
+        .await?
+        .map_err(Box::new)?;
+        self.queue_triggers.sys_validation.clone().trigger();
+        Ok(report)
+    }
+
+    /// The cap grants committed to this cell's source chain in chain order.
+    /// Grants that have been updated or deleted are only
+    /// included if `include_revoked` is set.
+    pub(crate) fn cap_grants(&self, include_revoked: bool) -> CellResult<Vec<CapGrantInfo>> {
+        let source_chain = SourceChain::new(self.env.clone().into())?;
+        Ok(source_chain
+            .cap_grants()?
+            .into_iter()
+            .filter(|(_, _, revoked)| include_revoked || !revoked)
+            .map(|(header_hash, grant, revoked)| CapGrantInfo {
+                header_hash,
+                access: (&grant.access).into(),
+                tag: grant.tag,
+                functions: grant.functions,
+                revoked,
+            })
+            .collect())
+    }
+
     #[cfg(any(test, feature = "test_utils"))]
     /// Get the triggers for the cell
     /// Useful for testing when you want to
@@ -45,18 +45,11 @@ async fn gossip_test() {
     let bob_cell_id = &bob_call_data.cell_id;
 
     // Give gossip some time to finish
-    const NUM_ATTEMPTS: usize = 100;
-    const DELAY_PER_ATTEMPT: std::time::Duration = std::time::Duration::from_millis(100);
+    const DEADLINE: std::time::Duration = std::time::Duration::from_secs(10);
 
     // 13 ops per anchor plus 7 for genesis + 2 for init + 2 for cap
     let expected_count = NUM * 13 + 7 * 2 + 2 + 2;
-    wait_for_integration(
-        &bob_call_data.env,
-        expected_count,
-        NUM_ATTEMPTS,
-        DELAY_PER_ATTEMPT.clone(),
-    )
-    .await;
+    wait_for_integration(&bob_call_data.env, expected_count, DEADLINE).await;
 
     // Bob list anchors
     let invocation = new_zome_call(
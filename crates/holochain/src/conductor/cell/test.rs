@@ -45,6 +45,7 @@ async fn test_cell_handle_publish() {
         holochain_p2p_cell,
         add_task_sender,
         stop_tx.clone(),
+        Default::default(),
     )
     .await
     .unwrap();
@@ -32,9 +32,10 @@ use super::manager::ManagedTaskHandle;
 use super::manager::TaskManagerRunHandle;
 use super::manager::TaskOutcome;
 use super::p2p_store;
-use super::p2p_store::all_agent_infos;
+use super::p2p_store::agent_info_page;
 use super::p2p_store::get_single_agent_info;
 use super::p2p_store::inject_agent_infos;
+use super::p2p_store::AgentKvKey;
 use super::paths::EnvironmentRootPath;
 use super::state::AppInterfaceId;
 use super::state::ConductorState;
@@ -47,6 +48,7 @@ use crate::conductor::config::ConductorConfig;
 use crate::conductor::error::ConductorResult;
 use crate::conductor::handle::ConductorHandle;
 use crate::core::queue_consumer::InitialQueueTriggers;
+use crate::core::queue_consumer::ValidationReceiptTuning;
 use crate::core::workflow::integrate_dht_ops_workflow;
 pub use builder::*;
 use fallible_iterator::FallibleIterator;
@@ -54,6 +56,9 @@ use futures::future;
 use futures::future::TryFutureExt;
 use futures::stream::StreamExt;
 use holo_hash::DnaHash;
+use holochain_conductor_api::AdminEvent;
+use holochain_conductor_api::AgentInfoCursor;
+use holochain_conductor_api::AgentInfoPage;
 use holochain_conductor_api::JsonDump;
 use holochain_keystore::lair_keystore::spawn_lair_keystore;
 use holochain_keystore::test_keystore::spawn_test_keystore;
@@ -74,6 +79,7 @@ use holochain_state::wasm::WasmBuf;
 use holochain_types::prelude::*;
 use kitsune_p2p::agent_store::AgentInfoSigned;
 use std::collections::HashMap;
+use std::collections::HashSet;
 use std::sync::Arc;
 use tokio::sync::mpsc;
 use tokio::sync::RwLock;
@@ -142,6 +148,10 @@ where
     /// By sending on this channel,
     managed_task_stop_broadcaster: StopBroadcaster,
 
+    /// Broadcasts [AdminEvent]s to every admin interface connection,
+    /// which forward the ones their client has subscribed to
+    admin_event_tx: tokio::sync::broadcast::Sender<AdminEvent>,
+
     /// The main task join handle to await on.
     /// The conductor is intended to live as long as this task does.
     task_manager_run_handle: Option<TaskManagerRunHandle>,
@@ -155,6 +165,10 @@ where
     /// The root environment directory where all environments are created
     root_env_dir: EnvironmentRootPath,
 
+    /// How each cell's validation receipt workflow paces
+    /// and batches the receipts it sends
+    validation_receipt_tuning: ValidationReceiptTuning,
+
     /// Handle to the network actor.
     holochain_p2p: holochain_p2p::HolochainP2pRef,
 }
@@ -236,22 +250,25 @@ where
     }
 
     /// Spawn all admin interface tasks, register them with the TaskManager,
-    /// and modify the conductor accordingly, based on the config passed in
+    /// and modify the conductor accordingly, based on the config passed in.
+    /// Returns the ports that were actually bound, in the same order as the configs.
     pub(super) async fn add_admin_interfaces_via_handle(
         &mut self,
         configs: Vec<AdminInterfaceConfig>,
         handle: ConductorHandle,
-    ) -> ConductorResult<()>
+    ) -> ConductorResult<Vec<u16>>
     where
         DS: DnaStore + 'static,
     {
         let admin_api = RealAdminInterfaceApi::new(handle);
         let stop_tx = self.managed_task_stop_broadcaster.clone();
+        let admin_event_tx = self.admin_event_tx.clone();
 
         // Closure to process each admin config item
         let spawn_from_config = |AdminInterfaceConfig { driver, .. }| {
             let admin_api = admin_api.clone();
             let stop_tx = stop_tx.clone();
+            let admin_event_tx = admin_event_tx.clone();
             async move {
                 match driver {
                     InterfaceDriver::Websocket { port } => {
@@ -261,6 +278,7 @@ where
                             listener_handle,
                             listener,
                             admin_api.clone(),
+                            admin_event_tx,
                             stop_tx.subscribe(),
                         )?;
                         InterfaceResult::Ok((port, handle))
@@ -279,9 +297,8 @@ where
         // Exit if the admin interfaces fail to be created
         let handles = handles.map_err(Box::new)?;
 
+        let mut ports = Vec::new();
         {
-            let mut ports = Vec::new();
-
             // First, register the keepalive task, to ensure the conductor doesn't shut down
             // in the absence of other "real" tasks
             self.manage_task(ManagedTaskAdd::ignore(tokio::spawn(keep_alive_task(
@@ -303,11 +320,11 @@ where
                 ))
                 .await?
             }
-            for p in ports {
-                self.add_admin_port(p);
+            for p in ports.iter() {
+                self.add_admin_port(*p);
             }
         }
-        Ok(())
+        Ok(ports)
     }
 
     pub(super) async fn add_app_interface_via_handle(
@@ -535,6 +552,7 @@ where
                                 holochain_p2p_cell,
                                 self.managed_task_add_sender.clone(),
                                 self.managed_task_stop_broadcaster.clone(),
+                                self.validation_receipt_tuning,
                             )
                             .await
                         },
@@ -591,6 +609,7 @@ where
         app: InstalledApp,
     ) -> ConductorResult<()> {
         trace!(?app);
+        let installed_app_id = app.installed_app_id().clone();
         self.update_state(move |mut state| {
             debug!(?app);
             let is_active = state.active_apps.contains_key(app.installed_app_id());
@@ -604,6 +623,7 @@ where
             }
         })
         .await?;
+        self.emit_admin_event(AdminEvent::AppInstalled(installed_app_id));
         Ok(())
     }
 
@@ -612,22 +632,29 @@ where
         &mut self,
         installed_app_id: InstalledAppId,
     ) -> ConductorResult<()> {
-        self.update_state(move |mut state| {
-            let app = state
-                .inactive_apps
-                .remove(&installed_app_id)
-                .ok_or_else(|| ConductorError::AppNotInstalled(installed_app_id.clone()))?;
-            state.active_apps.insert(app);
-            Ok(state)
+        self.update_state({
+            let installed_app_id = installed_app_id.clone();
+            move |mut state| {
+                let app = state
+                    .inactive_apps
+                    .remove(&installed_app_id)
+                    .ok_or_else(|| ConductorError::AppNotInstalled(installed_app_id.clone()))?;
+                state.disabled_reasons.remove(&installed_app_id);
+                state.active_apps.insert(app);
+                Ok(state)
+            }
         })
         .await?;
+        self.emit_admin_event(AdminEvent::AppActivated(installed_app_id));
         Ok(())
     }
 
-    /// Deactivate an app in the database
+    /// Deactivate an app in the database,
+    /// recording why it was disabled if there is a reason
     pub(super) async fn deactivate_app_in_db(
         &mut self,
         installed_app_id: InstalledAppId,
+        reason: Option<String>,
     ) -> ConductorResult<Vec<CellId>> {
         let state = self
             .update_state({
@@ -637,11 +664,22 @@ where
                         .active_apps
                         .remove(&installed_app_id)
                         .ok_or_else(|| ConductorError::AppNotActive(installed_app_id.clone()))?;
+                    match reason {
+                        Some(reason) => {
+                            state
+                                .disabled_reasons
+                                .insert(installed_app_id.clone(), reason);
+                        }
+                        None => {
+                            state.disabled_reasons.remove(&installed_app_id);
+                        }
+                    }
                     state.inactive_apps.insert(app);
                     Ok(state)
                 }
             })
             .await?;
+        self.emit_admin_event(AdminEvent::AppDeactivated(installed_app_id.clone()));
         Ok(state
             .inactive_apps
             .get(&installed_app_id)
@@ -652,18 +690,54 @@ where
             .collect())
     }
 
+    /// Remove an app from the database, whether it is active or inactive.
+    /// Returns the app's cells which are no longer used by any other app
+    /// followed by the cells which are still used by other apps.
+    pub(super) async fn remove_app_from_db(
+        &mut self,
+        installed_app_id: InstalledAppId,
+    ) -> ConductorResult<(Vec<CellId>, Vec<CellId>)> {
+        let (_, cells) = self
+            .update_state_prime({
+                let installed_app_id = installed_app_id.clone();
+                move |mut state| {
+                    let app = state
+                        .active_apps
+                        .remove(&installed_app_id)
+                        .or_else(|| state.inactive_apps.remove(&installed_app_id))
+                        .ok_or_else(|| ConductorError::AppNotInstalled(installed_app_id.clone()))?;
+                    state.disabled_reasons.remove(&installed_app_id);
+                    let still_used: HashSet<_> = state
+                        .active_apps
+                        .values()
+                        .chain(state.inactive_apps.values())
+                        .flat_map(|app| app.all_cells())
+                        .collect();
+                    let (kept, removed): (Vec<CellId>, Vec<CellId>) = app
+                        .all_cells()
+                        .cloned()
+                        .partition(|cell_id| still_used.contains(cell_id));
+                    Ok((state, (removed, kept)))
+                }
+            })
+            .await?;
+        self.emit_admin_event(AdminEvent::AppUninstalled(installed_app_id));
+        Ok(cells)
+    }
+
     /// Add fully constructed cells to the cell map in the Conductor
     pub(super) fn add_cells(&mut self, cells: Vec<Cell>) {
         for cell in cells {
             let cell_id = cell.id().clone();
             tracing::info!(?cell_id, "ADD CELL");
             self.cells.insert(
-                cell_id,
+                cell_id.clone(),
                 CellItem {
                     cell: Arc::new(cell),
                     _state: CellState { _active: false },
                 },
             );
+            self.emit_admin_event(AdminEvent::CellAdded(cell_id));
         }
     }
 
@@ -710,6 +784,36 @@ where
         Ok(cell_id)
     }
 
+    /// Remove a cloned Cell from an App.
+    /// Returns true if no other App uses the Cell
+    /// so it can be removed from the conductor.
+    pub(super) async fn remove_clone_cell_from_app(
+        &mut self,
+        installed_app_id: &InstalledAppId,
+        slot_id: &SlotId,
+        cell_id: &CellId,
+    ) -> ConductorResult<bool> {
+        let (_, unused) = self
+            .update_state_prime(|mut state| {
+                let app = state
+                    .active_apps
+                    .get_mut(installed_app_id)
+                    .ok_or_else(|| ConductorError::AppNotActive(installed_app_id.clone()))?;
+                if !app.remove_clone(slot_id, cell_id)? {
+                    return Err(AppError::CloneCellMissing(slot_id.clone(), cell_id.clone()).into());
+                }
+                let unused = !state
+                    .active_apps
+                    .values()
+                    .chain(state.inactive_apps.values())
+                    .flat_map(|app| app.all_cells())
+                    .any(|c| c == cell_id);
+                Ok((state, unused))
+            })
+            .await?;
+        Ok(unused)
+    }
+
     pub(super) async fn load_wasms_into_dna_files(
         &self,
     ) -> ConductorResult<(
@@ -757,10 +861,25 @@ where
     /// Remove cells from the cell map in the Conductor
     pub(super) fn remove_cells(&mut self, cell_ids: Vec<CellId>) {
         for cell_id in cell_ids {
-            self.cells.remove(&cell_id);
+            if self.cells.remove(&cell_id).is_some() {
+                self.emit_admin_event(AdminEvent::CellRemoved(cell_id));
+            }
         }
     }
 
+    /// Send an event to any admin connections subscribed to its topic.
+    /// It's fine for there to be no connections to receive it.
+    fn emit_admin_event(&self, event: AdminEvent) {
+        trace!(?event, "Admin event");
+        let _ = self.admin_event_tx.send(event);
+    }
+
+    /// A sender for admin events that can be used without the conductor,
+    /// i.e. to report progress from a blocking task
+    pub(super) fn admin_event_sender(&self) -> tokio::sync::broadcast::Sender<AdminEvent> {
+        self.admin_event_tx.clone()
+    }
+
     pub(super) fn add_agent_infos(
         &self,
         agent_infos: Vec<AgentInfoSigned>,
@@ -771,15 +890,32 @@ where
     pub(super) fn get_agent_infos(
         &self,
         cell_id: Option<CellId>,
-    ) -> ConductorApiResult<Vec<AgentInfoSigned>> {
+        limit: Option<u32>,
+        cursor: Option<AgentInfoCursor>,
+    ) -> ConductorApiResult<AgentInfoPage> {
         match cell_id {
             Some(c) => {
                 let (d, a) = c.into_dna_and_agent();
-                Ok(get_single_agent_info(self.p2p_env.clone().into(), d, a)?
+                let agent_infos = get_single_agent_info(self.p2p_env.clone().into(), d, a)?
                     .map(|a| vec![a])
-                    .unwrap_or_default())
+                    .unwrap_or_default();
+                Ok(AgentInfoPage {
+                    agent_infos,
+                    next_cursor: None,
+                })
+            }
+            None => {
+                let cursor = cursor.map(|c| AgentKvKey::try_from(&c)).transpose()?;
+                let (agent_infos, next_cursor) = agent_info_page(
+                    self.p2p_env.clone().into(),
+                    cursor,
+                    limit.map(|l| l as usize),
+                )?;
+                Ok(AgentInfoPage {
+                    agent_infos,
+                    next_cursor: next_cursor.map(Into::into),
+                })
             }
-            None => Ok(all_agent_infos(self.p2p_env.clone().into())?),
         }
     }
 
@@ -834,7 +970,11 @@ where
         Ok(active_apps.keys().cloned().collect())
     }
 
-    pub(super) async fn dump_cell_state(&self, cell_id: &CellId) -> ConductorApiResult<String> {
+    pub(super) async fn dump_cell_state(
+        &self,
+        cell_id: &CellId,
+        queue_entries: Option<usize>,
+    ) -> ConductorApiResult<String> {
         let cell = self.cell_by_id(cell_id)?;
         let arc = cell.env();
         let source_chain = SourceChainBuf::new(arc.clone().into())?;
@@ -842,11 +982,15 @@ where
         let peer_dump = p2p_store::dump_state(self.p2p_env.clone().into(), Some(cell_id.clone()))?;
         let source_chain_dump = source_chain.dump_state().await?;
         let integration_dump = integrate_dht_ops_workflow::dump_state(arc.clone().into())?;
+        let queue_dump = queue_entries
+            .map(|max| integrate_dht_ops_workflow::dump_queues(arc.clone().into(), max))
+            .transpose()?;
 
         let out = JsonDump {
             peer_dump,
             source_chain_dump,
             integration_dump,
+            queue_dump,
         };
         // Add summary
         let summary = out.to_string();
@@ -854,6 +998,18 @@ where
         Ok(serde_json::to_string_pretty(&out)?)
     }
 
+    /// The agents of the local cells grouped by the space they are in.
+    pub(super) fn local_agents_by_dna(&self) -> HashMap<DnaHash, Vec<AgentPubKey>> {
+        let mut local_agents: HashMap<DnaHash, Vec<AgentPubKey>> = HashMap::new();
+        for cell_id in self.cells.keys() {
+            local_agents
+                .entry(cell_id.dna_hash().clone())
+                .or_default()
+                .push(cell_id.agent_pubkey().clone());
+        }
+        local_agents
+    }
+
     pub(super) fn p2p_env(&self) -> EnvironmentWrite {
         self.p2p_env.clone()
     }
@@ -910,6 +1066,9 @@ where
         let (task_tx, task_manager_run_handle) = spawn_task_manager();
         let task_manager_run_handle = Some(task_manager_run_handle);
         let (stop_tx, _) = tokio::sync::broadcast::channel::<()>(1);
+        // This receiver is thrown away because each admin
+        // connection subscribes to the Sender
+        let (admin_event_tx, _) = tokio::sync::broadcast::channel(SIGNAL_BUFFER_SIZE);
         Ok(Self {
             env,
             wasm_env,
@@ -920,12 +1079,14 @@ where
             app_interfaces: HashMap::new(),
             managed_task_add_sender: task_tx,
             managed_task_stop_broadcaster: stop_tx,
+            admin_event_tx,
             task_manager_run_handle,
             admin_websocket_ports: Vec::new(),
             dna_store,
             keystore,
             root_env_dir,
             holochain_p2p,
+            validation_receipt_tuning: ValidationReceiptTuning::default(),
         })
     }
 
@@ -1115,10 +1276,21 @@ mod builder {
         }
 
         async fn finish(
-            conductor: Conductor<DS>,
+            mut conductor: Conductor<DS>,
             conductor_config: ConductorConfig,
             p2p_evt: holochain_p2p::event::HolochainP2pEventReceiver,
         ) -> ConductorResult<ConductorHandle> {
+            let tuning = &mut conductor.validation_receipt_tuning;
+            if let Some(delay) = conductor_config.validation_receipt_retrigger_delay_ms {
+                tuning.retrigger_delay = std::time::Duration::from_millis(delay);
+            }
+            if let Some(size) = conductor_config.validation_receipt_max_batch_size {
+                tuning.batching.max_batch_size = size;
+            }
+            if let Some(delay) = conductor_config.validation_receipt_max_batch_delay_ms {
+                tuning.batching.max_delay = std::time::Duration::from_millis(delay);
+            }
+
             // Get data before handle
             let keystore = conductor.keystore.clone();
             let holochain_p2p = conductor.holochain_p2p.clone();
@@ -112,6 +112,82 @@ async fn can_add_clone_cell_to_app() {
     );
 }
 
+#[tokio::test(flavor = "multi_thread")]
+async fn can_remove_clone_cell_from_app() {
+    let envs = test_environments();
+    let keystore = envs.conductor().keystore().clone();
+    let holochain_p2p = holochain_p2p::stub_network().await;
+
+    let agent = fixt!(AgentPubKey);
+    let dna = fake_valid_dna_file("");
+    let cell_id = CellId::new(dna.dna_hash().to_owned(), agent.clone());
+
+    let dna_store = RealDnaStore::new();
+
+    let mut conductor = Conductor::new(
+        envs.conductor(),
+        envs.wasm(),
+        envs.p2p(),
+        dna_store,
+        keystore,
+        envs.tempdir().path().to_path_buf().into(),
+        holochain_p2p,
+    )
+    .await
+    .unwrap();
+
+    let slot = AppSlot::new(cell_id.clone(), true, 2);
+    let app = InstalledApp::new("clones", agent, vec![("nick".into(), slot)]);
+
+    conductor.register_phenotype(dna).await.unwrap();
+    conductor
+        .update_state(|mut state| {
+            state.active_apps.insert(app.clone());
+            Ok(state)
+        })
+        .await
+        .unwrap();
+
+    let app_id = "clones".to_string();
+    let nick = "nick".to_string();
+    let clone_a = conductor
+        .add_clone_cell_to_app(&app_id, &nick, ().into())
+        .await
+        .unwrap();
+    let clone_b = conductor
+        .add_clone_cell_to_app(&app_id, &nick, ().into())
+        .await
+        .unwrap();
+
+    assert!(conductor
+        .remove_clone_cell_from_app(&app_id, &nick, &clone_a)
+        .await
+        .unwrap());
+
+    // The original cell and the other clone are untouched
+    let state = conductor.get_state().await.unwrap();
+    let app = state.active_apps.get("clones").unwrap();
+    assert_eq!(
+        app.cloned_cells().cloned().collect::<Vec<CellId>>(),
+        vec![clone_b]
+    );
+    assert!(app.all_cells().any(|c| *c == cell_id));
+
+    // The base cell is not a clone so can't be removed this way
+    matches::assert_matches!(
+        conductor
+            .remove_clone_cell_from_app(&app_id, &nick, &cell_id)
+            .await,
+        Err(ConductorError::AppError(AppError::CloneCellMissing(_, _)))
+    );
+    matches::assert_matches!(
+        conductor
+            .remove_clone_cell_from_app(&app_id, &nick, &clone_a)
+            .await,
+        Err(ConductorError::AppError(AppError::CloneCellMissing(_, _)))
+    );
+}
+
 /// App can't be installed if another app is already installed under the
 /// same InstalledAppId
 #[tokio::test(flavor = "multi_thread")]
@@ -156,6 +232,135 @@ async fn app_ids_are_unique() {
     );
 }
 
+/// Uninstalling an app only removes the cells that no other app uses
+#[tokio::test(flavor = "multi_thread")]
+async fn uninstall_app_keeps_shared_cells() {
+    let environments = test_environments();
+    let dna_store = MockDnaStore::new();
+    let holochain_p2p = holochain_p2p::stub_network().await;
+    let mut conductor = Conductor::new(
+        environments.conductor(),
+        environments.wasm(),
+        environments.p2p(),
+        dna_store,
+        environments.keystore().clone(),
+        environments.tempdir().path().to_path_buf().into(),
+        holochain_p2p,
+    )
+    .await
+    .unwrap();
+
+    let shared = fake_cell_id(1);
+    let own = fake_cell_id(2);
+    let app1 = InstalledApp::new_legacy(
+        "app1",
+        vec![
+            InstalledCell::new(shared.clone(), "shared".to_string()),
+            InstalledCell::new(own.clone(), "own".to_string()),
+        ],
+    )
+    .unwrap();
+    let app2 = InstalledApp::new_legacy(
+        "app2",
+        vec![InstalledCell::new(shared.clone(), "shared".to_string())],
+    )
+    .unwrap();
+
+    conductor.add_inactive_app_to_db(app1).await.unwrap();
+    conductor.add_inactive_app_to_db(app2).await.unwrap();
+    conductor
+        .activate_app_in_db("app1".to_string())
+        .await
+        .unwrap();
+
+    let (removed, kept) = conductor
+        .remove_app_from_db("app1".to_string())
+        .await
+        .unwrap();
+    assert_eq!(removed, vec![own]);
+    assert_eq!(kept, vec![shared.clone()]);
+
+    let state = conductor.get_state().await.unwrap();
+    assert_eq!(state.active_apps.keys().count(), 0);
+    assert_eq!(
+        state.inactive_apps.keys().collect::<Vec<_>>(),
+        vec![&"app2".to_string()]
+    );
+
+    assert_matches!(
+        conductor.remove_app_from_db("app1".to_string()).await,
+        Err(ConductorError::AppNotInstalled(id))
+        if id == "app1".to_string()
+    );
+
+    //- once the last app using a cell is gone the cell is removed
+    let (removed, kept) = conductor
+        .remove_app_from_db("app2".to_string())
+        .await
+        .unwrap();
+    assert_eq!(removed, vec![shared]);
+    assert!(kept.is_empty());
+}
+
+/// Disabling an app records why until it is activated again
+#[tokio::test(flavor = "multi_thread")]
+async fn disabled_reason_is_recorded_and_cleared() {
+    let environments = test_environments();
+    let dna_store = MockDnaStore::new();
+    let holochain_p2p = holochain_p2p::stub_network().await;
+    let mut conductor = Conductor::new(
+        environments.conductor(),
+        environments.wasm(),
+        environments.p2p(),
+        dna_store,
+        environments.keystore().clone(),
+        environments.tempdir().path().to_path_buf().into(),
+        holochain_p2p,
+    )
+    .await
+    .unwrap();
+
+    let app = InstalledApp::new_legacy(
+        "app",
+        vec![InstalledCell::new(fake_cell_id(1), "cell".to_string())],
+    )
+    .unwrap();
+    conductor.add_inactive_app_to_db(app).await.unwrap();
+    conductor
+        .activate_app_in_db("app".to_string())
+        .await
+        .unwrap();
+    conductor
+        .deactivate_app_in_db("app".to_string(), Some("maintenance".to_string()))
+        .await
+        .unwrap();
+
+    let info = conductor
+        .get_state()
+        .await
+        .unwrap()
+        .get_app_info(&"app".to_string())
+        .unwrap();
+    assert!(!info.active);
+    assert_eq!(info.disabled_reason, Some("maintenance".to_string()));
+
+    conductor
+        .activate_app_in_db("app".to_string())
+        .await
+        .unwrap();
+    let state = conductor.get_state().await.unwrap();
+    assert!(state.disabled_reasons.is_empty());
+    assert_eq!(state.list_apps(None)[0].disabled_reason, None);
+
+    //- deactivating without a reason doesn't record one
+    conductor
+        .deactivate_app_in_db("app".to_string(), None)
+        .await
+        .unwrap();
+    let state = conductor.get_state().await.unwrap();
+    assert_eq!(state.list_apps(None)[0].disabled_reason, None);
+}
+
 /// App can't be installed if it contains duplicate CellNicks
 #[tokio::test(flavor = "multi_thread")]
 async fn cell_nicks_are_unique() {
@@ -275,3 +480,50 @@ async fn proxy_tls_inner(
 
     Ok(())
 }
+
+#[tokio::test(flavor = "multi_thread")]
+#[cfg(feature = "slow_tests")]
+async fn zome_call_stats_count_real_calls() {
+    use crate::conductor::api::ZomeCall;
+    use crate::test_utils::sweetest::SweetConductor;
+    use crate::test_utils::sweetest::SweetDnaFile;
+    use holochain_wasm_test_utils::TestWasm;
+
+    let (dna_file, _) = SweetDnaFile::unique_from_test_wasms(vec![TestWasm::WhoAmI])
+        .await
+        .unwrap();
+    let mut conductor = SweetConductor::from_standard_config().await;
+    let app = conductor.setup_app("app", &[dna_file]).await;
+    let cell = app.into_cells().pop().unwrap();
+    let zome = cell.zome(TestWasm::WhoAmI);
+
+    let _: AgentInfo = conductor.call(&zome, "whoami", ()).await;
+    let _: AgentInfo = conductor.call(&zome, "whoami", ()).await;
+
+    // Another agent without a grant is unauthorized.
+    let response = conductor
+        .call_zome(ZomeCall {
+            cell_id: cell.cell_id().clone(),
+            zome_name: zome.name().clone(),
+            fn_name: "whoami".into(),
+            cap: None,
+            provenance: fixt!(AgentPubKey),
+            payload: ExternIO::encode(()).unwrap(),
+        })
+        .await
+        .unwrap()
+        .unwrap();
+    assert_matches!(response, ZomeCallResponse::Unauthorized(..));
+
+    // Functions that don't exist aren't recorded.
+    let missing: ConductorApiResult<()> = conductor.call_fallible(&zome, "not_a_fn", ()).await;
+    assert!(missing.is_err());
+
+    let stats = conductor.zome_call_stats(cell.cell_id()).await.unwrap();
+    assert_eq!(stats.len(), 1);
+    assert_eq!(stats[0].zome_name, zome.name().clone());
+    assert_eq!(stats[0].fn_name, "whoami".into());
+    assert_eq!(stats[0].calls, 3);
+    assert_eq!(stats[0].errors, 1);
+    assert_eq!(stats[0].latency_buckets.iter().sum::<u64>(), 3);
+}
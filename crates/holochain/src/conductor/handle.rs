@@ -48,6 +48,8 @@ use super::error::ConductorResult;
 use super::error::CreateAppError;
 use super::interface::SignalBroadcaster;
 use super::manager::TaskManagerRunHandle;
+use super::p2p_store::check_agent_info;
+use super::p2p_store::count_peers;
 use super::p2p_store::get_agent_info_signed;
 use super::p2p_store::put_agent_info_signed;
 use super::p2p_store::query_agent_info_signed;
@@ -59,7 +61,17 @@ use crate::core::workflow::ZomeCallResult;
 use derive_more::From;
 use futures::future::FutureExt;
 use futures::StreamExt;
+use holochain_conductor_api::AdminEvent;
+use holochain_conductor_api::AgentInfoAddResult;
+use holochain_conductor_api::AgentInfoCursor;
+use holochain_conductor_api::AgentInfoPage;
+use holochain_conductor_api::AppStatusFilter;
+use holochain_conductor_api::CapGrantInfo;
+use holochain_conductor_api::CellReindexReport;
 use holochain_conductor_api::InstalledAppInfo;
+use holochain_conductor_api::NetworkStats;
+use holochain_conductor_api::SpaceStats;
+use holochain_conductor_api::ZomeFnCallStats;
 use holochain_p2p::event::HolochainP2pEvent::*;
 use holochain_p2p::HolochainP2pCellT;
 use holochain_types::prelude::*;
@@ -93,10 +105,13 @@ pub trait ConductorHandleT: Send + Sync {
     /// around having a circular reference in the types.
     ///
     /// Never use a ConductorHandle for different Conductor here!
+    ///
+    /// Returns the ports that were bound, which will differ from the
+    /// configs wherever port `0` was used to let the OS choose.
     async fn add_admin_interfaces(
         self: Arc<Self>,
         configs: Vec<AdminInterfaceConfig>,
-    ) -> ConductorResult<()>;
+    ) -> ConductorResult<Vec<u16>>;
 
     /// Start up persisted app interfaces.
     /// Should only be run once at Conductor initialization.
@@ -171,8 +186,12 @@ pub trait ConductorHandleT: Send + Sync {
         payload: CreateCloneCellPayload,
     ) -> ConductorResult<CellId>;
 
-    /// Destroy a cloned Cell
-    async fn destroy_clone_cell(self: Arc<Self>, cell_id: CellId) -> ConductorResult<()>;
+    /// Destroy a cloned Cell, leaving the Cell it was cloned from
+    /// and any other clones in place
+    async fn destroy_clone_cell(
+        self: Arc<Self>,
+        payload: DeleteCloneCellPayload,
+    ) -> ConductorResult<()>;
 
     /// Install Cells into ConductorState based on installation info, and run
     /// genesis on all new source chains
@@ -198,14 +217,56 @@ pub trait ConductorHandleT: Send + Sync {
     /// Deactivate an app
     async fn deactivate_app(&self, installed_app_id: InstalledAppId) -> ConductorResult<()>;
 
+    /// Deactivate an app and record why it was disabled.
+    /// The reason is cleared when the app is activated again.
+    async fn disable_app(
+        &self,
+        installed_app_id: InstalledAppId,
+        reason: Option<String>,
+    ) -> ConductorResult<()>;
+
+    /// Uninstall an app, whether it is active or inactive.
+    /// Cells that are not used by any other app are removed from the conductor.
+    /// Returns the cells that were removed followed by the cells
+    /// that were kept because other apps still use them.
+    async fn uninstall_app(
+        &self,
+        installed_app_id: InstalledAppId,
+    ) -> ConductorResult<(Vec<CellId>, Vec<CellId>)>;
+
     /// List Cell Ids
     async fn list_cell_ids(&self) -> ConductorResult<Vec<CellId>>;
 
     /// List Active AppIds
     async fn list_active_apps(&self) -> ConductorResult<Vec<InstalledAppId>>;
 
-    /// Dump the cells state
-    async fn dump_cell_state(&self, cell_id: &CellId) -> ConductorApiResult<String>;
+    /// Dump the cells state, optionally with up to
+    /// `queue_entries` ops from each of its op queues
+    async fn dump_cell_state(
+        &self,
+        cell_id: &CellId,
+        queue_entries: Option<usize>,
+    ) -> ConductorApiResult<String>;
+
+    /// Run the workflows that process the ops a cell
+    /// has received through gossip or publish now
+    async fn trigger_gossip(&self, cell_id: &CellId) -> ConductorApiResult<()>;
+
+    /// Run the publish workflow for a cell now
+    async fn trigger_publish(&self, cell_id: &CellId) -> ConductorApiResult<()>;
+
+    /// The calls made to each zome function of a cell
+    async fn zome_call_stats(&self, cell_id: &CellId) -> ConductorApiResult<Vec<ZomeFnCallStats>>;
+
+    /// Rebuild the element and metadata stores of a cell from its integrated ops
+    async fn reindex_cell(&self, cell_id: &CellId) -> ConductorApiResult<CellReindexReport>;
+
+    /// The capability grants committed to a cell's source chain
+    async fn list_capability_grants(
+        &self,
+        cell_id: &CellId,
+        include_revoked: bool,
+    ) -> ConductorApiResult<Vec<CapGrantInfo>>;
 
     /// Access the broadcast Sender which will send a Signal across every
     /// attached app interface
@@ -217,14 +278,31 @@ pub trait ConductorHandleT: Send + Sync {
         installed_app_id: &InstalledAppId,
     ) -> ConductorResult<Option<InstalledAppInfo>>;
 
-    /// Add signed agent info to the conductor
-    async fn add_agent_infos(&self, agent_infos: Vec<AgentInfoSigned>) -> ConductorApiResult<()>;
+    /// Get info about all installed Apps, optionally filtered by status
+    async fn list_apps(
+        &self,
+        status_filter: Option<AppStatusFilter>,
+    ) -> ConductorResult<Vec<InstalledAppInfo>>;
+
+    /// Add signed agent info to the conductor.
+    /// Each agent info is checked on its own and only those that
+    /// are accepted are added.
+    async fn add_agent_infos(
+        &self,
+        agent_infos: Vec<AgentInfoSigned>,
+    ) -> ConductorApiResult<Vec<AgentInfoAddResult>>;
 
-    /// Get signed agent info from the conductor
+    /// Get a page of signed agent info from the conductor.
+    /// Starts after the cursor if one is given.
     async fn get_agent_infos(
         &self,
         cell_id: Option<CellId>,
-    ) -> ConductorApiResult<Vec<AgentInfoSigned>>;
+        limit: Option<u32>,
+        cursor: Option<AgentInfoCursor>,
+    ) -> ConductorApiResult<AgentInfoPage>;
+
+    /// Get a snapshot of the networking activity of the conductor.
+    async fn network_stats(&self) -> ConductorResult<NetworkStats>;
 
     /// Print the current setup in a machine readable way.
     async fn print_setup(&self);
@@ -275,7 +353,7 @@ impl<DS: DnaStore + 'static> ConductorHandleT for ConductorHandleImpl<DS> {
     async fn add_admin_interfaces(
         self: Arc<Self>,
         configs: Vec<AdminInterfaceConfig>,
-    ) -> ConductorResult<()> {
+    ) -> ConductorResult<Vec<u16>> {
         let mut lock = self.conductor.write().await;
         lock.add_admin_interfaces_via_handle(configs, self.clone())
             .await
@@ -468,8 +546,23 @@ impl<DS: DnaStore + 'static> ConductorHandleT for ConductorHandleImpl<DS> {
         }
     }
 
-    async fn destroy_clone_cell(self: Arc<Self>, _cell_id: CellId) -> ConductorResult<()> {
-        todo!()
+    async fn destroy_clone_cell(
+        self: Arc<Self>,
+        payload: DeleteCloneCellPayload,
+    ) -> ConductorResult<()> {
+        let DeleteCloneCellPayload {
+            installed_app_id,
+            slot_id,
+            cell_id,
+        } = payload;
+        let mut conductor = self.conductor.write().await;
+        let unused = conductor
+            .remove_clone_cell_from_app(&installed_app_id, &slot_id, &cell_id)
+            .await?;
+        if unused {
+            conductor.remove_cells(vec![cell_id]);
+        }
+        Ok(())
     }
 
     async fn install_app(
@@ -592,11 +685,19 @@ impl<DS: DnaStore + 'static> ConductorHandleT for ConductorHandleImpl<DS> {
     }
 
     async fn deactivate_app(&self, installed_app_id: InstalledAppId) -> ConductorResult<()> {
+        self.disable_app(installed_app_id, None).await
+    }
+
+    async fn disable_app(
+        &self,
+        installed_app_id: InstalledAppId,
+        reason: Option<String>,
+    ) -> ConductorResult<()> {
         let cell_ids_to_remove = self
             .conductor
             .write()
             .await
-            .deactivate_app_in_db(installed_app_id)
+            .deactivate_app_in_db(installed_app_id, reason)
             .await?;
         // MD: I'm not sure about this. We never add the cells back in after re-activating an app,
         //     so it seems either we shouldn't remove them here, or we should be sure to add them
@@ -608,6 +709,16 @@ impl<DS: DnaStore + 'static> ConductorHandleT for ConductorHandleImpl<DS> {
         Ok(())
     }
 
+    async fn uninstall_app(
+        &self,
+        installed_app_id: InstalledAppId,
+    ) -> ConductorResult<(Vec<CellId>, Vec<CellId>)> {
+        let mut conductor = self.conductor.write().await;
+        let (removed, kept) = conductor.remove_app_from_db(installed_app_id).await?;
+        conductor.remove_cells(removed.clone());
+        Ok((removed, kept))
+    }
+
     async fn list_cell_ids(&self) -> ConductorResult<Vec<CellId>> {
         self.conductor.read().await.list_cell_ids().await
     }
@@ -616,8 +727,60 @@ impl<DS: DnaStore + 'static> ConductorHandleT for ConductorHandleImpl<DS> {
         self.conductor.read().await.list_active_apps().await
     }
 
-    async fn dump_cell_state(&self, cell_id: &CellId) -> ConductorApiResult<String> {
-        self.conductor.read().await.dump_cell_state(cell_id).await
+    async fn dump_cell_state(
+        &self,
+        cell_id: &CellId,
+        queue_entries: Option<usize>,
+    ) -> ConductorApiResult<String> {
+        self.conductor
+            .read()
+            .await
+            .dump_cell_state(cell_id, queue_entries)
+            .await
+    }
+
+    async fn trigger_gossip(&self, cell_id: &CellId) -> ConductorApiResult<()> {
+        self.cell_by_id(cell_id)
+            .await?
+            .trigger_gossip_workflows()
+            .await?;
+        Ok(())
+    }
+
+    async fn trigger_publish(&self, cell_id: &CellId) -> ConductorApiResult<()> {
+        self.cell_by_id(cell_id).await?.trigger_publish_workflow();
+        Ok(())
+    }
+
+    async fn zome_call_stats(&self, cell_id: &CellId) -> ConductorApiResult<Vec<ZomeFnCallStats>> {
+        Ok(self.cell_by_id(cell_id).await?.zome_call_stats())
+    }
+
+    async fn reindex_cell(&self, cell_id: &CellId) -> ConductorApiResult<CellReindexReport> {
+        let cell = self.cell_by_id(cell_id).await?;
+        let events = self.conductor.read().await.admin_event_sender();
+        let cell_id = cell_id.clone();
+        let report = cell
+            .reindex(move |done, total| {
+                let _ = events.send(AdminEvent::CellReindexProgress {
+                    cell_id: cell_id.clone(),
+                    done,
+                    total,
+                });
+            })
+            .await?;
+        Ok(report)
+    }
+
+    async fn list_capability_grants(
+        &self,
+        cell_id: &CellId,
+        include_revoked: bool,
+    ) -> ConductorApiResult<Vec<CapGrantInfo>> {
+        Ok(self
+            .cell_by_id(cell_id)
+            .await?
+            .cap_grants(include_revoked)?)
     }
 
     async fn signal_broadcaster(&self) -> SignalBroadcaster {
@@ -637,15 +800,89 @@ impl<DS: DnaStore + 'static> ConductorHandleT for ConductorHandleImpl<DS> {
             .get_app_info(installed_app_id))
     }
 
-    async fn add_agent_infos(&self, agent_infos: Vec<AgentInfoSigned>) -> ConductorApiResult<()> {
-        self.conductor.read().await.add_agent_infos(agent_infos)
+    async fn list_apps(
+        &self,
+        status_filter: Option<AppStatusFilter>,
+    ) -> ConductorResult<Vec<InstalledAppInfo>> {
+        Ok(self
+            .conductor
+            .read()
+            .await
+            .get_state()
+            .await?
+            .list_apps(status_filter))
+    }
+
+    async fn add_agent_infos(
+        &self,
+        agent_infos: Vec<AgentInfoSigned>,
+    ) -> ConductorApiResult<Vec<AgentInfoAddResult>> {
+        let now_ms = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.as_millis() as u64)
+            .unwrap_or(0);
+        let spaces = self.list_dnas().await?;
+        let mut results = Vec::with_capacity(agent_infos.len());
+        let mut accepted = Vec::with_capacity(agent_infos.len());
+        for agent_info in agent_infos {
+            match check_agent_info(&agent_info, &spaces, now_ms).await {
+                Ok(()) => {
+                    accepted.push(agent_info);
+                    results.push(AgentInfoAddResult::Accepted);
+                }
+                Err(rejection) => {
+                    debug!(%rejection, "Rejected agent info");
+                    results.push(AgentInfoAddResult::Rejected(rejection));
+                }
+            }
+        }
+        self.conductor.read().await.add_agent_infos(accepted)?;
+        Ok(results)
     }
 
     async fn get_agent_infos(
         &self,
         cell_id: Option<CellId>,
-    ) -> ConductorApiResult<Vec<AgentInfoSigned>> {
-        self.conductor.read().await.get_agent_infos(cell_id)
+        limit: Option<u32>,
+        cursor: Option<AgentInfoCursor>,
+    ) -> ConductorApiResult<AgentInfoPage> {
+        self.conductor
+            .read()
+            .await
+            .get_agent_infos(cell_id, limit, cursor)
+    }
+
+    async fn network_stats(&self) -> ConductorResult<NetworkStats> {
+        use holochain_p2p::HolochainP2pSender;
+        // Don't hold the conductor lock across the network calls
+        // because checking the bootstrap service can take seconds.
+        let (local_agents, p2p_env) = {
+            let lock = self.conductor.read().await;
+            (lock.local_agents_by_dna(), lock.p2p_env())
+        };
+        let transport = self.holochain_p2p.get_transport_metrics().await?;
+        let transport_addresses = self.holochain_p2p.list_transport_bindings().await?;
+        let bootstrap_reachable = self.holochain_p2p.check_bootstrap().await?;
+
+        let mut spaces = local_agents
+            .into_iter()
+            .map(|(dna_hash, agents)| {
+                let peers = count_peers(p2p_env.clone().into(), dna_hash.clone(), &agents)?;
+                Ok(SpaceStats {
+                    dna_hash,
+                    local_agents: agents.len() as u32,
+                    peers,
+                })
+            })
+            .collect::<ConductorResult<Vec<_>>>()?;
+        spaces.sort_by(|a, b| a.dna_hash.cmp(&b.dna_hash));
+
+        Ok(NetworkStats {
+            transport,
+            transport_addresses,
+            bootstrap_reachable,
+            spaces,
+        })
     }
 
     async fn print_setup(&self) {
@@ -7,6 +7,10 @@ use crate::conductor::conductor::StopReceiver;
 use crate::conductor::interface::*;
 use crate::conductor::manager::ManagedTaskHandle;
 use crate::conductor::manager::ManagedTaskResult;
+use holochain_conductor_api::AdminEvent;
+use holochain_conductor_api::AdminEventTopic;
+use holochain_conductor_api::AdminRequest;
+use holochain_conductor_api::AdminResponse;
 use holochain_serialized_bytes::SerializedBytes;
 use holochain_types::signal::Signal;
 use holochain_websocket::ListenerHandle;
@@ -16,6 +20,7 @@ use holochain_websocket::WebsocketListener;
 use holochain_websocket::WebsocketMessage;
 use holochain_websocket::WebsocketReceiver;
 use holochain_websocket::WebsocketSender;
+use std::collections::HashSet;
 use std::convert::TryFrom;
 
 use std::sync::atomic::AtomicIsize;
@@ -49,14 +54,18 @@ pub async fn spawn_websocket_listener(
     Ok(listener)
 }
 
-/// Create an Admin Interface, which only receives AdminRequest messages
-/// from the external client
-pub fn spawn_admin_interface_task<A: InterfaceApi>(
+/// Create an Admin Interface, which receives AdminRequest messages
+/// from the external client and sends it any AdminEvents it subscribes to
+pub fn spawn_admin_interface_task<A>(
     handle: ListenerHandle,
     listener: impl futures::stream::Stream<Item = ListenerItem> + Send + 'static,
     api: A,
+    admin_event_broadcaster: broadcast::Sender<AdminEvent>,
     mut stop_rx: StopReceiver,
-) -> InterfaceResult<ManagedTaskHandle> {
+) -> InterfaceResult<ManagedTaskHandle>
+where
+    A: InterfaceApi<ApiRequest = AdminRequest, ApiResponse = AdminResponse>,
+{
     Ok(tokio::task::spawn(async move {
         // Task that will kill the listener and all child connections.
         tokio::task::spawn(
@@ -68,15 +77,18 @@ pub fn spawn_admin_interface_task<A: InterfaceApi>(
         // establish a new connection to a client
         while let Some(connection) = listener.next().await {
             match connection {
-                Ok((_, rx_from_iface)) => {
+                Ok((tx_to_iface, rx_from_iface)) => {
                     if num_connections.fetch_add(1, Ordering::Relaxed) > MAX_CONNECTIONS {
                         // Max connections so drop this connection
                         // which will close it.
                         continue;
                     };
+                    let rx_from_conductor = admin_event_broadcaster.subscribe();
                     tokio::task::spawn(recv_incoming_admin_msgs(
                         api.clone(),
                         rx_from_iface,
+                        rx_from_conductor,
+                        tx_to_iface,
                         num_connections.clone(),
                     ));
                 }
@@ -136,17 +148,54 @@ pub async fn spawn_app_interface_task<A: InterfaceApi>(
     Ok((port, task))
 }
 
-/// Polls for messages coming in from the external client.
+/// Polls for messages coming in from the external client while simultaneously
+/// polling for events from the conductor, which are pushed out across the
+/// interface if the client has subscribed to their topic.
 /// Used by Admin interface.
-async fn recv_incoming_admin_msgs<A: InterfaceApi>(
+async fn recv_incoming_admin_msgs<A>(
     api: A,
     mut rx_from_iface: WebsocketReceiver,
+    mut rx_from_conductor: broadcast::Receiver<AdminEvent>,
+    mut tx_to_iface: WebsocketSender,
     num_connections: Arc<AtomicIsize>,
-) {
-    while let Some(msg) = rx_from_iface.next().await {
-        match handle_incoming_message(msg, api.clone()).await {
-            Err(e) => error!(error = &e as &dyn std::error::Error),
-            Ok(()) => {}
+) where
+    A: InterfaceApi<ApiRequest = AdminRequest, ApiResponse = AdminResponse>,
+{
+    // Nothing is sent until the client subscribes
+    let mut topics = HashSet::new();
+    loop {
+        tokio::select! {
+            event = rx_from_conductor.recv() => {
+                match event {
+                    Ok(event) if topics.contains(&event.topic()) => {
+                        trace!(msg = "Sending admin event", ?event);
+                        if let Err(e) = send_admin_event(&mut tx_to_iface, event).await {
+                            error!(error = &e as &dyn std::error::Error);
+                        }
+                    }
+                    Ok(_) => {}
+                    Err(broadcast::error::RecvError::Lagged(n)) => {
+                        warn!("Admin interface missed {} events", n);
+                    }
+                    Err(broadcast::error::RecvError::Closed) => {
+                        debug!("Closing interface: admin event stream closed");
+                        break;
+                    }
+                }
+            },
+
+            msg = rx_from_iface.next() => {
+                if let Some(msg) = msg {
+                    let result =
+                        handle_incoming_admin_message(msg, api.clone(), &mut topics).await;
+                    if let Err(e) = result {
+                        error!(error = &e as &dyn std::error::Error);
+                    }
+                } else {
+                    debug!("Closing interface: message stream empty");
+                    break;
+                }
+            },
         }
     }
     num_connections.fetch_sub(1, Ordering::SeqCst);
@@ -196,6 +245,36 @@ async fn recv_incoming_msgs_and_outgoing_signals<A: InterfaceApi>(
     Ok(())
 }
 
+/// Push an event out across an admin interface
+async fn send_admin_event(
+    tx_to_iface: &mut WebsocketSender,
+    event: AdminEvent,
+) -> InterfaceResult<()> {
+    Ok(tx_to_iface
+        .signal(SerializedBytes::try_from(event)?)
+        .await?)
+}
+
+/// Handles messages on admin interfaces,
+/// keeping track of the topics this connection has subscribed to
+async fn handle_incoming_admin_message<A>(
+    ws_msg: WebsocketMessage,
+    api: A,
+    topics: &mut HashSet<AdminEventTopic>,
+) -> InterfaceResult<()>
+where
+    A: InterfaceApi<ApiRequest = AdminRequest, ApiResponse = AdminResponse>,
+{
+    let (bytes, respond) = ws_msg;
+    let request: Result<AdminRequest, _> = bytes.try_into();
+    if let Ok(AdminRequest::Subscribe { topics: subscribed }) = &request {
+        *topics = subscribed.iter().copied().collect();
+    }
+    Ok(respond
+        .respond(api.handle_request(request).await?.try_into()?)
+        .await?)
+}
+
 /// Handles messages on all interfaces
 async fn handle_incoming_message<A>(ws_msg: WebsocketMessage, api: A) -> InterfaceResult<()>
 where
@@ -272,9 +351,14 @@ pub mod test {
     use crate::conductor::ConductorHandle;
     use crate::fixt::RealRibosomeFixturator;
     use crate::test_utils::conductor_setup::ConductorTestData;
+    use crate::test_utils::wait_for_integration_1m;
     use ::fixt::prelude::*;
     use fallible_iterator::FallibleIterator;
     use futures::future::FutureExt;
+    use holochain_conductor_api::AgentInfoAddResult;
+    use holochain_conductor_api::AgentInfoRejection;
+    use holochain_conductor_api::JsonDump;
+    use holochain_keystore::AgentPubKeyExt;
     use holochain_lmdb::buffer::KvStoreT;
     use holochain_lmdb::fresh_reader_test;
     use holochain_lmdb::test_utils::test_environments;
@@ -290,8 +374,12 @@ pub mod test {
     use holochain_zome_types::cell::CellId;
     use holochain_zome_types::test_utils::fake_agent_pubkey_2;
     use holochain_zome_types::ExternIO;
+    use kitsune_p2p::agent_store::AgentInfo;
     use kitsune_p2p::agent_store::AgentInfoSigned;
     use kitsune_p2p::fixt::AgentInfoSignedFixturator;
+    use kitsune_p2p::KitsuneAgent;
+    use kitsune_p2p::KitsuneSignature;
+    use kitsune_p2p::KitsuneSpace;
     use matches::assert_matches;
     use mockall::predicate;
     use observability;
@@ -648,13 +736,34 @@ pub mod test {
         let shutdown = conductor_handle.take_shutdown_handle().await.unwrap();
         // Allow agents time to join
         tokio::time::sleep(std::time::Duration::from_secs(2)).await;
+        // Genesis ops must be integrated so the cap has something to cut
+        let env = conductor_handle.get_cell_env(&cell_id).await.unwrap();
+        wait_for_integration_1m(&env, 7).await;
 
         // Get state
-        let expected = conductor_handle.dump_cell_state(&cell_id).await.unwrap();
+        let expected = conductor_handle
+            .dump_cell_state(&cell_id, None)
+            .await
+            .unwrap();
+        let (dump, _): (JsonDump, String) = serde_json::from_str(&expected).unwrap();
+        assert!(dump.queue_dump.is_none());
+        assert!(dump.integration_dump.integrated > 1);
+
+        // Queues are capped
+        let with_queues = conductor_handle
+            .dump_cell_state(&cell_id, Some(1))
+            .await
+            .unwrap();
+        let (dump, _): (JsonDump, String) = serde_json::from_str(&with_queues).unwrap();
+        let queues = dump.queue_dump.unwrap();
+        assert!(queues.validation_limbo.len() <= 1);
+        assert!(queues.integration_limbo.len() <= 1);
+        assert_eq!(queues.integrated.len(), 1);
 
         let admin_api = RealAdminInterfaceApi::new(conductor_handle.clone());
         let msg = AdminRequest::DumpState {
             cell_id: Box::new(cell_id),
+            queue_entries: None,
         };
         let msg = msg.try_into().unwrap();
         let respond = move |bytes: SerializedBytes| {
@@ -720,11 +829,23 @@ pub mod test {
         );
 
         // - Get agents and space
-        let agent_infos = AgentInfoSignedFixturator::new(Unpredictable)
+        // The fixtures aren't signed by their agents so they will be rejected
+        let fake_agent_infos = AgentInfoSignedFixturator::new(Unpredictable)
             .take(5)
             .collect::<Vec<_>>();
+        let real_agent_infos = handle
+            .get_agent_infos(None, None, None)
+            .await
+            .unwrap()
+            .agent_infos;
+        let num_fake = fake_agent_infos.len();
+        let num_real = real_agent_infos.len();
+        let agent_infos = fake_agent_infos
+            .into_iter()
+            .chain(real_agent_infos.into_iter())
+            .collect::<Vec<_>>();
 
-        let mut expect = to_key(agent_infos.clone());
+        let mut expect = Vec::new();
         let k00: AgentKvKey = (dnas[0].clone(), agents[0].clone()).into();
         let k01: AgentKvKey = (dnas[0].clone(), agents[1].clone()).into();
         let k10: AgentKvKey = (dnas[1].clone(), agents[0].clone()).into();
@@ -740,53 +861,239 @@ pub mod test {
         // - Add the agent infos
         let req = AdminRequest::AddAgentInfo { agent_infos };
         let r = make_req(admin_api.clone(), req).await.await.unwrap();
-        assert_matches!(r, AdminResponse::AgentInfoAdded);
+        let added = unwrap_to::unwrap_to!(r => AdminResponse::AgentInfoAdded).clone();
+        assert_eq!(added.len(), num_fake + num_real);
+        for result in &added[..num_fake] {
+            assert_matches!(result, AgentInfoAddResult::Rejected(_));
+        }
+        for result in &added[num_fake..] {
+            assert_eq!(result, &AgentInfoAddResult::Accepted);
+        }
 
         // - Request all the infos
-        let req = AdminRequest::RequestAgentInfo { cell_id: None };
+        let req = AdminRequest::RequestAgentInfo {
+            cell_id: None,
+            limit: None,
+            cursor: None,
+        };
         let r = make_req(admin_api.clone(), req).await.await.unwrap();
-        let results = to_key(unwrap_to::unwrap_to!(r => AdminResponse::AgentInfoRequested).clone());
+        let results = to_key(
+            unwrap_to::unwrap_to!(r => AdminResponse::AgentInfoRequested)
+                .agent_infos
+                .clone(),
+        );
+        assert_eq!(expect, results);
+
+        // - Page through the infos
+        let mut results = Vec::new();
+        let mut cursor = None;
+        loop {
+            let req = AdminRequest::RequestAgentInfo {
+                cell_id: None,
+                limit: Some(3),
+                cursor,
+            };
+            let r = make_req(admin_api.clone(), req).await.await.unwrap();
+            let page = unwrap_to::unwrap_to!(r => AdminResponse::AgentInfoRequested).clone();
+            assert!(page.agent_infos.len() <= 3);
+            results.extend(to_key(page.agent_infos));
+            cursor = page.next_cursor;
+            if cursor.is_none() {
+                break;
+            }
+        }
         assert_eq!(expect, results);
 
         // - Request the dna 0 agent 0
         let req = AdminRequest::RequestAgentInfo {
             cell_id: Some(CellId::new(dnas[0].clone(), agents[0].clone())),
+            limit: None,
+            cursor: None,
         };
         let r = make_req(admin_api.clone(), req).await.await.unwrap();
-        let results = to_key(unwrap_to::unwrap_to!(r => AdminResponse::AgentInfoRequested).clone());
+        let results = to_key(
+            unwrap_to::unwrap_to!(r => AdminResponse::AgentInfoRequested)
+                .agent_infos
+                .clone(),
+        );
 
         assert_eq!(vec![k00], results);
 
         // - Request the dna 0 agent 1
         let req = AdminRequest::RequestAgentInfo {
             cell_id: Some(CellId::new(dnas[0].clone(), agents[1].clone())),
+            limit: None,
+            cursor: None,
         };
         let r = make_req(admin_api.clone(), req).await.await.unwrap();
-        let results = to_key(unwrap_to::unwrap_to!(r => AdminResponse::AgentInfoRequested).clone());
+        let results = to_key(
+            unwrap_to::unwrap_to!(r => AdminResponse::AgentInfoRequested)
+                .agent_infos
+                .clone(),
+        );
 
         assert_eq!(vec![k01], results);
 
         // - Request the dna 1 agent 0
         let req = AdminRequest::RequestAgentInfo {
             cell_id: Some(CellId::new(dnas[1].clone(), agents[0].clone())),
+            limit: None,
+            cursor: None,
         };
         let r = make_req(admin_api.clone(), req).await.await.unwrap();
-        let results = to_key(unwrap_to::unwrap_to!(r => AdminResponse::AgentInfoRequested).clone());
+        let results = to_key(
+            unwrap_to::unwrap_to!(r => AdminResponse::AgentInfoRequested)
+                .agent_infos
+                .clone(),
+        );
 
         assert_eq!(vec![k10], results);
 
         // - Request the dna 1 agent 1
         let req = AdminRequest::RequestAgentInfo {
             cell_id: Some(CellId::new(dnas[1].clone(), agents[1].clone())),
+            limit: None,
+            cursor: None,
         };
         let r = make_req(admin_api.clone(), req).await.await.unwrap();
-        let results = to_key(unwrap_to::unwrap_to!(r => AdminResponse::AgentInfoRequested).clone());
+        let results = to_key(
+            unwrap_to::unwrap_to!(r => AdminResponse::AgentInfoRequested)
+                .agent_infos
+                .clone(),
+        );
 
         assert_eq!(vec![k11], results);
 
         conductor_test.shutdown_conductor().await;
     }
 
+    /// A conductor with a single Dna installed for checking
+    /// how agent info is accepted or rejected.
+    async fn agent_info_conductor() -> (ConductorTestData, RealAdminInterfaceApi, DnaHash) {
+        let dna = make_dna("1", vec![TestWasm::Anchor]).await;
+        let dna_hash = dna.dna_hash().clone();
+        let conductor_test = ConductorTestData::new(
+            test_environments(),
+            vec![dna],
+            vec![fake_agent_pubkey_1()],
+            Default::default(),
+        )
+        .await
+        .0;
+        let admin_api = RealAdminInterfaceApi::new(conductor_test.handle());
+        (conductor_test, admin_api, dna_hash)
+    }
+
+    /// Agent info for a new agent signed with the conductor's keystore.
+    async fn signed_agent_info(
+        handle: &ConductorHandle,
+        space: &DnaHash,
+        signed_at_ms: u64,
+        expires_after_ms: u64,
+    ) -> (AgentPubKey, AgentInfoSigned) {
+        let agent = AgentPubKey::new_from_pure_entropy(handle.keystore())
+            .await
+            .unwrap();
+        let info = AgentInfo::new(
+            KitsuneSpace(space.get_raw_36().to_vec()),
+            KitsuneAgent(agent.get_raw_36().to_vec()),
+            Vec::new(),
+            signed_at_ms,
+            expires_after_ms,
+        );
+        let mut data = Vec::new();
+        kitsune_p2p_types::codec::rmp_encode(&mut data, &info).unwrap();
+        let signature = agent.sign_raw(handle.keystore(), &data).await.unwrap();
+        let signed = AgentInfoSigned::try_new(
+            KitsuneAgent(agent.get_raw_36().to_vec()),
+            KitsuneSignature(signature.0.to_vec()),
+            data,
+        )
+        .unwrap();
+        (agent, signed)
+    }
+
+    fn now_ms() -> u64 {
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_millis() as u64
+    }
+
+    async fn add_and_request(
+        admin_api: &RealAdminInterfaceApi,
+        agent_info: AgentInfoSigned,
+        cell_id: CellId,
+    ) -> (Vec<AgentInfoAddResult>, Vec<AgentInfoSigned>) {
+        let req = AdminRequest::AddAgentInfo {
+            agent_infos: vec![agent_info],
+        };
+        let r = make_req(admin_api.clone(), req).await.await.unwrap();
+        let added = unwrap_to::unwrap_to!(r => AdminResponse::AgentInfoAdded).clone();
+        let req = AdminRequest::RequestAgentInfo {
+            cell_id: Some(cell_id),
+            limit: None,
+            cursor: None,
+        };
+        let r = make_req(admin_api.clone(), req).await.await.unwrap();
+        let stored = unwrap_to::unwrap_to!(r => AdminResponse::AgentInfoRequested)
+            .agent_infos
+            .clone();
+        (added, stored)
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn accepted_agent_info_is_stored() {
+        observability::test_run().ok();
+        let (mut conductor_test, admin_api, dna_hash) = agent_info_conductor().await;
+        let (agent, agent_info) =
+            signed_agent_info(&conductor_test.handle(), &dna_hash, now_ms(), 600_000).await;
+
+        let (added, stored) =
+            add_and_request(&admin_api, agent_info.clone(), CellId::new(dna_hash, agent)).await;
+        assert_eq!(added, vec![AgentInfoAddResult::Accepted]);
+        assert_eq!(stored, vec![agent_info]);
+
+        conductor_test.shutdown_conductor().await;
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn expired_agent_info_is_rejected() {
+        observability::test_run().ok();
+        let (mut conductor_test, admin_api, dna_hash) = agent_info_conductor().await;
+        let (agent, agent_info) =
+            signed_agent_info(&conductor_test.handle(), &dna_hash, 1000, 1000).await;
+
+        let (added, stored) =
+            add_and_request(&admin_api, agent_info, CellId::new(dna_hash, agent)).await;
+        assert_eq!(
+            added,
+            vec![AgentInfoAddResult::Rejected(AgentInfoRejection::Expired)]
+        );
+        assert!(stored.is_empty());
+
+        conductor_test.shutdown_conductor().await;
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn wrong_space_agent_info_is_rejected() {
+        observability::test_run().ok();
+        let (mut conductor_test, admin_api, _) = agent_info_conductor().await;
+        let other_space = fake_dna_hash(99);
+        let (agent, agent_info) =
+            signed_agent_info(&conductor_test.handle(), &other_space, now_ms(), 600_000).await;
+
+        let (added, stored) =
+            add_and_request(&admin_api, agent_info, CellId::new(other_space, agent)).await;
+        assert_eq!(
+            added,
+            vec![AgentInfoAddResult::Rejected(AgentInfoRejection::WrongSpace)]
+        );
+        assert!(stored.is_empty());
+
+        conductor_test.shutdown_conductor().await;
+    }
+
     async fn make_req(
         admin_api: RealAdminInterfaceApi,
         req: AdminRequest,
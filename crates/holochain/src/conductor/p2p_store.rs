@@ -3,8 +3,12 @@
 use fallible_iterator::FallibleIterator;
 use holo_hash::AgentPubKey;
 use holo_hash::DnaHash;
+use holo_hash::HOLO_HASH_UNTYPED_LEN;
+use holochain_conductor_api::AgentInfoCursor;
 use holochain_conductor_api::AgentInfoDump;
+use holochain_conductor_api::AgentInfoRejection;
 use holochain_conductor_api::P2pStateDump;
+use holochain_keystore::AgentPubKeyExt;
 use holochain_lmdb::buffer::KvStore;
 use holochain_lmdb::buffer::KvStoreT;
 use holochain_lmdb::db::GetDb;
@@ -22,6 +26,7 @@ use holochain_p2p::dht_arc::PeerDensity;
 use holochain_p2p::kitsune_p2p::agent_store::AgentInfo;
 use holochain_p2p::kitsune_p2p::agent_store::AgentInfoSigned;
 use holochain_zome_types::CellId;
+use holochain_zome_types::Signature;
 use kitsune_p2p::KitsuneBinType;
 use std::convert::TryFrom;
 use std::convert::TryInto;
@@ -104,6 +109,22 @@ impl From<&[u8]> for AgentKvKey {
     }
 }
 
+impl TryFrom<&AgentInfoCursor> for AgentKvKey {
+    type Error = DatabaseError;
+    fn try_from(cursor: &AgentInfoCursor) -> Result<Self, Self::Error> {
+        if cursor.0.len() != AGENT_KEY_LEN {
+            return Err(DatabaseError::KeyConstruction);
+        }
+        Ok(cursor.0[..].into())
+    }
+}
+
+impl From<AgentKvKey> for AgentInfoCursor {
+    fn from(key: AgentKvKey) -> Self {
+        Self(key.0.to_vec())
+    }
+}
+
 impl AsRef<[u8]> for AgentKvKey {
     fn as_ref(&self) -> &[u8] {
         &self.0
@@ -190,6 +211,53 @@ pub fn inject_agent_infos<I: IntoIterator<Item = AgentInfoSigned> + Send>(
     })?)
 }
 
+/// Check that an agent info is well formed, for one of the `spaces`,
+/// signed by its agent and not expired at `now_ms`
+/// before it is added to the peer store.
+pub async fn check_agent_info(
+    agent_info_signed: &AgentInfoSigned,
+    spaces: &[DnaHash],
+    now_ms: u64,
+) -> Result<(), AgentInfoRejection> {
+    let agent_info: AgentInfo = agent_info_signed
+        .try_into()
+        .map_err(|e: kitsune_p2p::KitsuneP2pError| AgentInfoRejection::Malformed(e.to_string()))?;
+    if agent_info.as_space_ref().len() != HOLO_HASH_UNTYPED_LEN {
+        return Err(AgentInfoRejection::Malformed(
+            "Space is not a DnaHash".to_string(),
+        ));
+    }
+    if agent_info.as_agent_ref().len() != HOLO_HASH_UNTYPED_LEN {
+        return Err(AgentInfoRejection::Malformed(
+            "Agent is not an AgentPubKey".to_string(),
+        ));
+    }
+    let space = DnaHash::from_raw_36(agent_info.as_space_ref().to_vec());
+    if !spaces.contains(&space) {
+        return Err(AgentInfoRejection::WrongSpace);
+    }
+    if agent_info.as_agent_ref() != agent_info_signed.as_agent_ref() {
+        return Err(AgentInfoRejection::BadSignature);
+    }
+    let signature = Signature::try_from(&agent_info_signed.as_signature_ref()[..])
+        .map_err(|_| AgentInfoRejection::BadSignature)?;
+    let agent = AgentPubKey::from_raw_36(agent_info.as_agent_ref().to_vec());
+    let valid = agent
+        .verify_signature_raw(&signature, agent_info_signed.as_agent_info_ref())
+        .await
+        .unwrap_or(false);
+    if !valid {
+        return Err(AgentInfoRejection::BadSignature);
+    }
+    let expires_at_ms = agent_info
+        .signed_at_ms()
+        .saturating_add(agent_info.expires_after_ms());
+    if expires_at_ms <= now_ms {
+        return Err(AgentInfoRejection::Expired);
+    }
+    Ok(())
+}
+
 /// Helper function to get all the peer data from this conductor
 pub fn all_agent_infos(env: EnvironmentRead) -> DatabaseResult<Vec<AgentInfoSigned>> {
     let p2p_store = AgentKv::new(env.clone())?;
@@ -198,6 +266,37 @@ pub fn all_agent_infos(env: EnvironmentRead) -> DatabaseResult<Vec<AgentInfoSign
     })
 }
 
+/// Get up to `limit` agent info from this conductor in key order.
+/// Starts after the `cursor` key when there is one.
+/// Also returns the key to continue from if there are more.
+pub fn agent_info_page(
+    env: EnvironmentRead,
+    cursor: Option<AgentKvKey>,
+    limit: Option<usize>,
+) -> DatabaseResult<(Vec<AgentInfoSigned>, Option<AgentKvKey>)> {
+    let p2p_store = AgentKv::new(env.clone())?;
+    let limit = limit.map(|l| l.max(1));
+    let mut page: Vec<(AgentKvKey, AgentInfoSigned)> = fresh_reader!(env, |r| {
+        let iter = match &cursor {
+            Some(cursor) => p2p_store.as_store_ref().iter_raw_from(&r, cursor.clone())?,
+            None => p2p_store.as_store_ref().iter_raw(&r)?,
+        };
+        // Take one extra to find out if there is another page.
+        iter.map(|(k, v)| Ok((AgentKvKey::from(k), v)))
+            .filter(|(k, _)| Ok(Some(k) != cursor.as_ref()))
+            .take(limit.map_or(usize::MAX, |l| l + 1))
+            .collect()
+    })?;
+    let next_cursor = match limit {
+        Some(limit) if page.len() > limit => {
+            page.truncate(limit);
+            page.last().map(|(k, _)| k.clone())
+        }
+        _ => None,
+    };
+    Ok((page.into_iter().map(|(_, v)| v).collect(), next_cursor))
+}
+
 /// Helper function to get a single agent info
 pub fn get_single_agent_info(
     env: EnvironmentRead,
@@ -208,6 +307,27 @@ pub fn get_single_agent_info(
     fresh_reader!(env, |r| { p2p_store.get_agent_info(&r, space, agent) })
 }
 
+/// Count the agents in the peer store for a space
+/// that aren't one of the `local_agents`.
+pub fn count_peers(
+    env: EnvironmentRead,
+    space: DnaHash,
+    local_agents: &[AgentPubKey],
+) -> DatabaseResult<u32> {
+    let space = holochain_p2p::space_holo_to_kit(space);
+    let local_agents: Vec<_> = local_agents
+        .iter()
+        .cloned()
+        .map(holochain_p2p::agent_holo_to_kit)
+        .collect();
+    let count = all_agent_infos(env)?
+        .iter()
+        .filter_map(|a| AgentInfo::try_from(a).ok())
+        .filter(|a| *a.as_space_ref() == space && !local_agents.contains(a.as_agent_ref()))
+        .count();
+    Ok(count as u32)
+}
+
 /// Interconnect every provided pair of conductors via their peer store lmdb environments
 #[cfg(any(test, feature = "test_utils"))]
 pub fn exchange_peer_info(envs: Vec<EnvironmentWrite>) {
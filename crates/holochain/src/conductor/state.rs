@@ -2,7 +2,7 @@
 //! startups and shutdowns
 
 use holochain_conductor_api::signal_subscription::SignalSubscription;
-use holochain_conductor_api::{config::InterfaceDriver, InstalledAppInfo};
+use holochain_conductor_api::{config::InterfaceDriver, AppStatusFilter, InstalledAppInfo};
 use holochain_types::prelude::*;
 use serde::Deserialize;
 use serde::Serialize;
@@ -25,6 +25,9 @@ pub struct ConductorState {
     /// List of interfaces any UI can use to access zome functions.
     #[serde(default)]
     pub app_interfaces: HashMap<AppInterfaceId, AppInterfaceConfig>,
+    /// Why each disabled app was disabled
+    #[serde(default)]
+    pub disabled_reasons: HashMap<InstalledAppId, String>,
 }
 
 /// A unique identifier used to refer to an App Interface internally.
@@ -69,10 +72,45 @@ impl ConductorState {
             .or_else(|| {
                 self.inactive_apps
                     .get(installed_app_id)
-                    .map(|app| InstalledAppInfo::from_installed_app(app, false))
+                    .map(|app| self.inactive_app_info(app))
             })
     }
 
+    /// Retrieve info about all installed Apps, optionally only those with
+    /// a particular status. Apps are sorted by InstalledAppId.
+    pub fn list_apps(&self, status_filter: Option<AppStatusFilter>) -> Vec<InstalledAppInfo> {
+        let active = match status_filter {
+            Some(AppStatusFilter::Inactive) => None,
+            _ => Some(
+                self.active_apps
+                    .values()
+                    .map(|app| InstalledAppInfo::from_installed_app(app, true)),
+            ),
+        };
+        let inactive = match status_filter {
+            Some(AppStatusFilter::Active) => None,
+            _ => Some(
+                self.inactive_apps
+                    .values()
+                    .map(|app| self.inactive_app_info(app)),
+            ),
+        };
+        let mut apps: Vec<_> = active
+            .into_iter()
+            .flatten()
+            .chain(inactive.into_iter().flatten())
+            .collect();
+        apps.sort_by(|a, b| a.installed_app_id.cmp(&b.installed_app_id));
+        apps
+    }
+
+    /// Info about an inactive app including why it was disabled
+    fn inactive_app_info(&self, app: &InstalledApp) -> InstalledAppInfo {
+        let mut info = InstalledAppInfo::from_installed_app(app, false);
+        info.disabled_reason = self.disabled_reasons.get(app.installed_app_id()).cloned();
+        info
+    }
+
     /// Returns the interface configuration with the given ID if present
     pub fn interface_by_id(&self, id: &AppInterfaceId) -> Option<AppInterfaceConfig> {
         self.app_interfaces.get(id).cloned()
@@ -32,7 +32,9 @@ use derive_more::From;
 use futures::future::Either;
 use holochain_lmdb::env::EnvironmentWrite;
 use holochain_lmdb::env::WriteManager;
+use holochain_lmdb::error::DatabaseError;
 use holochain_lmdb::prelude::Writer;
+use std::sync::Arc;
 use tokio::sync;
 use tokio::sync::mpsc;
 
@@ -46,11 +48,14 @@ use app_validation_consumer::*;
 mod produce_dht_ops_consumer;
 use produce_dht_ops_consumer::*;
 mod publish_dht_ops_consumer;
+pub use validation_receipt_consumer::ValidationReceiptTuning;
+pub use validation_receipt_consumer::DEFAULT_VALIDATION_RECEIPT_RETRIGGER_DELAY;
 use validation_receipt_consumer::*;
 mod validation_receipt_consumer;
 use crate::conductor::api::CellConductorApiT;
 use crate::conductor::manager::ManagedTaskAdd;
 use holochain_p2p::HolochainP2pCell;
+use holochain_state::source_chain::SourceChainError;
 use holochain_state::workspace::WorkspaceError;
 use publish_dht_ops_consumer::*;
 
@@ -65,6 +70,7 @@ pub async fn spawn_queue_consumer_tasks(
     conductor_api: impl CellConductorApiT + 'static,
     task_sender: sync::mpsc::Sender<ManagedTaskAdd>,
     stop: sync::broadcast::Sender<()>,
+    validation_receipt_tuning: ValidationReceiptTuning,
 ) -> (QueueTriggers, InitialQueueTriggers) {
     // Publish
     let (tx_publish, handle) =
@@ -75,8 +81,12 @@ pub async fn spawn_queue_consumer_tasks(
         .expect("Failed to manage workflow handle");
 
     // Validation Receipt
-    let (tx_receipt, handle) =
-        spawn_validation_receipt_consumer(env.clone(), stop.subscribe(), cell_network.clone());
+    let (tx_receipt, handle) = spawn_validation_receipt_consumer(
+        env.clone(),
+        stop.subscribe(),
+        cell_network.clone(),
+        validation_receipt_tuning,
+    );
     task_sender
         .send(ManagedTaskAdd::unrecoverable(handle))
         .await
@@ -85,11 +95,13 @@ pub async fn spawn_queue_consumer_tasks(
     let (create_tx_sys, get_tx_sys) = tokio::sync::oneshot::channel();
 
     // Integration
+    let integration_lock = Arc::new(sync::Mutex::new(()));
     let (tx_integration, handle) = spawn_integrate_dht_ops_consumer(
         env.clone(),
         stop.subscribe(),
         get_tx_sys,
         tx_receipt.clone(),
+        integration_lock.clone(),
     );
     task_sender
         .send(ManagedTaskAdd::unrecoverable(handle))
@@ -134,7 +146,12 @@ pub async fn spawn_queue_consumer_tasks(
         .expect("Failed to manage workflow handle");
 
     (
-        QueueTriggers::new(tx_sys.clone(), tx_produce.clone()),
+        QueueTriggers::new(
+            tx_sys.clone(),
+            tx_produce.clone(),
+            tx_publish.clone(),
+            integration_lock,
+        ),
         InitialQueueTriggers::new(
             tx_sys,
             tx_produce,
@@ -153,6 +170,11 @@ pub struct QueueTriggers {
     pub sys_validation: TriggerSender,
     /// Notify the ProduceDhtOps workflow to run, i.e. after InvokeCallZome
     pub produce_dht_ops: TriggerSender,
+    /// Notify the Publish workflow to run, i.e. when asked to publish now
+    pub publish_dht_ops: TriggerSender,
+    /// Held by the DhtOpIntegration workflow while it runs.
+    /// Hold it to keep integration from running, i.e. while reindexing
+    pub integration_lock: Arc<sync::Mutex<()>>,
 }
 
 /// The triggers to run once at the start of a cell
@@ -169,10 +191,17 @@ pub struct InitialQueueTriggers {
 
 impl QueueTriggers {
     /// Create a new queue trigger
-    pub fn new(sys_validation: TriggerSender, produce_dht_ops: TriggerSender) -> Self {
+    pub fn new(
+        sys_validation: TriggerSender,
+        produce_dht_ops: TriggerSender,
+        publish_dht_ops: TriggerSender,
+        integration_lock: Arc<sync::Mutex<()>>,
+    ) -> Self {
         Self {
             sys_validation,
             produce_dht_ops,
+            publish_dht_ops,
+            integration_lock,
         }
     }
 }
@@ -274,16 +303,26 @@ pub struct OneshotWriter(EnvironmentWrite);
 
 impl OneshotWriter {
     /// Create the writer and pass it into a closure.
-    pub fn with_writer<F>(self, f: F) -> Result<(), WorkspaceError>
+    /// If the environment's map fills up it is grown and the closure
+    /// is run again with a new writer, so the closure should flush
+    /// with `flush_to_txn_ref` rather than consume the workspace.
+    pub fn with_writer<F>(self, mut f: F) -> Result<(), WorkspaceError>
     where
-        F: FnOnce(&mut Writer) -> Result<(), WorkspaceError> + Send,
+        F: FnMut(&mut Writer) -> Result<(), WorkspaceError> + Send,
     {
-        let env_ref = self.0.guard();
-        env_ref.with_commit::<WorkspaceError, (), _>(|w| {
-            f(w)?;
-            Ok(())
-        })?;
-        Ok(())
+        loop {
+            let result = self
+                .0
+                .guard()
+                .with_commit::<WorkspaceError, (), _>(|w| f(w));
+            match result {
+                Err(WorkspaceError::DatabaseError(DatabaseError::MapFull))
+                | Err(WorkspaceError::SourceChainError(SourceChainError::DatabaseError(
+                    DatabaseError::MapFull,
+                ))) => self.0.grow_map()?,
+                result => return result,
+            }
+        }
     }
 }
 
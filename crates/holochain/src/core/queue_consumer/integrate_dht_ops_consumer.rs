@@ -6,17 +6,20 @@ use crate::conductor::manager::ManagedTaskResult;
 use crate::core::workflow::integrate_dht_ops_workflow::integrate_dht_ops_workflow;
 use crate::core::workflow::integrate_dht_ops_workflow::IntegrateDhtOpsWorkspace;
 use holochain_lmdb::env::EnvironmentWrite;
+use std::sync::Arc;
 
 use tokio::task::JoinHandle;
 use tracing::*;
 
 /// Spawn the QueueConsumer for DhtOpIntegration workflow
-#[instrument(skip(env, stop, trigger_sys, trigger_receipt))]
+/// The workflow only runs while holding the `lock`.
+#[instrument(skip(env, stop, trigger_sys, trigger_receipt, lock))]
 pub fn spawn_integrate_dht_ops_consumer(
     env: EnvironmentWrite,
     mut stop: sync::broadcast::Receiver<()>,
     trigger_sys: sync::oneshot::Receiver<TriggerSender>,
     mut trigger_receipt: TriggerSender,
+    lock: Arc<sync::Mutex<()>>,
 ) -> (TriggerSender, JoinHandle<ManagedTaskResult>) {
     let (tx, mut rx) = TriggerSender::new();
     let mut trigger_self = tx.clone();
@@ -32,6 +35,7 @@ pub fn spawn_integrate_dht_ops_consumer(
             }
 
             // Run the workflow
+            let _guard = lock.lock().await;
             let workspace = IntegrateDhtOpsWorkspace::new(env.clone().into())
                 .expect("Could not create Workspace");
             if let WorkComplete::Incomplete = integrate_dht_ops_workflow(
@@ -2,20 +2,66 @@
 
 use super::*;
 
+use crate::conductor::error::ConductorError;
 use crate::conductor::manager::ManagedTaskResult;
+use crate::core::workflow::error::WorkflowResult;
 use crate::core::workflow::validation_receipt_workflow::validation_receipt_workflow;
+use crate::core::workflow::validation_receipt_workflow::ReceiptBatching;
 use crate::core::workflow::validation_receipt_workflow::ValidationReceiptWorkspace;
 use holochain_lmdb::env::EnvironmentWrite;
 
 use tokio::task::JoinHandle;
 use tracing::*;
 
+/// The maximum number of workflow passes to run when draining
+/// the receipts that are still pending at shutdown.
+const MAX_SHUTDOWN_DRAIN_PASSES: usize = 10;
+
+/// How many times to retry creating the workspace before giving up.
+const WORKSPACE_RETRIES: usize = 3;
+
+/// How long to wait between attempts at creating the workspace.
+const WORKSPACE_RETRY_DELAY: std::time::Duration = std::time::Duration::from_millis(100);
+
+/// How long to wait before running the workflow again when there is
+/// more work to do, unless the conductor config sets a different delay.
+pub const DEFAULT_VALIDATION_RECEIPT_RETRIGGER_DELAY: std::time::Duration =
+    std::time::Duration::from_millis(10);
+
+/// How each cell's validation receipt consumer paces its work.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ValidationReceiptTuning {
+    /// How long to wait before running the workflow again
+    /// when there is more work to do.
+    pub retrigger_delay: std::time::Duration,
+    /// How receipts for the same author are batched.
+    pub batching: ReceiptBatching,
+}
+
+impl ValidationReceiptTuning {
+    /// How long to wait before running the workflow again
+    /// after it held back a partial batch.
+    pub fn backoff(&self) -> std::time::Duration {
+        self.retrigger_delay.max(self.batching.max_delay)
+    }
+}
+
+impl Default for ValidationReceiptTuning {
+    fn default() -> Self {
+        Self {
+            retrigger_delay: DEFAULT_VALIDATION_RECEIPT_RETRIGGER_DELAY,
+            batching: ReceiptBatching::default(),
+        }
+    }
+}
+
 /// Spawn the QueueConsumer for validation receipt workflow
 #[instrument(skip(env, stop, cell_network))]
 pub fn spawn_validation_receipt_consumer(
     env: EnvironmentWrite,
     mut stop: sync::broadcast::Receiver<()>,
     mut cell_network: HolochainP2pCell,
+    tuning: ValidationReceiptTuning,
 ) -> (TriggerSender, JoinHandle<ManagedTaskResult>) {
     let (tx, mut rx) = TriggerSender::new();
     let mut trigger_self = tx.clone();
@@ -26,21 +72,101 @@ pub fn spawn_validation_receipt_consumer(
                 tracing::warn!(
                     "Cell is shutting down: stopping validation_receipt_workflow queue consumer."
                 );
+                drain_on_shutdown(&env, &mut cell_network, tuning.batching).await;
                 break;
             }
 
             // Run the workflow
-            let workspace = ValidationReceiptWorkspace::new(env.clone().into())
-                .expect("Could not create ValidationReceiptWorkspace");
-            if let WorkComplete::Incomplete =
-                validation_receipt_workflow(workspace, env.clone().into(), &mut cell_network)
-                    .await
-                    .expect("Error running validation receipt workflow")
-            {
-                trigger_self.trigger()
+            let workspace = match create_workspace(&env).await {
+                Ok(workspace) => workspace,
+                Err(e) => {
+                    error!(?e, "Could not create ValidationReceiptWorkspace");
+                    return Err(ConductorError::from(e).into());
+                }
             };
+            match validation_receipt_workflow(
+                workspace,
+                env.clone().into(),
+                &mut cell_network,
+                tuning.batching,
+            )
+            .await
+            {
+                Ok(WorkComplete::Incomplete) => {
+                    // Only held back batches leave work behind and they are
+                    // all ready once the max delay has passed, so there's no
+                    // point scanning the integrated ops again before then.
+                    tokio::time::sleep(tuning.backoff()).await;
+                    trigger_self.trigger()
+                }
+                Ok(WorkComplete::Complete) => (),
+                Err(e) => {
+                    error!(?e, "Error running validation receipt workflow");
+                    return Err(ConductorError::from(e).into());
+                }
+            }
         }
         Ok(())
     });
     (tx, handle)
 }
+
+/// Create the workspace, retrying a few times because
+/// failures are usually transient lock contention.
+async fn create_workspace(env: &EnvironmentWrite) -> WorkflowResult<ValidationReceiptWorkspace> {
+    let mut attempts = 0;
+    loop {
+        match ValidationReceiptWorkspace::new(env.clone().into()) {
+            Ok(workspace) => return Ok(workspace),
+            Err(e) if attempts < WORKSPACE_RETRIES => {
+                attempts += 1;
+                warn!(
+                    ?e,
+                    ?attempts,
+                    "Retrying creating ValidationReceiptWorkspace"
+                );
+                tokio::time::sleep(WORKSPACE_RETRY_DELAY).await;
+            }
+            Err(e) => return Err(e.into()),
+        }
+    }
+}
+
+/// Run the workflow until it reports there is no more work
+/// so pending receipts are sent before the consumer exits.
+/// Partial batches are sent straight away instead of waiting to fill.
+async fn drain_on_shutdown(
+    env: &EnvironmentWrite,
+    cell_network: &mut HolochainP2pCell,
+    batching: ReceiptBatching,
+) {
+    for _ in 0..MAX_SHUTDOWN_DRAIN_PASSES {
+        let result = match create_workspace(env).await {
+            Ok(workspace) => {
+                validation_receipt_workflow(
+                    workspace,
+                    env.clone().into(),
+                    cell_network,
+                    batching.flush(),
+                )
+                .await
+            }
+            Err(e) => Err(e),
+        };
+        match result {
+            Ok(WorkComplete::Complete) => return,
+            Ok(WorkComplete::Incomplete) => continue,
+            Err(e) => {
+                tracing::warn!(
+                    ?e,
+                    "Failed to drain validation receipts on shutdown: some may not be sent."
+                );
+                return;
+            }
+        }
+    }
+    tracing::warn!(
+        "Validation receipts were still pending after {} passes on shutdown.",
+        MAX_SHUTDOWN_DRAIN_PASSES
+    );
+}
@@ -46,7 +46,9 @@ use holochain_p2p::HolochainP2pCell;
 use holochain_serialized_bytes::prelude::*;
 use holochain_types::prelude::*;
 use mockall::automock;
+use std::collections::HashMap;
 use std::iter::Iterator;
+use std::sync::Arc;
 
 use self::error::RibosomeError;
 use self::guest_callback::entry_defs::EntryDefsInvocation;
@@ -55,11 +57,16 @@ use self::guest_callback::entry_defs::EntryDefsInvocation;
 pub struct CallContext {
     pub(crate) zome: Zome,
     pub(crate) host_access: HostAccess,
+    pub(crate) get_cache: GetCache,
 }
 
 impl CallContext {
     pub fn new(zome: Zome, host_access: HostAccess) -> Self {
-        Self { zome, host_access }
+        Self {
+            zome,
+            host_access,
+            get_cache: GetCache::default(),
+        }
     }
 
     pub fn zome(&self) -> Zome {
@@ -69,6 +76,87 @@ impl CallContext {
     pub fn host_access(&self) -> HostAccess {
         self.host_access.clone()
     }
+
+    /// Forget every get made earlier in the call.
+    /// Host fns that commit call this because the commit
+    /// can change the result of those gets.
+    pub fn invalidate_after_commit(&self) {
+        self.get_cache.clear();
+    }
+}
+
+/// The results of `get` and `get_details` within a single call
+/// so looking up the same hash again doesn't go back to the cascade.
+/// Anything committed during the call clears the cache because
+/// it can change the result for hashes that were already fetched.
+#[derive(Clone, Default)]
+pub struct GetCache(Arc<parking_lot::Mutex<GetCacheInner>>);
+
+type GetCacheKey = (AnyDhtHash, GetStrategy);
+
+#[derive(Default)]
+struct GetCacheInner {
+    elements: HashMap<GetCacheKey, Option<Element>>,
+    details: HashMap<GetCacheKey, Option<Details>>,
+}
+
+impl GetCache {
+    /// The element from an earlier `get` with these options.
+    /// The outer option is `None` if there wasn't one.
+    /// Gets that always ask the network are never cached.
+    pub fn element(&self, hash: &AnyDhtHash, options: &GetOptions) -> Option<Option<Element>> {
+        if options.strategy.always_asks_network() {
+            return None;
+        }
+        self.0
+            .lock()
+            .elements
+            .get(&(hash.clone(), options.strategy))
+            .cloned()
+    }
+
+    /// Cache the result of a `get`.
+    pub fn put_element(&self, hash: AnyDhtHash, options: &GetOptions, element: Option<Element>) {
+        if options.strategy.always_asks_network() {
+            return;
+        }
+        self.0
+            .lock()
+            .elements
+            .insert((hash, options.strategy), element);
+    }
+
+    /// The details from an earlier `get_details` with these options.
+    /// The outer option is `None` if there wasn't one.
+    /// Gets that always ask the network are never cached.
+    pub fn details(&self, hash: &AnyDhtHash, options: &GetOptions) -> Option<Option<Details>> {
+        if options.strategy.always_asks_network() {
+            return None;
+        }
+        self.0
+            .lock()
+            .details
+            .get(&(hash.clone(), options.strategy))
+            .cloned()
+    }
+
+    /// Cache the result of a `get_details`.
+    pub fn put_details(&self, hash: AnyDhtHash, options: &GetOptions, details: Option<Details>) {
+        if options.strategy.always_asks_network() {
+            return;
+        }
+        self.0
+            .lock()
+            .details
+            .insert((hash, options.strategy), details);
+    }
+
+    /// Forget everything, called whenever something is committed.
+    pub fn clear(&self) {
+        let mut inner = self.0.lock();
+        inner.elements.clear();
+        inner.details.clear();
+    }
 }
 
 #[derive(Clone)]
@@ -437,6 +525,10 @@ pub trait RibosomeT: Sized + std::fmt::Debug {
         to_call: &FunctionName,
     ) -> Result<Option<ExternIO>, RibosomeError>;
 
+    /// Whether the zome exports a function or callback called `to_call`,
+    /// without instantiating it.
+    fn has_callback(&self, zome: &Zome, to_call: &FunctionName) -> RibosomeResult<bool>;
+
     /// @todo list out all the available callbacks and maybe cache them somewhere
     fn list_callbacks(&self) {
         unimplemented!()
@@ -591,6 +683,34 @@ pub mod wasm_test {
         }};
     }
 
+    #[test]
+    fn get_cache_is_cleared_by_commits() {
+        use crate::core::ribosome::GetCache;
+        use ::fixt::prelude::*;
+        use holo_hash::fixt::EntryHashFixturator;
+        use holochain_zome_types::GetOptions;
+
+        let cache = GetCache::default();
+        let hash: holo_hash::AnyDhtHash = fixt!(EntryHash).into();
+        assert_eq!(cache.element(&hash, &GetOptions::latest()), None);
+
+        cache.put_element(hash.clone(), &GetOptions::latest(), None);
+        cache.put_details(hash.clone(), &GetOptions::content(), None);
+        assert_eq!(cache.element(&hash, &GetOptions::latest()), Some(None));
+        assert_eq!(cache.element(&hash, &GetOptions::content()), None);
+        assert_eq!(cache.details(&hash, &GetOptions::content()), Some(None));
+
+        // Network gets always reach the network.
+        cache.put_element(hash.clone(), &GetOptions::network(), None);
+        assert_eq!(cache.element(&hash, &GetOptions::network()), None);
+        cache.put_details(hash.clone(), &GetOptions::network_only(), None);
+        assert_eq!(cache.details(&hash, &GetOptions::network_only()), None);
+
+        cache.clear();
+        assert_eq!(cache.element(&hash, &GetOptions::latest()), None);
+        assert_eq!(cache.details(&hash, &GetOptions::content()), None);
+    }
+
     #[test]
     fn fn_components_iterate() {
         let fn_components = FnComponents::from(vec!["foo".into(), "bar".into(), "baz".into()]);
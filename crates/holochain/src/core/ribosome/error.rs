@@ -40,6 +40,10 @@ pub enum RibosomeError {
     #[error("An error with entry defs: {0}")]
     EntryDefs(ZomeName, String),
 
+    /// a migrate_agent callback refused to migrate the agent
+    #[error("The migrate_agent callback failed: Zome: {0} Reason: {1}")]
+    MigrateAgent(ZomeName, String),
+
     /// a mandatory dependency for an element doesn't exist
     /// for example a remove link ribosome call needs to find the add link in order to infer the
     /// correct base and this dependent relationship exists before even subconscious validation
@@ -213,11 +213,16 @@ mod test {
 #[cfg(feature = "slow_tests")]
 mod slow_tests {
     use super::InitResult;
+    use crate::conductor::api::error::ConductorApiError;
+    use crate::conductor::api::error::ConductorApiResult;
+    use crate::conductor::CellError;
     use crate::core::ribosome::RibosomeT;
     use crate::fixt::curve::Zomes;
     use crate::fixt::InitHostAccessFixturator;
     use crate::fixt::InitInvocationFixturator;
     use crate::fixt::RealRibosomeFixturator;
+    use crate::test_utils::sweetest::SweetConductor;
+    use crate::test_utils::sweetest::SweetDnaFile;
     use ::fixt::prelude::*;
     use holochain_wasm_test_utils::TestWasm;
 
@@ -279,4 +284,49 @@ mod slow_tests {
             InitResult::Fail(TestWasm::InitFail.into(), "because i said so".into()),
         );
     }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn conductor_runs_init_once() {
+        observability::test_run().ok();
+        let (dna_file, _) = SweetDnaFile::unique_from_test_wasms(vec![TestWasm::InitMarker])
+            .await
+            .unwrap();
+        let mut conductor = SweetConductor::from_standard_config().await;
+        let app = conductor.setup_app("app", &[dna_file]).await;
+        let cell = app.into_cells().pop().unwrap();
+        let zome = cell.zome(TestWasm::InitMarker);
+
+        // The first call runs init which commits the marker
+        let markers: u32 = conductor.call(&zome, "markers", ()).await;
+        assert_eq!(markers, 1);
+        // Later calls don't run it again
+        let markers: u32 = conductor.call(&zome, "markers", ()).await;
+        assert_eq!(markers, 1);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn conductor_refuses_calls_after_init_fails() {
+        observability::test_run().ok();
+        let (dna_file, _) =
+            SweetDnaFile::unique_from_test_wasms(vec![TestWasm::InitMarker, TestWasm::InitFail])
+                .await
+                .unwrap();
+        let mut conductor = SweetConductor::from_standard_config().await;
+        let app = conductor.setup_app("app", &[dna_file]).await;
+        let cell = app.into_cells().pop().unwrap();
+        let zome = cell.zome(TestWasm::InitMarker);
+
+        // Init is retried on every call and fails every time
+        for _ in 0..2 {
+            let result: ConductorApiResult<u32> =
+                conductor.call_fallible(&zome, "markers", ()).await;
+            match result {
+                Err(ConductorApiError::CellError(CellError::InitFailed(r))) => assert_eq!(
+                    r,
+                    InitResult::Fail(TestWasm::InitFail.into(), "because i said so".into()),
+                ),
+                other => panic!("expected init to fail, got {:?}", other),
+            }
+        }
+    }
 }
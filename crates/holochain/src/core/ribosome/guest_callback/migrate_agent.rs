@@ -1,11 +1,18 @@
+use crate::core::ribosome::error::RibosomeError;
+use crate::core::ribosome::CallContext;
 use crate::core::ribosome::FnComponents;
 use crate::core::ribosome::HostAccess;
 use crate::core::ribosome::Invocation;
+use crate::core::ribosome::RibosomeT;
 use crate::core::ribosome::ZomesToInvoke;
+use crate::core::workflow::call_zome_workflow::CallZomeWorkspace;
+use crate::core::workflow::integrate_dht_ops_workflow::integrate_to_authored;
 use crate::core::workflow::CallZomeWorkspaceLock;
 use derive_more::Constructor;
 use holochain_serialized_bytes::prelude::*;
 use holochain_types::prelude::*;
+use holochain_wasmer_host::prelude::WasmError;
+use std::sync::Arc;
 
 #[derive(Clone)]
 pub struct MigrateAgentInvocation {
@@ -99,6 +106,56 @@ impl From<Vec<(ZomeName, MigrateAgentCallbackResult)>> for MigrateAgentResult {
     }
 }
 
+/// run the migrate_agent callbacks of the current dna
+/// a failure from any zome is returned as an error
+pub fn run_migrate_agent(
+    ribosome: Arc<impl RibosomeT>,
+    call_context: Arc<CallContext>,
+    migrate_agent: MigrateAgent,
+) -> Result<(), WasmError> {
+    let host_access = MigrateAgentHostAccess::new(call_context.host_access.workspace().clone());
+    let invocation =
+        MigrateAgentInvocation::new(ribosome.dna_def().as_content().clone(), migrate_agent);
+    match ribosome
+        .run_migrate_agent(host_access, invocation)
+        .map_err(|ribosome_error| WasmError::Host(ribosome_error.to_string()))?
+    {
+        MigrateAgentResult::Pass => Ok(()),
+        MigrateAgentResult::Fail(zome_name, reason) => Err(WasmError::Host(
+            RibosomeError::MigrateAgent(zome_name, reason).to_string(),
+        )),
+    }
+}
+
+/// commit the CloseChain or OpenChain header of a migration
+/// to the source chain of the current call
+pub fn commit_migration_header<H: HeaderInner, B: HeaderBuilder<H>>(
+    call_context: Arc<CallContext>,
+    header_builder: B,
+) -> Result<HeaderHash, WasmError> {
+    call_context.invalidate_after_commit();
+    tokio_helper::block_forever_on(async move {
+        let mut guard = call_context.host_access.workspace().write().await;
+        let workspace: &mut CallZomeWorkspace = &mut guard;
+        let source_chain = &mut workspace.source_chain;
+        let header_hash = source_chain
+            .put(header_builder, None)
+            .await
+            .map_err(|source_chain_error| WasmError::Host(source_chain_error.to_string()))?;
+        let element = source_chain
+            .get_element(&header_hash)
+            .map_err(|source_chain_error| WasmError::Host(source_chain_error.to_string()))?
+            .expect("Element we just put in SourceChain must be gettable");
+        integrate_to_authored(
+            &element,
+            workspace.source_chain.elements(),
+            &mut workspace.meta_authored,
+        )
+        .map_err(|dht_op_convert_error| WasmError::Host(dht_op_convert_error.to_string()))?;
+        Ok(header_hash)
+    })
+}
+
 #[cfg(test)]
 mod test {
     use super::MigrateAgentResult;
@@ -222,12 +279,18 @@ mod test {
 #[cfg(test)]
 #[cfg(feature = "slow_tests")]
 mod slow_tests {
+    use super::MigrateAgentInvocation;
     use super::MigrateAgentResult;
+    use crate::conductor::api::error::ConductorApiResult;
     use crate::core::ribosome::RibosomeT;
     use crate::fixt::curve::Zomes;
     use crate::fixt::MigrateAgentHostAccessFixturator;
     use crate::fixt::MigrateAgentInvocationFixturator;
     use crate::fixt::RealRibosomeFixturator;
+    use crate::test_utils::sweetest::SweetConductor;
+    use crate::test_utils::sweetest::SweetDnaFile;
+    use holochain_state::source_chain::SourceChainError;
+    use holochain_types::prelude::*;
     use holochain_wasm_test_utils::TestWasm;
 
     #[tokio::test(flavor = "multi_thread")]
@@ -290,6 +353,34 @@ mod slow_tests {
         );
     }
 
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_migrate_agent_fail_reason() {
+        let ribosome = RealRibosomeFixturator::new(Zomes(vec![TestWasm::MigrateAgentFailReason]))
+            .next()
+            .unwrap();
+
+        for migrate_agent in vec![MigrateAgent::Open, MigrateAgent::Close] {
+            let host_access = MigrateAgentHostAccessFixturator::new(::fixt::Unpredictable)
+                .next()
+                .unwrap();
+            let migrate_agent_invocation = MigrateAgentInvocation::new(
+                ribosome.dna_file.dna_def().clone(),
+                migrate_agent.clone(),
+            );
+
+            let result = ribosome
+                .run_migrate_agent(host_access, migrate_agent_invocation)
+                .unwrap();
+            assert_eq!(
+                result,
+                MigrateAgentResult::Fail(
+                    TestWasm::MigrateAgentFailReason.into(),
+                    format!("no migrate {:?}", migrate_agent),
+                ),
+            );
+        }
+    }
+
     #[tokio::test(flavor = "multi_thread")]
     async fn test_migrate_agent_multi_implemented_fail() {
         let host_access = MigrateAgentHostAccessFixturator::new(::fixt::Unpredictable)
@@ -314,4 +405,67 @@ mod slow_tests {
             MigrateAgentResult::Fail(TestWasm::MigrateAgentFail.into(), "no migrate".into()),
         );
     }
+
+    #[tokio::test(flavor = "multi_thread")]
+    /// an agent closes their chain in one cell and opens it in another,
+    /// carrying their notes over, with the migrate_agent callback passing
+    async fn test_migrate_agent_between_cells() {
+        observability::test_run().ok();
+        let (old_dna_file, _) =
+            SweetDnaFile::unique_from_test_wasms(vec![TestWasm::MigrateAgentPass])
+                .await
+                .unwrap();
+        let (new_dna_file, _) =
+            SweetDnaFile::unique_from_test_wasms(vec![TestWasm::MigrateAgentPass])
+                .await
+                .unwrap();
+        let mut conductor = SweetConductor::from_standard_config().await;
+        let app = conductor
+            .setup_app("app", &[old_dna_file, new_dna_file])
+            .await;
+        let cells = app.into_cells();
+        let (old_cell, new_cell) = (&cells[0], &cells[1]);
+        let old_zome = old_cell.zome(TestWasm::MigrateAgentPass);
+        let new_zome = new_cell.zome(TestWasm::MigrateAgentPass);
+
+        let _: HeaderHash = conductor.call(&old_zome, "create_note", "one").await;
+        let _: HeaderHash = conductor.call(&old_zome, "create_note", "two").await;
+
+        let close_hash: HeaderHash = conductor
+            .call(&old_zome, "close_chain", new_cell.dna_hash().clone())
+            .await;
+
+        // nothing more can be committed to the closed chain
+        let after_close: ConductorApiResult<HeaderHash> = conductor
+            .call_fallible(&old_zome, "create_note", "three")
+            .await;
+        assert!(format!("{:?}", after_close.unwrap_err())
+            .contains(&SourceChainError::ChainClosed.to_string()));
+        let open_hash: HeaderHash = conductor
+            .call(&new_zome, "open_chain", old_cell.dna_hash().clone())
+            .await;
+        let _: Vec<HeaderHash> = conductor
+            .call(&new_zome, "migrate_notes", old_cell.cell_id().clone())
+            .await;
+
+        let old_headers: Vec<Header> = conductor.call(&old_zome, "migration_headers", ()).await;
+        match &old_headers[..] {
+            [header @ Header::CloseChain(close_chain)] => {
+                assert_eq!(&close_chain.new_dna_hash, new_cell.dna_hash());
+                assert_eq!(HeaderHash::with_data_sync(header), close_hash);
+            }
+            headers => panic!("expected a single CloseChain header but got {:?}", headers),
+        }
+        let new_headers: Vec<Header> = conductor.call(&new_zome, "migration_headers", ()).await;
+        match &new_headers[..] {
+            [header @ Header::OpenChain(open_chain)] => {
+                assert_eq!(&open_chain.prev_dna_hash, old_cell.dna_hash());
+                assert_eq!(HeaderHash::with_data_sync(header), open_hash);
+            }
+            headers => panic!("expected a single OpenChain header but got {:?}", headers),
+        }
+
+        let notes: Vec<String> = conductor.call(&new_zome, "notes", ()).await;
+        assert_eq!(notes, vec!["one".to_string(), "two".to_string()]);
+    }
 }
@@ -199,7 +199,10 @@ mod slow_tests {
     use crate::fixt::PostCommitHostAccessFixturator;
     use crate::fixt::PostCommitInvocationFixturator;
     use crate::fixt::RealRibosomeFixturator;
+    use crate::test_utils::sweetest::SweetConductor;
+    use crate::test_utils::sweetest::SweetDnaFile;
     use holo_hash::fixt::HeaderHashFixturator;
+    use holo_hash::HeaderHash;
     use holochain_wasm_test_utils::TestWasm;
 
     #[tokio::test(flavor = "multi_thread")]
@@ -268,4 +271,30 @@ mod slow_tests {
             ),
         );
     }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn conductor_runs_post_commit_after_commit() {
+        observability::test_run().ok();
+        let (dna_file, _) = SweetDnaFile::unique_from_test_wasms(vec![TestWasm::PostCommitRecord])
+            .await
+            .unwrap();
+        let mut conductor = SweetConductor::from_standard_config().await;
+        let app = conductor.setup_app("app", &[dna_file]).await;
+        let cell = app.into_cells().pop().unwrap();
+        let zome = cell.zome(TestWasm::PostCommitRecord);
+
+        // post_commit can already get the header it is called with
+        let first: HeaderHash = conductor
+            .call(&zome, "create_post", "first".to_string())
+            .await;
+        let records: Vec<Vec<HeaderHash>> = conductor.call(&zome, "post_commit_records", ()).await;
+        assert_eq!(records, vec![vec![first.clone()]]);
+
+        // The record committed by post_commit doesn't run post_commit again
+        let second: HeaderHash = conductor
+            .call(&zome, "create_post", "second".to_string())
+            .await;
+        let records: Vec<Vec<HeaderHash>> = conductor.call(&zome, "post_commit_records", ()).await;
+        assert_eq!(records, vec![vec![first], vec![second]]);
+    }
 }
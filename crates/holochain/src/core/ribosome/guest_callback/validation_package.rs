@@ -247,6 +247,7 @@ mod slow_tests {
     use crate::fixt::ValidationPackageHostAccessFixturator;
     use crate::fixt::ValidationPackageInvocationFixturator;
     use hdk::prelude::AppEntryType;
+    use hdk::prelude::EntryHash;
     use hdk::prelude::EntryVisibility;
     use holochain_wasm_test_utils::TestWasm;
     use holochain_zome_types::validate::ValidationPackage;
@@ -315,4 +316,29 @@ mod slow_tests {
             .unwrap();
         assert_eq!(result, ValidationPackageResult::Fail("bad package".into()),);
     }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_validation_package_implemented_unresolved() {
+        let host_access = ValidationPackageHostAccessFixturator::new(::fixt::Unpredictable)
+            .next()
+            .unwrap();
+        let ribosome =
+            RealRibosomeFixturator::new(Zomes(vec![TestWasm::ValidationPackageUnresolved]))
+                .next()
+                .unwrap();
+        let mut validation_package_invocation =
+            ValidationPackageInvocationFixturator::new(::fixt::Empty)
+                .next()
+                .unwrap();
+        validation_package_invocation.zome = TestWasm::ValidationPackageUnresolved.into();
+
+        let result = ribosome
+            .run_validation_package(host_access, validation_package_invocation)
+            .unwrap();
+        let missing = EntryHash::from_raw_36(vec![0; 36]);
+        assert_eq!(
+            result,
+            ValidationPackageResult::UnresolvedDependencies(vec![missing.into()]),
+        );
+    }
 }
@@ -101,6 +101,12 @@ host_fn_api_impls! {
     // Delete an entry.
     fn delete (holo_hash::HeaderHash) -> holo_hash::HeaderHash;
 
+    // Commit a CloseChain header pointing at the new DNA.
+    fn close_chain (holo_hash::DnaHash) -> holo_hash::HeaderHash;
+
+    // Commit an OpenChain header pointing at the previous DNA.
+    fn open_chain (holo_hash::DnaHash) -> holo_hash::HeaderHash;
+
     // Header hash of the CreateLink element.
     fn delete_link (holo_hash::HeaderHash) -> holo_hash::HeaderHash;
 
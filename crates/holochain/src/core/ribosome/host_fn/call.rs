@@ -33,14 +33,20 @@ pub fn call(
     };
 
     // Make the call using this workspace
-    Ok(tokio_helper::block_forever_on(async move {
+    let result = tokio_helper::block_forever_on(async move {
         conductor_handle
             .call_zome(invocation, workspace)
             .await
             .map_err(Box::new)
-    })
-    .map_err(|conductor_api_error| WasmError::Host(conductor_api_error.to_string()))?
-    .map_err(|ribosome_error| WasmError::Host(ribosome_error.to_string()))?)
+    });
+
+    // The callee shares our workspace so anything it committed
+    // can change the result of gets made before the call.
+    call_context.invalidate_after_commit();
+
+    Ok(result
+        .map_err(|conductor_api_error| WasmError::Host(conductor_api_error.to_string()))?
+        .map_err(|ribosome_error| WasmError::Host(ribosome_error.to_string()))?)
 }
 
 #[cfg(test)]
@@ -56,6 +62,7 @@ pub mod wasm_test {
     use holochain_types::dna::DnaFile;
     use holochain_wasm_test_utils::TestWasm;
     use holochain_zome_types::test_utils::fake_agent_pubkey_2;
+    use holochain_zome_types::Element;
     use holochain_zome_types::ExternIO;
     use holochain_zome_types::ZomeCallResponse;
     use matches::assert_matches;
@@ -157,6 +164,45 @@ pub mod wasm_test {
         conductor_test.shutdown_conductor().await;
     }
 
+    /// A get made before calling the same cell must not
+    /// hide what the callee committed from a get made after.
+    #[tokio::test(flavor = "multi_thread")]
+    async fn get_after_call_sees_callee_commits() {
+        observability::test_run().ok();
+
+        let zomes = vec![TestWasm::Create];
+        let mut conductor_test = ConductorTestData::two_agents(zomes, false).await;
+        let handle = conductor_test.handle();
+        let alice_call_data = conductor_test.alice_call_data();
+        let alice_cell_id = &alice_call_data.cell_id;
+
+        let invocation =
+            new_zome_call(&alice_cell_id, "create_entry", (), TestWasm::Create).unwrap();
+        let result = handle.call_zome(invocation).await;
+        let original: HeaderHash =
+            unwrap_to::unwrap_to!(result.unwrap().unwrap() => ZomeCallResponse::Ok)
+                .decode()
+                .unwrap();
+
+        // Get the updated post, update it through a call then get it again
+        let invocation = new_zome_call(
+            &alice_cell_id,
+            "call_update_post",
+            original,
+            TestWasm::Create,
+        )
+        .unwrap();
+        let result = handle.call_zome(invocation).await;
+        let (before, after): (Option<Element>, Option<Element>) =
+            unwrap_to::unwrap_to!(result.unwrap().unwrap() => ZomeCallResponse::Ok)
+                .decode()
+                .unwrap();
+        assert_matches!(before, None);
+        assert_matches!(after, Some(_));
+
+        conductor_test.shutdown_conductor().await;
+    }
+
     /// test calling a different zome
     /// in a different cell.
     #[tokio::test(flavor = "multi_thread")]
@@ -23,6 +23,7 @@ pub mod wasm_test {
     };
     use ::fixt::prelude::*;
     use hdk::prelude::*;
+    use holochain_conductor_api::CapGrantAccess;
     use holochain_types::fixt::CapSecretFixturator;
     use holochain_types::prelude::*;
     use holochain_types::test_utils::fake_agent_pubkey_1;
@@ -147,6 +148,12 @@ pub mod wasm_test {
             .call(&bobbo, "transferable_cap_grant", original_secret)
             .await;
 
+        // The unrestricted grant from init and the transferable grant are active.
+        let active: Vec<(HeaderHash, CapGrantEntry)> =
+            conductor.call(&bobbo, "active_cap_grants", ()).await;
+        assert_eq!(active.len(), 2);
+        assert!(active.iter().any(|(h, _)| *h == original_grant_hash));
+
         // ALICE CAN NOW CALL THE AUTHED REMOTE FN
 
         let response: ZomeCallResponse = conductor
@@ -165,9 +172,34 @@ pub mod wasm_test {
         // BOB ROLLS THE GRANT SO ONLY THE NEW ONE WILL WORK FOR ALICE
 
         let new_grant_header_hash: HeaderHash = conductor
-            .call(&bobbo, "roll_cap_grant", original_grant_hash)
+            .call(&bobbo, "roll_cap_grant", original_grant_hash.clone())
             .await;
 
+        // The rolled grant replaces the original.
+        let active: Vec<(HeaderHash, CapGrantEntry)> =
+            conductor.call(&bobbo, "active_cap_grants", ()).await;
+        assert_eq!(active.len(), 2);
+        assert!(active.iter().all(|(h, _)| *h != original_grant_hash));
+        assert!(active.iter().any(|(h, _)| *h == new_grant_header_hash));
+
+        // The admin api only lists the original grant when asked for revoked grants.
+        let grants = conductor
+            .list_capability_grants(bobbo.cell_id(), false)
+            .await?;
+        assert_eq!(grants.len(), 2);
+        assert!(grants.iter().all(|g| !g.revoked));
+        let grants = conductor
+            .list_capability_grants(bobbo.cell_id(), true)
+            .await?;
+        assert_eq!(grants.len(), 3);
+        assert!(grants
+            .iter()
+            .any(|g| g.header_hash == original_grant_hash && g.revoked));
+        assert_eq!(
+            grants.last().map(|g| (&g.header_hash, &g.access)),
+            Some((&new_grant_header_hash, &CapGrantAccess::Transferable))
+        );
+
         let output: Option<Element> = conductor
             .call(&bobbo, "get_entry", new_grant_header_hash.clone())
             .await;
@@ -205,9 +237,15 @@ pub mod wasm_test {
         // BOB DELETES THE GRANT SO NO SECRETS WORK
 
         let _: HeaderHash = conductor
-            .call(&bobbo, "delete_cap_grant", new_grant_header_hash)
+            .call(&bobbo, "delete_cap_grant", new_grant_header_hash.clone())
             .await;
 
+        // Only the unrestricted grant from init is left.
+        let active: Vec<(HeaderHash, CapGrantEntry)> =
+            conductor.call(&bobbo, "active_cap_grants", ()).await;
+        assert_eq!(active.len(), 1);
+        assert!(active.iter().all(|(h, _)| *h != new_grant_header_hash));
+
         let output: ZomeCallResponse = conductor
             .call(
                 &alice,
@@ -0,0 +1,117 @@
+use crate::core::ribosome::guest_callback::migrate_agent::commit_migration_header;
+use crate::core::ribosome::guest_callback::migrate_agent::run_migrate_agent;
+use crate::core::ribosome::CallContext;
+use crate::core::ribosome::RibosomeT;
+use holochain_types::prelude::*;
+use holochain_wasmer_host::prelude::WasmError;
+use std::sync::Arc;
+
+/// close the source chain so the agent can migrate to the dna with the given hash
+#[allow(clippy::extra_unused_lifetimes)]
+pub fn close_chain<'a>(
+    ribosome: Arc<impl RibosomeT>,
+    call_context: Arc<CallContext>,
+    input: DnaHash,
+) -> Result<HeaderHash, WasmError> {
+    // every zome gets a chance to refuse the migration before anything is committed
+    run_migrate_agent(ribosome, call_context.clone(), MigrateAgent::Close)?;
+    commit_migration_header(
+        call_context,
+        builder::CloseChain {
+            new_dna_hash: input,
+        },
+    )
+}
+
+#[cfg(test)]
+#[cfg(feature = "slow_tests")]
+pub mod slow_tests {
+    use super::*;
+    use crate::core::ribosome::error::RibosomeError;
+    use crate::fixt::CallContextFixturator;
+    use crate::fixt::RealRibosomeFixturator;
+    use crate::fixt::ZomeCallHostAccessFixturator;
+    use ::fixt::prelude::*;
+    use holo_hash::fixt::DnaHashFixturator;
+    use holochain_wasm_test_utils::TestWasm;
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn ribosome_close_chain() {
+        let test_env = holochain_lmdb::test_utils::test_cell_env();
+        let env = test_env.env();
+        let mut workspace =
+            crate::core::workflow::CallZomeWorkspace::new(env.clone().into()).unwrap();
+        crate::core::workflow::fake_genesis(&mut workspace.source_chain)
+            .await
+            .unwrap();
+        let workspace_lock = crate::core::workflow::CallZomeWorkspaceLock::new(workspace);
+
+        let mut host_access = fixt!(ZomeCallHostAccess);
+        host_access.workspace = workspace_lock.clone();
+
+        let new_dna_hash = fixt!(DnaHash);
+        let header_hash: HeaderHash = crate::call_test_ribosome!(
+            host_access,
+            TestWasm::MigrateAgentPass,
+            "close_chain",
+            new_dna_hash
+        );
+
+        let workspace = workspace_lock.read().await;
+        assert_eq!(workspace.source_chain.chain_head().unwrap(), &header_hash);
+        let element = workspace
+            .source_chain
+            .get_element(&header_hash)
+            .unwrap()
+            .unwrap();
+        match element.header() {
+            Header::CloseChain(close_chain) => assert_eq!(close_chain.new_dna_hash, new_dna_hash),
+            header => panic!("expected a CloseChain header but got {:?}", header),
+        }
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    /// a failing migrate_agent callback stops the chain from being closed
+    async fn close_chain_migrate_agent_fail_test() {
+        let test_env = holochain_lmdb::test_utils::test_cell_env();
+        let env = test_env.env();
+        let mut workspace =
+            crate::core::workflow::CallZomeWorkspace::new(env.clone().into()).unwrap();
+        crate::core::workflow::fake_genesis(&mut workspace.source_chain)
+            .await
+            .unwrap();
+        let chain_head = workspace.source_chain.chain_head().unwrap().to_owned();
+        let workspace_lock = crate::core::workflow::CallZomeWorkspaceLock::new(workspace);
+
+        let ribosome = RealRibosomeFixturator::new(crate::fixt::curve::Zomes(vec![
+            TestWasm::MigrateAgentFail,
+        ]))
+        .next()
+        .unwrap();
+        let mut call_context = CallContextFixturator::new(Unpredictable).next().unwrap();
+        call_context.zome = TestWasm::MigrateAgentFail.into();
+        let mut host_access = fixt!(ZomeCallHostAccess);
+        host_access.workspace = workspace_lock.clone();
+        call_context.host_access = host_access.into();
+
+        let output = close_chain(Arc::new(ribosome), Arc::new(call_context), fixt!(DnaHash));
+        assert_eq!(
+            output.unwrap_err().to_string(),
+            WasmError::Host(
+                RibosomeError::MigrateAgent(TestWasm::MigrateAgentFail.into(), "no migrate".into())
+                    .to_string()
+            )
+            .to_string(),
+        );
+
+        // nothing was committed
+        let new_head = workspace_lock
+            .read()
+            .await
+            .source_chain
+            .chain_head()
+            .unwrap()
+            .to_owned();
+        assert_eq!(new_head, chain_head);
+    }
+}
@@ -55,6 +55,7 @@ pub fn create<'a>(
     // if the validation fails this commit will be rolled back by virtue of the lmdb transaction
     // being atomic
     let entry = AsRef::<Entry>::as_ref(&input).to_owned();
+    call_context.invalidate_after_commit();
     tokio_helper::block_forever_on(async move {
         let mut guard = call_context.host_access.workspace().write().await;
         let workspace: &mut CallZomeWorkspace = &mut guard;
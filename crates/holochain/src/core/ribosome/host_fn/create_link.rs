@@ -28,6 +28,7 @@ pub fn create_link<'a>(
     // Construct the link add
     let header_builder = builder::CreateLink::new(base_address, target_address, zome_id, tag);
 
+    call_context.invalidate_after_commit();
     let header_hash = tokio_helper::block_forever_on(tokio::task::spawn(async move {
         let mut guard = call_context.host_access.workspace().write().await;
         let workspace: &mut CallZomeWorkspace = &mut guard;
@@ -21,6 +21,8 @@ pub fn delete<'a>(
 
     let host_access = call_context.host_access();
 
+    call_context.invalidate_after_commit();
+
     // handle timeouts at the source chain layer
     tokio_helper::block_forever_on(async move {
         let mut guard = host_access.workspace().write().await;
@@ -60,6 +60,8 @@ pub fn delete_link<'a>(
 
     let workspace_lock = call_context.host_access.workspace();
 
+    call_context.invalidate_after_commit();
+
     // handle timeouts at the source chain layer
 
     // add a DeleteLink to the source chain
@@ -92,9 +94,13 @@ pub fn delete_link<'a>(
 #[cfg(test)]
 #[cfg(feature = "slow_tests")]
 pub mod slow_tests {
+    use super::*;
+    use crate::core::ribosome::RibosomeT;
+    use crate::fixt::CallContextFixturator;
+    use crate::fixt::RealRibosomeFixturator;
     use crate::fixt::ZomeCallHostAccessFixturator;
     use ::fixt::prelude::*;
-    use holo_hash::HeaderHash;
+    use holo_hash::fixt::HeaderHashFixturator;
     use holochain_wasm_test_utils::TestWasm;
     use holochain_zome_types::link::Links;
 
@@ -165,4 +171,49 @@ pub mod slow_tests {
 
         assert!(links.into_inner().len() == 0);
     }
+
+    #[tokio::test(flavor = "multi_thread")]
+    /// deleting a link we can't find the create for errors
+    /// instead of committing a dangling delete
+    async fn delete_link_missing_create_test() {
+        let test_env = holochain_lmdb::test_utils::test_cell_env();
+        let env = test_env.env();
+
+        let mut workspace =
+            crate::core::workflow::CallZomeWorkspace::new(env.clone().into()).unwrap();
+        crate::core::workflow::fake_genesis(&mut workspace.source_chain)
+            .await
+            .unwrap();
+        let chain_head = workspace.source_chain.chain_head().unwrap().to_owned();
+        let workspace_lock = crate::core::workflow::CallZomeWorkspaceLock::new(workspace);
+
+        let ribosome = RealRibosomeFixturator::new(crate::fixt::curve::Zomes(vec![TestWasm::Link]))
+            .next()
+            .unwrap();
+        let test_network =
+            crate::test_utils::test_network(Some(ribosome.dna_def().as_hash().clone()), None).await;
+        let mut call_context = CallContextFixturator::new(Unpredictable).next().unwrap();
+        call_context.zome = TestWasm::Link.into();
+        let mut host_access = fixt!(ZomeCallHostAccess);
+        host_access.workspace = workspace_lock.clone();
+        host_access.network = test_network.cell_network();
+        call_context.host_access = host_access.into();
+
+        let missing = fixt!(HeaderHash);
+        let output = delete_link(Arc::new(ribosome), Arc::new(call_context), missing.clone());
+        assert_eq!(
+            output.unwrap_err().to_string(),
+            WasmError::Host(RibosomeError::ElementDeps(missing.into()).to_string()).to_string(),
+        );
+
+        // nothing was committed
+        let new_head = workspace_lock
+            .read()
+            .await
+            .source_chain
+            .chain_head()
+            .unwrap()
+            .to_owned();
+        assert_eq!(new_head, chain_head);
+    }
 }
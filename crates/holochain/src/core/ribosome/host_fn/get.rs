@@ -15,6 +15,11 @@ pub fn get<'a>(
         get_options,
     } = input;
 
+    // Repeated gets within a call are served from the call's cache
+    if let Some(maybe_element) = call_context.get_cache.element(&any_dht_hash, &get_options) {
+        return Ok(maybe_element);
+    }
+
     // Get the network from the context
     let network = call_context.host_access.network().clone();
 
@@ -26,10 +31,13 @@ pub fn get<'a>(
             .write()
             .await
             .cascade(network)
-            .dht_get(any_dht_hash, get_options)
+            .dht_get(any_dht_hash.clone(), get_options.clone())
             .await
             .map_err(|cascade_error| WasmError::Host(cascade_error.to_string()))?;
 
+        call_context
+            .get_cache
+            .put_element(any_dht_hash, &get_options, maybe_element.clone());
         Ok(maybe_element)
     })
 }
@@ -49,3 +49,37 @@ pub fn get_agent_activity(
 
 // we are relying on the create tests to show the commit/get round trip
 // See commit_entry.rs
+
+#[cfg(test)]
+#[cfg(feature = "slow_tests")]
+pub mod slow_tests {
+    use crate::test_utils::consistency_10s;
+    use crate::test_utils::sweetest::SweetConductor;
+    use crate::test_utils::sweetest::SweetDnaFile;
+    use hdk::prelude::*;
+    use holochain_wasm_test_utils::TestWasm;
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn get_agent_activity_countree_history() {
+        observability::test_run().ok();
+        let (dna_file, _) = SweetDnaFile::unique_from_test_wasms(vec![TestWasm::Crud])
+            .await
+            .unwrap();
+        let mut conductor = SweetConductor::from_standard_config().await;
+        let app = conductor.setup_app("app", &[dna_file]).await;
+        let cell = app.into_cells().pop().unwrap();
+        let zome = cell.zome(TestWasm::Crud);
+
+        let zero: HeaderHash = conductor.call(&zome, "new", ()).await;
+        let one: HeaderHash = conductor.call(&zome, "inc", zero.clone()).await;
+        let two: HeaderHash = conductor.call(&zome, "inc", one.clone()).await;
+
+        consistency_10s(&[&cell]).await;
+
+        let history: Vec<HeaderHash> = conductor
+            .call(&zome, "history", cell.agent_pubkey().clone())
+            .await;
+        // The path and link headers are filtered out by entry type.
+        assert_eq!(history, vec![zero, one, two]);
+    }
+}
@@ -15,6 +15,11 @@ pub fn get_details<'a>(
         get_options,
     } = input;
 
+    // Repeated gets within a call are served from the call's cache
+    if let Some(maybe_details) = call_context.get_cache.details(&any_dht_hash, &get_options) {
+        return Ok(maybe_details);
+    }
+
     // Get the network from the context
     let network = call_context.host_access.network().clone();
 
@@ -26,9 +31,12 @@ pub fn get_details<'a>(
             .write()
             .await
             .cascade(network)
-            .get_details(any_dht_hash, get_options)
+            .get_details(any_dht_hash.clone(), get_options.clone())
             .await
             .map_err(|cascade_error| WasmError::Host(cascade_error.to_string()))?;
+        call_context
+            .get_cache
+            .put_details(any_dht_hash, &get_options, maybe_details.clone());
         Ok(maybe_details)
     })
 }
@@ -36,10 +44,16 @@ pub fn get_details<'a>(
 #[cfg(test)]
 #[cfg(feature = "slow_tests")]
 pub mod wasm_test {
+    use crate::conductor::api::ZomeCall;
+    use crate::core::ribosome::error::RibosomeError;
     use crate::core::workflow::CallZomeWorkspace;
     use crate::fixt::ZomeCallHostAccessFixturator;
+    use crate::test_utils::sweetest::SweetConductor;
+    use crate::test_utils::sweetest::SweetDnaFile;
+    use crate::test_utils::sweetest::SweetZome;
     use ::fixt::prelude::*;
     use hdk::prelude::*;
+    use holo_hash::fixt::HeaderHashFixturator;
     use holochain_wasm_test_utils::TestWasm;
 
     #[tokio::test(flavor = "multi_thread")]
@@ -100,6 +114,41 @@ pub mod wasm_test {
             crate::call_test_ribosome!(host_access, TestWasm::Crud, "entry_hash", CounTree(2));
 
         let zero_a: HeaderHash = crate::call_test_ribosome!(host_access, TestWasm::Crud, "new", ());
+        let all: Vec<HeaderHash> =
+            crate::call_test_ribosome!(host_access, TestWasm::Crud, "list_all", ());
+        assert_eq!(all, vec![zero_a.clone()]);
+        let link_history: Vec<(HeaderHash, Vec<HeaderHash>)> =
+            crate::call_test_ribosome!(host_access, TestWasm::Crud, "link_history", ());
+        assert_eq!(link_history.len(), 1);
+        assert!(link_history[0].1.is_empty());
+        let first_link = link_history[0].0.clone();
+
+        // unlisting keeps the removed link in the history
+        let unlisted: HeaderHash =
+            crate::call_test_ribosome!(host_access, TestWasm::Crud, "unlist", first_link.clone());
+        let all: Vec<HeaderHash> =
+            crate::call_test_ribosome!(host_access, TestWasm::Crud, "list_all", ());
+        assert!(all.is_empty());
+        let link_history: Vec<(HeaderHash, Vec<HeaderHash>)> =
+            crate::call_test_ribosome!(host_access, TestWasm::Crud, "link_history", ());
+        assert_eq!(
+            link_history,
+            vec![(first_link.clone(), vec![unlisted.clone()])]
+        );
+
+        // an unlisted countree can be listed again
+        let _: HeaderHash = crate::call_test_ribosome!(host_access, TestWasm::Crud, "list", zero_a);
+        let all: Vec<HeaderHash> =
+            crate::call_test_ribosome!(host_access, TestWasm::Crud, "list_all", ());
+        assert_eq!(all, vec![zero_a.clone()]);
+        let link_history: Vec<(HeaderHash, Vec<HeaderHash>)> =
+            crate::call_test_ribosome!(host_access, TestWasm::Crud, "link_history", ());
+        assert_eq!(link_history.len(), 2);
+        // the deleted link still shows its delete next to the new live link
+        assert!(link_history.contains(&(first_link.clone(), vec![unlisted])));
+        assert!(link_history
+            .iter()
+            .any(|(create, deletes)| create != &first_link && deletes.is_empty()));
         check(
             crate::call_test_ribosome!(host_access, TestWasm::Crud, "header_details", zero_a),
             0,
@@ -234,6 +283,22 @@ pub mod wasm_test {
             line!(),
         );
 
+        let deletions: Vec<HeaderHash> =
+            crate::call_test_ribosome!(host_access, TestWasm::Crud, "deletions", one_a);
+        assert_eq!(deletions, vec![zero_b.clone()]);
+        let deletions: Vec<HeaderHash> =
+            crate::call_test_ribosome!(host_access, TestWasm::Crud, "deletions", one_b);
+        assert!(deletions.is_empty());
+        let mut updates: Vec<HeaderHash> =
+            crate::call_test_ribosome!(host_access, TestWasm::Crud, "updates", zero_a);
+        updates.sort();
+        let mut expected = vec![one_a.clone(), one_b.clone()];
+        expected.sort();
+        assert_eq!(updates, expected);
+        let updates: Vec<HeaderHash> =
+            crate::call_test_ribosome!(host_access, TestWasm::Crud, "updates", one_b);
+        assert_eq!(updates, vec![two.clone()]);
+
         let zero_b_details: Option<Details> =
             crate::call_test_ribosome!(host_access, TestWasm::Crud, "header_details", zero_b);
         match zero_b_details {
@@ -248,4 +313,63 @@ pub mod wasm_test {
             _ => panic!("no element"),
         }
     }
+    /// Call inc_checked and return the guest error if it failed.
+    async fn inc_checked_error(
+        conductor: &SweetConductor,
+        zome: &SweetZome,
+        header_hash: HeaderHash,
+    ) -> Option<String> {
+        let call = ZomeCall {
+            cell_id: zome.cell_id().clone(),
+            zome_name: zome.name().clone(),
+            fn_name: "inc_checked".into(),
+            cap: None,
+            provenance: zome.cell_id().agent_pubkey().clone(),
+            payload: ExternIO::encode(header_hash).unwrap(),
+        };
+        match conductor.call_zome(call).await.unwrap() {
+            Ok(ZomeCallResponse::Ok(_)) => None,
+            Err(RibosomeError::WasmError(WasmError::Guest(e))) => Some(e),
+            other => panic!("unexpected inc_checked result {:?}", other),
+        }
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn inc_checked_errors() {
+        observability::test_run().ok();
+        let (dna_file, _) = SweetDnaFile::unique_from_test_wasms(vec![TestWasm::Crud])
+            .await
+            .unwrap();
+        let mut conductor = SweetConductor::from_standard_config().await;
+        let app = conductor.setup_app("app", &[dna_file]).await;
+        let cell = app.into_cells().pop().unwrap();
+        let zome = cell.zome(TestWasm::Crud);
+
+        let zero: HeaderHash = conductor.call(&zome, "new", ()).await;
+        assert_eq!(
+            inc_checked_error(&conductor, &zome, zero.clone()).await,
+            None
+        );
+
+        // a header that can't be fetched is reported so the caller can retry
+        assert_eq!(
+            inc_checked_error(&conductor, &zome, fixt!(HeaderHash)).await,
+            Some("countree header not found".to_string())
+        );
+
+        // a header that isn't for a countree
+        let link_history: Vec<(HeaderHash, Vec<HeaderHash>)> =
+            conductor.call(&zome, "link_history", ()).await;
+        assert_eq!(
+            inc_checked_error(&conductor, &zome, link_history[0].0.clone()).await,
+            Some("header is not for a countree".to_string())
+        );
+
+        // a deleted countree is really gone so it isn't reset
+        let _: HeaderHash = conductor.call(&zome, "dec", zero.clone()).await;
+        assert_eq!(
+            inc_checked_error(&conductor, &zome, zero).await,
+            Some("countree was deleted".to_string())
+        );
+    }
 }
@@ -0,0 +1,69 @@
+use crate::core::ribosome::guest_callback::migrate_agent::commit_migration_header;
+use crate::core::ribosome::guest_callback::migrate_agent::run_migrate_agent;
+use crate::core::ribosome::CallContext;
+use crate::core::ribosome::RibosomeT;
+use holochain_types::prelude::*;
+use holochain_wasmer_host::prelude::WasmError;
+use std::sync::Arc;
+
+/// open the source chain of an agent migrating from the dna with the given hash
+#[allow(clippy::extra_unused_lifetimes)]
+pub fn open_chain<'a>(
+    ribosome: Arc<impl RibosomeT>,
+    call_context: Arc<CallContext>,
+    input: DnaHash,
+) -> Result<HeaderHash, WasmError> {
+    // every zome gets a chance to refuse the migration before anything is committed
+    run_migrate_agent(ribosome, call_context.clone(), MigrateAgent::Open)?;
+    commit_migration_header(
+        call_context,
+        builder::OpenChain {
+            prev_dna_hash: input,
+        },
+    )
+}
+
+#[cfg(test)]
+#[cfg(feature = "slow_tests")]
+pub mod slow_tests {
+    use crate::fixt::ZomeCallHostAccessFixturator;
+    use ::fixt::prelude::*;
+    use holo_hash::fixt::DnaHashFixturator;
+    use holochain_types::prelude::*;
+    use holochain_wasm_test_utils::TestWasm;
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn ribosome_open_chain() {
+        let test_env = holochain_lmdb::test_utils::test_cell_env();
+        let env = test_env.env();
+        let mut workspace =
+            crate::core::workflow::CallZomeWorkspace::new(env.clone().into()).unwrap();
+        crate::core::workflow::fake_genesis(&mut workspace.source_chain)
+            .await
+            .unwrap();
+        let workspace_lock = crate::core::workflow::CallZomeWorkspaceLock::new(workspace);
+
+        let mut host_access = fixt!(ZomeCallHostAccess);
+        host_access.workspace = workspace_lock.clone();
+
+        let prev_dna_hash = fixt!(DnaHash);
+        let header_hash: HeaderHash = crate::call_test_ribosome!(
+            host_access,
+            TestWasm::MigrateAgentPass,
+            "open_chain",
+            prev_dna_hash
+        );
+
+        let workspace = workspace_lock.read().await;
+        assert_eq!(workspace.source_chain.chain_head().unwrap(), &header_hash);
+        let element = workspace
+            .source_chain
+            .get_element(&header_hash)
+            .unwrap()
+            .unwrap();
+        match element.header() {
+            Header::OpenChain(open_chain) => assert_eq!(open_chain.prev_dna_hash, prev_dna_hash),
+            header => panic!("expected an OpenChain header but got {:?}", header),
+        }
+    }
+}
@@ -70,4 +70,22 @@ pub mod slow_tests {
 
         assert_eq!(elements.len(), 5);
     }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn query_countree_mine() {
+        let (_test_env, host_access) = setup().await;
+
+        // simple replica of the internal type for the TestWasm::Crud entry
+        #[derive(Clone, Copy, Serialize, Deserialize, SerializedBytes, Debug, PartialEq)]
+        struct CounTree(u32);
+
+        let zero: HeaderHash = crate::call_test_ribosome!(host_access, TestWasm::Crud, "new", ());
+        let one: HeaderHash = crate::call_test_ribosome!(host_access, TestWasm::Crud, "inc", zero);
+        let _two: HeaderHash = crate::call_test_ribosome!(host_access, TestWasm::Crud, "inc", one);
+
+        // Nothing has been flushed so these all come from the scratch.
+        let mine: Vec<CounTree> =
+            crate::call_test_ribosome!(host_access, TestWasm::Crud, "mine", ());
+        assert_eq!(mine, vec![CounTree(2), CounTree(1), CounTree(0)]);
+    }
 }
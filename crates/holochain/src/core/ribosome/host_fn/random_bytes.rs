@@ -5,12 +5,22 @@ use holochain_wasmer_host::prelude::WasmError;
 use ring::rand::SecureRandom;
 use std::sync::Arc;
 
+/// The most bytes a single call to [random_bytes] can ask for.
+/// Stops a guest from making the host allocate arbitrary amounts of memory.
+pub const MAX_RANDOM_BYTES: u32 = 1024 * 1024;
+
 /// return n crypto secure random bytes from the standard holochain crypto lib
 pub fn random_bytes(
     _ribosome: Arc<impl RibosomeT>,
     _call_context: Arc<CallContext>,
     input: u32,
 ) -> Result<Bytes, WasmError> {
+    if input > MAX_RANDOM_BYTES {
+        return Err(WasmError::Host(format!(
+            "Asked for {} random bytes but at most {} can be generated at once",
+            input, MAX_RANDOM_BYTES
+        )));
+    }
     let system_random = ring::rand::SystemRandom::new();
     let mut bytes = vec![0; input as _];
     system_random
@@ -24,6 +34,7 @@ pub fn random_bytes(
 #[cfg(feature = "slow_tests")]
 pub mod wasm_test {
     use crate::core::ribosome::host_fn::random_bytes::random_bytes;
+    use crate::core::ribosome::host_fn::random_bytes::MAX_RANDOM_BYTES;
 
     use crate::fixt::CallContextFixturator;
     use crate::fixt::RealRibosomeFixturator;
@@ -51,6 +62,26 @@ pub mod wasm_test {
         assert_ne!(&[0; LEN as usize], output.as_ref(),);
     }
 
+    #[tokio::test(flavor = "multi_thread")]
+    /// asking for too many bytes is an error rather than a huge allocation
+    async fn random_bytes_max_test() {
+        let ribosome = Arc::new(
+            RealRibosomeFixturator::new(crate::fixt::curve::Zomes(vec![]))
+                .next()
+                .unwrap(),
+        );
+        let call_context = Arc::new(
+            CallContextFixturator::new(::fixt::Unpredictable)
+                .next()
+                .unwrap(),
+        );
+
+        let output =
+            random_bytes(ribosome.clone(), call_context.clone(), MAX_RANDOM_BYTES).unwrap();
+        assert_eq!(output.len(), MAX_RANDOM_BYTES as usize);
+        assert!(random_bytes(ribosome, call_context, MAX_RANDOM_BYTES + 1).is_err());
+    }
+
     #[tokio::test(flavor = "multi_thread")]
     /// we can get some random data out of the fn via. a wasm call
     async fn ribosome_random_bytes_test() {
@@ -70,5 +101,13 @@ pub mod wasm_test {
             crate::call_test_ribosome!(host_access, TestWasm::RandomBytes, "random_bytes", LEN);
 
         assert_ne!(&vec![0; LEN as usize], &output.to_vec());
+
+        // Two calls for a 32 byte secret don't return the same bytes
+        let first: hdk::prelude::Bytes =
+            crate::call_test_ribosome!(host_access, TestWasm::RandomBytes, "random_bytes", 32_u32);
+        let second: hdk::prelude::Bytes =
+            crate::call_test_ribosome!(host_access, TestWasm::RandomBytes, "random_bytes", 32_u32);
+        assert_eq!(first.len(), 32);
+        assert_ne!(first, second);
     }
 }
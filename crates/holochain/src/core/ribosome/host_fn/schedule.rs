@@ -8,5 +8,38 @@ pub fn schedule(
     _call_context: Arc<CallContext>,
     _input: core::time::Duration,
 ) -> Result<(), WasmError> {
-    unimplemented!()
+    // There is nothing to run scheduled calls yet so
+    // fail the call rather than panicking the host.
+    Err(WasmError::Host("schedule is not implemented yet".to_string()))
+}
+
+#[cfg(test)]
+#[cfg(feature = "slow_tests")]
+pub mod wasm_test {
+    use crate::core::ribosome::host_fn::schedule::schedule;
+
+    use crate::fixt::CallContextFixturator;
+    use crate::fixt::RealRibosomeFixturator;
+    use ::fixt::prelude::*;
+    use holochain_wasmer_host::prelude::WasmError;
+    use std::sync::Arc;
+
+    #[tokio::test(flavor = "multi_thread")]
+    /// scheduling is an error for the guest rather than a panic in the host
+    async fn schedule_is_an_error() {
+        let ribosome = RealRibosomeFixturator::new(crate::fixt::curve::Zomes(vec![]))
+            .next()
+            .unwrap();
+        let call_context = CallContextFixturator::new(::fixt::Unpredictable)
+            .next()
+            .unwrap();
+
+        let result = schedule(
+            Arc::new(ribosome),
+            Arc::new(call_context),
+            core::time::Duration::from_secs(1),
+        );
+
+        assert!(matches!(result, Err(WasmError::Host(_))));
+    }
 }
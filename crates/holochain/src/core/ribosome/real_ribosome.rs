@@ -34,6 +34,7 @@ use crate::core::ribosome::host_fn::call_remote::call_remote;
 use crate::core::ribosome::host_fn::capability_claims::capability_claims;
 use crate::core::ribosome::host_fn::capability_grants::capability_grants;
 use crate::core::ribosome::host_fn::capability_info::capability_info;
+use crate::core::ribosome::host_fn::close_chain::close_chain;
 use crate::core::ribosome::host_fn::create::create;
 use crate::core::ribosome::host_fn::create_link::create_link;
 use crate::core::ribosome::host_fn::create_x25519_keypair::create_x25519_keypair;
@@ -46,6 +47,7 @@ use crate::core::ribosome::host_fn::get_details::get_details;
 use crate::core::ribosome::host_fn::get_link_details::get_link_details;
 use crate::core::ribosome::host_fn::get_links::get_links;
 use crate::core::ribosome::host_fn::hash_entry::hash_entry;
+use crate::core::ribosome::host_fn::open_chain::open_chain;
 use crate::core::ribosome::host_fn::query::query;
 use crate::core::ribosome::host_fn::random_bytes::random_bytes;
 use crate::core::ribosome::host_fn::remote_signal::remote_signal;
@@ -367,6 +369,8 @@ impl RealRibosome {
             ns.insert("__delete_link", func!(invoke_host_function!(delete_link)));
             ns.insert("__update", func!(invoke_host_function!(update)));
             ns.insert("__delete", func!(invoke_host_function!(delete)));
+            ns.insert("__close_chain", func!(invoke_host_function!(close_chain)));
+            ns.insert("__open_chain", func!(invoke_host_function!(open_chain)));
             ns.insert("__schedule", func!(invoke_host_function!(schedule)));
         } else {
             ns.insert("__call", func!(invoke_host_function!(unreachable)));
@@ -376,6 +380,8 @@ impl RealRibosome {
             ns.insert("__delete_link", func!(invoke_host_function!(unreachable)));
             ns.insert("__update", func!(invoke_host_function!(unreachable)));
             ns.insert("__delete", func!(invoke_host_function!(unreachable)));
+            ns.insert("__close_chain", func!(invoke_host_function!(unreachable)));
+            ns.insert("__open_chain", func!(invoke_host_function!(unreachable)));
             ns.insert("__schedule", func!(invoke_host_function!(unreachable)));
         }
         imports.register("env", ns);
@@ -421,10 +427,7 @@ impl RibosomeT for RealRibosome {
         zome: &Zome,
         to_call: &FunctionName,
     ) -> Result<Option<ExternIO>, RibosomeError> {
-        let call_context = CallContext {
-            zome: zome.clone(),
-            host_access,
-        };
+        let call_context = CallContext::new(zome.clone(), host_access);
 
         match zome.zome_def() {
             ZomeDef::Wasm(_) => {
@@ -461,6 +464,17 @@ impl RibosomeT for RealRibosome {
         }
     }
 
+    fn has_callback(&self, zome: &Zome, to_call: &FunctionName) -> RibosomeResult<bool> {
+        Ok(match zome.zome_def() {
+            ZomeDef::Wasm(_) => self
+                .module(zome.zome_name())?
+                .info()
+                .exports
+                .contains_key(to_call.as_ref()),
+            ZomeDef::Inline(zome) => zome.has_callback(to_call),
+        })
+    }
+
     fn call_iterator<I: crate::core::ribosome::Invocation>(
         &self,
         access: HostAccess,
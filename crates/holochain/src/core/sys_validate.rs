@@ -173,6 +173,29 @@ pub fn check_prev_seq(header: &Header, prev_header: &Header) -> SysValidationRes
     }
 }
 
+/// Check the previous header hasn't closed the chain
+pub fn check_prev_not_close_chain(prev_header: &Header) -> SysValidationResult<()> {
+    match prev_header {
+        Header::CloseChain(_) => {
+            Err(PrevHeaderError::ChainClosed).map_err(|e| ValidationOutcome::from(e).into())
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Check an OpenChain header directly follows genesis or zome initialization
+pub fn check_open_chain_placement(
+    header: &Header,
+    prev_header: &Header,
+) -> SysValidationResult<()> {
+    match header {
+        Header::OpenChain(_) if !prev_header.can_precede_open_chain() => {
+            Err(PrevHeaderError::OpenChainPlacement).map_err(|e| ValidationOutcome::from(e).into())
+        }
+        _ => Ok(()),
+    }
+}
+
 /// Check the entry variant matches the variant in the headers entry type
 pub fn check_entry_type(entry_type: &EntryType, entry: &Entry) -> SysValidationResult<()> {
     match (entry_type, entry) {
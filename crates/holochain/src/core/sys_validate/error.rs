@@ -147,4 +147,8 @@ pub enum PrevHeaderError {
     MissingPrev,
     #[error("The previous header's timestamp is not before the current header's timestamp")]
     Timestamp,
+    #[error("The previous header is a CloseChain so the chain can't have more headers")]
+    ChainClosed,
+    #[error("OpenChain must directly follow genesis or zome initialization")]
+    OpenChainPlacement,
 }
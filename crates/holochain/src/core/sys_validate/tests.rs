@@ -153,6 +153,63 @@ async fn check_previous_seq() {
     );
 }
 
+#[tokio::test(flavor = "multi_thread")]
+async fn check_prev_close_chain_test() {
+    assert_matches!(
+        check_prev_not_close_chain(&fixt!(CreateLink).into()),
+        Ok(())
+    );
+    assert_matches!(
+        check_prev_not_close_chain(&fixt!(CloseChain).into()),
+        Err(SysValidationError::ValidationOutcome(
+            ValidationOutcome::PrevHeaderError(PrevHeaderError::ChainClosed)
+        ))
+    );
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn check_open_chain_placement_test() {
+    let open_chain: Header = fixt!(OpenChain).into();
+    let mut agent_key = fixt!(Create);
+    agent_key.entry_type = EntryType::AgentPubKey;
+    agent_key.header_seq = 2;
+
+    // other headers can go anywhere
+    assert_matches!(
+        check_open_chain_placement(&fixt!(CreateLink).into(), &fixt!(CreateLink).into()),
+        Ok(())
+    );
+    assert_matches!(
+        check_open_chain_placement(&open_chain, &agent_key.clone().into()),
+        Ok(())
+    );
+    assert_matches!(
+        check_open_chain_placement(&open_chain, &fixt!(InitZomesComplete).into()),
+        Ok(())
+    );
+
+    // the agent key is only the end of genesis at sequence 2
+    agent_key.header_seq = 5;
+    assert_matches!(
+        check_open_chain_placement(&open_chain, &agent_key.into()),
+        Err(SysValidationError::ValidationOutcome(
+            ValidationOutcome::PrevHeaderError(PrevHeaderError::OpenChainPlacement)
+        ))
+    );
+    for prev_header in vec![
+        Header::from(fixt!(CreateLink)),
+        fixt!(OpenChain).into(),
+        fixt!(CloseChain).into(),
+    ] {
+        assert_matches!(
+            check_open_chain_placement(&open_chain, &prev_header),
+            Err(SysValidationError::ValidationOutcome(
+                ValidationOutcome::PrevHeaderError(PrevHeaderError::OpenChainPlacement)
+            ))
+        );
+    }
+}
+
 #[tokio::test(flavor = "multi_thread")]
 async fn check_entry_type_test() {
     let entry_fixt = EntryFixturator::new(Predictable);
@@ -13,11 +13,14 @@ use holochain_wasm_test_utils::TestWasm;
 use matches::assert_matches;
 
 use crate::conductor::ConductorHandle;
+use crate::fixt::CreateFixturator;
+use crate::fixt::SignatureFixturator;
 use crate::test_utils::conductor_setup::CellHostFnCaller;
 use crate::test_utils::conductor_setup::ConductorTestData;
 use crate::test_utils::host_fn_caller::Post;
 use crate::test_utils::new_zome_call;
 use crate::test_utils::wait_for_integration;
+use ::fixt::prelude::*;
 use holochain_cascade::Cascade;
 use holochain_cascade::DbPair;
 use holochain_cascade::DbPairMut;
@@ -30,10 +33,9 @@ use holochain_state::source_chain::SourceChain;
 const NUM_COMMITS: usize = 5;
 const GET_AGENT_ACTIVITY_TIMEOUT_MS: u64 = 1000;
 // Check if the correct number of ops are integrated
-// every 100 ms for a maximum of 10 seconds but early exit
+// for a maximum of 10 seconds but early exit
 // if they are there.
-const NUM_ATTEMPTS: usize = 100;
-const DELAY_PER_ATTEMPT: std::time::Duration = std::time::Duration::from_millis(100);
+const DEADLINE: std::time::Duration = std::time::Duration::from_secs(10);
 
 #[tokio::test(flavor = "multi_thread")]
 async fn get_validation_package_test() {
@@ -281,13 +283,7 @@ async fn get_agent_activity_test() {
     // 3 ops per commit, 5 commits plus 7 for genesis + 2 for init + 2 for cap
     let mut expected_count = NUM_COMMITS * 3 + 9 + 2;
 
-    wait_for_integration(
-        &alice_call_data.env,
-        expected_count,
-        NUM_ATTEMPTS,
-        DELAY_PER_ATTEMPT.clone(),
-    )
-    .await;
+    wait_for_integration(&alice_call_data.env, expected_count, DEADLINE).await;
 
     let agent_activity = alice_call_data
         .network
@@ -383,13 +379,7 @@ async fn get_agent_activity_test() {
     alice_call_data.triggers.produce_dht_ops.trigger();
 
     expected_count += NUM_COMMITS * 2;
-    wait_for_integration(
-        &alice_call_data.env,
-        expected_count,
-        NUM_ATTEMPTS,
-        DELAY_PER_ATTEMPT.clone(),
-    )
-    .await;
+    wait_for_integration(&alice_call_data.env, expected_count, DEADLINE).await;
 
     let mut agent_activity = alice_call_data
         .network
@@ -429,13 +419,7 @@ async fn get_agent_activity_test() {
     // Wait for alice to integrate the chain as an authority
     alice_call_data.triggers.produce_dht_ops.trigger();
     expected_count += NUM_COMMITS * 3;
-    wait_for_integration(
-        &alice_call_data.env,
-        expected_count,
-        NUM_ATTEMPTS,
-        DELAY_PER_ATTEMPT.clone(),
-    )
-    .await;
+    wait_for_integration(&alice_call_data.env, expected_count, DEADLINE).await;
 
     // Call alice and get the activity
     let agent_activity = cascade
@@ -523,13 +507,7 @@ async fn get_custom_package_test() {
     let expected_count = 16 + 30 * 3 + 3;
 
     // Wait for bob to integrate and then check they have the package cached
-    wait_for_integration(
-        &bob_call_data.env,
-        expected_count,
-        NUM_ATTEMPTS,
-        DELAY_PER_ATTEMPT.clone(),
-    )
-    .await;
+    wait_for_integration(&bob_call_data.env, expected_count, DEADLINE).await;
 
     let alice_source_chain = SourceChain::public_only(alice_call_data.env.clone().into()).unwrap();
     let shh = alice_source_chain
@@ -569,6 +547,71 @@ async fn get_custom_package_test() {
     conductor_test.shutdown_conductor().await;
 }
 
+#[tokio::test(flavor = "multi_thread")]
+async fn get_full_chain_package_test() {
+    observability::test_run().ok();
+
+    let zomes = vec![TestWasm::ValidationPackageSuccess];
+    let mut conductor_test = ConductorTestData::two_agents(zomes, true).await;
+    let handle = conductor_test.handle();
+    let alice_call_data = conductor_test.alice_call_data();
+    let bob_call_data = conductor_test.bob_call_data().unwrap();
+    let alice_cell_id = &alice_call_data.cell_id;
+
+    let invocation = new_zome_call(
+        &alice_cell_id,
+        "commit_album",
+        (),
+        TestWasm::ValidationPackageSuccess,
+    )
+    .unwrap();
+    let result = handle.call_zome(invocation).await.unwrap().unwrap();
+    let album_hash: HeaderHash = unwrap_to::unwrap_to!(result => ZomeCallResponse::Ok)
+        .decode()
+        .unwrap();
+
+    // 15 for genesis plus 1 init
+    // 1 album is 3 ops.
+    let expected_count = 16 + 3;
+
+    // Wait for bob to integrate and then check they have the package cached
+    wait_for_integration(&bob_call_data.env, expected_count, DEADLINE).await;
+
+    // Albums are validated against alice's entire source chain
+    let alice_source_chain = SourceChain::public_only(alice_call_data.env.clone().into()).unwrap();
+    let mut expected_package = alice_source_chain
+        .iter_back()
+        .map(|shh| Ok(shh.header_address().clone()))
+        .collect::<Vec<_>>()
+        .unwrap();
+    assert!(expected_package.contains(&album_hash));
+    expected_package.sort();
+
+    {
+        let env: EnvironmentRead = bob_call_data.env.clone().into();
+        let element_integrated = ElementBuf::vault(env.clone(), false).unwrap();
+        let meta_integrated = MetadataBuf::vault(env.clone()).unwrap();
+        let mut element_cache = ElementBuf::cache(env.clone()).unwrap();
+        let mut meta_cache = MetadataBuf::cache(env.clone()).unwrap();
+        let cascade = Cascade::empty()
+            .with_cache(DbPairMut::new(&mut element_cache, &mut meta_cache))
+            .with_integrated(DbPair::new(&element_integrated, &meta_integrated));
+
+        let package = cascade
+            .get_validation_package_local(&album_hash)
+            .unwrap()
+            .expect("Bob should have the album's validation package cached");
+        let mut package = package
+            .iter()
+            .map(|el| el.header_address().clone())
+            .collect::<Vec<_>>();
+        package.sort();
+        assert_eq!(package, expected_package);
+    }
+
+    conductor_test.shutdown_conductor().await;
+}
+
 #[tokio::test(flavor = "multi_thread")]
 async fn get_agent_activity_host_fn_test() {
     observability::test_run().ok();
@@ -616,13 +659,7 @@ async fn get_agent_activity_host_fn_test() {
     // 3 ops per commit, 5 commits plus 7 for genesis + 2 for init
     let expected_count = NUM_COMMITS * 3 + 9;
 
-    wait_for_integration(
-        &alice_call_data.env,
-        expected_count,
-        NUM_ATTEMPTS,
-        DELAY_PER_ATTEMPT.clone(),
-    )
-    .await;
+    wait_for_integration(&alice_call_data.env, expected_count, DEADLINE).await;
 
     let agent_activity = alice_call_data
         .get_api(TestWasm::Create)
@@ -656,6 +693,71 @@ async fn get_agent_activity_host_fn_test() {
     conductor_test.shutdown_conductor().await;
 }
 
+#[tokio::test(flavor = "multi_thread")]
+async fn network_only_get_ignores_local_data() {
+    observability::test_run().ok();
+
+    let zomes = vec![TestWasm::Create];
+    let mut conductor_test = ConductorTestData::two_agents(zomes, false).await;
+    let handle = conductor_test.handle();
+    let alice_call_data = conductor_test.alice_call_data();
+
+    let header_hash = commit_some_data("create_entry", &alice_call_data, &handle).await;
+
+    // 3 ops per commit, 5 commits plus 7 for genesis + 2 for init + 2 for cap
+    let expected_count = NUM_COMMITS * 3 + 9 + 2;
+    wait_for_integration(&alice_call_data.env, expected_count, DEADLINE).await;
+
+    // A header that is only in alice's cache and no authority has
+    let cached_only = SignedHeaderHashed::with_presigned(
+        HeaderHashed::from_content_sync(fixt!(Create).into()),
+        fixt!(Signature),
+    );
+    let cached_only_hash = cached_only.header_address().clone();
+
+    let env: EnvironmentRead = alice_call_data.env.clone().into();
+    let element_authored = ElementBuf::authored(env.clone(), false).unwrap();
+    let meta_authored = MetadataBuf::authored(env.clone()).unwrap();
+    let element_integrated = ElementBuf::vault(env.clone(), false).unwrap();
+    let meta_integrated = MetadataBuf::vault(env.clone()).unwrap();
+    let mut element_cache = ElementBuf::cache(env.clone()).unwrap();
+    let mut meta_cache = MetadataBuf::cache(env.clone()).unwrap();
+    element_cache.put(cached_only, None).unwrap();
+    let mut cascade = Cascade::empty()
+        .with_authored(DbPair::new(&element_authored, &meta_authored))
+        .with_integrated(DbPair::new(&element_integrated, &meta_integrated))
+        .with_cache(DbPairMut::new(&mut element_cache, &mut meta_cache))
+        .with_network(alice_call_data.network.clone());
+
+    // Local data is used by the other strategies
+    let element = cascade
+        .dht_get(cached_only_hash.clone().into(), GetOptions::content())
+        .await
+        .unwrap();
+    assert_eq!(
+        element.map(|el| el.header_address().clone()),
+        Some(cached_only_hash.clone())
+    );
+
+    // but not when only the network is asked
+    let element = cascade
+        .dht_get(cached_only_hash.into(), GetOptions::network_only())
+        .await
+        .unwrap();
+    assert_eq!(element, None);
+
+    // What the authorities have is still found
+    let element = cascade
+        .dht_get(header_hash.clone().into(), GetOptions::network_only())
+        .await
+        .unwrap()
+        .expect("The authorities should have the header");
+    assert_eq!(element.header_address(), &header_hash);
+    assert!(element.entry().as_option().is_some());
+
+    conductor_test.shutdown_conductor().await;
+}
+
 async fn commit_some_data(
     call: &str,
     alice_call_data: &CellHostFnCaller,
@@ -753,13 +855,7 @@ async fn slow_lmdb_reads_test() {
     }
 
     let expected_count = 9 + 3 * 3 * num_commits + 2 * num_commits;
-    wait_for_integration(
-        &alice_call_data.env,
-        expected_count,
-        NUM_ATTEMPTS,
-        DELAY_PER_ATTEMPT.clone(),
-    )
-    .await;
+    wait_for_integration(&alice_call_data.env, expected_count, DEADLINE).await;
     // Get the source chain hashes
     let alice_source_chain = SourceChain::new(alice_env.clone().into()).unwrap();
     let hashes: Vec<_> = alice_source_chain
@@ -771,13 +867,7 @@ async fn slow_lmdb_reads_test() {
 
     // Time how long it takes to get the headers
     let expected_count = 9 + 3 * 2 * num_commits + 2 * num_commits;
-    wait_for_integration(
-        &alice_call_data.env,
-        expected_count,
-        NUM_ATTEMPTS,
-        DELAY_PER_ATTEMPT.clone(),
-    )
-    .await;
+    wait_for_integration(&alice_call_data.env, expected_count, DEADLINE).await;
 
     alice_call_data
         .network
@@ -194,10 +194,9 @@ async fn run_test(
     dna_file: &DnaFile,
 ) -> usize {
     // Check if the correct number of ops are integrated
-    // every 100 ms for a maximum of 10 seconds but early exit
+    // for a maximum of 10 seconds but early exit
     // if they are there.
-    let num_attempts = 100;
-    let delay_per_attempt = Duration::from_millis(100);
+    let deadline = Duration::from_secs(10);
 
     let invocation =
         new_zome_call(&bob_cell_id, "always_validates", (), TestWasm::Validate).unwrap();
@@ -208,7 +207,7 @@ async fn run_test(
     // Plus 2 for Cap Grant
     let expected_count = 3 + 16 + 2;
     let alice_env = handle.get_cell_env(&alice_cell_id).await.unwrap();
-    wait_for_integration(&alice_env, expected_count, num_attempts, delay_per_attempt).await;
+    wait_for_integration(&alice_env, expected_count, deadline).await;
 
     {
         let alice_env = handle.get_cell_env(&alice_cell_id).await.unwrap();
@@ -235,7 +234,7 @@ async fn run_test(
     // So they will be valid.
     let expected_count = 3 + expected_count;
     let alice_env = handle.get_cell_env(&alice_cell_id).await.unwrap();
-    wait_for_integration(&alice_env, expected_count, num_attempts, delay_per_attempt).await;
+    wait_for_integration(&alice_env, expected_count, deadline).await;
 
     {
         let alice_env = handle.get_cell_env(&alice_cell_id).await.unwrap();
@@ -261,7 +260,7 @@ async fn run_test(
     // Integration should have 6 ops in it
     let expected_count = 6 + expected_count;
     let alice_env = handle.get_cell_env(&alice_cell_id).await.unwrap();
-    wait_for_integration(&alice_env, expected_count, num_attempts, delay_per_attempt).await;
+    wait_for_integration(&alice_env, expected_count, deadline).await;
 
     {
         let alice_env = handle.get_cell_env(&alice_cell_id).await.unwrap();
@@ -289,7 +288,7 @@ async fn run_test(
     // Integration should have 9 ops in it
     let expected_count = 9 + expected_count;
     let alice_env = handle.get_cell_env(&alice_cell_id).await.unwrap();
-    wait_for_integration(&alice_env, expected_count, num_attempts, delay_per_attempt).await;
+    wait_for_integration(&alice_env, expected_count, deadline).await;
 
     {
         let alice_env = handle.get_cell_env(&alice_cell_id).await.unwrap();
@@ -321,7 +320,7 @@ async fn run_test(
     // Integration should have 9 ops in it
     let expected_count = 9 + expected_count;
     let alice_env = handle.get_cell_env(&alice_cell_id).await.unwrap();
-    wait_for_integration(&alice_env, expected_count, num_attempts, delay_per_attempt).await;
+    wait_for_integration(&alice_env, expected_count, deadline).await;
 
     {
         let alice_env = handle.get_cell_env(&alice_cell_id).await.unwrap();
@@ -357,7 +356,7 @@ async fn run_test(
     // Integration should have 12 ops in it
     let expected_count = 12 + expected_count;
     let alice_env = handle.get_cell_env(&alice_cell_id).await.unwrap();
-    wait_for_integration(&alice_env, expected_count, num_attempts, delay_per_attempt).await;
+    wait_for_integration(&alice_env, expected_count, deadline).await;
 
     {
         let alice_env = handle.get_cell_env(&alice_cell_id).await.unwrap();
@@ -392,10 +391,9 @@ async fn run_test_entry_def_id(
     expected_count: usize,
 ) {
     // Check if the correct number of ops are integrated
-    // every 100 ms for a maximum of 10 seconds but early exit
+    // for a maximum of 10 seconds but early exit
     // if they are there.
-    let num_attempts = 100;
-    let delay_per_attempt = Duration::from_millis(100);
+    let deadline = Duration::from_secs(10);
 
     let (invalid_header_hash, invalid_entry_hash) =
         commit_invalid_post(&bob_cell_id, &handle, dna_file).await;
@@ -405,7 +403,7 @@ async fn run_test_entry_def_id(
     // StoreEntry and StoreElement should be invalid.
     let expected_count = 3 + expected_count;
     let alice_env = handle.get_cell_env(&alice_cell_id).await.unwrap();
-    wait_for_integration(&alice_env, expected_count, num_attempts, delay_per_attempt).await;
+    wait_for_integration(&alice_env, expected_count, deadline).await;
 
     {
         let alice_env = handle.get_cell_env(&alice_cell_id).await.unwrap();
@@ -7,6 +7,9 @@ use crate::core::queue_consumer::OneshotWriter;
 use crate::core::queue_consumer::TriggerSender;
 use crate::core::ribosome::error::RibosomeError;
 use crate::core::ribosome::error::RibosomeResult;
+use crate::core::ribosome::guest_callback::post_commit::PostCommitHostAccess;
+use crate::core::ribosome::guest_callback::post_commit::PostCommitInvocation;
+use crate::core::ribosome::guest_callback::post_commit::PostCommitResult;
 use crate::core::ribosome::RibosomeT;
 use crate::core::ribosome::ZomeCallHostAccess;
 use crate::core::ribosome::ZomeCallInvocation;
@@ -48,14 +51,7 @@ pub struct CallZomeWorkflowArgs<Ribosome: RibosomeT + Send, C: CellConductorApiT
     pub is_root_zome_call: bool,
 }
 
-#[instrument(skip(
-    workspace_lock,
-    network,
-    keystore,
-    writer,
-    args,
-    trigger_produce_dht_ops
-))]
+#[instrument(skip(workspace_lock, network, keystore, env, args, trigger_produce_dht_ops))]
 pub async fn call_zome_workflow<
     'env,
     Ribosome: RibosomeT + Send + 'static,
@@ -64,12 +60,20 @@ pub async fn call_zome_workflow<
     workspace_lock: CallZomeWorkspaceLock,
     network: HolochainP2pCell,
     keystore: KeystoreSender,
-    writer: OneshotWriter,
+    env: EnvironmentWrite,
     args: CallZomeWorkflowArgs<Ribosome, C>,
     mut trigger_produce_dht_ops: TriggerSender,
 ) -> WorkflowResult<ZomeCallResult> {
     let should_write = args.is_root_zome_call;
-    let result = call_zome_workflow_inner(workspace_lock.clone(), network, keystore, args).await?;
+    let conductor_api = args.conductor_api.clone();
+    let zome = args.invocation.zome.clone();
+    let (result, ribosome, committed) = call_zome_workflow_inner(
+        workspace_lock.clone(),
+        network.clone(),
+        keystore.clone(),
+        args,
+    )
+    .await?;
 
     // --- END OF WORKFLOW, BEGIN FINISHER BOILERPLATE ---
 
@@ -77,7 +81,25 @@ pub async fn call_zome_workflow<
     if should_write {
         let mut guard = workspace_lock.write().await;
         let workspace = &mut guard;
-        writer.with_writer(|writer| Ok(workspace.flush_to_txn_ref(writer)?))?;
+        OneshotWriter::from(env.clone())
+            .with_writer(|writer| Ok(workspace.flush_to_txn_ref(writer)?))?;
+    }
+
+    // Only the root call runs post_commit so it
+    // also sees the headers from any nested calls
+    if should_write && !committed.is_empty() {
+        if let Ok(ZomeCallResponse::Ok(_)) = &result {
+            post_commit(
+                env,
+                network,
+                keystore,
+                ribosome,
+                zome,
+                committed,
+                conductor_api,
+            )
+            .await?;
+        }
     }
 
     trigger_produce_dht_ops.trigger();
@@ -94,7 +116,7 @@ async fn call_zome_workflow_inner<
     network: HolochainP2pCell,
     keystore: KeystoreSender,
     args: CallZomeWorkflowArgs<Ribosome, C>,
-) -> WorkflowResult<ZomeCallResult> {
+) -> WorkflowResult<(ZomeCallResult, Ribosome, Vec<HeaderHash>)> {
     let CallZomeWorkflowArgs {
         ribosome,
         invocation,
@@ -130,6 +152,115 @@ async fn call_zome_workflow_inner<
     .await?;
     tracing::trace!(line = line!());
 
+    let committed = validate_new_elements(
+        chain_head_start_len,
+        &zome,
+        &ribosome,
+        workspace_lock,
+        network,
+        &conductor_api,
+    )
+    .await?;
+
+    Ok((result, ribosome, committed))
+}
+
+/// Run the `post_commit` callback of the called zome with the headers
+/// the call has just flushed to the source chain.
+///
+/// The callback gets a fresh workspace so it can see the committed headers.
+/// Anything it commits is validated and flushed in a second transaction
+/// without running `post_commit` again.
+/// The zome call has already been committed at this point, so any failure
+/// here only discards the callback's own commits and is logged.
+/// Zomes that don't export `post_commit` are skipped without opening a workspace.
+async fn post_commit<Ribosome: RibosomeT + Send + 'static, C: CellConductorApiT>(
+    env: EnvironmentWrite,
+    network: HolochainP2pCell,
+    keystore: KeystoreSender,
+    ribosome: Ribosome,
+    zome: Zome,
+    headers: Vec<HeaderHash>,
+    conductor_api: C,
+) -> WorkflowResult<()> {
+    match ribosome.has_callback(&zome, &"post_commit".into()) {
+        Ok(true) => {}
+        Ok(false) => return Ok(()),
+        Err(error) => {
+            tracing::warn!(?error, "Error looking up post_commit");
+            return Ok(());
+        }
+    }
+
+    let workspace = match CallZomeWorkspace::new(env.clone().into()) {
+        Ok(workspace) => workspace,
+        Err(error) => {
+            tracing::warn!(?error, "Error creating the post_commit workspace");
+            return Ok(());
+        }
+    };
+    let workspace_lock = CallZomeWorkspaceLock::new(workspace);
+    let chain_head_start_len = workspace_lock.read().await.source_chain.len();
+
+    let (ribosome, result) = tokio::task::spawn_blocking({
+        let workspace_lock = workspace_lock.clone();
+        let network = network.clone();
+        let zome = zome.clone();
+        move || {
+            let host_access = PostCommitHostAccess::new(workspace_lock, keystore, network);
+            let invocation = PostCommitInvocation::new(zome, headers.into());
+            let result = ribosome.run_post_commit(host_access, invocation);
+            (ribosome, result)
+        }
+    })
+    .await?;
+
+    match result {
+        Ok(PostCommitResult::Success) => {}
+        Ok(PostCommitResult::Fail(headers, reason)) => {
+            tracing::warn!(?headers, %reason, "post_commit failed");
+            return Ok(());
+        }
+        Err(error) => {
+            tracing::warn!(?error, "Error running post_commit");
+            return Ok(());
+        }
+    }
+
+    if let Err(error) = validate_new_elements(
+        chain_head_start_len,
+        &zome,
+        &ribosome,
+        workspace_lock.clone(),
+        network,
+        &conductor_api,
+    )
+    .await
+    {
+        tracing::warn!(?error, "post_commit made an invalid commit");
+        return Ok(());
+    }
+
+    let mut guard = workspace_lock.write().await;
+    let workspace = &mut guard;
+    if let Err(error) =
+        OneshotWriter::from(env).with_writer(|writer| Ok(workspace.flush_to_txn_ref(writer)?))
+    {
+        tracing::warn!(?error, "Error flushing post_commit commits");
+    }
+    Ok(())
+}
+
+/// Sys and app validate every element committed to the source chain
+/// after `chain_head_start_len`, returning their header hashes.
+async fn validate_new_elements<Ribosome: RibosomeT, C: CellConductorApiT>(
+    chain_head_start_len: usize,
+    zome: &Zome,
+    ribosome: &Ribosome,
+    workspace_lock: CallZomeWorkspaceLock,
+    network: HolochainP2pCell,
+    conductor_api: &C,
+) -> WorkflowResult<Vec<HeaderHash>> {
     let to_app_validate = {
         let mut workspace = workspace_lock.write().await;
         // Get the new head
@@ -144,7 +275,7 @@ async fn call_zome_workflow_inner<
             // Loop forwards through all the new elements
             let mut i = chain_head_start_len;
             while let Some(element) = workspace.source_chain.get_at_index(i as u32)? {
-                sys_validate_element(&element, &mut workspace, network.clone(), &conductor_api)
+                sys_validate_element(&element, &mut workspace, network.clone(), conductor_api)
                     .await
                     // If the was en error exit
                     // If the validation failed, exit with an InvalidCommit
@@ -156,6 +287,10 @@ async fn call_zome_workflow_inner<
         }
         to_app_validate
     };
+    let committed = to_app_validate
+        .iter()
+        .map(|element| element.header_address().clone())
+        .collect();
 
     {
         for chain_element in to_app_validate {
@@ -200,7 +335,7 @@ async fn call_zome_workflow_inner<
                             link_add,
                             base,
                             target,
-                            &ribosome,
+                            ribosome,
                             workspace_lock.clone(),
                             network.clone(),
                         )?,
@@ -210,7 +345,7 @@ async fn call_zome_workflow_inner<
                     app_validation_workflow::run_delete_link_validation_callback(
                         zome.clone(),
                         delete_link.clone(),
-                        &ribosome,
+                        ribosome,
                         workspace_lock.clone(),
                         network.clone(),
                     )?,
@@ -219,10 +354,10 @@ async fn call_zome_workflow_inner<
                     app_validation_workflow::run_validation_callback_direct(
                         zome.clone(),
                         chain_element,
-                        &ribosome,
+                        ribosome,
                         workspace_lock.clone(),
                         network.clone(),
-                        &conductor_api,
+                        conductor_api,
                     )
                     .await?,
                 ),
@@ -257,7 +392,7 @@ async fn call_zome_workflow_inner<
         }
     }
 
-    Ok(result)
+    Ok(committed)
 }
 
 pub struct CallZomeWorkspace {
@@ -379,7 +514,9 @@ pub mod tests {
             conductor_api,
             is_root_zome_call: true,
         };
-        call_zome_workflow_inner(workspace.into(), network, keystore, args).await
+        call_zome_workflow_inner(workspace.into(), network, keystore, args)
+            .await
+            .map(|(result, _, _)| result)
     }
 
     // 1.  Check if there is a Capability token secret in the parameters.
@@ -66,7 +66,7 @@ pub async fn incoming_dht_ops_workflow(
     // commit our transaction
     let writer: crate::core::queue_consumer::OneshotWriter = state_env.clone().into();
 
-    writer.with_writer(|writer| Ok(workspace.flush_to_txn(writer)?))?;
+    writer.with_writer(|writer| Ok(workspace.flush_to_txn_ref(writer)?))?;
 
     // trigger validation of queued ops
     sys_validation_trigger.trigger();
@@ -17,6 +17,7 @@ use holochain_cascade::Cascade;
 use holochain_cascade::DbPair;
 use holochain_cascade::{error::CascadeError, integrate_single_metadata};
 use holochain_conductor_api::IntegrationStateDump;
+use holochain_conductor_api::QueueStateDump;
 use holochain_lmdb::buffer::BufferedStore;
 use holochain_lmdb::buffer::KvBufFresh;
 use holochain_lmdb::db::INTEGRATED_DHT_OPS;
@@ -37,8 +38,10 @@ use std::convert::TryInto;
 use tracing::*;
 
 pub use disintegrate::*;
+pub use reindex::*;
 
 mod disintegrate;
+mod reindex;
 
 #[cfg(feature = "test_utils")]
 mod tests;
@@ -137,7 +140,7 @@ pub async fn integrate_dht_ops_workflow(
     // --- END OF WORKFLOW, BEGIN FINISHER BOILERPLATE ---
 
     // commit the workspace
-    writer.with_writer(|writer| Ok(workspace.flush_to_txn(writer)?))?;
+    writer.with_writer(|writer| Ok(workspace.flush_to_txn_ref(writer)?))?;
 
     // trigger other workflows
 
@@ -597,3 +600,34 @@ pub fn dump_state(env: EnvironmentRead) -> WorkspaceResult<IntegrationStateDump>
         integrated,
     })
 }
+
+/// Dump up to `max_entries` ops from each of the queues
+/// counted by [`dump_state`].
+pub fn dump_queues(env: EnvironmentRead, max_entries: usize) -> WorkspaceResult<QueueStateDump> {
+    let workspace = IncomingDhtOpsWorkspace::new(env.clone())?;
+    Ok(fresh_reader!(env, |r| {
+        let validation_limbo = workspace
+            .validation_limbo
+            .iter(&r)?
+            .take(max_entries)
+            .map(|(k, v)| Ok((DhtOpHash::from_raw_39_panicky(k.to_vec()), v)))
+            .collect()?;
+        let integration_limbo = workspace
+            .integration_limbo
+            .iter(&r)?
+            .take(max_entries)
+            .map(|(k, v)| Ok((DhtOpHash::from_raw_39_panicky(k.to_vec()), v)))
+            .collect()?;
+        let integrated = workspace
+            .integrated_dht_ops
+            .iter(&r)?
+            .take(max_entries)
+            .map(|(k, v)| Ok((DhtOpHash::from_raw_39_panicky(k.to_vec()), v)))
+            .collect()?;
+        DatabaseResult::Ok(QueueStateDump {
+            validation_limbo,
+            integration_limbo,
+            integrated,
+        })
+    })?)
+}
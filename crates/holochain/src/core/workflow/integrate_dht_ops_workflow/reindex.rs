@@ -0,0 +1,100 @@
+use super::*;
+use holochain_conductor_api::CellReindexReport;
+use produce_dht_ops_workflow::dht_op_light::error::DhtOpConvertError;
+
+/// How many ops are integrated again between calls to the progress callback
+pub const REINDEX_PROGRESS_INTERVAL: usize = 1000;
+
+/// Integrate every op in the integrated ops store again.
+///
+/// The data for each op is looked up in the store it was integrated to,
+/// falling back to our own source chain for valid ops, and then written
+/// back along with its metadata.
+/// The ops are read from a snapshot and all the writes happen in a single
+/// transaction. The caller must keep the integration workflow from running
+/// until this returns, see [QueueTriggers::integration_lock].
+/// Putting data and registering metadata that is already there
+/// does nothing so this is safe to run more than once.
+///
+/// `progress` is called with how many ops have been integrated again
+/// and how many there are in total, at the start, every
+/// [REINDEX_PROGRESS_INTERVAL] ops and once everything is committed.
+/// This blocks on the database so run it on a blocking thread.
+///
+/// NB: Metadata that doesn't belong to any integrated op is not removed
+/// because the vault metadata is shared with the authored metadata.
+///
+/// [QueueTriggers::integration_lock]: crate::core::queue_consumer::QueueTriggers::integration_lock
+pub fn reindex_integrated_ops<F>(
+    env: EnvironmentWrite,
+    mut progress: F,
+) -> WorkflowResult<CellReindexReport>
+where
+    F: FnMut(usize, usize),
+{
+    let mut workspace = IntegrateDhtOpsWorkspace::new(env.clone().into())?;
+    let authored = ElementBuf::authored(env.clone().into(), true)?;
+    let mut report = CellReindexReport::default();
+
+    let integrated: Vec<IntegratedDhtOpsValue> = fresh_reader!(env, |r| workspace
+        .integrated_dht_ops
+        .iter(&r)?
+        .map(|(_, v)| Ok(v))
+        .collect())?;
+
+    let mut ops = Vec::with_capacity(integrated.len());
+    for v in integrated {
+        let op = match v.validation_status {
+            ValidationStatus::Valid => light_to_op(v.op.clone(), &workspace.elements)
+                .or_else(|_| light_to_op(v.op.clone(), &authored)),
+            ValidationStatus::Rejected => light_to_op(v.op.clone(), &workspace.element_rejected),
+            ValidationStatus::Abandoned => {
+                report.abandoned += 1;
+                continue;
+            }
+        };
+        match op {
+            Ok(op) => ops.push((DhtOpOrder::from(&op), op, v)),
+            Err(DhtOpConvertError::MissingData(hash)) => {
+                warn!(?hash, "Data for an integrated op is missing, skipping it");
+                report.missing_data += 1;
+            }
+            Err(e) => return Err(e.into()),
+        }
+    }
+    // Integrate in the same order the workflow does so the
+    // metadata for an op can find the data it depends on.
+    ops.sort_by_key(|(order, _, _)| *order);
+
+    let total = ops.len();
+    progress(0, total);
+    for (i, (_, op, v)) in ops.into_iter().enumerate() {
+        if i > 0 && i % REINDEX_PROGRESS_INTERVAL == 0 {
+            progress(i, total);
+        }
+        match v.validation_status {
+            ValidationStatus::Valid => {
+                integrate_single_data(op, &mut workspace.elements)?;
+                integrate_single_metadata(v.op, &workspace.elements, &mut workspace.meta)?;
+                report.valid += 1;
+            }
+            ValidationStatus::Rejected => {
+                update_activity_status(&op, &mut workspace.meta)?;
+                update_validation_status(&op, &mut workspace.meta)?;
+                integrate_single_data(op, &mut workspace.element_rejected)?;
+                report.rejected += 1;
+            }
+            ValidationStatus::Abandoned => unreachable!("Abandoned ops are skipped above"),
+        }
+    }
+
+    env.guard().with_commit::<WorkspaceError, _, _>(|writer| {
+        workspace.elements.flush_to_txn_ref(writer)?;
+        workspace.meta.flush_to_txn_ref(writer)?;
+        workspace.element_rejected.flush_to_txn_ref(writer)?;
+        Ok(())
+    })?;
+    progress(total, total);
+
+    Ok(report)
+}
@@ -1397,8 +1397,9 @@ mod slow_tests {
 
             // Wait for the ops to integrate but early exit if they do
             // 14 ops for genesis and 9 ops for two commits and a link
-            // Try 100 times for 100 millis each so maximum wait is 10 seconds
-            wait_for_integration(&call_data.env, 14 + 9, 100, Duration::from_millis(100)).await;
+            // Poll with a backoff from 10 millis up to 1 second between
+            // attempts so the maximum wait is 10 seconds
+            wait_for_integration(&call_data.env, 14 + 9, Duration::from_secs(10)).await;
 
             // Check the ops are not empty
             let env_ref = call_data.env.guard();
@@ -1448,3 +1449,73 @@ mod slow_tests {
         shutdown.await.unwrap().unwrap();
     }
 }
+
+#[tokio::test(flavor = "multi_thread")]
+async fn reindex_restores_cleared_metadata() {
+    observability::test_run().ok();
+    let test_env = holochain_lmdb::test_utils::test_cell_env();
+    let env = test_env.env();
+    let env_ref = env.guard();
+    clear_dbs(env.clone());
+
+    let mut td = TestData::with_app_entry_type().await;
+    td.link_add.zome_id = 0.into();
+    let link_tag = td.link_add.tag.clone();
+    let target_entry_hash = td.new_entry_hash.clone();
+    let zome_name = fixt!(ZomeName);
+    let (pre_state, _expect, _) = register_add_link(td);
+
+    genesis(env.clone()).await;
+    let base_address = commit_entry(pre_state, env.clone(), zome_name.clone())
+        .await
+        .0;
+    create_link(
+        env.clone(),
+        base_address.clone(),
+        target_entry_hash,
+        zome_name,
+        link_tag,
+    )
+    .await;
+    produce_dht_ops(env.clone()).await;
+    call_workflow(env.clone()).await;
+
+    let count_links = || {
+        let reader = env_ref.reader().unwrap();
+        let workspace = IntegrateDhtOpsWorkspace::new(env.clone().into()).unwrap();
+        workspace
+            .meta
+            .get_live_links(&reader, &LinkMetaKey::Base(&base_address))
+            .unwrap()
+            .count()
+            .unwrap()
+    };
+    assert_eq!(count_links(), 1);
+
+    // Lose the metadata
+    env_ref
+        .with_commit::<DatabaseError, _, _>(|writer| {
+            IntegrateDhtOpsWorkspace::new(env.clone().into())
+                .unwrap()
+                .meta
+                .clear_all(writer)
+        })
+        .unwrap();
+    assert_eq!(count_links(), 0);
+
+    let mut progress = Vec::new();
+    let report =
+        reindex_integrated_ops(env.clone(), |done, total| progress.push((done, total))).unwrap();
+    assert_eq!(report.missing_data, 0);
+    assert!(report.valid > 0);
+    assert_eq!(count_links(), 1);
+    // Progress is reported at the start and the end
+    let total = report.valid + report.rejected;
+    assert_eq!(progress.first(), Some(&(0, total)));
+    assert_eq!(progress.last(), Some(&(total, total)));
+
+    // Reindexing again changes nothing
+    let again = reindex_integrated_ops(env.clone(), |_, _| ()).unwrap();
+    assert_eq!(again, report);
+    assert_eq!(count_links(), 1);
+}
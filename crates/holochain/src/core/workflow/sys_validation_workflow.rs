@@ -489,6 +489,8 @@ async fn store_element(
             .ok_or_else(|| ValidationOutcome::DepMissingFromDht(prev_header_hash.clone().into()))?;
         check_prev_timestamp(&header, prev_header.header())?;
         check_prev_seq(&header, prev_header.header())?;
+        check_prev_not_close_chain(prev_header.header())?;
+        check_open_chain_placement(&header, prev_header.header())?;
     }
     Ok(())
 }
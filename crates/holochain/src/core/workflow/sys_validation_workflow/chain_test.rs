@@ -57,7 +57,7 @@ async fn run_test(alice_cell_id: CellId, handle: ConductorHandle) {
     let sys_validation_trigger = alice_triggers.sys_validation;
 
     // Wait for genesis to integrate
-    wait_for_integration(&alice_env, 7, 100, Duration::from_millis(100)).await;
+    wait_for_integration(&alice_env, 7, Duration::from_secs(10)).await;
 
     let source_chain = SourceChain::new(alice_env.clone().into()).unwrap();
     let mut timestamp = timestamp::now();
@@ -125,7 +125,7 @@ async fn run_test(alice_cell_id: CellId, handle: ConductorHandle) {
     .await
     .unwrap();
 
-    wait_for_integration(&alice_env, 7 + 2, 100, Duration::from_millis(100)).await;
+    wait_for_integration(&alice_env, 7 + 2, Duration::from_secs(10)).await;
 
     // Check you don't see any warning output
     // TODO: When we add invalid chains put a real check here
@@ -182,7 +182,7 @@ async fn run_test(alice_cell_id: CellId, handle: ConductorHandle) {
     .await
     .unwrap();
 
-    wait_for_integration(&alice_env, 9 + 2, 100, Duration::from_millis(100)).await;
+    wait_for_integration(&alice_env, 9 + 2, Duration::from_secs(10)).await;
 
     // Check you **do** see any warning output
     // TODO: When we add invalid chains put a real check here
@@ -73,10 +73,9 @@ async fn run_test(
     dna_file: DnaFile,
 ) {
     // Check if the correct number of ops are integrated
-    // every 100 ms for a maximum of 10 seconds but early exit
+    // for a maximum of 10 seconds but early exit
     // if they are there.
-    let num_attempts = 100;
-    let delay_per_attempt = Duration::from_millis(100);
+    let deadline = Duration::from_secs(10);
 
     bob_links_in_a_legit_way(&bob_cell_id, &handle, &dna_file).await;
 
@@ -87,13 +86,7 @@ async fn run_test(
 
     {
         let alice_env = handle.get_cell_env(&alice_cell_id).await.unwrap();
-        wait_for_integration(
-            &alice_env,
-            expected_count,
-            num_attempts,
-            delay_per_attempt.clone(),
-        )
-        .await;
+        wait_for_integration(&alice_env, expected_count, deadline).await;
 
         let workspace = IncomingDhtOpsWorkspace::new(alice_env.clone().into()).unwrap();
         // Validation should be empty
@@ -165,13 +158,7 @@ async fn run_test(
 
     {
         let alice_env = handle.get_cell_env(&alice_cell_id).await.unwrap();
-        wait_for_integration(
-            &alice_env,
-            expected_count,
-            num_attempts,
-            delay_per_attempt.clone(),
-        )
-        .await;
+        wait_for_integration(&alice_env, expected_count, deadline).await;
 
         let workspace = IncomingDhtOpsWorkspace::new(alice_env.clone().into()).unwrap();
         // Validation should be empty
@@ -254,13 +241,7 @@ async fn run_test(
 
     {
         let alice_env = handle.get_cell_env(&alice_cell_id).await.unwrap();
-        wait_for_integration(
-            &alice_env,
-            expected_count,
-            num_attempts,
-            delay_per_attempt.clone(),
-        )
-        .await;
+        wait_for_integration(&alice_env, expected_count, deadline).await;
         let env_ref = alice_env.guard();
 
         let workspace = IncomingDhtOpsWorkspace::new(alice_env.clone().into()).unwrap();
@@ -1,5 +1,6 @@
 use super::error::WorkflowError;
 use fallible_iterator::FallibleIterator;
+use holo_hash::AgentPubKey;
 use holo_hash::DhtOpHash;
 use holochain_cascade::Cascade;
 use holochain_cascade::DbPair;
@@ -13,7 +14,11 @@ use holochain_lmdb::prelude::*;
 use holochain_p2p::HolochainP2pCell;
 use holochain_p2p::HolochainP2pCellT;
 use holochain_state::prelude::*;
+use holochain_types::timestamp;
+use holochain_types::Timestamp;
 use holochain_zome_types::TryInto;
+use std::collections::HashMap;
+use std::time::Duration;
 use tracing::*;
 
 use crate::core::queue_consumer::OneshotWriter;
@@ -24,15 +29,53 @@ use super::error::WorkflowResult;
 #[cfg(test)]
 mod tests;
 
+/// The default most receipts sent to an author in one message.
+pub const DEFAULT_RECEIPT_MAX_BATCH_SIZE: usize = 100;
+
+/// The default longest a receipt waits for more receipts to the same author.
+pub const DEFAULT_RECEIPT_MAX_BATCH_DELAY: Duration = Duration::from_millis(50);
+
+/// How receipts for the same author are coalesced into one network message.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ReceiptBatching {
+    /// The most receipts sent to an author in one message.
+    pub max_batch_size: usize,
+    /// How long a receipt can wait for more receipts to the same
+    /// author before a batch smaller than `max_batch_size` is sent.
+    pub max_delay: Duration,
+}
+
+impl Default for ReceiptBatching {
+    fn default() -> Self {
+        Self {
+            max_batch_size: DEFAULT_RECEIPT_MAX_BATCH_SIZE,
+            max_delay: DEFAULT_RECEIPT_MAX_BATCH_DELAY,
+        }
+    }
+}
+
+impl ReceiptBatching {
+    /// Send every pending receipt without waiting to fill a batch.
+    pub fn flush(self) -> Self {
+        Self {
+            max_delay: Duration::default(),
+            ..self
+        }
+    }
+}
+
 #[instrument(skip(workspace, writer, network))]
-/// Send validation receipts to their authors in serial and without waiting for
+/// Send validation receipts to their authors in batches and without waiting for
 /// responses.
+/// A batch smaller than the max size is held back until its oldest receipt has
+/// waited the max delay, in which case the work is reported as incomplete.
 /// TODO: Currently still waiting for responses because we don't have a network call
 /// that doesn't.
 pub async fn validation_receipt_workflow(
     mut workspace: ValidationReceiptWorkspace,
     writer: OneshotWriter,
     network: &mut HolochainP2pCell,
+    batching: ReceiptBatching,
 ) -> WorkflowResult<WorkComplete> {
     // Get the env and keystore
     let env = workspace.elements.headers().env().clone();
@@ -50,8 +93,9 @@ pub async fn validation_receipt_workflow(
     // Who we are.
     let agent = network.from_agent();
 
-    // Send the validation receipts
-    for (dht_op_hash, mut op) in ops {
+    // Group the receipts by who they are going to.
+    let mut pending = HashMap::<_, Vec<_>>::new();
+    for (dht_op_hash, op) in ops {
         // Get the header so we know who to send it to.
         let header = {
             // Don't worry this cascade is constructed without a network so
@@ -75,37 +119,91 @@ pub async fn validation_receipt_workflow(
             continue;
         }
 
-        // Create the receipt.
-        let receipt = ValidationReceipt {
-            dht_op_hash: dht_op_hash.clone(),
-            validation_status: op.validation_status,
-            validator: agent.clone(),
-            when_integrated: op.when_integrated,
-        };
+        pending
+            .entry(to_agent)
+            .or_default()
+            .push((op.when_integrated, (dht_op_hash, op)));
+    }
 
-        // Sign on the dotted line.
-        let receipt = receipt.sign(&keystore).await?;
+    let (batches, held_back) = batch_receipts(pending, batching, timestamp::now());
+
+    // Send the validation receipts
+    for (to_agent, batch) in batches {
+        let mut receipts = Vec::with_capacity(batch.len());
+        for (dht_op_hash, op) in &batch {
+            // Create the receipt.
+            let receipt = ValidationReceipt {
+                dht_op_hash: dht_op_hash.clone(),
+                validation_status: op.validation_status,
+                validator: agent.clone(),
+                when_integrated: op.when_integrated,
+            };
+
+            // Sign on the dotted line.
+            receipts.push(receipt.sign(&keystore).await?);
+        }
 
         // Send it and don't wait for response.
         // TODO: When networking has a send without response we can use that
         // instead of waiting for response.
         if let Err(e) = network
-            .send_validation_receipt(to_agent, receipt.try_into()?)
+            .send_validation_receipt(to_agent, ValidationReceiptBundle(receipts).try_into()?)
             .await
         {
             // No one home, they will need to publish again.
             info!(failed_send_receipt = ?e);
         }
-        // Attempted to send the receipt so we now mark
-        // it to not send in the future.
-        op.send_receipt = false;
-        workspace.integrated_dht_ops.put(dht_op_hash, op)?;
+        // Attempted to send the receipts so we now mark
+        // them to not send in the future.
+        for (dht_op_hash, mut op) in batch {
+            op.send_receipt = false;
+            workspace.integrated_dht_ops.put(dht_op_hash, op)?;
+        }
     }
 
     // Write the acknowledgment to the db.
-    writer.with_writer(|writer| Ok(workspace.flush_to_txn(writer)?))?;
+    writer.with_writer(|writer| Ok(workspace.flush_to_txn_ref(writer)?))?;
 
-    Ok(WorkComplete::Complete)
+    if held_back {
+        Ok(WorkComplete::Incomplete)
+    } else {
+        Ok(WorkComplete::Complete)
+    }
+}
+
+/// Split each author's receipts into the batches to send now.
+/// The last batch for an author is held back if it isn't full and its
+/// oldest receipt was integrated less than the max delay ago.
+/// Returns the batches and whether any were held back.
+fn batch_receipts<T>(
+    pending: HashMap<AgentPubKey, Vec<(Timestamp, T)>>,
+    batching: ReceiptBatching,
+    now: Timestamp,
+) -> (Vec<(AgentPubKey, Vec<T>)>, bool) {
+    let max_batch_size = batching.max_batch_size.max(1);
+    let mut batches = Vec::new();
+    let mut held_back = false;
+    for (to_agent, mut receipts) in pending {
+        receipts.sort_by_key(|(when, _)| *when);
+        let mut receipts = receipts.into_iter().peekable();
+        while let Some((oldest, _)) = receipts.peek() {
+            let oldest = *oldest;
+            let batch: Vec<_> = receipts.by_ref().take(max_batch_size).collect();
+            let ready = match oldest + batching.max_delay {
+                Ok(send_by) => batch.len() == max_batch_size || send_by <= now,
+                Err(_) => true,
+            };
+            if !ready {
+                held_back = true;
+                break;
+            }
+            batches.push((
+                to_agent.clone(),
+                batch.into_iter().map(|(_, receipt)| receipt).collect(),
+            ));
+        }
+    }
+    (batches, held_back)
 }
 
 pub struct ValidationReceiptWorkspace {
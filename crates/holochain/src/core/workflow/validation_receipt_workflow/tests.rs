@@ -113,3 +113,64 @@ async fn test_validation_receipt() {
         vec![2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2]
     );
 }
+
+#[test]
+fn receipts_are_batched_per_author() {
+    use super::batch_receipts;
+    use super::ReceiptBatching;
+    use ::fixt::prelude::*;
+    use holo_hash::fixt::AgentPubKeyFixturator;
+    use std::collections::HashMap;
+
+    let alice = fixt!(AgentPubKey);
+    let bobbo = fixt!(AgentPubKey);
+    let batching = ReceiptBatching {
+        max_batch_size: 2,
+        max_delay: std::time::Duration::from_secs(10),
+    };
+    let now = Timestamp(100, 0);
+
+    let mut pending = HashMap::new();
+    // Alice's receipts have waited long enough to send a partial batch.
+    pending.insert(
+        alice.clone(),
+        vec![
+            (Timestamp(2, 0), 3),
+            (Timestamp(0, 0), 1),
+            (Timestamp(1, 0), 2),
+        ],
+    );
+    // Bobbo's receipt is recent so it waits for more.
+    pending.insert(bobbo.clone(), vec![(Timestamp(95, 0), 4)]);
+
+    let (batches, held_back) = batch_receipts(pending.clone(), batching, now);
+    assert!(held_back);
+    assert_eq!(batches, vec![(alice.clone(), vec![1, 2]), (alice, vec![3])]);
+
+    let (batches, held_back) = batch_receipts(pending, batching.flush(), now);
+    assert!(!held_back);
+    assert_eq!(batches.len(), 3);
+    assert!(batches.contains(&(bobbo, vec![4])));
+}
+
+#[test]
+fn held_back_batches_back_off_for_the_max_delay() {
+    use super::ReceiptBatching;
+    use crate::core::queue_consumer::ValidationReceiptTuning;
+    use std::time::Duration;
+
+    let tuning = ValidationReceiptTuning {
+        retrigger_delay: Duration::from_millis(10),
+        batching: ReceiptBatching {
+            max_batch_size: 2,
+            max_delay: Duration::from_millis(50),
+        },
+    };
+    assert_eq!(tuning.backoff(), Duration::from_millis(50));
+
+    let tuning = ValidationReceiptTuning {
+        retrigger_delay: Duration::from_millis(100),
+        ..tuning
+    };
+    assert_eq!(tuning.backoff(), Duration::from_millis(100));
+}
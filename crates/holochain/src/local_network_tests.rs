@@ -11,6 +11,7 @@ use holochain_serialized_bytes::SerializedBytes;
 use holochain_types::prelude::*;
 use holochain_wasm_test_utils::TestWasm;
 use holochain_zome_types::ZomeCallResponse;
+use kitsune_p2p::agent_store::AgentInfoSigned;
 use kitsune_p2p::KitsuneP2pConfig;
 use matches::assert_matches;
 use tempdir::TempDir;
@@ -21,11 +22,14 @@ use crate::conductor::p2p_store::exchange_peer_info;
 use crate::conductor::ConductorHandle;
 use crate::core::ribosome::error::RibosomeError;
 use crate::core::ribosome::error::RibosomeResult;
+use crate::test_utils::exchange_peer_info_along;
+use crate::test_utils::exchange_peer_info_between;
+use crate::test_utils::heal_partition;
 use crate::test_utils::host_fn_caller::Post;
 use crate::test_utils::install_app;
 use crate::test_utils::new_zome_call;
 use crate::test_utils::setup_app_with_network;
-use crate::test_utils::wait_for_integration_with_others;
+use crate::test_utils::try_wait_for_integration_with_others;
 use shrinkwraprs::Shrinkwrap;
 use test_case::test_case;
 
@@ -46,12 +50,16 @@ fn conductors_call_remote(num_conductors: usize) {
             override_host: None,
             override_port: None,
         }];
-        let handles = setup(zomes, Some(network), num_conductors, uid).await;
+        let handles = setup(zomes, Some(network), num_conductors, uid, false).await;
 
         init_all(&handles[..]).await;
 
         // 50 ms should be enough time to hit another conductor locally
-        let results = call_each_other(&handles[..], 50).await;
+        let results = call_each_other(
+            &handles[..],
+            CallRetry::Once(std::time::Duration::from_millis(50)),
+        )
+        .await;
         for (_, _, result) in results {
             match result {
                 Some(r) => match r {
@@ -69,17 +77,61 @@ fn conductors_call_remote(num_conductors: usize) {
         // Let the remote messages be dropped
         tokio::time::sleep(std::time::Duration::from_secs(2)).await;
 
-        let mut envs = Vec::with_capacity(handles.len());
-        for h in &handles {
-            envs.push(h.get_p2p_env().await);
+        exchange_peer_info_between(handles.iter().map(|h| &h.handle)).await;
+
+        // Keep retrying here because they must find each other to pass the test
+        // and a single slow round shouldn't fail it
+        let results = call_each_other(
+            &handles[..],
+            CallRetry::Backoff {
+                initial: std::time::Duration::from_millis(100),
+                deadline: std::time::Duration::from_secs(10),
+            },
+        )
+        .await;
+        for (_, _, result) in results {
+            self::assert_matches!(result, Some(Ok(ZomeCallResponse::Ok(_))));
         }
+        shutdown(handles).await;
+    };
+    tokio_helper::block_forever_on(f);
+}
 
-        exchange_peer_info(envs);
+#[test_case(2)]
+#[test_case(5)]
+fn conductors_call_remote_seeded(num_conductors: usize) {
+    let f = async move {
+        observability::test_run().ok();
 
-        // Give a little longer timeout here because they must find each other to pass the test
-        let results = call_each_other(&handles[..], 500).await;
-        for (_, _, result) in results {
-            self::assert_matches!(result, Some(Ok(ZomeCallResponse::Ok(_))));
+        let uid = nanoid::nanoid!().to_string();
+        let zomes = vec![TestWasm::Create];
+        let mut network = KitsuneP2pConfig::default();
+        network.transport_pool = vec![kitsune_p2p::TransportConfig::Quic {
+            bind_to: None,
+            override_host: None,
+            override_port: None,
+        }];
+        // Every conductor starts with the agent info of
+        // the conductors that were set up before it.
+        let handles = setup(zomes, Some(network), num_conductors, uid, true).await;
+
+        init_all(&handles[..]).await;
+
+        // No peer info is exchanged so only the later conductors
+        // can find the earlier ones.
+        for (i, a) in handles.iter().enumerate() {
+            for b in handles[..i].iter() {
+                let result = call_remote_retry(
+                    a.clone(),
+                    b.clone(),
+                    CallRetry::Backoff {
+                        initial: std::time::Duration::from_millis(100),
+                        deadline: std::time::Duration::from_secs(10),
+                    },
+                )
+                .await;
+                self::assert_matches!(result, Some(Ok(ZomeCallResponse::Ok(_))));
+            }
         }
         shutdown(handles).await;
     };
@@ -113,6 +165,42 @@ fn conductors_local_gossip(num_committers: usize, num_conductors: usize, new_con
     tokio_helper::block_forever_on(f);
 }
 
+#[test]
+#[ignore = "Don't want network tests running on ci"]
+fn conductors_gossip_across_healed_partition() {
+    let f = async move {
+        observability::test_run().ok();
+        let uid = nanoid::nanoid!().to_string();
+        let zomes = vec![TestWasm::Create];
+        let mut network = KitsuneP2pConfig::default();
+        network.transport_pool = vec![kitsune_p2p::TransportConfig::Quic {
+            bind_to: None,
+            override_host: None,
+            override_port: None,
+        }];
+        let handles = setup(zomes, Some(network), 3, uid, false).await;
+        let conductors = handles.iter().map(|h| h.handle.clone()).collect::<Vec<_>>();
+
+        // Conductor 2 is cut off from the other two
+        exchange_peer_info_along(&conductors, &[(0, 1)]).await;
+
+        let posts = init_all(&handles[..1]).await;
+
+        // 3 ops per create plus 7 for genesis + 2 for init + 2 for cap
+        // from the committer and 7 for genesis from the other conductor
+        let connected = handles[..2].iter().collect::<Vec<_>>();
+        check_gossip(&handles[1], &connected, &posts, 14 + 7, line!(), 1).await;
+
+        // Once the partition heals the posts reach conductor 2
+        heal_partition(&conductors).await;
+        let all_handles = handles.iter().collect::<Vec<_>>();
+        check_gossip(&handles[2], &all_handles, &posts, 14 + 2 * 7, line!(), 2).await;
+
+        shutdown(handles).await;
+    };
+    tokio_helper::block_forever_on(f);
+}
+
 #[test_case(2, 1, 1)]
 #[test_case(5, 1, 1)]
 #[test_case(1, 5, 5)]
@@ -275,6 +363,7 @@ async fn conductors_gossip_inner(
         Some(network.clone()),
         num_committers,
         uid.clone(),
+        false,
     )
     .await;
 
@@ -285,6 +374,7 @@ async fn conductors_gossip_inner(
         Some(network.clone()),
         num_conductors,
         uid.clone(),
+        false,
     )
     .await;
 
@@ -317,15 +407,23 @@ async fn conductors_gossip_inner(
 
     shutdown(handles).await;
 
-    let third_handles = setup(zomes.clone(), Some(network.clone()), new_conductors, uid).await;
-
-    let mut envs = Vec::with_capacity(third_handles.len() + second_handles.len());
-    for h in third_handles.iter().chain(second_handles.iter()) {
-        envs.push(h.get_p2p_env().await);
-    }
+    let third_handles = setup(
+        zomes.clone(),
+        Some(network.clone()),
+        new_conductors,
+        uid,
+        false,
+    )
+    .await;
 
     if share_peers {
-        exchange_peer_info(envs.clone());
+        exchange_peer_info_between(
+            third_handles
+                .iter()
+                .chain(second_handles.iter())
+                .map(|h| &h.handle),
+        )
+        .await;
     }
 
     let all_handles = third_handles
@@ -390,9 +488,54 @@ async fn call_remote(a: TestHandle, b: TestHandle) -> RibosomeResult<ZomeCallRes
     a.call_zome(invocation).await.unwrap()
 }
 
+/// How remote calls that time out are retried.
+#[derive(Clone, Copy, Debug)]
+enum CallRetry {
+    /// Make a single attempt with this timeout.
+    Once(std::time::Duration),
+    /// Retry with a timeout that starts at `initial` and doubles
+    /// each attempt until `deadline` has passed.
+    Backoff {
+        initial: std::time::Duration,
+        deadline: std::time::Duration,
+    },
+}
+
+/// Call remote until it doesn't time out or we run out of retries.
+/// Returns None if the final attempt timed out.
+async fn call_remote_retry(
+    a: TestHandle,
+    b: TestHandle,
+    retry: CallRetry,
+) -> Option<RibosomeResult<ZomeCallResponse>> {
+    let (mut timeout, deadline) = match retry {
+        CallRetry::Once(timeout) => (timeout, None),
+        CallRetry::Backoff { initial, deadline } => {
+            (initial, Some(tokio::time::Instant::now() + deadline))
+        }
+    };
+    loop {
+        // We don't want to wait the maximum network timeout
+        // in this test as it's a controlled local network
+        let result = tokio::time::timeout(timeout, call_remote(a.clone(), b.clone())).await;
+        let timed_out = match &result {
+            Err(_) => true,
+            Ok(Err(RibosomeError::WasmError(WasmError::Guest(e)))) => e == TIMEOUT_ERROR,
+            Ok(_) => false,
+        };
+        match deadline {
+            Some(deadline) if timed_out && tokio::time::Instant::now() < deadline => {
+                timeout *= 2;
+                tracing::debug!(?timeout, "Retrying call remote");
+            }
+            _ => return result.ok(),
+        }
+    }
+}
+
 async fn call_each_other(
     handles: &[TestHandle],
-    timeout: u64,
+    retry: CallRetry,
 ) -> Vec<(usize, usize, Option<RibosomeResult<ZomeCallResponse>>)> {
     let mut results = Vec::with_capacity(handles.len() * 2);
     for (i, a) in handles.iter().cloned().enumerate() {
@@ -404,15 +547,7 @@ async fn call_each_other(
             }
             let f = {
                 let a = a.clone();
-                async move {
-                    let f = call_remote(a, b);
-                    // We don't want to wait the maximum network timeout
-                    // in this test as it's a controlled local network
-                    match tokio::time::timeout(std::time::Duration::from_millis(timeout), f).await {
-                        Ok(r) => (i, j, Some(r)),
-                        Err(_) => (i, j, None),
-                    }
-                }
+                async move { (i, j, call_remote_retry(a, b, retry).await) }
             };
             // Run a set of call remotes in parallel.
             // Can't run everything in parallel or we get chain moved.
@@ -433,8 +568,7 @@ async fn check_gossip(
     line: u32,
     i: usize,
 ) {
-    const NUM_ATTEMPTS: usize = 600;
-    const DELAY_PER_ATTEMPT: std::time::Duration = std::time::Duration::from_millis(100);
+    const DEADLINE: std::time::Duration = std::time::Duration::from_secs(60);
 
     let mut others = Vec::with_capacity(all_handles.len());
     for other in all_handles {
@@ -443,15 +577,21 @@ async fn check_gossip(
     }
     let others_ref = others.iter().collect::<Vec<_>>();
 
-    wait_for_integration_with_others(
+    if let Err(e) = try_wait_for_integration_with_others(
         &handle.get_cell_env(&handle.cell_id).await.unwrap(),
         &others_ref,
         expected_count,
-        NUM_ATTEMPTS,
-        DELAY_PER_ATTEMPT.clone(),
+        DEADLINE,
         None,
+        true,
     )
-    .await;
+    .await
+    {
+        panic!(
+            "Gossip check for conductor {} at line {} failed: {}\nIntegrated ops: {:?}",
+            i, line, e, e.integrated_ops
+        );
+    }
     for hash in posts {
         let invocation =
             new_zome_call(&handle.cell_id, "get_post", hash, TestWasm::Create).unwrap();
@@ -502,11 +642,31 @@ async fn shutdown(handles: Vec<TestHandle>) {
     }
 }
 
+/// Wait for a conductor to put the agent info of its
+/// own cell in its peer store and return everything in the store.
+async fn wait_for_agent_info(handle: &TestHandle) -> Vec<AgentInfoSigned> {
+    let env = handle.get_p2p_env().await;
+    for _ in 0..100 {
+        let infos = all_agent_infos(env.clone().into()).unwrap();
+        if infos.iter().any(|info| {
+            &AgentPubKey::from_raw_36(info.as_agent_ref().to_vec()) == handle.cell_id.agent_pubkey()
+        }) {
+            return infos;
+        }
+        tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+    }
+    panic!(
+        "Conductor never published agent info for {}",
+        handle.cell_id
+    );
+}
+
 async fn setup(
     zomes: Vec<TestWasm>,
     network: Option<KitsuneP2pConfig>,
     num_conductors: usize,
     uid: String,
+    seed_peers: bool,
 ) -> Vec<TestHandle> {
     let dna_file = DnaFile::new(
         DnaDef {
@@ -523,8 +683,15 @@ async fn setup(
     let mut handles = Vec::with_capacity(num_conductors);
     for _ in 0..num_conductors {
         let dnas = vec![dna_file.clone()];
+        let mut peers = Vec::new();
+        if seed_peers {
+            for h in handles.iter() {
+                peers.extend(wait_for_agent_info(h).await);
+            }
+        }
         let (__tmpdir, _, handle) =
-            setup_app_with_network(vec![], vec![], network.clone().unwrap_or_default()).await;
+            setup_app_with_network(vec![], vec![], network.clone().unwrap_or_default(), peers)
+                .await;
 
         let agent_key = AgentPubKey::new_from_pure_entropy(handle.keystore())
             .await
@@ -37,6 +37,7 @@ use holochain_state::{element_buf::ElementBuf, prelude::SourceChain};
 use holochain_types::prelude::*;
 
 use holochain_wasm_test_utils::TestWasm;
+use kitsune_p2p::agent_store::AgentInfoSigned;
 use kitsune_p2p::KitsuneP2pConfig;
 use std::collections::HashSet;
 use std::sync::Arc;
@@ -270,17 +271,20 @@ pub async fn setup_app(
     apps_data: Vec<(&str, InstalledCellsWithProofs)>,
     dnas: Vec<DnaFile>,
 ) -> (Arc<TempDir>, RealAppInterfaceApi, ConductorHandle) {
-    setup_app_inner(test_environments(), apps_data, dnas, None).await
+    setup_app_inner(test_environments(), apps_data, dnas, None, vec![]).await
 }
 
 /// Setup an app with a custom network config for testing
 /// apps_data is a vec of app nicknames with vecs of their cell data.
+/// The peers are in the conductor's peer store before it starts
+/// so its cells know them as soon as they join the network.
 pub async fn setup_app_with_network(
     apps_data: Vec<(&str, InstalledCellsWithProofs)>,
     dnas: Vec<DnaFile>,
     network: KitsuneP2pConfig,
+    peers: Vec<AgentInfoSigned>,
 ) -> (Arc<TempDir>, RealAppInterfaceApi, ConductorHandle) {
-    setup_app_inner(test_environments(), apps_data, dnas, Some(network)).await
+    setup_app_inner(test_environments(), apps_data, dnas, Some(network), peers).await
 }
 
 /// Setup an app with full configurability
@@ -289,7 +293,11 @@ pub async fn setup_app_inner(
     apps_data: Vec<(&str, InstalledCellsWithProofs)>,
     dnas: Vec<DnaFile>,
     network: Option<KitsuneP2pConfig>,
+    peers: Vec<AgentInfoSigned>,
 ) -> (Arc<TempDir>, RealAppInterfaceApi, ConductorHandle) {
+    // Seed the peer store before the conductor exists so
+    // there's no race with the cells joining the network.
+    p2p_store::inject_agent_infos(envs.p2p(), peers).unwrap();
     let conductor_handle = ConductorBuilder::new()
         .config(ConductorConfig {
             admin_interfaces: Some(vec![AdminInterfaceConfig {
@@ -363,56 +371,86 @@ impl WaitOps {
     }
 }
 
+/// Inject the agent info from every conductor's peer store into
+/// every other conductor's peer store so they can find each other
+/// without a bootstrap service.
+pub async fn exchange_peer_info_between<'a, I>(handles: I)
+where
+    I: IntoIterator<Item = &'a ConductorHandle>,
+{
+    let mut envs = Vec::new();
+    for handle in handles {
+        envs.push(handle.get_p2p_env().await);
+    }
+    p2p_store::exchange_peer_info(envs);
+}
+
+/// Simulate a partitioned network by only exchanging agent info
+/// along the given `edges` between indices into `handles`.
+/// Agent info is read from every peer store before any is injected
+/// so it only travels one hop and can't leak across the partition.
+pub async fn exchange_peer_info_along(handles: &[ConductorHandle], edges: &[(usize, usize)]) {
+    let mut envs = Vec::with_capacity(handles.len());
+    for handle in handles {
+        envs.push(handle.get_p2p_env().await);
+    }
+    let infos = envs
+        .iter()
+        .map(|env| p2p_store::all_agent_infos(env.clone().into()).unwrap())
+        .collect::<Vec<_>>();
+    for &(a, b) in edges {
+        p2p_store::inject_agent_infos(envs[a].clone(), infos[b].clone()).unwrap();
+        p2p_store::inject_agent_infos(envs[b].clone(), infos[a].clone()).unwrap();
+    }
+}
+
+/// Heal a partition created with [`exchange_peer_info_along`]
+/// by completing the mesh between all the conductors.
+pub async fn heal_partition(handles: &[ConductorHandle]) {
+    exchange_peer_info_between(handles).await
+}
+
 /// Wait for all cells to reach consistency for 10 seconds
 pub async fn consistency_10s(all_cells: &[&SweetCell]) {
-    const NUM_ATTEMPTS: usize = 100;
-    const DELAY_PER_ATTEMPT: std::time::Duration = std::time::Duration::from_millis(100);
-    consistency(all_cells, NUM_ATTEMPTS, DELAY_PER_ATTEMPT).await
+    consistency(all_cells, Duration::from_secs(10)).await
 }
 
 /// Wait for all cells to reach consistency
+/// or until the deadline has passed
 #[tracing::instrument(skip(all_cells))]
-pub async fn consistency(all_cells: &[&SweetCell], num_attempts: usize, delay: Duration) {
+pub async fn consistency(all_cells: &[&SweetCell], deadline: Duration) {
     let all_cell_envs: Vec<_> = all_cells.iter().map(|c| c.env()).collect();
-    consistency_envs(&all_cell_envs[..], num_attempts, delay).await
+    consistency_envs(&all_cell_envs[..], deadline).await
 }
 
 /// Wait for all cell envs to reach consistency
-pub async fn consistency_envs(
-    all_cell_envs: &[&EnvironmentWrite],
-    num_attempts: usize,
-    delay: Duration,
-) {
+/// or until the deadline has passed
+pub async fn consistency_envs(all_cell_envs: &[&EnvironmentWrite], deadline: Duration) {
     let mut expected_count = 0;
     for &env in all_cell_envs.iter() {
         let count = get_authored_ops(env).len();
         expected_count += count;
     }
     for &env in all_cell_envs.iter() {
-        wait_for_integration(env, expected_count, num_attempts, delay).await
+        wait_for_integration(env, expected_count, deadline).await
     }
 }
 
 /// Same as wait_for_integration but with a default wait time of 60 seconds
 /// Wait for all cells to reach consistency for 10 seconds
 pub async fn consistency_10s_others(all_cells: &[&SweetCell]) {
-    const NUM_ATTEMPTS: usize = 100;
-    const DELAY_PER_ATTEMPT: std::time::Duration = std::time::Duration::from_millis(100);
-    consistency_others(all_cells, NUM_ATTEMPTS, DELAY_PER_ATTEMPT).await
+    consistency_others(all_cells, Duration::from_secs(10)).await
 }
 
 /// Wait for all cells to reach consistency
+/// or until the deadline has passed
 #[tracing::instrument(skip(all_cells))]
-pub async fn consistency_others(all_cells: &[&SweetCell], num_attempts: usize, delay: Duration) {
+pub async fn consistency_others(all_cells: &[&SweetCell], deadline: Duration) {
     let all_cell_envs: Vec<_> = all_cells.iter().map(|c| c.env()).collect();
-    consistency_envs_others(&all_cell_envs[..], num_attempts, delay).await
+    consistency_envs_others(&all_cell_envs[..], deadline).await
 }
 
-async fn consistency_envs_others(
-    all_cell_envs: &[&EnvironmentWrite],
-    num_attempts: usize,
-    delay: Duration,
-) {
+async fn consistency_envs_others(all_cell_envs: &[&EnvironmentWrite], deadline: Duration) {
     let mut expected_count = 0;
     for &env in all_cell_envs.iter() {
         let count = get_authored_ops(env).len();
@@ -422,8 +460,7 @@ async fn consistency_envs_others(
     for (i, &env) in all_cell_envs.iter().enumerate() {
         let mut others = all_cell_envs.to_vec();
         others.remove(i);
-        wait_for_integration_with_others(env, &others, expected_count, num_attempts, delay, start)
-            .await
+        wait_for_integration_with_others(env, &others, expected_count, deadline, start).await
     }
 }
 
@@ -456,33 +493,145 @@ fn get_authored_ops(env: &EnvironmentWrite) -> Vec<DhtOpLight> {
         .collect()
 }
 
-/// Same as wait_for_integration but with a default wait time of 10 seconds
+/// Same as wait_for_integration but with a deadline of 60 seconds
 #[tracing::instrument(skip(env))]
 pub async fn wait_for_integration_1m(env: &EnvironmentWrite, expected_count: usize) {
-    const NUM_ATTEMPTS: usize = 120;
-    const DELAY_PER_ATTEMPT: std::time::Duration = std::time::Duration::from_millis(500);
-    wait_for_integration(env, expected_count, NUM_ATTEMPTS, DELAY_PER_ATTEMPT).await
+    wait_for_integration(env, expected_count, Duration::from_secs(60)).await
+}
+
+/// The shortest delay between two polls of the integrated ops.
+const MIN_POLL_DELAY: Duration = Duration::from_millis(10);
+/// The longest delay between two polls of the integrated ops.
+const MAX_POLL_DELAY: Duration = Duration::from_secs(1);
+
+/// Exponential backoff between polls that stops at a deadline.
+/// The delay starts at [`MIN_POLL_DELAY`] and doubles after
+/// every poll up to [`MAX_POLL_DELAY`].
+struct PollBackoff {
+    delay: Duration,
+    deadline: std::time::Instant,
+}
+
+impl PollBackoff {
+    fn new(deadline: Duration) -> Self {
+        Self {
+            delay: MIN_POLL_DELAY,
+            deadline: std::time::Instant::now() + deadline,
+        }
+    }
+
+    /// Sleep until the next poll.
+    /// Returns false without sleeping if the deadline has passed.
+    async fn wait(&mut self) -> bool {
+        match self.next_delay(std::time::Instant::now()) {
+            Some(delay) => {
+                tokio::time::sleep(delay).await;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// How long to sleep at `now` before the next poll,
+    /// or None if the deadline has passed.
+    /// The last delay is cut short so the final poll happens at the deadline.
+    fn next_delay(&mut self, now: std::time::Instant) -> Option<Duration> {
+        if now >= self.deadline {
+            return None;
+        }
+        let delay = self.delay.min(self.deadline - now);
+        self.delay = (self.delay * 2).min(MAX_POLL_DELAY);
+        Some(delay)
+    }
 }
 
 /// Exit early if the expected number of ops
-/// have been integrated or wait for num_attempts * delay
+/// have been integrated or wait until the deadline has passed.
+/// The integrated ops are polled with an exponential backoff.
 #[tracing::instrument(skip(env))]
 pub async fn wait_for_integration(
     env: &EnvironmentWrite,
     expected_count: usize,
-    num_attempts: usize,
-    delay: Duration,
+    deadline: Duration,
 ) {
-    for i in 0..num_attempts {
-        let count = display_integration(env).await;
+    if let Err(e) = try_wait_for_integration(env, expected_count, deadline, false).await {
+        tracing::warn!(%e);
+    }
+}
+
+/// The expected number of ops were not integrated in time.
+#[derive(Debug, Clone, thiserror::Error)]
+#[error(
+    "Expected {expected} integrated ops but only reached {reached} after waiting {waited:?} \
+    (the last attempt changed the count by {last_delta})"
+)]
+pub struct IntegrationTimeout {
+    /// The number of integrated ops that was expected.
+    pub expected: usize,
+    /// The number of integrated ops on the last attempt.
+    pub reached: usize,
+    /// The change in the integrated count between the last two attempts.
+    pub last_delta: i64,
+    /// How long was spent waiting.
+    pub waited: Duration,
+    /// The ops that were integrated if they were requested.
+    pub integrated_ops: Option<Vec<DhtOpLight>>,
+}
+
+impl IntegrationTimeout {
+    fn new(
+        env: &EnvironmentWrite,
+        expected: usize,
+        reached: usize,
+        last_count: usize,
+        waited: Duration,
+        dump_ops: bool,
+    ) -> Self {
+        Self {
+            expected,
+            reached,
+            last_delta: reached as i64 - last_count as i64,
+            waited,
+            integrated_ops: if dump_ops { Some(int_ops(env)) } else { None },
+        }
+    }
+}
+
+/// Same as [`wait_for_integration`] but returns a diagnostic if the
+/// expected number of ops are not integrated in time.
+/// Set `dump_ops` to include the integrated ops in the diagnostic.
+#[tracing::instrument(skip(env))]
+pub async fn try_wait_for_integration(
+    env: &EnvironmentWrite,
+    expected_count: usize,
+    deadline: Duration,
+    dump_ops: bool,
+) -> Result<(), IntegrationTimeout> {
+    let start = std::time::Instant::now();
+    let mut backoff = PollBackoff::new(deadline);
+    let mut count = 0;
+    let mut last_count;
+    loop {
+        last_count = count;
+        count = display_integration(env).await;
         if count == expected_count {
-            return;
+            return Ok(());
         } else {
-            let total_time_waited = delay * i as u32;
+            let total_time_waited = start.elapsed();
             tracing::debug!(?count, ?total_time_waited);
         }
-        tokio::time::sleep(delay).await;
+        if !backoff.wait().await {
+            break;
+        }
     }
+    Err(IntegrationTimeout::new(
+        env,
+        expected_count,
+        count,
+        last_count,
+        start.elapsed(),
+        dump_ops,
+    ))
 }
 
 fn int_ops(env: &EnvironmentWrite) -> Vec<DhtOpLight> {
@@ -505,17 +654,8 @@ pub async fn wait_for_integration_with_others_10s(
     expected_count: usize,
     start: Option<std::time::Instant>,
 ) {
-    const NUM_ATTEMPTS: usize = 100;
-    const DELAY_PER_ATTEMPT: std::time::Duration = std::time::Duration::from_millis(100);
-    wait_for_integration_with_others(
-        env,
-        others,
-        expected_count,
-        NUM_ATTEMPTS,
-        DELAY_PER_ATTEMPT,
-        start,
-    )
-    .await
+    wait_for_integration_with_others(env, others, expected_count, Duration::from_secs(10), start)
+        .await
 }
 
 #[tracing::instrument(skip(env, others, start))]
@@ -524,14 +664,38 @@ pub async fn wait_for_integration_with_others(
     env: &EnvironmentWrite,
     others: &[&EnvironmentWrite],
     expected_count: usize,
-    num_attempts: usize,
-    delay: Duration,
+    deadline: Duration,
     start: Option<std::time::Instant>,
 ) {
+    if let Err(e) =
+        try_wait_for_integration_with_others(env, others, expected_count, deadline, start, false)
+            .await
+    {
+        tracing::warn!(%e);
+    }
+}
+
+#[tracing::instrument(skip(env, others, start))]
+/// Same as [`wait_for_integration_with_others`] but returns a diagnostic
+/// if the expected number of ops are not integrated in time.
+/// Set `dump_ops` to include the integrated ops in the diagnostic.
+pub async fn try_wait_for_integration_with_others(
+    env: &EnvironmentWrite,
+    others: &[&EnvironmentWrite],
+    expected_count: usize,
+    deadline: Duration,
+    start: Option<std::time::Instant>,
+    dump_ops: bool,
+) -> Result<(), IntegrationTimeout> {
     let mut last_total = 0;
+    let mut integrated = 0;
+    let mut last_integrated;
     let this_start = std::time::Instant::now();
-    for _ in 0..num_attempts {
+    let mut backoff = PollBackoff::new(deadline);
+    loop {
         let count = count_integration(env).await;
+        last_integrated = integrated;
+        integrated = count.integrated;
         let counts = get_counts(others).await;
         let total: usize = counts.0.clone().into_iter().map(|i| i.integrated).sum();
         let num_conductors = counts.0.len() + 1;
@@ -544,7 +708,7 @@ pub async fn wait_for_integration_with_others(
         let change = total.checked_sub(last_total).expect("LOST A VALUE");
         last_total = total;
         if count.integrated == expected_count {
-            return;
+            return Ok(());
         } else {
             let time_waited = this_start.elapsed().as_secs();
             let total_time_waited = start.map(|s| s.elapsed().as_secs()).unwrap_or(0);
@@ -568,8 +732,18 @@ pub async fn wait_for_integration_with_others(
                 ops_per_s,
             );
         }
-        tokio::time::sleep(delay).await;
+        if !backoff.wait().await {
+            break;
+        }
     }
+    Err(IntegrationTimeout::new(
+        env,
+        expected_count,
+        integrated,
+        last_integrated,
+        this_start.elapsed(),
+        dump_ops,
+    ))
 }
 
 #[tracing::instrument(skip(envs))]
@@ -772,3 +946,35 @@ where
 pub fn fake_valid_dna_file(uid: &str) -> DnaFile {
     fake_dna_zomes(uid, vec![(TestWasm::Foo.into(), TestWasm::Foo.into())])
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn poll_backoff_doubles_up_to_the_cap() {
+        let mut backoff = PollBackoff::new(Duration::from_secs(60));
+        let now = std::time::Instant::now();
+        let delays: Vec<_> = std::iter::from_fn(|| backoff.next_delay(now))
+            .take(10)
+            .map(|d| d.as_millis())
+            .collect();
+        assert_eq!(
+            delays,
+            vec![10, 20, 40, 80, 160, 320, 640, 1000, 1000, 1000]
+        );
+    }
+
+    #[test]
+    fn poll_backoff_stops_at_the_deadline() {
+        let mut backoff = PollBackoff::new(Duration::from_millis(25));
+        let start = backoff.deadline - Duration::from_millis(25);
+        assert_eq!(backoff.next_delay(start), Some(MIN_POLL_DELAY));
+        // Only 5 millis are left so the 20 milli delay is cut short
+        assert_eq!(
+            backoff.next_delay(start + Duration::from_millis(20)),
+            Some(Duration::from_millis(5))
+        );
+        assert_eq!(backoff.next_delay(backoff.deadline), None);
+    }
+}
@@ -116,6 +116,7 @@ impl ConductorTestData {
             vec![("test_app", cells)],
             dna_files.clone(),
             Some(network_config),
+            vec![],
         )
         .await;
 
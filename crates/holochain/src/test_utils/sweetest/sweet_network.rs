@@ -35,4 +35,18 @@ impl SweetNetwork {
         }];
         network
     }
+
+    /// In-process mem network where conductors find each other
+    /// through a bootstrap service shared by every conductor
+    /// in this process that uses the same name.
+    pub fn mem_bootstrap(name: &str) -> KitsuneP2pConfig {
+        let mut network = KitsuneP2pConfig::default();
+        network.transport_pool = vec![kitsune_p2p::TransportConfig::Mem {}];
+        network.bootstrap_service = Some(url2::url2!(
+            "{}://{}",
+            kitsune_p2p::MEM_BOOTSTRAP_SCHEME,
+            name
+        ));
+        network
+    }
 }
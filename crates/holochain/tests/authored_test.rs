@@ -20,10 +20,9 @@ use holochain_state::source_chain::SourceChain;
 async fn authored_test() {
     observability::test_run().ok();
     // Check if the correct number of ops are integrated
-    // every 100 ms for a maximum of 10 seconds but early exit
+    // for a maximum of 10 seconds but early exit
     // if they are there.
-    let num_attempts = 100;
-    let delay_per_attempt = Duration::from_millis(100);
+    let deadline = Duration::from_secs(10);
 
     let zomes = vec![TestWasm::Create];
     let mut conductor_test = ConductorTestData::two_agents(zomes, true).await;
@@ -59,13 +58,7 @@ async fn authored_test() {
     // Init is not run because we aren't calling the zome.
     let expected_count = 3 + 14;
 
-    wait_for_integration(
-        &bob_call_data.env,
-        expected_count,
-        num_attempts,
-        delay_per_attempt.clone(),
-    )
-    .await;
+    wait_for_integration(&bob_call_data.env, expected_count, deadline).await;
 
     let bob_source_chain = SourceChain::new(bob_call_data.env.clone().into()).unwrap();
     let bob_authored = bob_source_chain.elements();
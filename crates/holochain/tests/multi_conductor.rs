@@ -89,6 +89,38 @@ async fn multi_conductor() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[cfg(feature = "test_utils")]
+#[tokio::test(flavor = "multi_thread")]
+async fn mem_bootstrap_conductors_find_each_other() -> anyhow::Result<()> {
+    let _g = observability::test_run().ok();
+
+    let mut config = ConductorConfig::default();
+    config.network = Some(SweetNetwork::mem_bootstrap("multi_conductor"));
+    let mut conductors = SweetConductorBatch::from_config(2, config).await;
+
+    let (dna_file, _) = SweetDnaFile::unique_from_inline_zome("zome1", simple_crud_zome())
+        .await
+        .unwrap();
+
+    // No peer info is exchanged, the conductors only have the shared bootstrap.
+    let apps = conductors.setup_app("app", &[dna_file]).await;
+    let ((alice,), (bobbo,)) = apps.into_tuples();
+
+    let hash: HeaderHash = conductors[0].call(&alice.zome("zome1"), "create", ()).await;
+
+    wait_for_integration_1m(
+        bobbo.env(),
+        WaitOps::start() * 1 + WaitOps::cold_start() * 1 + WaitOps::ENTRY * 1,
+    )
+    .await;
+
+    let element: Option<Element> = conductors[1].call(&bobbo.zome("zome1"), "read", hash).await;
+    let element = element.expect("Element was None: bobbo couldn't `get` it");
+    assert_eq!(element.header().author(), alice.agent_pubkey());
+
+    Ok(())
+}
+
 #[tokio::test(flavor = "multi_thread")]
 #[cfg(feature = "test_utils")]
 #[ignore = "I'm not convinced this test is actually adding value and worth fixing right now"]
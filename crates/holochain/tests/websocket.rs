@@ -10,7 +10,7 @@ use holochain::test_utils::sweetest::SweetDnaFile;
 use holochain::{
     conductor::api::ZomeCall,
     conductor::{
-        api::{AdminRequest, AdminResponse, AppRequest, AppResponse},
+        api::{AdminEvent, AdminEventTopic, AdminRequest, AdminResponse, AppRequest, AppResponse},
         config::*,
         error::ConductorError,
         Conductor,
@@ -86,6 +86,9 @@ fn create_config(port: u16, environment_path: PathBuf) -> ConductorConfig {
         }),
         keystore_path: None,
         use_dangerous_test_keystore: true,
+        validation_receipt_retrigger_delay_ms: None,
+        validation_receipt_max_batch_size: None,
+        validation_receipt_max_batch_delay_ms: None,
     }
 }
 
@@ -599,6 +602,130 @@ async fn conductor_admin_interface_runs_from_config() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test(flavor = "multi_thread")]
+async fn add_admin_interfaces_returns_bound_ports() -> Result<()> {
+    observability::test_run().ok();
+    let tmp_dir = TempDir::new("conductor_cfg").unwrap();
+    let environment_path = tmp_dir.path().to_path_buf();
+    let config = create_config(0, environment_path);
+    let conductor_handle = Conductor::builder().config(config).build().await?;
+    let (mut client, _) = websocket_client(&conductor_handle).await?;
+
+    // Find a free port to request explicitly.
+    let explicit_port = std::net::TcpListener::bind("127.0.0.1:0")?
+        .local_addr()?
+        .port();
+    let configs = vec![0, explicit_port, 0]
+        .into_iter()
+        .map(|port| AdminInterfaceConfig {
+            driver: InterfaceDriver::Websocket { port },
+        })
+        .collect();
+    let request = AdminRequest::AddAdminInterfaces(configs);
+    let response = client.request(request).await.unwrap();
+    let ports = match response {
+        AdminResponse::AdminInterfacesAdded(ports) => ports,
+        _ => panic!("Expected AdminInterfacesAdded but got {:?}", response),
+    };
+
+    assert_eq!(ports.len(), 3);
+    assert_ne!(ports[0], 0);
+    assert_eq!(ports[1], explicit_port);
+    assert_ne!(ports[2], 0);
+
+    // Every reported port should be accepting admin requests.
+    for port in ports {
+        let (mut client, _) = websocket_client_by_port(port).await?;
+        let response = client.request(AdminRequest::ListDnas).await.unwrap();
+        assert_matches!(response, AdminResponse::DnasListed(_));
+    }
+
+    conductor_handle.shutdown().await;
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn admin_events_are_sent_for_subscribed_topics() -> Result<()> {
+    observability::test_run().ok();
+    let tmp_dir = TempDir::new("conductor_cfg").unwrap();
+    let environment_path = tmp_dir.path().to_path_buf();
+    let config = create_config(0, environment_path);
+    let conductor_handle = Conductor::builder().config(config).build().await?;
+    let (mut client, mut rx) = websocket_client(&conductor_handle).await?;
+    let (_, mut unsubscribed_rx) = websocket_client(&conductor_handle).await?;
+
+    let request = AdminRequest::Subscribe {
+        topics: vec![AdminEventTopic::Apps],
+    };
+    let response = client.request(request).await.unwrap();
+    assert_matches!(response, AdminResponse::Subscribed);
+
+    let dna = fake_dna_zomes(
+        "".into(),
+        vec![(TestWasm::Foo.into(), TestWasm::Foo.into())],
+    );
+    let (fake_dna_path, _tmpdir) = write_fake_dna_file(dna).await.unwrap();
+    let register_payload = RegisterDnaPayload {
+        uid: None,
+        properties: None,
+        source: DnaSource::Path(fake_dna_path),
+    };
+    let request = AdminRequest::RegisterDna(Box::new(register_payload));
+    let dna_hash = match client.request(request).await.unwrap() {
+        AdminResponse::DnaRegistered(hash) => hash,
+        response => panic!("Expected DnaRegistered but got {:?}", response),
+    };
+    let payload = InstallAppPayload {
+        dnas: vec![InstallAppDnaPayload {
+            hash: dna_hash,
+            nick: "nick".to_string(),
+            membrane_proof: None,
+        }],
+        installed_app_id: "test".to_string(),
+        agent_key: fake_agent_pubkey_1(),
+    };
+    let response = client
+        .request(AdminRequest::InstallApp(Box::new(payload)))
+        .await
+        .unwrap();
+    assert_matches!(response, AdminResponse::AppInstalled(_));
+    let response = client
+        .request(AdminRequest::ActivateApp {
+            installed_app_id: "test".to_string(),
+        })
+        .await
+        .unwrap();
+    assert_matches!(response, AdminResponse::AppActivated);
+
+    // Only the app events arrive even though cells were added too
+    assert_eq!(
+        next_admin_event(&mut rx).await,
+        AdminEvent::AppInstalled("test".to_string())
+    );
+    assert_eq!(
+        next_admin_event(&mut rx).await,
+        AdminEvent::AppActivated("test".to_string())
+    );
+
+    // A connection that never subscribed gets nothing
+    let nothing = tokio::time::timeout(Duration::from_millis(500), unsubscribed_rx.next()).await;
+    assert!(nothing.is_err());
+
+    conductor_handle.shutdown().await;
+
+    Ok(())
+}
+
+async fn next_admin_event(rx: &mut WebsocketReceiver) -> AdminEvent {
+    let (bytes, respond) = tokio::time::timeout(Duration::from_secs(5), rx.next())
+        .await
+        .expect("Timed out waiting for an admin event")
+        .expect("Admin connection closed");
+    assert!(!respond.is_request());
+    AdminEvent::try_from(bytes).unwrap()
+}
+
 #[tokio::test(flavor = "multi_thread")]
 async fn conductor_admin_interface_ends_with_shutdown() -> Result<()> {
     if let Err(e) = conductor_admin_interface_ends_with_shutdown_inner().await {
@@ -640,6 +640,75 @@ where
         Ok(())
     }
 
+    /// Get the live [Element] for this [HeaderHash] from only what the
+    /// authorities return, without reading or writing any local store.
+    async fn fetch_live_element_via_header(
+        &mut self,
+        hash: HeaderHash,
+        options: NetworkGetOptions,
+    ) -> CascadeResult<Option<Element>> {
+        let network = ok_or_return!(self.network.as_mut(), None);
+        let results = network.get(hash.into(), options).await?;
+        let mut live = None;
+        for response in results {
+            if let GetElementResponse::GetHeader(Some(we)) = response {
+                let (element_status, deletes, _) = we.into_parts();
+                // A valid delete from any authority means the header is dead
+                if deletes.iter().any(|d| d.status == ValidationStatus::Valid) {
+                    return Ok(None);
+                }
+                if element_status.status == ValidationStatus::Valid {
+                    live.get_or_insert(element_status.element);
+                }
+            }
+        }
+        Ok(live)
+    }
+
+    /// Get the oldest live [Element] for this [EntryHash] from only what
+    /// the authorities return, without reading or writing any local store.
+    async fn fetch_oldest_live_element_via_entry(
+        &mut self,
+        hash: EntryHash,
+        options: NetworkGetOptions,
+    ) -> CascadeResult<Option<Element>> {
+        let network = ok_or_return!(self.network.as_mut(), None);
+        let results = network.get(hash.into(), options).await?;
+        let mut live = BTreeMap::new();
+        let mut deleted = BTreeSet::new();
+        for response in results {
+            if let GetElementResponse::GetEntryFull(Some(raw)) = response {
+                let RawGetEntryResponse {
+                    live_headers,
+                    deletes,
+                    entry,
+                    entry_type,
+                    ..
+                } = *raw;
+                let valid_headers = live_headers
+                    .into_iter()
+                    .filter(|h| h.validation_status == ValidationStatus::Valid);
+                let elements =
+                    ElementGroup::from_wire_elements(valid_headers, entry_type, entry.clone())?;
+                for shh in elements.owned_signed_headers() {
+                    let hash = shh.header_address().clone();
+                    live.insert(hash, Element::new(shh, Some(entry.clone())));
+                }
+                deleted.extend(
+                    deletes
+                        .into_iter()
+                        .filter(|d| d.validation_status == ValidationStatus::Valid)
+                        .map(|d| d.header.delete.deletes_address),
+                );
+            }
+        }
+        Ok(live
+            .into_iter()
+            .filter(|(hash, _)| !deleted.contains(hash))
+            .map(|(_, element)| element)
+            .min_by_key(|e| (e.header().timestamp(), e.header_address().clone())))
+    }
+
     // TODO: Remove when used
     #[allow(dead_code)]
     async fn fetch_meta(
@@ -1004,7 +1073,8 @@ where
         let get_call = options.strategy;
         let mut options: NetworkGetOptions = options.into();
         options.all_live_headers_with_metadata = true;
-        let authority = self.am_i_an_authority(entry_hash.clone().into()).await?;
+        let authority = may_short_circuit(get_call)
+            && self.am_i_an_authority(entry_hash.clone().into()).await?;
 
         if authority {
             // Authorities only need to return local data
@@ -1110,9 +1180,16 @@ where
     ) -> CascadeResult<Option<Element>> {
         debug!("in get entry");
         let get_call = options.strategy;
+        if let GetStrategy::NetworkOnly = get_call {
+            return self
+                .fetch_oldest_live_element_via_entry(entry_hash, options.into())
+                .await;
+        }
         let mut oldest_live_element = Search::NotInCascade;
-        let authority = self.am_i_an_authority(entry_hash.clone().into()).await?;
-        let authoring = self.am_i_authoring(&entry_hash.clone().into()).await?;
+        let authority = may_short_circuit(get_call)
+            && self.am_i_an_authority(entry_hash.clone().into()).await?;
+        let authoring =
+            may_short_circuit(get_call) && self.am_i_authoring(&entry_hash.clone().into()).await?;
 
         // If this agent is in the process of authoring then
         // there is no reason to go to the network
@@ -1159,8 +1236,10 @@ where
         let mut options: NetworkGetOptions = options.into();
         options.all_live_headers_with_metadata = true;
 
-        let authority = self.am_i_an_authority(header_hash.clone().into()).await?;
-        let authoring = self.am_i_authoring(&header_hash.clone().into()).await?;
+        let authority = may_short_circuit(get_call)
+            && self.am_i_an_authority(header_hash.clone().into()).await?;
+        let authoring =
+            may_short_circuit(get_call) && self.am_i_authoring(&header_hash.clone().into()).await?;
 
         // If this agent is in the process of authoring then
         // there is no reason to go to the network
@@ -1196,6 +1275,11 @@ where
         header_hash: HeaderHash,
         options: GetOptions,
     ) -> CascadeResult<Option<Element>> {
+        if let GetStrategy::NetworkOnly = options.strategy {
+            return self
+                .fetch_live_element_via_header(header_hash, options.into())
+                .await;
+        }
         let cache_data = ok_or_return!(self.cache_data.as_ref(), None);
         let integrated_data = ok_or_return!(self.integrated_data.as_ref(), None);
         let authored_data = ok_or_return!(self.authored_data.as_ref(), None);
@@ -1236,8 +1320,10 @@ where
         }
 
         let get_call = options.strategy;
-        let authority = self.am_i_an_authority(header_hash.clone().into()).await?;
-        let authoring = self.am_i_authoring(&header_hash.clone().into()).await?;
+        let authority = may_short_circuit(get_call)
+            && self.am_i_an_authority(header_hash.clone().into()).await?;
+        let authoring =
+            may_short_circuit(get_call) && self.am_i_authoring(&header_hash.clone().into()).await?;
 
         // If this agent is in the process of authoring then
         // there is no reason to go to the network
@@ -2108,6 +2194,12 @@ impl<'a, M: MetadataBufT> From<&'a DbPairMut<'a, M>> for DbPair<'a, M> {
     }
 }
 
+/// Whether a get may be answered locally because this agent
+/// is an authority for the data or is authoring it.
+fn may_short_circuit(strategy: GetStrategy) -> bool {
+    !strategy.always_asks_network()
+}
+
 pub fn integrate_single_metadata<C, P>(
     op: DhtOpLight,
     element_store: &ElementBuf<P>,
@@ -2,6 +2,10 @@ use holo_hash::*;
 use holochain_types::prelude::*;
 use holochain_zome_types::cell::CellId;
 use kitsune_p2p::agent_store::AgentInfoSigned;
+use kitsune_p2p::metrics::TransportMetrics;
+use std::collections::BTreeSet;
+
+use crate::InstalledAppInfo;
 
 /// Represents the available conductor functions to call over an Admin interface
 /// and will result in a corresponding [`AdminResponse`] message being sent back over the
@@ -44,13 +48,26 @@ pub enum AdminRequest {
     /// UID and the specified properties, create a new Cell from this cloned DNA,
     /// and add the Cell to the specified App.
     ///
-    /// Will be responded to with an [`AdminResponse::DnaCloned`]
+    /// Will be responded to with an [`AdminResponse::CloneCellCreated`]
     /// or an [`AdminResponse::Error`]
     ///
     /// [`CreateCloneCellPayload`]: ../../../holochain_types/app/struct.CreateCloneCellPayload.html
-    /// [`AdminResponse::DnaCloned`]: enum.AdminResponse.html#variant.DnaCloned
+    /// [`AdminResponse::CloneCellCreated`]: enum.AdminResponse.html#variant.CloneCellCreated
     CreateCloneCell(Box<CreateCloneCellPayload>),
 
+    /// Remove a Cell that was cloned with [`AdminRequest::CreateCloneCell`]
+    /// from its App.
+    ///
+    /// Only the clone is removed. The Cell it was cloned from and any other
+    /// clones in the same slot are left as they are.
+    ///
+    /// Will be responded to with an [`AdminResponse::CloneCellDeleted`]
+    /// or an [`AdminResponse::Error`]
+    ///
+    /// [`AdminRequest::CreateCloneCell`]: enum.AdminRequest.html#variant.CreateCloneCell
+    /// [`AdminResponse::CloneCellDeleted`]: enum.AdminResponse.html#variant.CloneCellDeleted
+    DeleteCloneCell(Box<DeleteCloneCellPayload>),
+
     /// Install an app from a list of `Dna` paths.
     /// Triggers genesis to be run on all `Cell`s and to be stored.
     /// An `App` is intended for use by
@@ -100,6 +117,17 @@ pub enum AdminRequest {
     /// [`AdminResponse::Error`]: enum.AppResponse.html#variant.Error
     ListDnas,
 
+    /// List a summary of every installed `Dna`:
+    /// its hash, name, uid and the names of its zomes.
+    /// Takes no arguments.
+    ///
+    /// Will be responded to with an [`AdminResponse::DnaSummariesListed`]
+    /// or an [`AdminResponse::Error`]
+    ///
+    /// [`AdminResponse::DnaSummariesListed`]: enum.AdminResponse.html#variant.DnaSummariesListed
+    /// [`AdminResponse::Error`]: enum.AppResponse.html#variant.Error
+    ListDnaSummaries,
+
     /// Generate a new AgentPubKey.
     /// Takes no arguments.
     ///
@@ -128,6 +156,20 @@ pub enum AdminRequest {
     /// [`AdminResponse::ActiveAppsListed`]: enum.AdminResponse.html#variant.ActiveAppsListed
     /// [`AdminResponse::Error`]: enum.AppResponse.html#variant.Error
     ListActiveApps,
+    /// List info about all the installed Apps in the conductor,
+    /// including whether each App is active and its cells.
+    /// Use argument `status_filter` to only list Apps with that status.
+    ///
+    /// Will be responded to with an [`AdminResponse::AppsListed`]
+    /// or an [`AdminResponse::Error`]
+    ///
+    /// [`AdminResponse::AppsListed`]: enum.AdminResponse.html#variant.AppsListed
+    /// [`AdminResponse::Error`]: enum.AppResponse.html#variant.Error
+    ListApps {
+        /// Only list Apps with this status,
+        /// or all Apps if `None`
+        status_filter: Option<AppStatusFilter>,
+    },
     /// Changes the `App` specified by argument `installed_app_id` from an inactive state to an active state in the conductor,
     /// meaning that Zome calls can now be made and the `App` will be loaded on a reboot of the conductor.
     /// It is likely to want to call this after calling [`AdminRequest::InstallApp`], since a freshly
@@ -156,6 +198,51 @@ pub enum AdminRequest {
         /// The InstalledAppId to deactivate
         installed_app_id: InstalledAppId,
     },
+    /// Enable the `App` specified by argument `installed_app_id`.
+    /// This is the same as [`AdminRequest::ActivateApp`] and also
+    /// clears the reason recorded by [`AdminRequest::DisableApp`].
+    ///
+    /// Will be responded to with an [`AdminResponse::AppEnabled`]
+    /// or an [`AdminResponse::Error`]
+    ///
+    /// [`AdminRequest::ActivateApp`]: enum.AdminRequest.html#variant.ActivateApp
+    /// [`AdminRequest::DisableApp`]: enum.AdminRequest.html#variant.DisableApp
+    /// [`AdminResponse::AppEnabled`]: enum.AdminResponse.html#variant.AppEnabled
+    /// [`AdminResponse::Error`]: enum.AppResponse.html#variant.Error
+    EnableApp {
+        /// The InstalledAppId to enable
+        installed_app_id: InstalledAppId,
+    },
+    /// Disable the `App` specified by argument `installed_app_id` and record why.
+    /// This is the same as [`AdminRequest::DeactivateApp`] except the
+    /// reason is reported by [`AdminRequest::ListApps`] until the app is enabled again.
+    ///
+    /// Will be responded to with an [`AdminResponse::AppDisabled`]
+    /// or an [`AdminResponse::Error`]
+    ///
+    /// [`AdminRequest::DeactivateApp`]: enum.AdminRequest.html#variant.DeactivateApp
+    /// [`AdminRequest::ListApps`]: enum.AdminRequest.html#variant.ListApps
+    /// [`AdminResponse::AppDisabled`]: enum.AdminResponse.html#variant.AppDisabled
+    /// [`AdminResponse::Error`]: enum.AppResponse.html#variant.Error
+    DisableApp {
+        /// The InstalledAppId to disable
+        installed_app_id: InstalledAppId,
+        /// Why the app is being disabled
+        reason: String,
+    },
+    /// Uninstall the `App` specified by argument `installed_app_id`, whether it is
+    /// active or inactive. The `App` is removed from the conductor along with any
+    /// of its `Cell`s that are not also part of another installed `App`.
+    ///
+    /// Will be responded to with an [`AdminResponse::AppUninstalled`]
+    /// or an [`AdminResponse::Error`]
+    ///
+    /// [`AdminResponse::AppUninstalled`]: enum.AdminResponse.html#variant.AppUninstalled
+    /// [`AdminResponse::Error`]: enum.AppResponse.html#variant.Error
+    UninstallApp {
+        /// The InstalledAppId to uninstall
+        installed_app_id: InstalledAppId,
+    },
     /// Open up a new websocket interface at the networking port
     /// (optionally) specified by argument `port` (or using any free port if argument `port` is `None`)
     /// over which you can then use the [`AppRequest`] API.
@@ -186,6 +273,11 @@ pub enum AdminRequest {
     DumpState {
         /// The `CellId` for which to dump state
         cell_id: Box<CellId>,
+        /// If set, the dump also includes up to this many ops
+        /// from each of the validation limbo, integration limbo
+        /// and integrated ops stores.
+        #[serde(default)]
+        queue_entries: Option<u32>,
     },
     /// Add a list [AgentInfoSigned] to this conductor's peer store.
     /// This is another way of finding peers on a dht.
@@ -195,6 +287,14 @@ pub enum AdminRequest {
     /// It is also helpful if you know other
     /// agents on the network and they can send you
     /// their agent info.
+    ///
+    /// Each agent info is checked on its own and any that are
+    /// rejected don't stop the rest from being added.
+    ///
+    /// Will be responded to with an [`AdminResponse::AgentInfoAdded`]
+    /// containing the result for each agent info in request order.
+    ///
+    /// [`AdminResponse::AgentInfoAdded`]: enum.AdminResponse.html#variant.AgentInfoAdded
     AddAgentInfo {
         /// Vec of signed agent info to add to peer store
         agent_infos: Vec<AgentInfoSigned>,
@@ -211,7 +311,420 @@ pub enum AdminRequest {
     RequestAgentInfo {
         /// Optionally choose a specific agent info
         cell_id: Option<CellId>,
+        /// Return at most this many agent infos.
+        /// Returns all the agent info when not set.
+        /// A limit of zero is treated as one.
+        #[serde(default)]
+        limit: Option<u32>,
+        /// Continue after the last agent info of a previous page
+        /// by passing its [`AgentInfoPage::next_cursor`].
+        ///
+        /// [`AgentInfoPage::next_cursor`]: struct.AgentInfoPage.html#structfield.next_cursor
+        #[serde(default)]
+        cursor: Option<AgentInfoCursor>,
     },
+    /// Check that the conductor is responding on this interface.
+    /// Doesn't read or change any state so it's cheap to call
+    /// while waiting for a conductor to be ready.
+    ///
+    /// Will be responded to with an [`AdminResponse::Pong`].
+    ///
+    /// [`AdminResponse::Pong`]: enum.AdminResponse.html#variant.Pong
+    Ping,
+    /// Push [`AdminEvent`]s for these topics to this connection
+    /// as signals while it stays open.
+    /// Only events for the listed topics are sent, so calling this again
+    /// replaces the topics and an empty list stops all events.
+    ///
+    /// Will be responded to with an [`AdminResponse::Subscribed`].
+    ///
+    /// [`AdminEvent`]: enum.AdminEvent.html
+    /// [`AdminResponse::Subscribed`]: enum.AdminResponse.html#variant.Subscribed
+    Subscribe {
+        /// The topics of the events to send
+        topics: Vec<AdminEventTopic>,
+    },
+    /// Get a snapshot of the conductor's networking activity:
+    /// transport activity and addresses, whether the bootstrap
+    /// service is reachable and the peers known in each space.
+    ///
+    /// Will be responded to with an [`AdminResponse::NetworkStatsRequested`].
+    ///
+    /// [`AdminResponse::NetworkStatsRequested`]: enum.AdminResponse.html#variant.NetworkStatsRequested
+    NetworkStats,
+    /// Start a round of network gossip for a cell's DNA now, and run the
+    /// workflows that validate and integrate the ops the cell has received
+    /// through gossip or publish, instead of waiting for them to be triggered.
+    ///
+    /// Will be responded to with an [`AdminResponse::GossipTriggered`]
+    /// or an [`AdminResponse::Error`] if the cell doesn't exist.
+    ///
+    /// [`AdminResponse::GossipTriggered`]: enum.AdminResponse.html#variant.GossipTriggered
+    /// [`AdminResponse::Error`]: enum.AppResponse.html#variant.Error
+    TriggerGossip {
+        /// The cell to trigger
+        cell_id: Box<CellId>,
+    },
+    /// Run the publish workflow for a cell now so any of its
+    /// authored ops that are due to be published are sent.
+    ///
+    /// Will be responded to with an [`AdminResponse::PublishTriggered`]
+    /// or an [`AdminResponse::Error`] if the cell doesn't exist.
+    ///
+    /// [`AdminResponse::PublishTriggered`]: enum.AdminResponse.html#variant.PublishTriggered
+    /// [`AdminResponse::Error`]: enum.AppResponse.html#variant.Error
+    TriggerPublish {
+        /// The cell to trigger
+        cell_id: Box<CellId>,
+    },
+    /// How many times each zome function of a cell has been called
+    /// since the conductor started and how long the calls took.
+    ///
+    /// Will be responded to with an [`AdminResponse::ZomeCallStatsRequested`]
+    /// or an [`AdminResponse::Error`] if the cell doesn't exist.
+    ///
+    /// [`AdminResponse::ZomeCallStatsRequested`]: enum.AdminResponse.html#variant.ZomeCallStatsRequested
+    /// [`AdminResponse::Error`]: enum.AppResponse.html#variant.Error
+    ZomeCallStats {
+        /// The cell to get the stats for
+        cell_id: Box<CellId>,
+    },
+    /// Rebuild the element and metadata stores of a cell from the
+    /// ops it has already integrated.
+    ///
+    /// The integrated ops are read from a snapshot and re-integrated in
+    /// a single write transaction while the cell's integration workflow
+    /// waits, so the cell can keep running while this happens.
+    /// Integrating an op that is already integrated changes
+    /// nothing, so this can be run as many times as needed.
+    ///
+    /// Connections subscribed to [`AdminEventTopic::Reindex`] are sent
+    /// [`AdminEvent::CellReindexProgress`] events while this runs.
+    ///
+    /// Will be responded to with an [`AdminResponse::CellReindexed`]
+    /// or an [`AdminResponse::Error`] if the cell doesn't exist.
+    ///
+    /// [`AdminResponse::CellReindexed`]: enum.AdminResponse.html#variant.CellReindexed
+    /// [`AdminResponse::Error`]: enum.AppResponse.html#variant.Error
+    /// [`AdminEventTopic::Reindex`]: enum.AdminEventTopic.html#variant.Reindex
+    /// [`AdminEvent::CellReindexProgress`]: enum.AdminEvent.html#variant.CellReindexProgress
+    ReindexCell {
+        /// The cell to reindex
+        cell_id: Box<CellId>,
+    },
+    /// List the capability grants committed to a cell's source chain,
+    /// to audit which agents can call into the cell.
+    ///
+    /// Grants don't expire, they are revoked by updating or deleting them.
+    ///
+    /// Will be responded to with an [`AdminResponse::CapabilityGrantsListed`]
+    /// or an [`AdminResponse::Error`] if the cell doesn't exist.
+    ///
+    /// [`AdminResponse::CapabilityGrantsListed`]: enum.AdminResponse.html#variant.CapabilityGrantsListed
+    /// [`AdminResponse::Error`]: enum.AppResponse.html#variant.Error
+    ListCapabilityGrants {
+        /// The cell to list the grants of
+        cell_id: Box<CellId>,
+        /// Also list grants that have been revoked
+        #[serde(default)]
+        include_revoked: bool,
+    },
+}
+
+/// The status of an installed App, used to filter [`AdminRequest::ListApps`].
+///
+/// [`AdminRequest::ListApps`]: enum.AdminRequest.html#variant.ListApps
+#[derive(Clone, Copy, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AppStatusFilter {
+    /// Apps that are active and can be called
+    Active,
+    /// Apps that are installed but not active
+    Inactive,
+}
+
+/// The result of adding a single [AgentInfoSigned] with an [`AdminRequest::AddAgentInfo`].
+///
+/// [`AdminRequest::AddAgentInfo`]: enum.AdminRequest.html#variant.AddAgentInfo
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AgentInfoAddResult {
+    /// The agent info was added to the peer store
+    Accepted,
+    /// The agent info was not added to the peer store
+    Rejected(AgentInfoRejection),
+}
+
+/// A page of the agent info returned from an [`AdminRequest::RequestAgentInfo`].
+///
+/// [`AdminRequest::RequestAgentInfo`]: enum.AdminRequest.html#variant.RequestAgentInfo
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct AgentInfoPage {
+    /// The agent info in this page
+    pub agent_infos: Vec<AgentInfoSigned>,
+    /// Where to continue from to get the next page.
+    /// `None` if there are no more pages.
+    pub next_cursor: Option<AgentInfoCursor>,
+}
+
+/// An opaque position in the peer store.
+///
+/// The cursor is the key of the last agent info that was returned
+/// so the next page starts after it even if agent info is added
+/// or removed between calls.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct AgentInfoCursor(pub Vec<u8>);
+
+/// Why an [AgentInfoSigned] was not added to the peer store.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize, thiserror::Error)]
+#[serde(rename_all = "snake_case")]
+pub enum AgentInfoRejection {
+    /// The agent info or its space or agent could not be decoded
+    #[error("Agent info is malformed: {0}")]
+    Malformed(String),
+    /// The space is not a Dna installed on this conductor
+    #[error("Agent info is for a space this conductor doesn't have")]
+    WrongSpace,
+    /// The signature is not from the agent the info is for
+    #[error("Agent info has a bad signature")]
+    BadSignature,
+    /// The agent info expired before it was added
+    #[error("Agent info has expired")]
+    Expired,
+}
+
+/// What an installed `Dna` is, returned from an [`AdminRequest::ListDnaSummaries`].
+///
+/// [`AdminRequest::ListDnaSummaries`]: enum.AdminRequest.html#variant.ListDnaSummaries
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct DnaSummary {
+    /// The hash of the dna
+    pub hash: DnaHash,
+    /// The friendly name of the dna
+    pub name: String,
+    /// The uid of the dna
+    pub uid: String,
+    /// The names of the dna's zomes in order
+    pub zome_names: Vec<ZomeName>,
+}
+
+/// The networking activity of a conductor returned from an [`AdminRequest::NetworkStats`].
+///
+/// [`AdminRequest::NetworkStats`]: enum.AdminRequest.html#variant.NetworkStats
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct NetworkStats {
+    /// Activity of the transport shared by every cell on the conductor
+    pub transport: TransportMetrics,
+    /// The addresses other conductors can reach this conductor on
+    pub transport_addresses: Vec<url2::Url2>,
+    /// Whether the bootstrap service responded.
+    /// `None` if no bootstrap service is configured.
+    pub bootstrap_reachable: Option<bool>,
+    /// The agents in each space this conductor has a cell in
+    pub spaces: Vec<SpaceStats>,
+}
+
+/// The agents in a single space, part of [`NetworkStats`].
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct SpaceStats {
+    /// The dna of the space
+    pub dna_hash: DnaHash,
+    /// Agents of cells on this conductor
+    pub local_agents: u32,
+    /// Other agents in the peer store for this space
+    pub peers: u32,
+}
+
+/// Upper bounds in milliseconds of the buckets in [`ZomeFnCallStats::latency_buckets`].
+/// The last bucket counts every call slower than the last bound.
+pub const ZOME_CALL_LATENCY_BUCKETS_MS: [u64; 8] = [1, 5, 10, 50, 100, 500, 1000, 5000];
+
+/// The calls to a single zome function on a cell,
+/// returned from an [`AdminRequest::ZomeCallStats`].
+///
+/// [`AdminRequest::ZomeCallStats`]: enum.AdminRequest.html#variant.ZomeCallStats
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct ZomeFnCallStats {
+    /// The zome the function is in
+    pub zome_name: ZomeName,
+    /// The function that was called
+    pub fn_name: FunctionName,
+    /// Number of calls, including the ones that failed
+    pub calls: u64,
+    /// Number of calls that didn't return a successful response,
+    /// including unauthorized calls and network errors
+    pub errors: u64,
+    /// Total time spent in the calls in microseconds
+    pub total_micros: u64,
+    /// The slowest call in microseconds
+    pub max_micros: u64,
+    /// Number of calls in each latency bucket.
+    /// The first bucket counts calls that took up to the first of
+    /// [`ZOME_CALL_LATENCY_BUCKETS_MS`] and so on, with one more
+    /// bucket at the end for calls slower than all of them.
+    pub latency_buckets: Vec<u64>,
+}
+
+impl ZomeFnCallStats {
+    /// Stats for a function that hasn't been called yet
+    pub fn new(zome_name: ZomeName, fn_name: FunctionName) -> Self {
+        Self {
+            zome_name,
+            fn_name,
+            calls: 0,
+            errors: 0,
+            total_micros: 0,
+            max_micros: 0,
+            latency_buckets: vec![0; ZOME_CALL_LATENCY_BUCKETS_MS.len() + 1],
+        }
+    }
+
+    /// Add a call that took this long
+    pub fn record(&mut self, elapsed: std::time::Duration, failed: bool) {
+        let micros = elapsed.as_micros() as u64;
+        self.calls += 1;
+        if failed {
+            self.errors += 1;
+        }
+        self.total_micros += micros;
+        self.max_micros = self.max_micros.max(micros);
+        let bucket = ZOME_CALL_LATENCY_BUCKETS_MS
+            .iter()
+            .position(|bound| micros <= bound * 1000)
+            .unwrap_or(ZOME_CALL_LATENCY_BUCKETS_MS.len());
+        self.latency_buckets[bucket] += 1;
+    }
+
+    /// The average time a call took.
+    /// `None` if there have been no calls.
+    pub fn mean(&self) -> Option<std::time::Duration> {
+        if self.calls == 0 {
+            None
+        } else {
+            Some(std::time::Duration::from_micros(
+                self.total_micros / self.calls,
+            ))
+        }
+    }
+}
+
+/// What happened to the integrated ops of a cell during an [`AdminRequest::ReindexCell`].
+///
+/// [`AdminRequest::ReindexCell`]: enum.AdminRequest.html#variant.ReindexCell
+#[derive(Clone, Debug, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct CellReindexReport {
+    /// Valid ops whose data and metadata were integrated again
+    pub valid: usize,
+    /// Rejected ops whose data and metadata were integrated again
+    pub rejected: usize,
+    /// Abandoned ops, which have no data to integrate
+    pub abandoned: usize,
+    /// Ops that were skipped because their data couldn't be found
+    /// in the vault or the source chain
+    pub missing_data: usize,
+}
+
+/// A capability grant on a cell's source chain,
+/// listed by [`AdminRequest::ListCapabilityGrants`].
+/// The grant's secret is never included.
+///
+/// [`AdminRequest::ListCapabilityGrants`]: enum.AdminRequest.html#variant.ListCapabilityGrants
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct CapGrantInfo {
+    /// The header that committed the grant
+    pub header_hash: HeaderHash,
+    /// The tag the grant was committed with
+    pub tag: String,
+    /// Who can claim the grant
+    pub access: CapGrantAccess,
+    /// The functions the grant allows calling
+    pub functions: GrantedFunctions,
+    /// Whether a later update or delete has revoked the grant
+    pub revoked: bool,
+}
+
+/// Who can claim a [`CapGrantInfo`].
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case", tag = "type", content = "data")]
+pub enum CapGrantAccess {
+    /// Anyone
+    Unrestricted,
+    /// Anyone with the secret
+    Transferable,
+    /// Only these agents, with the secret
+    Assigned(BTreeSet<AgentPubKey>),
+}
+
+impl From<&CapAccess> for CapGrantAccess {
+    fn from(access: &CapAccess) -> Self {
+        match access {
+            CapAccess::Unrestricted => Self::Unrestricted,
+            CapAccess::Transferable { .. } => Self::Transferable,
+            CapAccess::Assigned { assignees, .. } => Self::Assigned(assignees.clone()),
+        }
+    }
+}
+
+/// Something that changed on the conductor, sent as a signal to admin
+/// connections that have subscribed to its [`AdminEventTopic`]
+/// with [`AdminRequest::Subscribe`].
+///
+/// [`AdminRequest::Subscribe`]: enum.AdminRequest.html#variant.Subscribe
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize, SerializedBytes)]
+#[serde(rename_all = "snake_case", tag = "type", content = "data")]
+pub enum AdminEvent {
+    /// A cell was added to the conductor
+    CellAdded(CellId),
+    /// A cell was removed from the conductor
+    CellRemoved(CellId),
+    /// An app was installed
+    AppInstalled(InstalledAppId),
+    /// An app was activated or enabled
+    AppActivated(InstalledAppId),
+    /// An app was deactivated or disabled
+    AppDeactivated(InstalledAppId),
+    /// An app was uninstalled
+    AppUninstalled(InstalledAppId),
+    /// Some of a cell's integrated ops have been integrated again
+    /// during an [`AdminRequest::ReindexCell`].
+    ///
+    /// [`AdminRequest::ReindexCell`]: enum.AdminRequest.html#variant.ReindexCell
+    CellReindexProgress {
+        /// The cell being reindexed
+        cell_id: CellId,
+        /// How many ops have been integrated again so far
+        done: usize,
+        /// How many ops will be integrated again
+        total: usize,
+    },
+}
+
+impl AdminEvent {
+    /// The topic this event is sent on
+    pub fn topic(&self) -> AdminEventTopic {
+        match self {
+            AdminEvent::CellAdded(_) | AdminEvent::CellRemoved(_) => AdminEventTopic::Cells,
+            AdminEvent::AppInstalled(_)
+            | AdminEvent::AppActivated(_)
+            | AdminEvent::AppDeactivated(_)
+            | AdminEvent::AppUninstalled(_) => AdminEventTopic::Apps,
+            AdminEvent::CellReindexProgress { .. } => AdminEventTopic::Reindex,
+        }
+    }
+}
+
+/// The kinds of [`AdminEvent`] a connection can subscribe to.
+///
+/// [`AdminEvent`]: enum.AdminEvent.html
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AdminEventTopic {
+    /// Cells being added to or removed from the conductor
+    Cells,
+    /// Apps being installed, activated, deactivated or uninstalled
+    Apps,
+    /// Progress of cells being reindexed
+    Reindex,
 }
 
 /// Represents the possible responses to an [`AdminRequest`]
@@ -273,12 +786,20 @@ pub enum AdminResponse {
     /// [`CellId`]: ../../../holochain_types/cell/struct.CellId.html
     CloneCellCreated(CellId),
 
+    /// The successful response to an [`AdminRequest::DeleteCloneCell`].
+    ///
+    /// [`AdminRequest::DeleteCloneCell`]: enum.AdminRequest.html#variant.DeleteCloneCell
+    CloneCellDeleted,
+
     /// The succesful response to an [`AdminRequest::AddAdminInterfaces`].
     ///
-    /// It means the `AdminInterface`s have successfully been added
+    /// It means the `AdminInterface`s have successfully been added.
+    /// Contains the port each interface was bound to, in the same order
+    /// as the configs in the request. This is how you find out which port
+    /// was chosen by the OS when port `0` was used.
     ///
     /// [`AdminRequest::AddAdminInterfaces`]: enum.AdminRequest.html#variant.AddAdminInterfaces
-    AdminInterfacesAdded,
+    AdminInterfacesAdded(Vec<u16>),
 
     /// The succesful response to an [`AdminRequest::GenerateAgentPubKey`].
     ///
@@ -294,6 +815,13 @@ pub enum AdminResponse {
     /// [`AdminRequest::ListDnas`]: enum.AdminRequest.html#variant.ListDnas
     DnasListed(Vec<DnaHash>),
 
+    /// The successful response to an [`AdminRequest::ListDnaSummaries`].
+    ///
+    /// Contains a summary of each installed `Dna`
+    ///
+    /// [`AdminRequest::ListDnaSummaries`]: enum.AdminRequest.html#variant.ListDnaSummaries
+    DnaSummariesListed(Vec<DnaSummary>),
+
     /// The succesful response to an [`AdminRequest::ListCellIds`].
     ///
     /// Contains a list of all the `Cell` ids in the conductor
@@ -308,6 +836,13 @@ pub enum AdminResponse {
     /// [`AdminRequest::ListActiveApps`]: enum.AdminRequest.html#variant.ListActiveApps
     ActiveAppsListed(Vec<InstalledAppId>),
 
+    /// The succesful response to an [`AdminRequest::ListApps`].
+    ///
+    /// Contains info about all the installed `App`s that match the filter
+    ///
+    /// [`AdminRequest::ListApps`]: enum.AdminRequest.html#variant.ListApps
+    AppsListed(Vec<InstalledAppInfo>),
+
     /// The succesful response to an [`AdminRequest::AttachAppInterface`].
     ///
     /// `AppInterfaceApi` successfully attached.
@@ -337,6 +872,28 @@ pub enum AdminResponse {
     /// [`AdminRequest::DeactivateApp`]: enum.AdminRequest.html#variant.DeactivateApp
     AppDeactivated,
 
+    /// The succesful response to an [`AdminRequest::EnableApp`].
+    ///
+    /// [`AdminRequest::EnableApp`]: enum.AdminRequest.html#variant.EnableApp
+    AppEnabled,
+
+    /// The succesful response to an [`AdminRequest::DisableApp`].
+    ///
+    /// [`AdminRequest::DisableApp`]: enum.AdminRequest.html#variant.DisableApp
+    AppDisabled,
+
+    /// The succesful response to an [`AdminRequest::UninstallApp`].
+    ///
+    /// It means the `App` was uninstalled successfully.
+    ///
+    /// [`AdminRequest::UninstallApp`]: enum.AdminRequest.html#variant.UninstallApp
+    AppUninstalled {
+        /// The `Cell`s that were removed from the conductor
+        removed_cells: Vec<CellId>,
+        /// The `Cell`s that were kept because another `App` still uses them
+        kept_cells: Vec<CellId>,
+    },
+
     /// The succesful response to an [`AdminRequest::DumpState`].
     ///
     /// The result contains a string of serialized JSON data which can be deserialized to access the
@@ -347,17 +904,73 @@ pub enum AdminResponse {
 
     /// The succesful response to an [`AdminRequest::AddAgentInfo`].
     ///
-    /// This means the agent info was successfully added to the peer store.
+    /// Contains whether each agent info was added to the peer store
+    /// in the same order as the request.
     ///
     /// [`AdminRequest::AddAgentInfo`]: enum.AdminRequest.html#variant.AddAgentInfo
-    AgentInfoAdded,
+    AgentInfoAdded(Vec<AgentInfoAddResult>),
 
     /// The succesful response to an [`AdminRequest::RequestAgentInfo`].
     ///
-    /// This is all the agent info that was found for the request.
+    /// This is the agent info that was found for the request
+    /// along with a cursor for the next page if there is one.
     ///
     /// [`AdminRequest::RequestAgentInfo`]: enum.AdminRequest.html#variant.RequestAgentInfo
-    AgentInfoRequested(Vec<AgentInfoSigned>),
+    AgentInfoRequested(AgentInfoPage),
+
+    /// The succesful response to an [`AdminRequest::Ping`].
+    ///
+    /// [`AdminRequest::Ping`]: enum.AdminRequest.html#variant.Ping
+    Pong,
+
+    /// The succesful response to an [`AdminRequest::Subscribe`].
+    ///
+    /// Events for the requested topics will now arrive as signals
+    /// on this connection.
+    ///
+    /// [`AdminRequest::Subscribe`]: enum.AdminRequest.html#variant.Subscribe
+    Subscribed,
+
+    /// The succesful response to an [`AdminRequest::NetworkStats`].
+    ///
+    /// [`AdminRequest::NetworkStats`]: enum.AdminRequest.html#variant.NetworkStats
+    NetworkStatsRequested(NetworkStats),
+
+    /// The succesful response to an [`AdminRequest::TriggerGossip`].
+    ///
+    /// The workflows have been triggered but may not have run yet.
+    ///
+    /// [`AdminRequest::TriggerGossip`]: enum.AdminRequest.html#variant.TriggerGossip
+    GossipTriggered,
+
+    /// The succesful response to an [`AdminRequest::TriggerPublish`].
+    ///
+    /// The workflow has been triggered but may not have run yet.
+    ///
+    /// [`AdminRequest::TriggerPublish`]: enum.AdminRequest.html#variant.TriggerPublish
+    PublishTriggered,
+
+    /// The succesful response to an [`AdminRequest::ZomeCallStats`].
+    ///
+    /// Contains the stats of each zome function that has been called,
+    /// sorted by zome name and then function name.
+    ///
+    /// [`AdminRequest::ZomeCallStats`]: enum.AdminRequest.html#variant.ZomeCallStats
+    ZomeCallStatsRequested(Vec<ZomeFnCallStats>),
+
+    /// The succesful response to an [`AdminRequest::ReindexCell`].
+    ///
+    /// Contains how many of the cell's integrated ops were integrated again.
+    ///
+    /// [`AdminRequest::ReindexCell`]: enum.AdminRequest.html#variant.ReindexCell
+    CellReindexed(CellReindexReport),
+
+    /// The succesful response to an [`AdminRequest::ListCapabilityGrants`].
+    ///
+    /// Contains the cell's grants in the order they were committed.
+    ///
+    /// [`AdminRequest::ListCapabilityGrants`]: enum.AdminRequest.html#variant.ListCapabilityGrants
+    CapabilityGrantsListed(Vec<CapGrantInfo>),
 }
 
 /// Error type that goes over the websocket wire.
@@ -391,3 +1004,29 @@ impl ExternalApiWireError {
         ExternalApiWireError::InternalError(e.to_string())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn zome_fn_call_stats_are_bucketed_by_latency() {
+        let mut stats = ZomeFnCallStats::new("zome".into(), "f".into());
+        assert_eq!(stats.mean(), None);
+        stats.record(Duration::from_micros(500), false);
+        stats.record(Duration::from_millis(1), false);
+        stats.record(Duration::from_millis(20), true);
+        stats.record(Duration::from_secs(10), false);
+        assert_eq!(stats.calls, 4);
+        assert_eq!(stats.errors, 1);
+        assert_eq!(stats.max_micros, 10_000_000);
+        assert_eq!(stats.latency_buckets, vec![2, 0, 0, 1, 0, 0, 0, 0, 1]);
+        assert_eq!(
+            stats.mean(),
+            Some(Duration::from_micros(
+                (500 + 1_000 + 20_000 + 10_000_000) / 4
+            ))
+        );
+    }
+}
@@ -125,8 +125,15 @@ pub struct InstalledAppInfo {
     pub installed_app_id: InstalledAppId,
     /// Info about the Cells installed in this app
     pub cell_data: Vec<InstalledCell>,
+    /// Info about the Cells cloned at runtime in this app.
+    /// Each has the cell nick of the slot it was cloned from.
+    #[serde(default)]
+    pub clone_cells: Vec<InstalledCell>,
     /// Is this app currently active?
     pub active: bool,
+    /// Why this app was disabled, if it was disabled with a reason
+    #[serde(default)]
+    pub disabled_reason: Option<String>,
 }
 
 impl InstalledAppInfo {
@@ -136,10 +143,16 @@ impl InstalledAppInfo {
             .provisioned_cells()
             .map(|(nick, id)| InstalledCell::new(id.clone(), nick.clone()))
             .collect();
+        let clone_cells = app
+            .cloned_cells_by_slot()
+            .map(|(nick, id)| InstalledCell::new(id.clone(), nick.clone()))
+            .collect();
         Self {
             installed_app_id,
             cell_data,
+            clone_cells,
             active,
+            disabled_reason: None,
         }
     }
 }
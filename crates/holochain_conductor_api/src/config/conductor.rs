@@ -75,6 +75,24 @@ pub struct ConductorConfig {
 
     /// Config options for the network module. Optional.
     pub network: Option<holochain_p2p::kitsune_p2p::KitsuneP2pConfig>,
+
+    /// How long in milliseconds each cell's validation receipt workflow waits
+    /// before running again when it still has receipts to send.
+    /// This stops a large backlog of receipts from starving the other workflows.
+    /// If omitted, a short default delay is used.
+    #[serde(default)]
+    pub validation_receipt_retrigger_delay_ms: Option<u64>,
+
+    /// The most validation receipts sent to the same author in one message.
+    /// If omitted, a default batch size is used.
+    #[serde(default)]
+    pub validation_receipt_max_batch_size: Option<usize>,
+
+    /// How long in milliseconds a validation receipt can wait for more
+    /// receipts to the same author before a partial batch is sent.
+    /// If omitted, a short default delay is used.
+    #[serde(default)]
+    pub validation_receipt_max_batch_delay_ms: Option<u64>,
     //
     //
     // /// Which signals to emit
@@ -150,6 +168,9 @@ pub mod tests {
                 keystore_path: None,
                 admin_interfaces: None,
                 use_dangerous_test_keystore: false,
+                validation_receipt_retrigger_delay_ms: None,
+                validation_receipt_max_batch_size: None,
+                validation_receipt_max_batch_delay_ms: None,
             }
         );
     }
@@ -199,6 +220,10 @@ pub mod tests {
         proxy_keepalive_ms: 42
         proxy_to_expire_ms: 42
       network_type: quic_bootstrap
+
+    validation_receipt_retrigger_delay_ms: 42
+    validation_receipt_max_batch_size: 42
+    validation_receipt_max_batch_delay_ms: 42
     "#;
         let result: ConductorConfigResult<ConductorConfig> = config_from_yaml(yaml);
         use holochain_p2p::kitsune_p2p::*;
@@ -245,6 +270,9 @@ pub mod tests {
                     driver: InterfaceDriver::Websocket { port: 1234 }
                 }]),
                 network: Some(network_config),
+                validation_receipt_retrigger_delay_ms: Some(42),
+                validation_receipt_max_batch_size: Some(42),
+                validation_receipt_max_batch_delay_ms: Some(42),
             }
         );
     }
@@ -276,6 +304,9 @@ pub mod tests {
                 keystore_path: Some(PathBuf::from("/path/to/keystore").into()),
                 admin_interfaces: None,
                 use_dangerous_test_keystore: true,
+                validation_receipt_retrigger_delay_ms: None,
+                validation_receipt_max_batch_size: None,
+                validation_receipt_max_batch_delay_ms: None,
             }
         );
     }
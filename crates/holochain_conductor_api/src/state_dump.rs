@@ -1,6 +1,10 @@
 use holo_hash::AgentPubKey;
+use holo_hash::DhtOpHash;
 use holo_hash::DnaHash;
+use holochain_state::dht_op_integration::IntegratedDhtOpsValue;
+use holochain_state::dht_op_integration::IntegrationLimboValue;
 use holochain_state::source_chain::SourceChainJsonDump;
+use holochain_state::validation_db::ValidationLimboValue;
 use serde::Deserialize;
 use serde::Serialize;
 
@@ -9,6 +13,9 @@ pub struct JsonDump {
     pub peer_dump: P2pStateDump,
     pub source_chain_dump: SourceChainJsonDump,
     pub integration_dump: IntegrationStateDump,
+    /// Only present if the queues were asked for.
+    #[serde(default)]
+    pub queue_dump: Option<QueueStateDump>,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -32,6 +39,19 @@ pub struct IntegrationStateDump {
     pub integrated: usize,
 }
 
+#[derive(Serialize, Deserialize, Debug, Clone)]
+/// The ops in each of the queues counted by [`IntegrationStateDump`].
+/// Each queue is capped at a maximum number of ops
+/// so these can be shorter than the counts.
+pub struct QueueStateDump {
+    /// Ops in validation limbo.
+    pub validation_limbo: Vec<(DhtOpHash, ValidationLimboValue)>,
+    /// Ops waiting to be integrated.
+    pub integration_limbo: Vec<(DhtOpHash, IntegrationLimboValue)>,
+    /// Ops that are integrated.
+    pub integrated: Vec<(DhtOpHash, IntegratedDhtOpsValue)>,
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone)]
 /// State dump of all the peer info
 pub struct P2pStateDump {
@@ -71,6 +91,15 @@ impl std::fmt::Display for JsonDump {
             "Ops: Limbo (validation: {} integration: {}) Integrated: {}",
             int.validation_limbo, int.integration_limbo, int.integrated
         )?;
+        if let Some(q) = &self.queue_dump {
+            writeln!(
+                f,
+                "Ops dumped: Limbo (validation: {} integration: {}) Integrated: {}",
+                q.validation_limbo.len(),
+                q.integration_limbo.len(),
+                q.integrated.len()
+            )?;
+        }
         writeln!(
             f,
             "Elements authored: {}, Ops published: {}",
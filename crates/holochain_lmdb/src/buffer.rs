@@ -1,7 +1,9 @@
 #![allow(missing_docs)]
 use crate::error::DatabaseError;
 use crate::error::DatabaseResult;
+use crate::key::BufVal;
 use crate::transaction::Writer;
+use holochain_serialized_bytes::SerializedBytesError;
 
 mod cas;
 pub mod iter;
@@ -15,6 +17,7 @@ pub use kv::KvBufUsed;
 pub use kv::KvIntBufFresh;
 pub use kv::KvIntBufUsed;
 pub use kv::KvIntStore;
+pub use kv::KvOpKind;
 pub use kv::KvStore;
 pub use kv::KvStoreT;
 pub use kvv::KvvBufUsed;
@@ -28,6 +31,43 @@ pub(super) fn check_empty_key<K: AsRef<[u8]>>(k: &K) -> DatabaseResult<()> {
     }
 }
 
+/// Leading byte of a value stored with a version.
+/// MessagePack never uses this byte so values stored
+/// without a version can't be mistaken for versioned ones.
+const VERSIONED_VALUE_MARKER: u8 = 0xc1;
+
+/// Encode a value, prefixing it with the version if there is one.
+/// Values without a version are stored exactly as they always have been.
+pub(crate) fn encode_value<V: BufVal>(
+    v: &V,
+    version: Option<u8>,
+) -> Result<Vec<u8>, SerializedBytesError> {
+    let encoded = holochain_serialized_bytes::encode(v)?;
+    Ok(match version {
+        Some(version) => {
+            let mut buf = Vec::with_capacity(encoded.len() + 2);
+            buf.push(VERSIONED_VALUE_MARKER);
+            buf.push(version);
+            buf.extend(encoded);
+            buf
+        }
+        None => encoded,
+    })
+}
+
+/// Split the version from a stored value if it has one
+pub(crate) fn split_value_version(bytes: &[u8]) -> (Option<u8>, &[u8]) {
+    match bytes {
+        [VERSIONED_VALUE_MARKER, version, rest @ ..] => (Some(*version), rest),
+        _ => (None, bytes),
+    }
+}
+
+/// Decode a stored value with or without a version
+pub(crate) fn decode_value<V: BufVal>(bytes: &[u8]) -> Result<V, SerializedBytesError> {
+    holochain_serialized_bytes::decode(split_value_version(bytes).1)
+}
+
 /// General trait for transactional stores, exposing only the method which
 /// adds changes to the write transaction. This generalization is not really used,
 /// but could be used in Workspaces i.e. iterating over a Vec<dyn BufferedStore>
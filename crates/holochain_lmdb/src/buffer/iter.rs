@@ -1,15 +1,34 @@
+use crate::buffer::decode_value;
 use crate::buffer::kv::KvOp;
+use crate::buffer::kv::KvOpKind;
 use crate::error::DatabaseError;
 use crate::prelude::*;
 use fallible_iterator::DoubleEndedFallibleIterator;
 use fallible_iterator::FallibleIterator;
 use rkv::StoreError;
+use std::cmp::Ordering;
 use std::collections::BTreeMap;
 use tracing::*;
 
 type IterItem<'env, V> = (&'env [u8], V);
 type IterError = DatabaseError;
 
+/// Orders keys from their bytes, see [BufKey::cmp_key_bytes].
+pub type KeyOrder = fn(&[u8], &[u8]) -> Ordering;
+
+/// The ops in the scratch at or after this key in key order.
+/// The scratch is sorted by bytes which isn't always key order
+/// so this can't be a range.
+fn scratch_from<'a, V>(
+    scratch: &'a BTreeMap<Vec<u8>, KvOp<V>>,
+    key: Vec<u8>,
+    order: KeyOrder,
+) -> impl DoubleEndedIterator<Item = (&'a Vec<u8>, &'a KvOp<V>)> + 'a {
+    scratch
+        .iter()
+        .filter(move |(k, _)| order(k, &key) != Ordering::Less)
+}
+
 /// Returns all the elements on this key
 pub struct SingleIterKeyMatch<'env, 'a, V>
 where
@@ -43,6 +62,10 @@ where
     }
 }
 
+/// Returns all the elements with keys starting with a prefix.
+/// The prefix can be any length, not just a whole key.
+pub type SingleIterPrefix<'env, 'a, V> = SingleIterKeyMatch<'env, 'a, V>;
+
 /// Match a key on another partial key
 pub fn partial_key_match(partial_key: &[u8], key: &[u8]) -> bool {
     let len = partial_key.len();
@@ -68,8 +91,9 @@ where
         scratch: &'a BTreeMap<Vec<u8>, KvOp<V>>,
         iter: SingleIterRaw<'env, V>,
         key: Vec<u8>,
+        order: KeyOrder,
     ) -> Self {
-        let iter = SingleIter::new(&scratch, scratch.range(key..), iter);
+        let iter = SingleIter::new(&scratch, scratch_from(scratch, key, order), iter, order);
         Self { iter }
     }
 }
@@ -94,20 +118,40 @@ where
     iter: Box<
         dyn DoubleEndedFallibleIterator<Item = IterItem<'env, V>, Error = DatabaseError> + 'env,
     >,
+    strict: bool,
 }
 
 impl<'env, 'a: 'env, V> DrainIter<'env, 'a, V>
 where
     V: BufVal,
 {
+    /// In strict mode draining a key with a pending put is an error
+    /// instead of replacing the put with a delete.
     pub fn new(
         scratch: &'a mut BTreeMap<Vec<u8>, KvOp<V>>,
         iter: impl DoubleEndedFallibleIterator<Item = IterItem<'env, V>, Error = DatabaseError> + 'env,
+        strict: bool,
     ) -> Self {
         Self {
             scratch,
             iter: Box::new(iter),
+            strict,
+        }
+    }
+
+    /// Mark the key as deleted in the scratch.
+    fn drain(&mut self, k: &[u8]) -> Result<(), DatabaseError> {
+        if self.strict {
+            if let Some(KvOp::Put(_)) = self.scratch.get(k) {
+                return Err(DatabaseError::ConflictingScratchOp {
+                    key: k.to_vec(),
+                    pending: KvOpKind::Put,
+                    new: KvOpKind::Delete,
+                });
+            }
         }
+        self.scratch.insert(k.to_vec(), KvOp::Delete);
+        Ok(())
     }
 }
 
@@ -118,10 +162,13 @@ where
     type Error = DatabaseError;
     type Item = V;
     fn next(&mut self) -> Result<Option<Self::Item>, Self::Error> {
-        Ok(self.iter.next()?.map(|(k, v)| {
-            self.scratch.insert(k.to_vec(), KvOp::Delete);
-            v
-        }))
+        match self.iter.next()? {
+            Some((k, v)) => {
+                self.drain(k)?;
+                Ok(Some(v))
+            }
+            None => Ok(None),
+        }
     }
 }
 
@@ -130,12 +177,109 @@ where
     V: BufVal,
 {
     fn next_back(&mut self) -> Result<Option<Self::Item>, Self::Error> {
-        Ok(self.iter.next_back()?.map(|(k, v)| {
-            self.scratch.insert(k.to_vec(), KvOp::Delete);
-            v
-        }))
+        match self.iter.next_back()? {
+            Some((k, v)) => {
+                self.drain(k)?;
+                Ok(Some(v))
+            }
+            None => Ok(None),
+        }
     }
 }
+
+/// Draining iterator that starts at a key and moves forward.
+/// Unlike [DrainIter] this takes the scratch into account:
+/// pending puts are drained along with the db values and
+/// pending deletes are skipped.
+/// Every drained key is marked as deleted in the scratch so
+/// a later put of the same key will overwrite the delete.
+pub struct DrainIterFrom<'env, 'a: 'env, V>
+where
+    V: BufVal,
+{
+    scratch: &'a mut BTreeMap<Vec<u8>, KvOp<V>>,
+    scratch_puts: std::collections::VecDeque<(Vec<u8>, V)>,
+    iter: SingleIterRaw<'env, V>,
+    current: Option<IterItem<'env, V>>,
+    order: KeyOrder,
+}
+
+impl<'env, 'a: 'env, V> DrainIterFrom<'env, 'a, V>
+where
+    V: BufVal,
+{
+    pub fn new(
+        scratch: &'a mut BTreeMap<Vec<u8>, KvOp<V>>,
+        iter: SingleIterRaw<'env, V>,
+        key: Vec<u8>,
+        order: KeyOrder,
+    ) -> Self {
+        // Take a snapshot of the pending puts because the
+        // scratch is modified as we drain.
+        let mut scratch_puts: Vec<_> = scratch_from(scratch, key, order)
+            .filter_map(|(k, v)| match v {
+                KvOp::Put(v) => Some((k.clone(), *v.clone())),
+                KvOp::Delete => None,
+            })
+            .collect();
+        scratch_puts.sort_by(|(a, _), (b, _)| order(a, b));
+        Self {
+            scratch,
+            scratch_puts: scratch_puts.into(),
+            iter,
+            current: None,
+            order,
+        }
+    }
+
+    /// Get the next db value that isn't overridden by the scratch
+    fn next_db(&mut self) -> Result<Option<IterItem<'env, V>>, IterError> {
+        if let Some(c) = self.current.take() {
+            return Ok(Some(c));
+        }
+        while let Some((k, v)) = self.iter.next()? {
+            // Any key in the scratch is either deleted or
+            // will be returned from the scratch puts
+            if !self.scratch.contains_key(k) {
+                return Ok(Some((k, v)));
+            }
+        }
+        Ok(None)
+    }
+}
+
+impl<'env, 'a, V> FallibleIterator for DrainIterFrom<'env, 'a, V>
+where
+    V: BufVal,
+{
+    type Error = DatabaseError;
+    type Item = V;
+    fn next(&mut self) -> Result<Option<Self::Item>, Self::Error> {
+        let db = self.next_db()?;
+        // Return whichever key is first.
+        // The keys can't be equal because db keys that
+        // are in the scratch are skipped.
+        let scratch_first = match (&db, self.scratch_puts.front()) {
+            (Some((k, _)), Some((sk, _))) => (self.order)(sk, k) == Ordering::Less,
+            (None, Some(_)) => true,
+            (_, None) => false,
+        };
+        let (key, v) = if scratch_first {
+            self.current = db;
+            self.scratch_puts
+                .pop_front()
+                .expect("Checked there is a scratch put")
+        } else {
+            match db {
+                Some((k, v)) => (k.to_vec(), v),
+                None => return Ok(None),
+            }
+        };
+        self.scratch.insert(key, KvOp::Delete);
+        Ok(Some(v))
+    }
+}
+
 /// Iterate taking into account the scratch
 pub struct SingleIter<'env, 'a, V>
 where
@@ -147,6 +291,7 @@ where
     >,
     current: Option<IterItem<'env, V>>,
     scratch_current: Option<IterItem<'a, V>>,
+    order: KeyOrder,
 }
 
 impl<'env, 'a: 'env, V> SingleIter<'env, 'a, V>
@@ -157,8 +302,14 @@ where
         scratch: &'a BTreeMap<Vec<u8>, KvOp<V>>,
         scratch_iter: impl DoubleEndedIterator<Item = (&'a Vec<u8>, &'a KvOp<V>)> + 'a,
         iter: SingleIterRaw<'env, V>,
+        order: KeyOrder,
     ) -> Self {
+        // The scratch is sorted by bytes so put it in key order
+        // to merge with the db. This is cheap when they're the same.
+        let mut scratch_iter: Vec<_> = scratch_iter.collect();
+        scratch_iter.sort_by(|(a, _), (b, _)| order(a, b));
         let scratch_iter = scratch_iter
+            .into_iter()
             // TODO: These inspects should be eventally removed
             // but I'm tempted to included them for a while
             // incase any bugs are found in the iterator.
@@ -208,18 +359,21 @@ where
             iter: Box::new(iter),
             current: None,
             scratch_current: None,
+            order,
         }
     }
 
+    /// `first` is the ordering of a scratch key that comes
+    /// before a db key in the direction we are iterating.
     fn check_scratch(
         &mut self,
         scratch_current: Option<IterItem<'a, V>>,
         db: IterItem<'env, V>,
-        compare: fn(scratch: &[u8], db: &[u8]) -> bool,
+        first: Ordering,
     ) -> Option<IterItem<'env, V>> {
         match scratch_current {
             // Return scratch value and keep db value
-            Some(scratch) if compare(scratch.0, db.0) => {
+            Some(scratch) if (self.order)(scratch.0, db.0) == first => {
                 trace!(msg = "r scratch key first", k = %String::from_utf8_lossy(&scratch.0[..]), v = ?scratch.1);
                 self.current = Some(db);
                 Some(scratch)
@@ -242,10 +396,10 @@ where
         &mut self,
         current: Option<IterItem<'env, V>>,
         scratch_current: Option<IterItem<'a, V>>,
-        compare: fn(scratch: &[u8], db: &[u8]) -> bool,
+        first: Ordering,
     ) -> Result<Option<IterItem<'env, V>>, IterError> {
         let r = match current {
-            Some(db) => self.check_scratch(scratch_current, db, compare),
+            Some(db) => self.check_scratch(scratch_current, db, first),
             None => {
                 if let Some((k, v)) = &scratch_current {
                     trace!(msg = "r scratch no db", k = %String::from_utf8_lossy(k), ?v);
@@ -275,7 +429,7 @@ where
             Some(c) => Some(c),
             None => self.scratch_iter.next(),
         };
-        self.next_inner(current, scratch_current, |scratch, db| scratch < db)
+        self.next_inner(current, scratch_current, Ordering::Less)
     }
 }
 
@@ -292,7 +446,7 @@ where
             Some(c) => Some(c),
             None => self.scratch_iter.next_back(),
         };
-        self.next_inner(current, scratch_current, |scratch, db| scratch > db)
+        self.next_inner(current, scratch_current, Ordering::Greater)
     }
 }
 
@@ -301,6 +455,7 @@ pub struct SingleIterRaw<'txn, V> {
     rev: rkv::store::single::Iter<'txn>,
     key: Option<&'txn [u8]>,
     key_back: Option<&'txn [u8]>,
+    order: KeyOrder,
     __type: std::marker::PhantomData<V>,
 }
 
@@ -310,12 +465,18 @@ impl<'txn, V> SingleIterRaw<'txn, V>
 where
     V: BufVal,
 {
-    pub fn new(iter: rkv::store::single::Iter<'txn>, rev: rkv::store::single::Iter<'txn>) -> Self {
+    /// The `order` must be the order the store keeps its keys in.
+    pub fn new(
+        iter: rkv::store::single::Iter<'txn>,
+        rev: rkv::store::single::Iter<'txn>,
+        order: KeyOrder,
+    ) -> Self {
         Self {
             iter,
             rev,
             key: None,
             key_back: None,
+            order,
             __type: std::marker::PhantomData,
         }
     }
@@ -326,7 +487,7 @@ where
         match item {
             Some(Ok((k, Some(rkv::Value::Blob(buf))))) => Ok(Some((
                 k,
-                holochain_serialized_bytes::decode(buf).expect(
+                decode_value(buf).expect(
                     "Failed to deserialize data from database. Database might be corrupted",
                 ),
             ))),
@@ -355,7 +516,7 @@ where
         if let Ok(Some((k, _))) = r {
             self.key = Some(k);
             match self.key_back {
-                Some(k_back) if k >= k_back => return Ok(None),
+                Some(k_back) if (self.order)(k, k_back) != Ordering::Less => return Ok(None),
                 _ => {}
             }
         }
@@ -372,7 +533,7 @@ where
         if let Ok(Some((k_back, _))) = r {
             self.key_back = Some(k_back);
             match self.key {
-                Some(key) if k_back <= key => return Ok(None),
+                Some(key) if (self.order)(k_back, key) != Ordering::Greater => return Ok(None),
                 _ => {}
             }
         }
@@ -1,11 +1,16 @@
 use super::KvIntStore;
 use crate::buffer::check_empty_key;
+use crate::buffer::encode_value;
+use crate::buffer::iter::partial_key_match;
 use crate::buffer::iter::DrainIter;
+use crate::buffer::iter::DrainIterFrom;
 use crate::buffer::iter::SingleIter;
 use crate::buffer::iter::SingleIterFrom;
 use crate::buffer::iter::SingleIterKeyMatch;
+use crate::buffer::iter::SingleIterPrefix;
 use crate::buffer::kv::generic::KvStoreT;
 use crate::buffer::kv::KvStore;
+use crate::buffer::split_value_version;
 use crate::buffer::BufferedStore;
 use crate::env::EnvironmentRead;
 use crate::error::DatabaseError;
@@ -38,6 +43,25 @@ pub enum KvOp<V> {
     Delete,
 }
 
+/// The kind of a [KvOp] without the value,
+/// useful for logging the scratch space
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum KvOpKind {
+    /// add or replace the value at a key
+    Put,
+    /// remove the value at a key
+    Delete,
+}
+
+impl<V> From<&KvOp<V>> for KvOpKind {
+    fn from(op: &KvOp<V>) -> Self {
+        match op {
+            KvOp::Put(_) => KvOpKind::Put,
+            KvOp::Delete => KvOpKind::Delete,
+        }
+    }
+}
+
 pub struct Used<K, V, Store>
 where
     K: BufKey,
@@ -46,6 +70,8 @@ where
 {
     store: Store,
     scratch: Scratch<V>,
+    value_version: Option<u8>,
+    strict: bool,
     __phantom: std::marker::PhantomData<K>,
 }
 
@@ -59,6 +85,8 @@ where
         Self {
             store: KvIntStore::new(db),
             scratch: BTreeMap::new(),
+            value_version: None,
+            strict: false,
             __phantom: std::marker::PhantomData,
         }
     }
@@ -81,6 +109,8 @@ where
         Self {
             store: KvStore::new(db),
             scratch: BTreeMap::new(),
+            value_version: None,
+            strict: false,
             __phantom: std::marker::PhantomData,
         }
     }
@@ -91,6 +121,102 @@ where
         self.scratch.clear();
         Ok(self.store.delete_all(writer)?)
     }
+
+    /// Count the entries that partially match this key,
+    /// taking the scratch space into account.
+    /// Like [Self::iter_all_key_matches] but values are never deserialized.
+    pub fn count_key_matches<R: Readable>(&self, r: &R, k: K) -> DatabaseResult<usize> {
+        check_empty_key(&k)?;
+        use KvOp::*;
+        let key = k.as_ref().to_vec();
+        let mut count = 0;
+        for item in self.store.db().iter_from(r, &key)? {
+            let (db_key, _) = item?;
+            if !partial_key_match(&key[..], db_key) {
+                break;
+            }
+            count += 1;
+        }
+        let in_range = self
+            .scratch
+            .range(key.clone()..)
+            .take_while(|(k, _)| partial_key_match(&key[..], k));
+        for (k, op) in in_range {
+            let persisted = self
+                .store
+                .get_bytes(r, &K::from_key_bytes_or_friendly_panic(k))?
+                .is_some();
+            match (op, persisted) {
+                // New key
+                (Put(_), false) => count += 1,
+                // Removing an existing key
+                (Delete, true) => count -= 1,
+                // Overwriting an existing key or deleting a missing key
+                _ => {}
+            }
+        }
+        Ok(count)
+    }
+
+    /// Count the keys at or after this key, taking the scratch space into account.
+    /// This is the number of items [Self::iter_from] returns,
+    /// so the number of keys before this key is [Self::count] minus this.
+    /// Values are never deserialized.
+    pub fn count_from<R: Readable>(&self, r: &R, k: K) -> DatabaseResult<usize> {
+        check_empty_key(&k)?;
+        use KvOp::*;
+        let key = k.as_ref().to_vec();
+        let mut scratch = self.scratch.range(key.clone()..).peekable();
+        let mut count = 0;
+        for item in self.store.db().iter_from(r, &key)? {
+            let (db_key, _) = item?;
+            // Puts before this persisted key are new keys
+            while let Some(&(k, op)) = scratch.peek() {
+                if &k[..] >= db_key {
+                    break;
+                }
+                if let Put(_) = op {
+                    count += 1;
+                }
+                scratch.next();
+            }
+            match scratch.peek() {
+                // Overwriting an existing key or removing it
+                Some(&(k, op)) if &k[..] == db_key => {
+                    if let Put(_) = op {
+                        count += 1;
+                    }
+                    scratch.next();
+                }
+                _ => count += 1,
+            }
+        }
+        // Puts after the last persisted key are new keys
+        count += scratch.filter(|(_, op)| matches!(op, Put(_))).count();
+        Ok(count)
+    }
+
+    /// Iterator over all keys starting with these prefix bytes,
+    /// taking the scratch space into account.
+    /// Unlike [Self::iter_all_key_matches] the prefix can be part of
+    /// a key, e.g. the first 32 bytes of a composite key.
+    pub fn iter_prefix<'r, R: Readable>(
+        &'r self,
+        r: &'r R,
+        prefix: &[u8],
+    ) -> DatabaseResult<SingleIterPrefix<'r, 'r, V>> {
+        check_empty_key(&prefix)?;
+        let prefix = prefix.to_vec();
+        Ok(SingleIterPrefix::new(
+            SingleIterFrom::new(
+                &self.scratch,
+                self.store.iter_raw_from_bytes(r, &prefix)?,
+                prefix.clone(),
+                K::cmp_key_bytes,
+            ),
+            prefix,
+        ))
+    }
 }
 
 impl<'env, K, V, Store> Used<K, V, Store>
@@ -103,6 +229,67 @@ where
         &self.store
     }
 
+    /// Store values flushed from this buffer with a version
+    /// so they can be migrated if the value type changes.
+    /// Values without a version are still read.
+    /// Reading versioned values is always supported but
+    /// older code can't read them so only turn this on
+    /// once every reader of this database can.
+    pub fn with_value_version(mut self, version: u8) -> Self {
+        self.value_version = Some(version);
+        self
+    }
+
+    /// Return an error instead of replacing a pending op when a Put
+    /// is recorded for a key with a pending Delete or the other way round.
+    /// Useful when a workflow composes ops from several steps
+    /// that shouldn't touch the same keys.
+    /// Replacing a Put with a Put or a Delete with a Delete is still allowed.
+    pub fn strict(mut self) -> Self {
+        self.strict = true;
+        self
+    }
+
+    /// In strict mode check that recording this op for the key
+    /// doesn't replace a pending op of the other kind.
+    fn check_conflict(&self, key: &[u8], new: KvOpKind) -> DatabaseResult<()> {
+        if !self.strict {
+            return Ok(());
+        }
+        match self.scratch.get(key).map(KvOpKind::from) {
+            Some(pending) if pending != new => Err(DatabaseError::ConflictingScratchOp {
+                key: key.to_vec(),
+                pending,
+                new,
+            }),
+            _ => Ok(()),
+        }
+    }
+
+    /// Get a value along with the version it was stored with.
+    /// The version is `None` for values stored without one.
+    /// Values in the scratch space have this buffer's version.
+    pub fn get_versioned<R: Readable>(
+        &self,
+        r: &R,
+        k: &K,
+    ) -> DatabaseResult<Option<(Option<u8>, V)>> {
+        check_empty_key(k)?;
+        use KvOp::*;
+        let val = match self.scratch.get(k.as_ref()) {
+            Some(Put(scratch_val)) => Some((self.value_version, *scratch_val.clone())),
+            Some(Delete) => None,
+            None => match self.store.get_bytes(r, k)? {
+                Some(bytes) => {
+                    let (version, bytes) = split_value_version(bytes);
+                    Some((version, holochain_serialized_bytes::decode(bytes)?))
+                }
+                None => None,
+            },
+        };
+        Ok(val)
+    }
+
     /// See if a value exists, avoiding deserialization
     pub fn contains<R: Readable>(&self, r: &R, k: &K) -> DatabaseResult<bool> {
         check_empty_key(k)?;
@@ -110,11 +297,33 @@ where
         let exists = match self.scratch.get(k.as_ref()) {
             Some(Put(_)) => true,
             Some(Delete) => false,
-            None => self.store.get(r, k)?.is_some(),
+            None => self.store.get_bytes(r, k)?.is_some(),
         };
         Ok(exists)
     }
 
+    /// See if all the values exist, avoiding deserialization.
+    /// Stops at the first missing value.
+    pub fn contains_all<R: Readable>(&self, r: &R, keys: &[K]) -> DatabaseResult<bool> {
+        for k in keys {
+            if !self.contains(r, k)? {
+                return Ok(false);
+            }
+        }
+        Ok(true)
+    }
+
+    /// See if any of the values exist, avoiding deserialization.
+    /// Stops at the first value found.
+    pub fn contains_any<R: Readable>(&self, r: &R, keys: &[K]) -> DatabaseResult<bool> {
+        for k in keys {
+            if self.contains(r, k)? {
+                return Ok(true);
+            }
+        }
+        Ok(false)
+    }
+
     /// Check if a value is in the scratch space
     pub fn contains_in_scratch(&self, k: &K) -> DatabaseResult<bool> {
         check_empty_key(k)?;
@@ -143,15 +352,93 @@ where
     /// Update the scratch space to record a Put operation for the KV
     pub fn put(&mut self, k: K, v: V) -> DatabaseResult<()> {
         check_empty_key(&k)?;
-        self.scratch
-            .insert(k.to_key_bytes(), KvOp::Put(Box::new(v)));
+        let k = k.to_key_bytes();
+        self.check_conflict(&k, KvOpKind::Put)?;
+        self.scratch.insert(k, KvOp::Put(Box::new(v)));
         Ok(())
     }
 
     /// Update the scratch space to record a Delete operation for the KV
     pub fn delete(&mut self, k: K) -> DatabaseResult<()> {
         check_empty_key(&k)?;
-        self.scratch.insert(k.to_key_bytes(), KvOp::Delete);
+        let k = k.to_key_bytes();
+        self.check_conflict(&k, KvOpKind::Delete)?;
+        self.scratch.insert(k, KvOp::Delete);
+        Ok(())
+    }
+
+    /// Get the current value, taking the scratch space into account,
+    /// and pass it to the closure.
+    /// Records a Put of the value it returns or a Delete if it returns `None`.
+    pub fn modify<R, F>(&mut self, r: &R, k: K, f: F) -> DatabaseResult<()>
+    where
+        R: Readable,
+        F: FnOnce(Option<V>) -> Option<V>,
+    {
+        let current = self.get(r, &k)?;
+        match f(current) {
+            Some(v) => self.put(k, v),
+            None => self.delete(k),
+        }
+    }
+
+    /// Get the current value, taking the scratch space into account,
+    /// or record a Put of the value built by the closure and return it.
+    /// The closure is only called if there is no value for the key.
+    pub fn get_or_insert_with<R, F>(&mut self, r: &R, k: K, f: F) -> DatabaseResult<V>
+    where
+        R: Readable,
+        F: FnOnce() -> V,
+    {
+        if let Some(v) = self.get(r, &k)? {
+            return Ok(v);
+        }
+        let v = f();
+        self.put(k, v.clone())?;
+        Ok(v)
+    }
+
+    /// Update the scratch space to record a Put operation for each KV.
+    /// If any key is empty nothing is added and the index of
+    /// the first empty key is returned in the error.
+    /// In [strict](Self::strict) mode nothing is added if any key conflicts.
+    pub fn put_all<I: IntoIterator<Item = (K, V)>>(&mut self, kvs: I) -> DatabaseResult<()> {
+        let ops = kvs
+            .into_iter()
+            .enumerate()
+            .map(|(i, (k, v))| {
+                if k.as_ref().is_empty() {
+                    Err(DatabaseError::EmptyKeyInBatch(i))
+                } else {
+                    let k = k.to_key_bytes();
+                    self.check_conflict(&k, KvOpKind::Put)?;
+                    Ok((k, KvOp::Put(Box::new(v))))
+                }
+            })
+            .collect::<DatabaseResult<Vec<_>>>()?;
+        self.scratch.extend(ops);
+        Ok(())
+    }
+
+    /// Update the scratch space to record a Delete operation for each key.
+    /// If any key is empty nothing is deleted and the index of
+    /// the first empty key is returned in the error.
+    /// In [strict](Self::strict) mode nothing is deleted if any key conflicts.
+    pub fn delete_keys<I: IntoIterator<Item = K>>(&mut self, keys: I) -> DatabaseResult<()> {
+        let ops = keys
+            .into_iter()
+            .enumerate()
+            .map(|(i, k)| {
+                if k.as_ref().is_empty() {
+                    Err(DatabaseError::EmptyKeyInBatch(i))
+                } else {
+                    let k = k.to_key_bytes();
+                    self.check_conflict(&k, KvOpKind::Delete)?;
+                    Ok((k, KvOp::Delete))
+                }
+            })
+            .collect::<DatabaseResult<Vec<_>>>()?;
+        self.scratch.extend(ops);
         Ok(())
     }
 
@@ -165,10 +452,41 @@ where
         Ok(())
     }
 
+    /// Count the entries, taking the scratch space into account.
+    /// Values are never deserialized.
+    pub fn count<R: Readable>(&self, r: &R) -> DatabaseResult<usize> {
+        use KvOp::*;
+        let mut count = self.store.count(r)?;
+        for (k, op) in self.scratch.iter() {
+            let persisted = self
+                .store
+                .get_bytes(r, &K::from_key_bytes_or_friendly_panic(k))?
+                .is_some();
+            match (op, persisted) {
+                // New key
+                (Put(_), false) => count += 1,
+                // Removing an existing key
+                (Delete, true) => count -= 1,
+                // Overwriting an existing key or deleting a missing key
+                _ => {}
+            }
+        }
+        Ok(count)
+    }
+
     pub fn is_scratch_fresh(&self) -> bool {
         self.scratch.is_empty()
     }
 
+    /// Summary of the pending operations in the scratch space.
+    /// Only the keys and kind of operation are cloned, not the values.
+    pub fn pending_ops(&self) -> Vec<(Vec<u8>, KvOpKind)> {
+        self.scratch
+            .iter()
+            .map(|(k, op)| (k.clone(), op.into()))
+            .collect()
+    }
+
     #[cfg(test)]
     pub(crate) fn scratch(&self) -> &Scratch<V> {
         &self.scratch
@@ -180,15 +498,21 @@ where
             &self.scratch,
             self.scratch.iter(),
             self.store.iter(r)?,
+            K::cmp_key_bytes,
         ))
     }
 
-    /// Iterator that tracks elements so they can be deleted
+    /// Iterator that tracks elements so they can be deleted.
+    /// In [strict](Self::strict) mode draining a key with a pending put is an error.
     pub fn drain_iter<'a, R: Readable>(
         &mut self,
         r: &'a R,
     ) -> DatabaseResult<DrainIter<'a, '_, V>> {
-        Ok(DrainIter::new(&mut self.scratch, self.store.iter(r)?))
+        Ok(DrainIter::new(
+            &mut self.scratch,
+            self.store.iter(r)?,
+            self.strict,
+        ))
     }
 
     /// Iterator that tracks elements so they can be deleted.
@@ -208,6 +532,26 @@ where
         Ok(DrainIter::new(
             &mut self.scratch,
             self.store.iter(r)?.filter(filter),
+            self.strict,
+        ))
+    }
+
+    /// Iterator that drains forward from a key onwards.
+    /// Tracks elements so they can be deleted on flush like [Self::drain_iter]
+    /// but also drains any pending puts in the scratch and skips pending deletes.
+    pub fn drain_iter_from<'a, R: Readable>(
+        &mut self,
+        r: &'a R,
+        k: K,
+    ) -> DatabaseResult<DrainIterFrom<'a, '_, V>> {
+        check_empty_key(&k)?;
+
+        let key = k.as_ref().to_vec();
+        Ok(DrainIterFrom::new(
+            &mut self.scratch,
+            self.store.iter_from(r, k)?,
+            key,
+            K::cmp_key_bytes,
         ))
     }
 
@@ -220,7 +564,12 @@ where
         check_empty_key(&k)?;
         let key = k.as_ref().to_vec();
         Ok(SingleIterKeyMatch::new(
-            SingleIterFrom::new(&self.scratch, self.store.iter_from(r, k)?, key.clone()),
+            SingleIterFrom::new(
+                &self.scratch,
+                self.store.iter_from(r, k)?,
+                key.clone(),
+                K::cmp_key_bytes,
+            ),
             key,
         ))
     }
@@ -238,6 +587,7 @@ where
             &self.scratch,
             self.store.iter_from(r, k)?,
             key,
+            K::cmp_key_bytes,
         ))
     }
 
@@ -285,6 +635,40 @@ where
             inner: Used::new(db),
         }
     }
+
+    /// Count the entries that partially match this key
+    /// without deserializing any values
+    pub fn count_key_matches(&self, k: K) -> DatabaseResult<usize> {
+        fresh_reader!(self.env, |reader| self.inner.count_key_matches(&reader, k))
+    }
+
+    /// Count the keys at or after this key, taking the scratch space into account,
+    /// without deserializing any values
+    pub fn count_from(&self, k: K) -> DatabaseResult<usize> {
+        fresh_reader!(self.env, |reader| self.inner.count_from(&reader, k))
+    }
+
+    /// **Danger**: Permanently remove everything in this database
+    /// and discard the scratch space.
+    /// The database is cleared in its own write transaction
+    /// which is committed before this returns.
+    /// `env` must be the writable environment this buffer was created from,
+    /// otherwise nothing is removed and an error is returned.
+    /// Returns the number of persisted keys that were removed.
+    pub fn reset_danger(&mut self, env: &EnvironmentWrite) -> DatabaseResult<usize> {
+        if !self.env.same_env(env) {
+            return Err(DatabaseError::EnvironmentMismatch {
+                expected: self.env.path().clone(),
+                got: env.path().clone(),
+            });
+        }
+        let inner = &mut self.inner;
+        env.guard().with_commit(|writer| {
+            let removed = inner.store.count(&*writer)?;
+            inner.clear_all(writer)?;
+            Ok(removed)
+        })
+    }
 }
 
 impl<V> Fresh<IntKey, V, KvIntStore<V>>
@@ -310,6 +694,15 @@ where
         &self.env
     }
 
+    /// Run a closure with a single fresh reader so several
+    /// reads of the inner buffer see the same transaction
+    pub fn with_reader<T>(
+        &self,
+        f: impl FnOnce(&Reader) -> DatabaseResult<T>,
+    ) -> DatabaseResult<T> {
+        fresh_reader!(self.env, |reader| f(&reader))
+    }
+
     /// See if a value exists, avoiding deserialization
     pub fn contains(&self, k: &K) -> DatabaseResult<bool> {
         fresh_reader!(self.env, |reader| self.inner.contains(&reader, k))
@@ -320,44 +713,253 @@ where
     pub fn get(&self, k: &K) -> DatabaseResult<Option<V>> {
         fresh_reader!(self.env, |reader| self.inner.get(&reader, k))
     }
+
+    /// Get many values using a single reader.
+    /// The results are in the same order as the keys.
+    pub fn get_multi(&self, keys: &[K]) -> DatabaseResult<Vec<Option<V>>> {
+        fresh_reader!(self.env, |reader| keys
+            .iter()
+            .map(|k| self.inner.get(&reader, k))
+            .collect())
+    }
+
+    /// Stream every key and value in key order, taking the scratch space
+    /// into account, without collecting the whole database.
+    /// Items are read `chunk_size` at a time with a fresh reader for each
+    /// chunk so no read transaction is held open between chunks.
+    /// Changes persisted between chunks may or may not be seen.
+    pub fn iter_stream(
+        &self,
+        chunk_size: usize,
+    ) -> impl futures::stream::Stream<Item = DatabaseResult<(Vec<u8>, V)>> + '_ {
+        use futures::stream::StreamExt;
+        let chunk_size = chunk_size.max(1);
+        // The state is the key to read after and whether the end was reached
+        futures::stream::unfold((None::<Vec<u8>>, false), move |(after, done)| async move {
+            if done {
+                return None;
+            }
+            let chunk: Vec<(Vec<u8>, V)> = match self.read_chunk(after.as_deref(), chunk_size) {
+                Ok(chunk) => chunk,
+                Err(e) => return Some((vec![Err(e)], (None, true))),
+            };
+            if chunk.is_empty() {
+                return None;
+            }
+            let done = chunk.len() < chunk_size;
+            let after = chunk.last().map(|(k, _)| k.clone());
+            Some((chunk.into_iter().map(Ok).collect(), (after, done)))
+        })
+        .flat_map(futures::stream::iter)
+    }
+
+    /// Read up to `chunk_size` items after this key
+    /// or from the start if there is no key.
+    fn read_chunk(
+        &self,
+        after: Option<&[u8]>,
+        chunk_size: usize,
+    ) -> DatabaseResult<Vec<(Vec<u8>, V)>> {
+        fresh_reader!(self.env, |reader| match after {
+            None => self
+                .inner
+                .iter(&reader)?
+                .take(chunk_size)
+                .map(|(k, v)| Ok((k.to_vec(), v)))
+                .collect(),
+            Some(after) => self
+                .inner
+                .iter_from(&reader, K::from_key_bytes_or_friendly_panic(after))?
+                .filter(|(k, _)| Ok(*k != after))
+                .take(chunk_size)
+                .map(|(k, v)| Ok((k.to_vec(), v)))
+                .collect(),
+        })
+    }
 }
 
-impl<K, V> BufferedStore for KvBufUsed<K, V>
+/// Statistics about what was written during a flush
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct FlushStats {
+    /// Number of values put
+    pub puts: usize,
+    /// Number of deletes, including keys that were not persisted
+    pub deletes: usize,
+    /// Total size of the serialized values put
+    pub bytes: usize,
+}
+
+impl std::ops::AddAssign for FlushStats {
+    fn add_assign(&mut self, other: Self) {
+        self.puts += other.puts;
+        self.deletes += other.deletes;
+        self.bytes += other.bytes;
+    }
+}
+
+impl std::iter::Sum for FlushStats {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::default(), |mut acc, s| {
+            acc += s;
+            acc
+        })
+    }
+}
+
+impl<K, V> KvBufUsed<K, V>
 where
     K: BufKey,
     V: BufVal,
 {
-    type Error = DatabaseError;
-
-    fn is_clean(&self) -> bool {
-        self.scratch.is_empty()
+    /// Flush the scratch space to the read-write transaction
+    /// and report what was written
+    pub fn flush_to_txn_metered(mut self, writer: &mut Writer) -> DatabaseResult<FlushStats> {
+        self.flush_to_txn_ref_metered(writer)
     }
 
-    fn flush_to_txn_ref(&mut self, writer: &mut Writer) -> DatabaseResult<()> {
-        use KvOp::*;
-
+    fn flush_to_txn_ref_metered(&mut self, writer: &mut Writer) -> DatabaseResult<FlushStats> {
+        let mut stats = FlushStats::default();
         if self.is_clean() {
-            return Ok(());
+            return Ok(stats);
         }
 
         for (k, op) in self.scratch.iter() {
-            match op {
-                Put(v) => {
-                    let buf = holochain_serialized_bytes::encode(v)?;
-                    let encoded = rkv::Value::Blob(&buf);
-                    self.store.db().put(writer, k, &encoded)?;
-                }
-                Delete => match self.store.db().delete(writer, k) {
+            self.write_op(writer, k, op, &mut stats)?;
+        }
+
+        Ok(stats)
+    }
+
+    /// Flush the scratch space in chunks of up to `chunk_size` ops,
+    /// each committed in its own write transaction.
+    /// Each item is the progress after a chunk is committed.
+    ///
+    /// This trades atomicity for bounded transaction size:
+    /// if a chunk fails the chunks before it stay committed
+    /// and the failed chunk and everything after it stay in the scratch space.
+    /// The iterator ends after the first error.
+    /// Use [BufferedStore::flush_to_txn] unless the scratch space is
+    /// too large to write in one transaction, e.g. for a bulk import.
+    pub fn flush_iter<'a>(
+        &'a mut self,
+        env: &'a EnvironmentWrite,
+        chunk_size: usize,
+    ) -> FlushIter<'a, K, V> {
+        FlushIter {
+            buf: self,
+            env,
+            chunk_size: chunk_size.max(1),
+            done: false,
+        }
+    }
+
+    fn write_op(
+        &self,
+        writer: &mut Writer,
+        k: &[u8],
+        op: &KvOp<V>,
+        stats: &mut FlushStats,
+    ) -> DatabaseResult<()> {
+        use KvOp::*;
+        match op {
+            Put(v) => {
+                let buf = encode_value(v, self.value_version)?;
+                let encoded = rkv::Value::Blob(&buf);
+                self.store.db().put(writer, k, &encoded)?;
+                stats.puts += 1;
+                stats.bytes += buf.len();
+            }
+            Delete => {
+                match self.store.db().delete(writer, k) {
                     Err(rkv::StoreError::LmdbError(rkv::LmdbError::NotFound)) => {}
                     r => r?,
-                },
+                }
+                stats.deletes += 1;
             }
         }
-
         Ok(())
     }
 }
 
+/// Progress of a [FlushIter] after a chunk is committed
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct FlushProgress {
+    /// What was written in this chunk
+    pub chunk: FlushStats,
+    /// Number of ops still in the scratch space
+    pub remaining: usize,
+}
+
+/// Iterator returned by [KvBufUsed::flush_iter]
+pub struct FlushIter<'a, K, V>
+where
+    K: BufKey,
+    V: BufVal,
+{
+    buf: &'a mut KvBufUsed<K, V>,
+    env: &'a EnvironmentWrite,
+    chunk_size: usize,
+    done: bool,
+}
+
+impl<'a, K, V> Iterator for FlushIter<'a, K, V>
+where
+    K: BufKey,
+    V: BufVal,
+{
+    type Item = DatabaseResult<FlushProgress>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done || self.buf.is_clean() {
+            return None;
+        }
+        let keys: Vec<Vec<u8>> = self
+            .buf
+            .scratch
+            .keys()
+            .take(self.chunk_size)
+            .cloned()
+            .collect();
+        let buf = &*self.buf;
+        let result = self.env.guard().with_commit(|writer| {
+            let mut stats = FlushStats::default();
+            for k in &keys {
+                if let Some(op) = buf.scratch.get(k) {
+                    buf.write_op(writer, k, op, &mut stats)?;
+                }
+            }
+            DatabaseResult::Ok(stats)
+        });
+        // Don't retry the failed chunk on the next call
+        self.done = result.is_err();
+        Some(result.map(|chunk| {
+            for k in &keys {
+                self.buf.scratch.remove(k);
+            }
+            FlushProgress {
+                chunk,
+                remaining: self.buf.scratch.len(),
+            }
+        }))
+    }
+}
+
+impl<K, V> BufferedStore for KvBufUsed<K, V>
+where
+    K: BufKey,
+    V: BufVal,
+{
+    type Error = DatabaseError;
+
+    fn is_clean(&self) -> bool {
+        self.scratch.is_empty()
+    }
+
+    fn flush_to_txn_ref(&mut self, writer: &mut Writer) -> DatabaseResult<()> {
+        self.flush_to_txn_ref_metered(writer).map(|_| ())
+    }
+}
+
 impl<V> BufferedStore for KvIntBufUsed<V>
 where
     V: BufVal,
@@ -378,7 +980,7 @@ where
         for (k, op) in self.scratch.iter() {
             match op {
                 Put(v) => {
-                    let buf = holochain_serialized_bytes::encode(v)?;
+                    let buf = encode_value(v, self.value_version)?;
                     let encoded = rkv::Value::Blob(&buf);
                     self.store.db().put(
                         writer,
@@ -443,6 +1045,8 @@ where
         Self {
             store: KvStore::new(other.store.db()),
             scratch: other.scratch.clone(),
+            value_version: other.value_version,
+            strict: other.strict,
             __phantom: std::marker::PhantomData,
         }
     }
@@ -1,14 +1,22 @@
 use super::BufferedStore;
+use super::FlushStats;
+use super::KvBufFresh;
 use super::KvBufUsed;
+use super::KvIntBufUsed;
 use super::KvOp;
+use super::KvOpKind;
 use crate::buffer::kv::generic::KvStoreT;
 use crate::env::ReadManager;
 use crate::env::WriteManager;
+use crate::error::DatabaseError;
 use crate::error::DatabaseResult;
+use crate::key::IntKey;
 use crate::test_utils::test_cell_env;
 use crate::test_utils::DbString;
+use crate::test_utils::TestEnvironment;
 use ::fixt::prelude::*;
 use fallible_iterator::FallibleIterator;
+use rkv::SingleStore;
 use rkv::StoreOptions;
 use serde_derive::Deserialize;
 use serde_derive::Serialize;
@@ -42,6 +50,25 @@ macro_rules! res {
     };
 }
 
+/// A test env with a "kv" store holding the given keys and values
+fn kv_with(items: &[(&str, u32)]) -> DatabaseResult<(TestEnvironment, SingleStore)> {
+    let test_env = test_cell_env();
+    let arc = test_env.env();
+    let env = arc.guard();
+    let db = env.inner().open_single("kv", StoreOptions::create())?;
+    let mut buf = Store::new(db);
+    for (k, v) in items {
+        buf.put((*k).into(), V(*v))?;
+    }
+    env.with_commit(|mut writer| buf.flush_to_txn(&mut writer))?;
+    Ok((test_env, db))
+}
+
+/// A test env with a "kv" store holding "a" => 1 and "b" => 2
+fn kv_with_ab() -> DatabaseResult<(TestEnvironment, SingleStore)> {
+    kv_with(&[("a", 1), ("b", 2)])
+}
+
 fn test_buf(a: &BTreeMap<Vec<u8>, KvOp<V>>, b: impl Iterator<Item = (&'static str, KvOp<V>)>) {
     for (k, v) in b {
         let val = a.get(k.as_bytes()).expect("Missing key");
@@ -51,22 +78,9 @@ fn test_buf(a: &BTreeMap<Vec<u8>, KvOp<V>>, b: impl Iterator<Item = (&'static st
 
 #[tokio::test(flavor = "multi_thread")]
 async fn kv_iterators() -> DatabaseResult<()> {
-    let test_env = test_cell_env();
+    let (test_env, db) = kv_with(&[("a", 1), ("b", 2), ("c", 3), ("d", 4), ("e", 5)])?;
     let arc = test_env.env();
     let env = arc.guard();
-    let db = env.inner().open_single("kv", StoreOptions::create())?;
-
-    {
-        let mut buf = Store::new(db);
-
-        buf.put("a".into(), V(1)).unwrap();
-        buf.put("b".into(), V(2)).unwrap();
-        buf.put("c".into(), V(3)).unwrap();
-        buf.put("d".into(), V(4)).unwrap();
-        buf.put("e".into(), V(5)).unwrap();
-
-        env.with_commit(|mut writer| buf.flush_to_txn(&mut writer))?;
-    }
 
     env.with_reader(|reader| {
         let buf = Store::new(db);
@@ -279,20 +293,9 @@ async fn kv_get_buffer() -> DatabaseResult<()> {
 #[tokio::test(flavor = "multi_thread")]
 async fn kv_get_persisted() -> DatabaseResult<()> {
     observability::test_run().ok();
-    let test_env = test_cell_env();
+    let (test_env, db) = kv_with(&[("a", 1), ("b", 2), ("c", 3)])?;
     let arc = test_env.env();
     let env = arc.guard();
-    let db = env.inner().open_single("kv", StoreOptions::create())?;
-
-    {
-        let mut buf = Store::new(db);
-
-        buf.put("a".into(), V(1)).unwrap();
-        buf.put("b".into(), V(2)).unwrap();
-        buf.put("c".into(), V(3)).unwrap();
-
-        env.with_commit(|mut writer| buf.flush_to_txn(&mut writer))?;
-    }
 
     env.with_reader(|reader| {
         let buf = Store::new(db);
@@ -328,6 +331,83 @@ async fn kv_get_del_buffer() -> DatabaseResult<()> {
 
 #[tokio::test(flavor = "multi_thread")]
 async fn kv_get_del_persisted() -> DatabaseResult<()> {
+    observability::test_run().ok();
+    let (test_env, db) = kv_with(&[("a", 1), ("b", 2), ("c", 3)])?;
+    let arc = test_env.env();
+    let env = arc.guard();
+
+    env.with_reader(|reader| {
+        let mut buf: KvBufUsed<DbString, V> = KvBufUsed::new(db);
+
+        buf.delete("b".into()).unwrap();
+        let n = buf.get(&reader, &"b".into())?;
+        assert_eq!(n, None);
+
+        env.with_commit(|mut writer| buf.flush_to_txn(&mut writer))
+    })?;
+
+    env.with_reader(|reader| {
+        let buf: KvBufUsed<DbString, V> = KvBufUsed::new(db);
+
+        let n = buf.get(&reader, &"b".into())?;
+        assert_eq!(n, None);
+        Ok(())
+    })
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn kv_drain_iter_from() -> DatabaseResult<()> {
+    observability::test_run().ok();
+    let (test_env, db) = kv_with(&[("a", 1), ("b", 2), ("d", 4), ("f", 6)])?;
+    let arc = test_env.env();
+    let env = arc.guard();
+
+    env.with_reader(|reader| {
+        let mut buf = Store::new(db);
+
+        // Pending ops in the scratch
+        buf.put("c".into(), V(3)).unwrap();
+        buf.put("d".into(), V(40)).unwrap();
+        buf.delete("f".into()).unwrap();
+        buf.put("g".into(), V(7)).unwrap();
+
+        let drained: Vec<_> = buf.drain_iter_from(&reader, "b".into())?.collect()?;
+        assert_eq!(drained, vec![V(2), V(3), V(40), V(7)]);
+        test_buf(
+            &buf.scratch,
+            [
+                res!("b", Delete),
+                res!("c", Delete),
+                res!("d", Delete),
+                res!("f", Delete),
+                res!("g", Delete),
+            ]
+            .iter()
+            .cloned(),
+        );
+        assert!(buf.scratch.get(&b"a"[..]).is_none());
+
+        // Nothing left to drain
+        let drained: Vec<_> = buf.drain_iter_from(&reader, "b".into())?.collect()?;
+        assert!(drained.is_empty());
+
+        // Reinsert a drained key
+        buf.put("d".into(), V(400)).unwrap();
+
+        env.with_commit(|mut writer| buf.flush_to_txn(&mut writer))
+    })?;
+
+    env.with_reader(|reader| {
+        let buf: KvBufUsed<DbString, _> = KvBufUsed::new(db);
+
+        let forward: Vec<_> = buf.store().iter(&reader).unwrap().collect().unwrap();
+        assert_eq!(forward, vec![(&b"a"[..], V(1)), (&b"d"[..], V(400))]);
+        Ok(())
+    })
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn kv_count() -> DatabaseResult<()> {
     observability::test_run().ok();
     let test_env = test_cell_env();
     let arc = test_env.env();
@@ -340,25 +420,674 @@ async fn kv_get_del_persisted() -> DatabaseResult<()> {
         buf.put("a".into(), V(1)).unwrap();
         buf.put("b".into(), V(2)).unwrap();
         buf.put("c".into(), V(3)).unwrap();
+        env.with_reader(|reader| {
+            assert_eq!(buf.count(&reader)?, 3);
+            DatabaseResult::Ok(())
+        })?;
 
         env.with_commit(|mut writer| buf.flush_to_txn(&mut writer))?;
     }
 
     env.with_reader(|reader| {
-        let mut buf: KvBufUsed<DbString, V> = KvBufUsed::new(db);
+        let mut buf = Store::new(db);
+        assert_eq!(buf.count(&reader)?, 3);
+
+        // Overwriting a persisted key doesn't change the count
+        buf.put("a".into(), V(10)).unwrap();
+        assert_eq!(buf.count(&reader)?, 3);
 
+        // New key
+        buf.put("d".into(), V(4)).unwrap();
+        assert_eq!(buf.count(&reader)?, 4);
+
+        // Deleting a persisted key
         buf.delete("b".into()).unwrap();
-        let n = buf.get(&reader, &"b".into())?;
-        assert_eq!(n, None);
+        assert_eq!(buf.count(&reader)?, 3);
 
-        env.with_commit(|mut writer| buf.flush_to_txn(&mut writer))
+        // Deleting a missing key
+        buf.delete("z".into()).unwrap();
+        assert_eq!(buf.count(&reader)?, 3);
+
+        // Deleting a key that only exists in the scratch
+        buf.delete("d".into()).unwrap();
+        assert_eq!(buf.count(&reader)?, 2);
+        Ok(())
+    })
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn kv_put_all_delete_keys() -> DatabaseResult<()> {
+    observability::test_run().ok();
+    let test_env = test_cell_env();
+    let arc = test_env.env();
+    let env = arc.guard();
+    let db = env.inner().open_single("kv", StoreOptions::create())?;
+
+    env.with_reader(|reader| {
+        let mut buf = Store::new(db);
+
+        buf.put_all(vec![
+            ("a".into(), V(1)),
+            ("b".into(), V(2)),
+            ("c".into(), V(3)),
+        ])?;
+        assert_eq!(buf.get(&reader, &"b".into())?, Some(V(2)));
+
+        // An empty key rejects the whole batch
+        let r = buf.put_all(vec![("d".into(), V(4)), ("".into(), V(5))]);
+        assert_eq!(r, Err(DatabaseError::EmptyKeyInBatch(1)));
+        assert!(!buf.contains(&reader, &"d".into())?);
+
+        let r = buf.delete_keys(vec!["a".into(), "".into()]);
+        assert_eq!(r, Err(DatabaseError::EmptyKeyInBatch(1)));
+        assert!(buf.contains(&reader, &"a".into())?);
+
+        buf.delete_keys(vec!["a".into(), "c".into()])?;
+        test_buf(
+            &buf.scratch,
+            [res!("a", Delete), res!("b", Put, 2), res!("c", Delete)]
+                .iter()
+                .cloned(),
+        );
+        assert!(buf.scratch.get(&b"d"[..]).is_none());
+        Ok(())
+    })
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn kv_pending_ops() -> DatabaseResult<()> {
+    let test_env = test_cell_env();
+    let arc = test_env.env();
+    let env = arc.guard();
+    let db = env.inner().open_single("kv", StoreOptions::create())?;
+
+    let mut buf = Store::new(db);
+    assert!(buf.pending_ops().is_empty());
+
+    buf.put("b".into(), V(2)).unwrap();
+    buf.put("a".into(), V(1)).unwrap();
+    buf.delete("c".into()).unwrap();
+    assert_eq!(
+        buf.pending_ops(),
+        vec![
+            (b"a".to_vec(), KvOpKind::Put),
+            (b"b".to_vec(), KvOpKind::Put),
+            (b"c".to_vec(), KvOpKind::Delete),
+        ]
+    );
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn kv_flush_metered() -> DatabaseResult<()> {
+    let test_env = test_cell_env();
+    let arc = test_env.env();
+    let env = arc.guard();
+    let db = env.inner().open_single("kv", StoreOptions::create())?;
+
+    let mut buf = Store::new(db);
+    buf.put("a".into(), V(1)).unwrap();
+    buf.put("b".into(), V(2)).unwrap();
+    buf.delete("c".into()).unwrap();
+    let expected_bytes = 2 * holochain_serialized_bytes::encode(&V(1))?.len();
+
+    let stats = env.with_commit(|mut writer| buf.flush_to_txn_metered(&mut writer))?;
+    assert_eq!(
+        stats,
+        FlushStats {
+            puts: 2,
+            deletes: 1,
+            bytes: expected_bytes,
+        }
+    );
+
+    let total: FlushStats = vec![stats, stats].into_iter().sum();
+    assert_eq!(total.puts, 4);
+    assert_eq!(total.deletes, 2);
+    assert_eq!(total.bytes, 2 * expected_bytes);
+
+    // Flushing an empty buffer writes nothing
+    let buf = Store::new(db);
+    let stats = env.with_commit(|mut writer| buf.flush_to_txn_metered(&mut writer))?;
+    assert_eq!(stats, FlushStats::default());
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn kv_flush_iter_chunks() -> DatabaseResult<()> {
+    let (test_env, db) = kv_with(&[("a", 1)])?;
+    let arc = test_env.env();
+    let env = arc.guard();
+
+    let mut buf = Store::new(db);
+    buf.delete("a".into())?;
+    for (k, v) in &[("b", 2), ("c", 3), ("d", 4), ("e", 5)] {
+        buf.put((*k).into(), V(*v))?;
+    }
+    let progress: Vec<_> = buf.flush_iter(&arc, 2).collect::<DatabaseResult<_>>()?;
+    assert_eq!(
+        progress.iter().map(|p| p.remaining).collect::<Vec<_>>(),
+        vec![3, 1, 0]
+    );
+    let total: FlushStats = progress.iter().map(|p| p.chunk).sum();
+    assert_eq!((total.puts, total.deletes), (4, 1));
+    assert!(buf.is_clean());
+    // A clean buffer has nothing to flush
+    assert!(buf.flush_iter(&arc, 2).next().is_none());
+
+    env.with_reader(|reader| {
+        let buf = Store::new(db);
+        assert_eq!(buf.get(&reader, &"a".into())?, None);
+        assert_eq!(buf.get(&reader, &"e".into())?, Some(V(5)));
+        assert_eq!(buf.iter(&reader)?.count()?, 4);
+        Ok(())
+    })
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn kv_flush_iter_stops_after_error() -> DatabaseResult<()> {
+    let (test_env, db) = kv_with(&[])?;
+    let arc = test_env.env();
+    let env = arc.guard();
+
+    // Longer than the largest key lmdb will write
+    let too_long = "b".repeat(5000);
+    let mut buf = Store::new(db);
+    buf.put("a".into(), V(1))?;
+    buf.put(too_long.as_str().into(), V(2))?;
+    buf.put("c".into(), V(3))?;
+
+    let mut iter = buf.flush_iter(&arc, 1);
+    assert_eq!(iter.next().map(|r| r.map(|p| p.remaining)), Some(Ok(2)));
+    assert!(matches!(iter.next(), Some(Err(_))));
+    // The failed chunk is not retried and "c" is never written
+    assert!(iter.next().is_none());
+    assert!(iter.next().is_none());
+    drop(iter);
+    assert_eq!(buf.pending_ops().len(), 2);
+
+    env.with_reader(|reader| {
+        let buf = Store::new(db);
+        assert_eq!(buf.get(&reader, &"a".into())?, Some(V(1)));
+        assert_eq!(buf.get(&reader, &"c".into())?, None);
+        Ok(())
+    })
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn kv_fresh_get_multi() -> DatabaseResult<()> {
+    let (test_env, db) = kv_with_ab()?;
+    let arc = test_env.env();
+
+    let mut buf: KvBufFresh<DbString, V> = KvBufFresh::new(arc.clone().into(), db);
+    buf.put("c".into(), V(3)).unwrap();
+    buf.delete("a".into()).unwrap();
+
+    let keys: Vec<DbString> = vec!["c".into(), "z".into(), "b".into(), "a".into()];
+    assert_eq!(
+        buf.get_multi(&keys)?,
+        vec![Some(V(3)), None, Some(V(2)), None]
+    );
+    assert!(buf.get_multi(&[])?.is_empty());
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn kv_fresh_iter_stream() -> DatabaseResult<()> {
+    use futures::stream::TryStreamExt;
+    let (test_env, db) = kv_with(&[("a", 0), ("b", 1), ("d", 2), ("e", 3), ("g", 4)])?;
+    let arc = test_env.env();
+
+    let mut buf: KvBufFresh<DbString, V> = KvBufFresh::new(arc.clone().into(), db);
+    buf.put("c".into(), V(10)).unwrap();
+    buf.put("f".into(), V(11)).unwrap();
+    buf.delete("a".into()).unwrap();
+
+    let expected: Vec<(Vec<u8>, V)> = vec![
+        (b"b".to_vec(), V(1)),
+        (b"c".to_vec(), V(10)),
+        (b"d".to_vec(), V(2)),
+        (b"e".to_vec(), V(3)),
+        (b"f".to_vec(), V(11)),
+        (b"g".to_vec(), V(4)),
+    ];
+    // Chunks that split the items, match them exactly and hold them all
+    for chunk_size in &[1, 2, 3, 6, 100] {
+        let items: Vec<_> = buf.iter_stream(*chunk_size).try_collect().await?;
+        assert_eq!(items, expected, "chunk size {}", chunk_size);
+    }
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn kv_versioned_values() -> DatabaseResult<()> {
+    let test_env = test_cell_env();
+    let arc = test_env.env();
+    let env = arc.guard();
+    let db = env.inner().open_single("kv", StoreOptions::create())?;
+
+    {
+        let mut old = Store::new(db);
+        old.put("a".into(), V(1)).unwrap();
+        env.with_commit(|mut writer| old.flush_to_txn(&mut writer))?;
+        let mut new = Store::new(db).with_value_version(2);
+        new.put("b".into(), V(2)).unwrap();
+        env.with_commit(|mut writer| new.flush_to_txn(&mut writer))?;
+    }
+
+    env.with_reader(|reader| {
+        let mut buf = Store::new(db).with_value_version(3);
+        buf.put("c".into(), V(3)).unwrap();
+
+        // Values without a version are stored as they always were
+        assert_eq!(
+            buf.store().get_bytes(&reader, &"a".into())?.unwrap(),
+            &holochain_serialized_bytes::encode(&V(1))?[..]
+        );
+
+        assert_eq!(buf.get_versioned(&reader, &"a".into())?, Some((None, V(1))));
+        assert_eq!(
+            buf.get_versioned(&reader, &"b".into())?,
+            Some((Some(2), V(2)))
+        );
+        assert_eq!(
+            buf.get_versioned(&reader, &"c".into())?,
+            Some((Some(3), V(3)))
+        );
+        assert_eq!(buf.get_versioned(&reader, &"z".into())?, None);
+
+        // Plain reads ignore the version
+        assert_eq!(buf.get(&reader, &"b".into())?, Some(V(2)));
+        let all: Vec<V> = buf.iter(&reader)?.map(|(_, v)| Ok(v)).collect()?;
+        assert_eq!(all, vec![V(1), V(2), V(3)]);
+        Ok(())
+    })
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn kv_count_key_matches() -> DatabaseResult<()> {
+    let (test_env, db) = kv_with(&[("a1", 1), ("a2", 2), ("a3", 3), ("b1", 4)])?;
+    let arc = test_env.env();
+
+    let mut buf: KvBufFresh<DbString, V> = KvBufFresh::new(arc.clone().into(), db);
+    assert_eq!(buf.count_key_matches("a".into())?, 3);
+    assert_eq!(buf.count_key_matches("b".into())?, 1);
+    assert_eq!(buf.count_key_matches("c".into())?, 0);
+
+    // New key, overwrite and delete in range
+    buf.put("a4".into(), V(5)).unwrap();
+    buf.put("a1".into(), V(6)).unwrap();
+    buf.delete("a2".into()).unwrap();
+    // Delete of a missing key and a key out of range
+    buf.delete("a9".into()).unwrap();
+    buf.put("b2".into(), V(7)).unwrap();
+
+    assert_eq!(buf.count_key_matches("a".into())?, 3);
+    assert_eq!(buf.count_key_matches("b".into())?, 2);
+    let expected = buf.with_reader(|reader| {
+        let inner: &KvBufUsed<DbString, V> = &buf;
+        Ok(inner.iter_all_key_matches(reader, "a".into())?.count()?)
     })?;
+    assert_eq!(buf.count_key_matches("a".into())?, expected);
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn kv_count_from() -> DatabaseResult<()> {
+    let (test_env, db) = kv_with(&[("a", 1), ("b", 2), ("c", 3), ("d", 4)])?;
+    let arc = test_env.env();
+
+    let mut buf: KvBufFresh<DbString, V> = KvBufFresh::new(arc.clone().into(), db);
+    assert_eq!(buf.count_from("a".into())?, 4);
+    assert_eq!(buf.count_from("b".into())?, 3);
+    assert_eq!(buf.count_from("bb".into())?, 2);
+    assert_eq!(buf.count_from("e".into())?, 0);
+
+    // New keys before and after the key and a delete after it
+    buf.put("a0".into(), V(5)).unwrap();
+    buf.put("bb".into(), V(6)).unwrap();
+    buf.delete("c".into()).unwrap();
+
+    assert_eq!(buf.count_from("b".into())?, 3);
+    let (before, expected) = buf.with_reader(|reader| {
+        let inner: &KvBufUsed<DbString, V> = &buf;
+        let count = inner.count(reader)?;
+        let from = inner.iter_from(reader, "b".into())?.count()?;
+        Ok((count - inner.count_from(reader, "b".into())?, from))
+    })?;
+    assert_eq!(buf.count_from("b".into())?, expected);
+    // "a" and "a0" are before "b"
+    assert_eq!(before, 2);
+
+    // Overwrites, deletes of missing keys and new keys past the last persisted key
+    buf.put("d".into(), V(7)).unwrap();
+    buf.delete("cc".into()).unwrap();
+    buf.put("x".into(), V(8)).unwrap();
+    buf.delete("y".into()).unwrap();
+    buf.delete("a".into()).unwrap();
+    for k in &["a", "a0", "b", "c", "cc", "d", "e", "x", "z"] {
+        let expected = buf.with_reader(|reader| {
+            let inner: &KvBufUsed<DbString, V> = &buf;
+            Ok(inner.iter_from(reader, (*k).into())?.count()?)
+        })?;
+        assert_eq!(buf.count_from((*k).into())?, expected, "from {}", k);
+    }
+    // "a0", "b", "bb", "d" and "x"
+    assert_eq!(buf.count_from("a".into())?, 5);
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn kv_fresh_with_reader() -> DatabaseResult<()> {
+    let (test_env, db) = kv_with_ab()?;
+    let arc = test_env.env();
+
+    let mut buf: KvBufFresh<DbString, V> = KvBufFresh::new(arc.clone().into(), db);
+    buf.put("c".into(), V(3)).unwrap();
+
+    let inner: &KvBufUsed<DbString, V> = &buf;
+    let (a, c, count) = buf.with_reader(|reader| {
+        Ok((
+            inner.get(reader, &"a".into())?,
+            inner.get(reader, &"c".into())?,
+            inner.iter(reader)?.count()?,
+        ))
+    })?;
+    assert_eq!(a, Some(V(1)));
+    assert_eq!(c, Some(V(3)));
+    assert_eq!(count, 3);
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn kv_fresh_reset_danger() -> DatabaseResult<()> {
+    let (test_env, db) = kv_with_ab()?;
+    let arc = test_env.env();
+    let env = arc.guard();
+
+    let mut buf: KvBufFresh<DbString, V> = KvBufFresh::new(arc.clone().into(), db);
+    buf.put("c".into(), V(3)).unwrap();
+
+    assert_eq!(buf.reset_danger(&arc)?, 2);
+    assert!(buf.is_clean());
+    assert_eq!(buf.get(&"a".into())?, None);
+    assert_eq!(buf.get(&"c".into())?, None);
+    env.with_reader::<DatabaseError, _, _>(|reader| {
+        assert_eq!(buf.store().count(&reader)?, 0);
+        Ok(())
+    })?;
+
+    // Nothing left to remove
+    assert_eq!(buf.reset_danger(&arc)?, 0);
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn kv_fresh_reset_danger_wrong_env() -> DatabaseResult<()> {
+    let (test_env, db) = kv_with_ab()?;
+    let arc = test_env.env();
+    let other_env = test_cell_env();
+
+    let mut buf: KvBufFresh<DbString, V> = KvBufFresh::new(arc.clone().into(), db);
+    buf.put("c".into(), V(3)).unwrap();
+
+    assert!(matches!(
+        buf.reset_danger(&other_env.env()),
+        Err(DatabaseError::EnvironmentMismatch { .. })
+    ));
+    // Nothing was removed
+    assert!(!buf.is_clean());
+    assert_eq!(buf.get(&"a".into())?, Some(V(1)));
+    assert_eq!(buf.get(&"c".into())?, Some(V(3)));
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn kv_contains_all_any() -> DatabaseResult<()> {
+    let (test_env, db) = kv_with_ab()?;
+    let arc = test_env.env();
+    let env = arc.guard();
 
     env.with_reader(|reader| {
-        let buf: KvBufUsed<DbString, V> = KvBufUsed::new(db);
+        let mut buf = Store::new(db);
+        buf.put("c".into(), V(3)).unwrap();
+        buf.delete("b".into()).unwrap();
 
-        let n = buf.get(&reader, &"b".into())?;
-        assert_eq!(n, None);
+        let present: Vec<DbString> = vec!["a".into(), "c".into()];
+        let mixed: Vec<DbString> = vec!["z".into(), "b".into(), "c".into()];
+        let missing: Vec<DbString> = vec!["z".into(), "b".into()];
+
+        assert!(buf.contains_all(&reader, &present)?);
+        assert!(!buf.contains_all(&reader, &mixed)?);
+        assert!(buf.contains_any(&reader, &mixed)?);
+        assert!(!buf.contains_any(&reader, &missing)?);
+
+        // Empty sets
+        assert!(buf.contains_all(&reader, &[])?);
+        assert!(!buf.contains_any(&reader, &[])?);
+        Ok(())
+    })
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn kv_iter_prefix() -> DatabaseResult<()> {
+    let (test_env, db) = kv_with(&[("aa1", 1), ("aa2", 2), ("ab1", 3), ("b1", 4)])?;
+    let arc = test_env.env();
+    let env = arc.guard();
+
+    env.with_reader(|reader| {
+        let mut buf = Store::new(db);
+        buf.put("aa3".into(), V(5)).unwrap();
+        buf.delete("aa1".into()).unwrap();
+
+        let keys = |prefix: &[u8]| -> DatabaseResult<Vec<Vec<u8>>> {
+            buf.iter_prefix(&reader, prefix)?
+                .map(|(k, _)| Ok(k.to_vec()))
+                .collect()
+        };
+
+        assert_eq!(keys(b"aa")?, vec![b"aa2".to_vec(), b"aa3".to_vec()]);
+        assert_eq!(
+            keys(b"a")?,
+            vec![b"aa2".to_vec(), b"aa3".to_vec(), b"ab1".to_vec()]
+        );
+        assert!(keys(b"c")?.is_empty());
+        assert_eq!(keys(b""), Err(DatabaseError::EmptyKey));
+        Ok(())
+    })
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn kv_modify() -> DatabaseResult<()> {
+    let (test_env, db) = kv_with(&[("a", 1)])?;
+    let arc = test_env.env();
+    let env = arc.guard();
+    let inc = |v: Option<V>| Some(V(v.map(|V(n)| n + 1).unwrap_or(0)));
+
+    let mut buf = Store::new(db);
+    env.with_reader(|reader| {
+        // Persisted value
+        buf.modify(&reader, "a".into(), inc)?;
+        // Scratch value
+        buf.modify(&reader, "a".into(), inc)?;
+        // Missing value
+        buf.modify(&reader, "b".into(), inc)?;
+        assert_eq!(buf.get(&reader, &"a".into())?, Some(V(3)));
+        assert_eq!(buf.get(&reader, &"b".into())?, Some(V(0)));
+
+        buf.put("c".into(), V(5))?;
+        buf.modify(&reader, "c".into(), |_| None)?;
+        assert_eq!(buf.get(&reader, &"c".into())?, None);
+        DatabaseResult::Ok(())
+    })?;
+    env.with_commit(|mut writer| buf.flush_to_txn(&mut writer))?;
+
+    env.with_reader(|reader| {
+        let buf = Store::new(db);
+        assert_eq!(buf.get(&reader, &"a".into())?, Some(V(3)));
+        assert_eq!(buf.get(&reader, &"b".into())?, Some(V(0)));
+        assert_eq!(buf.get(&reader, &"c".into())?, None);
+        Ok(())
+    })
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn kv_get_or_insert_with() -> DatabaseResult<()> {
+    let (test_env, db) = kv_with(&[("a", 1)])?;
+    let arc = test_env.env();
+    let env = arc.guard();
+
+    let mut buf = Store::new(db);
+    env.with_reader(|reader| {
+        // Persisted value is returned without calling the closure
+        let v = buf.get_or_insert_with(&reader, "a".into(), || unreachable!())?;
+        assert_eq!(v, V(1));
+        assert!(buf.pending_ops().is_empty());
+        // Missing value is inserted
+        assert_eq!(buf.get_or_insert_with(&reader, "b".into(), || V(2))?, V(2));
+        // Scratch value is returned
+        assert_eq!(buf.get_or_insert_with(&reader, "b".into(), || V(3))?, V(2));
+        // Deleted value is replaced
+        buf.put("c".into(), V(4))?;
+        buf.delete("c".into())?;
+        assert_eq!(buf.get_or_insert_with(&reader, "c".into(), || V(5))?, V(5));
+        DatabaseResult::Ok(())
+    })?;
+    env.with_commit(|mut writer| buf.flush_to_txn(&mut writer))?;
+
+    env.with_reader(|reader| {
+        let buf = Store::new(db);
+        assert_eq!(buf.get(&reader, &"b".into())?, Some(V(2)));
+        assert_eq!(buf.get(&reader, &"c".into())?, Some(V(5)));
+        Ok(())
+    })
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn kv_strict_conflicts() -> DatabaseResult<()> {
+    let test_env = test_cell_env();
+    let arc = test_env.env();
+    let env = arc.guard();
+    let db = env.inner().open_single("kv", StoreOptions::create())?;
+
+    // Not strict: the last op wins
+    let mut buf = Store::new(db);
+    buf.put("a".into(), V(1))?;
+    buf.delete("a".into())?;
+    assert_eq!(buf.pending_ops(), vec![(b"a".to_vec(), KvOpKind::Delete)]);
+
+    let mut buf = Store::new(db).strict();
+    buf.put("a".into(), V(1))?;
+    buf.put("a".into(), V(2))?;
+    buf.delete("b".into())?;
+    buf.delete("b".into())?;
+    assert_eq!(
+        buf.delete("a".into()),
+        Err(DatabaseError::ConflictingScratchOp {
+            key: b"a".to_vec(),
+            pending: KvOpKind::Put,
+            new: KvOpKind::Delete,
+        })
+    );
+    assert!(buf.put("b".into(), V(3)).is_err());
+    assert!(buf
+        .put_all(vec![("c".into(), V(4)), ("b".into(), V(5))])
+        .is_err());
+    assert!(buf.delete_keys(vec!["a".into()]).is_err());
+    assert_eq!(
+        buf.pending_ops(),
+        vec![
+            (b"a".to_vec(), KvOpKind::Put),
+            (b"b".to_vec(), KvOpKind::Delete),
+        ]
+    );
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn kv_strict_drain_conflicts() -> DatabaseResult<()> {
+    let (test_env, db) = kv_with_ab()?;
+    let arc = test_env.env();
+    let env = arc.guard();
+
+    env.with_reader(|reader| {
+        // Not strict: draining replaces the pending put
+        let mut buf = Store::new(db);
+        buf.put("a".into(), V(3))?;
+        assert_eq!(buf.drain_iter(&reader)?.count()?, 2);
+        assert_eq!(
+            buf.pending_ops(),
+            vec![
+                (b"a".to_vec(), KvOpKind::Delete),
+                (b"b".to_vec(), KvOpKind::Delete),
+            ]
+        );
+
+        let mut buf = Store::new(db).strict();
+        buf.put("a".into(), V(3))?;
+        assert_eq!(
+            buf.drain_iter(&reader)?.next(),
+            Err(DatabaseError::ConflictingScratchOp {
+                key: b"a".to_vec(),
+                pending: KvOpKind::Put,
+                new: KvOpKind::Delete,
+            })
+        );
+        assert_eq!(buf.pending_ops(), vec![(b"a".to_vec(), KvOpKind::Put)]);
+
+        // Keys without a pending put still drain
+        let mut buf = Store::new(db).strict();
+        buf.delete("a".into())?;
+        assert_eq!(buf.drain_iter(&reader)?.count()?, 2);
+        Ok(())
+    })
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn kv_int_iter_orders_by_value() -> DatabaseResult<()> {
+    let test_env = test_cell_env();
+    let arc = test_env.env();
+    let env = arc.guard();
+    let db = env
+        .inner()
+        .open_integer::<&str, IntKey>("kv_int", StoreOptions::create())?;
+
+    // Persisted keys on both sides of 255 / 256, which
+    // are out of order as little-endian bytes
+    let mut buf: KvIntBufUsed<V> = KvIntBufUsed::new_int(db);
+    for k in &[1u32, 255, 257, 512] {
+        buf.put((*k).into(), V(*k))?;
+    }
+    env.with_commit(|mut writer| buf.flush_to_txn(&mut writer))?;
+
+    env.with_reader(|reader| {
+        let mut buf: KvIntBufUsed<V> = KvIntBufUsed::new_int(db);
+        // Scratch keys between the persisted ones
+        for k in &[0u32, 256, 258, 1000] {
+            buf.put((*k).into(), V(*k))?;
+        }
+        buf.put(255.into(), V(2550))?;
+        buf.delete(512.into())?;
+
+        let forward: Vec<_> = buf.iter(&reader)?.map(|(_, v)| Ok(v)).collect()?;
+        assert_eq!(
+            forward,
+            vec![V(0), V(1), V(2550), V(256), V(257), V(258), V(1000)]
+        );
+        let reverse: Vec<_> = buf.iter(&reader)?.rev().map(|(_, v)| Ok(v)).collect()?;
+        assert_eq!(
+            reverse,
+            vec![V(1000), V(258), V(257), V(256), V(2550), V(1), V(0)]
+        );
+
+        let from: Vec<_> = buf
+            .iter_from(&reader, 256.into())?
+            .map(|(_, v)| Ok(v))
+            .collect()?;
+        assert_eq!(from, vec![V(256), V(257), V(258), V(1000)]);
+
+        let drained: Vec<_> = buf.drain_iter_from(&reader, 256.into())?.collect()?;
+        assert_eq!(drained, vec![V(256), V(257), V(258), V(1000)]);
         Ok(())
     })
 }
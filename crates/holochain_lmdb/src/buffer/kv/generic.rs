@@ -19,6 +19,9 @@ pub trait KvStoreT<K, V> {
     /// Delete value from DB by key
     fn delete(&self, writer: &mut Writer, k: &K) -> DatabaseResult<()>;
 
+    /// Count the persisted keys without deserializing the values
+    fn count<R: Readable>(&self, reader: &R) -> DatabaseResult<usize>;
+
     /// Iterate over the underlying persisted data
     fn iter<'env, R: Readable>(&self, reader: &'env R) -> DatabaseResult<SingleIterRaw<'env, V>>;
 
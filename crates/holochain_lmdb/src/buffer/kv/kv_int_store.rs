@@ -1,3 +1,4 @@
+use crate::buffer::decode_value;
 use crate::buffer::iter::SingleIterRaw;
 use crate::buffer::kv::KvStoreT;
 use crate::error::DatabaseError;
@@ -20,7 +21,7 @@ where
 
 impl<K, V> KvStoreT<K, V> for KvIntStoreGeneric<K, V>
 where
-    K: BufIntKey,
+    K: BufIntKey + BufKey,
     V: BufVal,
 {
     /// Fetch data from DB as raw byte slice
@@ -39,7 +40,7 @@ where
     /// Fetch data from DB, deserialize into V type
     fn get<R: Readable>(&self, reader: &R, k: &K) -> DatabaseResult<Option<V>> {
         match self.get_bytes(reader, k)? {
-            Some(bytes) => Ok(Some(holochain_serialized_bytes::decode(bytes)?)),
+            Some(bytes) => Ok(Some(decode_value(bytes)?)),
             None => Ok(None),
         }
     }
@@ -57,11 +58,22 @@ where
         Ok(self.db.delete(writer, *k)?)
     }
 
+    /// Count the persisted keys without deserializing the values
+    fn count<R: Readable>(&self, reader: &R) -> DatabaseResult<usize> {
+        let mut count = 0;
+        for item in self.db.iter_start(reader)? {
+            item?;
+            count += 1;
+        }
+        Ok(count)
+    }
+
     /// Iterate over the underlying persisted data
     fn iter<'env, R: Readable>(&self, reader: &'env R) -> DatabaseResult<SingleIterRaw<'env, V>> {
         Ok(SingleIterRaw::new(
             self.db.iter_start(reader)?,
             self.db.iter_end(reader)?,
+            K::cmp_key_bytes,
         ))
     }
 
@@ -74,6 +86,7 @@ where
         Ok(SingleIterRaw::new(
             self.db.iter_from(reader, k)?,
             self.db.iter_end(reader)?,
+            K::cmp_key_bytes,
         ))
     }
 
@@ -82,7 +95,12 @@ where
         &self,
         reader: &'env R,
     ) -> DatabaseResult<fallible_iterator::Rev<SingleIterRaw<'env, V>>> {
-        Ok(SingleIterRaw::new(self.db.iter_start(reader)?, self.db.iter_end(reader)?).rev())
+        Ok(SingleIterRaw::new(
+            self.db.iter_start(reader)?,
+            self.db.iter_end(reader)?,
+            K::cmp_key_bytes,
+        )
+        .rev())
     }
 }
 
@@ -1,5 +1,6 @@
 use super::KvStoreT;
 use crate::buffer::check_empty_key;
+use crate::buffer::decode_value;
 use crate::buffer::iter::SingleIterRaw;
 use crate::error::DatabaseError;
 use crate::error::DatabaseResult;
@@ -40,7 +41,7 @@ where
     fn get<R: Readable>(&self, reader: &R, k: &K) -> DatabaseResult<Option<V>> {
         check_empty_key(k)?;
         match self.get_bytes(reader, k)? {
-            Some(bytes) => Ok(Some(holochain_serialized_bytes::decode(bytes)?)),
+            Some(bytes) => Ok(Some(decode_value(bytes)?)),
             None => Ok(None),
         }
     }
@@ -58,11 +59,22 @@ where
         Ok(self.db.delete(writer, k)?)
     }
 
+    /// Count the persisted keys without deserializing the values
+    fn count<R: Readable>(&self, reader: &R) -> DatabaseResult<usize> {
+        let mut count = 0;
+        for item in self.db.iter_start(reader)? {
+            item?;
+            count += 1;
+        }
+        Ok(count)
+    }
+
     /// Iterate over the underlying persisted data
     fn iter<'env, R: Readable>(&self, reader: &'env R) -> DatabaseResult<SingleIterRaw<'env, V>> {
         Ok(SingleIterRaw::new(
             self.db.iter_start(reader)?,
             self.db.iter_end(reader)?,
+            K::cmp_key_bytes,
         ))
     }
 
@@ -76,6 +88,7 @@ where
         Ok(SingleIterRaw::new(
             self.db.iter_from(reader, k)?,
             self.db.iter_end(reader)?,
+            K::cmp_key_bytes,
         ))
     }
 
@@ -84,7 +97,12 @@ where
         &self,
         reader: &'env R,
     ) -> DatabaseResult<fallible_iterator::Rev<SingleIterRaw<'env, V>>> {
-        Ok(SingleIterRaw::new(self.db.iter_start(reader)?, self.db.iter_end(reader)?).rev())
+        Ok(SingleIterRaw::new(
+            self.db.iter_start(reader)?,
+            self.db.iter_end(reader)?,
+            K::cmp_key_bytes,
+        )
+        .rev())
     }
 }
 
@@ -106,6 +124,43 @@ where
         self.db
     }
 
+    /// Iterate over only the data persisted in the database.
+    /// This is unaware of any scratch space so uncommitted changes
+    /// in a buffer wrapping this store are not seen.
+    pub fn iter_raw<'env, R: Readable>(
+        &self,
+        reader: &'env R,
+    ) -> DatabaseResult<SingleIterRaw<'env, V>> {
+        KvStoreT::iter(self, reader)
+    }
+
+    /// Iterate over only the data persisted in the database
+    /// from a key onwards.
+    /// Like [`KvStore::iter_raw`] this ignores any scratch space.
+    pub fn iter_raw_from<'env, R: Readable>(
+        &self,
+        reader: &'env R,
+        k: K,
+    ) -> DatabaseResult<SingleIterRaw<'env, V>> {
+        KvStoreT::iter_from(self, reader, k)
+    }
+
+    /// Iterate over only the data persisted in the database
+    /// from some raw key bytes onwards.
+    /// Unlike [`KvStore::iter_raw_from`] the bytes don't need to be a whole key.
+    pub fn iter_raw_from_bytes<'env, R: Readable>(
+        &self,
+        reader: &'env R,
+        k: &[u8],
+    ) -> DatabaseResult<SingleIterRaw<'env, V>> {
+        check_empty_key(&k)?;
+        Ok(SingleIterRaw::new(
+            self.db.iter_from(reader, k)?,
+            self.db.iter_end(reader)?,
+            K::cmp_key_bytes,
+        ))
+    }
+
     // TODO: This should be cfg test but can't because it's in a different crate
     /// Clear db, useful for tests
     pub fn delete_all(&mut self, writer: &mut Writer) -> DatabaseResult<()> {
@@ -67,6 +67,41 @@ async fn kvbuf_scratch_and_persistence() -> DatabaseResult<()> {
     })
 }
 
+#[tokio::test(flavor = "multi_thread")]
+async fn kv_store_raw_iter_skips_scratch() -> DatabaseResult<()> {
+    use fallible_iterator::FallibleIterator;
+    let test_env = test_cell_env();
+    let arc = test_env.env();
+    let env = arc.guard();
+    let db = env.inner().open_single("kv", StoreOptions::create())?;
+
+    let mut buf: KvBufUsed<DbString, DbString> = KvBufUsed::new(db)?;
+    buf.put("a".into(), "1".into())?;
+    buf.put("b".into(), "2".into())?;
+    env.with_commit(|writer| buf.flush_to_txn(writer))?;
+
+    env.with_reader::<DatabaseError, _, _>(|reader| {
+        let mut buf: KvBufUsed<DbString, DbString> = KvBufUsed::new(db)?;
+        buf.put("c".into(), "3".into())?;
+        buf.delete("a".into())?;
+
+        let persisted: Vec<DbString> = buf
+            .store()
+            .iter_raw(&reader)?
+            .map(|(_, v)| Ok(v))
+            .collect()?;
+        assert_eq!(persisted, vec!["1".into(), "2".into()]);
+
+        let from_b: Vec<DbString> = buf
+            .store()
+            .iter_raw_from(&reader, "b".into())?
+            .map(|(_, v)| Ok(v))
+            .collect()?;
+        assert_eq!(from_b, vec![DbString::from("2")]);
+        Ok(())
+    })
+}
+
 // pub(super) type TestBuf<'a> = KvBufUsed<&'a str, V>;
 
 // macro_rules! res {
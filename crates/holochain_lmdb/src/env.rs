@@ -122,6 +122,11 @@ impl EnvironmentRead {
     pub fn path(&self) -> &PathBuf {
         &self.path
     }
+
+    /// Is this the same underlying environment as `other`
+    pub(crate) fn same_env(&self, other: &EnvironmentRead) -> bool {
+        Arc::ptr_eq(&self.arc, &other.arc)
+    }
 }
 
 impl GetDb for EnvironmentWrite {
@@ -197,6 +202,34 @@ impl EnvironmentWrite {
         EnvironmentWriteRef(self.0.guard())
     }
 
+    /// Double the size of the memory map.
+    /// This waits for every transaction on the environment to finish,
+    /// so it must not be called while holding a guard.
+    pub fn grow_map(&self) -> DatabaseResult<()> {
+        let rkv = self.0.arc.write();
+        let map_size = rkv.info()?.map_size();
+        tracing::info!(path = ?self.0.path, map_size, "Growing full LMDB map");
+        rkv.set_map_size(map_size.saturating_mul(2))?;
+        Ok(())
+    }
+
+    /// Like [WriteManager::with_commit] but if the map is full
+    /// the transaction is aborted, the map is grown and the closure
+    /// is run again in a new transaction until it fits.
+    /// The closure must write everything again each time it runs,
+    /// e.g. use [BufferedStore::flush_to_txn_ref](crate::buffer::BufferedStore::flush_to_txn_ref).
+    pub fn with_commit_growing<R, F: Send>(&self, mut f: F) -> DatabaseResult<R>
+    where
+        F: FnMut(&mut Writer) -> DatabaseResult<R>,
+    {
+        loop {
+            match self.with_commit(|writer| f(writer)) {
+                Err(DatabaseError::MapFull) => self.grow_map()?,
+                result => return result,
+            }
+        }
+    }
+
     /// Remove the db and directory
     pub async fn remove(self) -> DatabaseResult<()> {
         let mut map = ENVIRONMENTS.write();
@@ -341,3 +374,52 @@ impl<'e> ReadManager<'e> for EnvironmentWriteRef<'e> {
         self.0.with_reader(f)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::buffer::BufferedStore;
+    use crate::buffer::KvBufUsed;
+    use crate::buffer::KvStoreT;
+    use crate::test_utils::test_keystore;
+    use crate::test_utils::DbString;
+    use rkv::StoreOptions;
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn full_map_is_grown_and_retried() -> DatabaseResult<()> {
+        let tmpdir = tempdir::TempDir::new("holochain-map-full").unwrap();
+        // Far too small for the writes below
+        let rkv = rkv_builder(Some(64 * 1024), None)(tmpdir.path())?;
+        let env = EnvironmentWrite(EnvironmentRead {
+            arc: Arc::new(RwLock::new(rkv)),
+            kind: EnvironmentKind::Conductor,
+            path: tmpdir.path().to_owned(),
+            keystore: test_keystore(),
+        });
+        let db = env
+            .guard()
+            .inner()
+            .open_single("kv", StoreOptions::create())?;
+
+        let value = DbString::from("x".repeat(1024).as_str());
+        let mut buf: KvBufUsed<DbString, DbString> = KvBufUsed::new(db)?;
+        for i in 0..256 {
+            buf.put(format!("{:03}", i).as_str().into(), value.clone())?;
+        }
+        let map_size = env.guard().rkv().info()?.map_size();
+
+        assert_eq!(
+            env.with_commit(|writer| buf.flush_to_txn_ref(writer)),
+            Err(DatabaseError::MapFull)
+        );
+        env.with_commit_growing(|writer| buf.flush_to_txn_ref(writer))?;
+        assert!(env.guard().rkv().info()?.map_size() > map_size);
+
+        env.guard().with_reader(|reader| {
+            let store = buf.store();
+            assert_eq!(store.get(&reader, &"000".into())?, Some(value.clone()));
+            assert_eq!(store.get(&reader, &"255".into())?, Some(value));
+            Ok(())
+        })
+    }
+}
@@ -3,6 +3,7 @@
 // missing_docs allowed here since the errors already have self-descriptive strings
 #![allow(missing_docs)]
 
+use crate::buffer::KvOpKind;
 use crate::db::DbName;
 use failure::Fail;
 use holochain_serialized_bytes::SerializedBytesError;
@@ -76,11 +77,28 @@ pub enum DatabaseError {
     #[error("Empty keys cannot be used with lmdb")]
     EmptyKey,
 
+    #[error("Empty keys cannot be used with lmdb, found one at index {0} of a batch")]
+    EmptyKeyInBatch(usize),
+
     #[error("Key range must be not empty and start < end")]
     InvalidKeyRange,
 
+    #[error("A {new:?} was recorded for key {key:?} which already has a pending {pending:?}")]
+    ConflictingScratchOp {
+        key: Vec<u8>,
+        pending: KvOpKind,
+        new: KvOpKind,
+    },
+
     #[error("Unable to construct a value key")]
     KeyConstruction,
+
+    #[error("Expected the LMDB environment at {expected:?} but was given the one at {got:?}")]
+    EnvironmentMismatch { expected: PathBuf, got: PathBuf },
+
+    /// The environment can be grown and the write retried
+    #[error("The LMDB environment's map is full")]
+    MapFull,
 }
 
 impl PartialEq for DatabaseError {
@@ -107,7 +125,10 @@ pub type DatabaseResult<T> = Result<T, DatabaseError>;
 // }
 impl From<rkv::StoreError> for DatabaseError {
     fn from(e: rkv::StoreError) -> DatabaseError {
-        DatabaseError::LmdbStoreError(e.compat())
+        match e {
+            rkv::StoreError::LmdbError(rkv::LmdbError::MapFull) => DatabaseError::MapFull,
+            e => DatabaseError::LmdbStoreError(e.compat()),
+        }
     }
 }
 
@@ -127,3 +148,16 @@ impl From<rkv::DataError> for DatabaseError {
         DatabaseError::LmdbDataError(e.compat())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn map_full_is_typed() {
+        let e: DatabaseError = rkv::StoreError::LmdbError(rkv::LmdbError::MapFull).into();
+        assert!(matches!(e, DatabaseError::MapFull));
+        let e: DatabaseError = rkv::StoreError::LmdbError(rkv::LmdbError::NotFound).into();
+        assert!(matches!(e, DatabaseError::LmdbStoreError(_)));
+    }
+}
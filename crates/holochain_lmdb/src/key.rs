@@ -29,6 +29,14 @@ pub trait BufKey: Sized + Ord + Eq + AsRef<[u8]> + Send + Sync {
     /// panic should be a friendly message that suggests that the database may
     /// have been corrupted
     fn from_key_bytes_or_friendly_panic(bytes: &[u8]) -> Self;
+
+    /// Order two keys from their bytes the same way the database does.
+    /// The scratch space is merged with the database in this order.
+    ///
+    /// Keys are ordered by their bytes by default.
+    fn cmp_key_bytes(a: &[u8], b: &[u8]) -> Ordering {
+        a.cmp(b)
+    }
 }
 
 /// Trait alias for the combination of constraints needed for keys in [KvIntStore](kv_int::KvIntStore)
@@ -47,9 +55,22 @@ impl<T> BufMultiVal for T where T: Ord + Eq + Clone + Serialize + DeserializeOwn
 ///
 /// This strange type is constrained by both rkv's interface, and our own
 /// database abstractions
-#[derive(Copy, PartialOrd, Ord, PartialEq, Eq, Clone, Serialize, serde::Deserialize)]
+#[derive(Copy, PartialEq, Eq, Clone, Serialize, serde::Deserialize)]
 pub struct IntKey([u8; 4]);
 
+impl PartialOrd for IntKey {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Ordered by value like the integer stores the keys are used in.
+impl Ord for IntKey {
+    fn cmp(&self, other: &Self) -> Ordering {
+        u32::from(*self).cmp(&u32::from(*other))
+    }
+}
+
 impl rkv::store::integer::PrimitiveInt for IntKey {}
 
 impl BufKey for IntKey {
@@ -65,6 +86,11 @@ impl BufKey for IntKey {
         };
         IntKey(*boxed_array)
     }
+
+    /// Integer stores order keys by their value, not their native-endian bytes.
+    fn cmp_key_bytes(a: &[u8], b: &[u8]) -> Ordering {
+        Self::from_key_bytes_or_friendly_panic(a).cmp(&Self::from_key_bytes_or_friendly_panic(b))
+    }
 }
 
 impl AsRef<[u8]> for IntKey {
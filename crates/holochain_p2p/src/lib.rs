@@ -37,6 +37,9 @@ pub trait HolochainP2pCellT {
     /// If a cell is deactivated, we'll need to \"leave\" the network module as well.
     async fn leave(&mut self) -> actor::HolochainP2pResult<()>;
 
+    /// Run a round of gossip for this dna now instead of waiting for the next one.
+    async fn trigger_gossip(&mut self) -> actor::HolochainP2pResult<()>;
+
     /// Invoke a zome function on a remote node (if you have been granted the capability).
     async fn call_remote(
         &mut self,
@@ -136,6 +139,11 @@ impl HolochainP2pCellT for HolochainP2pCell {
             .await
     }
 
+    /// Run a round of gossip for this dna now instead of waiting for the next one.
+    async fn trigger_gossip(&mut self) -> actor::HolochainP2pResult<()> {
+        self.sender.trigger_gossip((*self.dna_hash).clone()).await
+    }
+
     /// Invoke a zome function on a remote node (if you have been granted the capability).
     async fn call_remote(
         &mut self,
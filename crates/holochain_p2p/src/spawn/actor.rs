@@ -544,6 +544,16 @@ impl HolochainP2pHandler for HolochainP2pActor {
             .into())
     }
 
+    #[tracing::instrument(skip(self), level = "trace")]
+    fn handle_trigger_gossip(&mut self, dna_hash: DnaHash) -> HolochainP2pHandlerResult<()> {
+        let space = dna_hash.into_kitsune();
+
+        let kitsune_p2p = self.kitsune_p2p.clone();
+        Ok(async move { Ok(kitsune_p2p.trigger_gossip(space).await?) }
+            .boxed()
+            .into())
+    }
+
     #[tracing::instrument(skip(self), level = "trace")]
     fn handle_call_remote(
         &mut self,
@@ -835,4 +845,41 @@ impl HolochainP2pHandler for HolochainP2pActor {
         .boxed()
         .into())
     }
+
+    #[tracing::instrument(skip(self), level = "trace")]
+    fn handle_get_transport_metrics(
+        &mut self,
+    ) -> HolochainP2pHandlerResult<kitsune_p2p::metrics::TransportMetrics> {
+        let kitsune_p2p = self.kitsune_p2p.clone();
+        Ok(async move {
+            let metrics = kitsune_p2p.get_transport_metrics().await?;
+            Ok(metrics)
+        }
+        .boxed()
+        .into())
+    }
+
+    #[tracing::instrument(skip(self), level = "trace")]
+    fn handle_list_transport_bindings(
+        &mut self,
+    ) -> HolochainP2pHandlerResult<Vec<kitsune_p2p::dependencies::url2::Url2>> {
+        let kitsune_p2p = self.kitsune_p2p.clone();
+        Ok(async move {
+            let bindings = kitsune_p2p.list_transport_bindings().await?;
+            Ok(bindings)
+        }
+        .boxed()
+        .into())
+    }
+
+    #[tracing::instrument(skip(self), level = "trace")]
+    fn handle_check_bootstrap(&mut self) -> HolochainP2pHandlerResult<Option<bool>> {
+        let kitsune_p2p = self.kitsune_p2p.clone();
+        Ok(async move {
+            let reachable = kitsune_p2p.check_bootstrap().await?;
+            Ok(reachable)
+        }
+        .boxed()
+        .into())
+    }
 }
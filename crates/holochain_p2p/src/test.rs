@@ -28,6 +28,9 @@ impl HolochainP2pHandler for StubNetwork {
     ) -> HolochainP2pHandlerResult<()> {
         Err("stub".into())
     }
+    fn handle_trigger_gossip(&mut self, dna_hash: DnaHash) -> HolochainP2pHandlerResult<()> {
+        Err("stub".into())
+    }
     fn handle_call_remote(
         &mut self,
         dna_hash: DnaHash,
@@ -103,6 +106,19 @@ impl HolochainP2pHandler for StubNetwork {
     ) -> HolochainP2pHandlerResult<()> {
         Err("stub".into())
     }
+    fn handle_get_transport_metrics(
+        &mut self,
+    ) -> HolochainP2pHandlerResult<kitsune_p2p::metrics::TransportMetrics> {
+        Err("stub".into())
+    }
+    fn handle_list_transport_bindings(
+        &mut self,
+    ) -> HolochainP2pHandlerResult<Vec<kitsune_p2p::dependencies::url2::Url2>> {
+        Err("stub".into())
+    }
+    fn handle_check_bootstrap(&mut self) -> HolochainP2pHandlerResult<Option<bool>> {
+        Err("stub".into())
+    }
 }
 
 /// Spawn a stub network that doesn't respond to any messages.
@@ -197,6 +197,9 @@ ghost_actor::ghost_chan! {
         /// If a cell is deactivated, we'll need to \"leave\" the network module as well.
         fn leave(dna_hash: DnaHash, agent_pub_key: AgentPubKey) -> ();
 
+        /// Run a round of gossip for this dna now instead of waiting for the next one.
+        fn trigger_gossip(dna_hash: DnaHash) -> ();
+
         /// Invoke a zome function on a remote node (if you have been granted the capability).
         fn call_remote(
             dna_hash: DnaHash,
@@ -256,6 +259,16 @@ ghost_actor::ghost_chan! {
 
         /// Send a validation receipt to a remote node.
         fn send_validation_receipt(dna_hash: DnaHash, to_agent: AgentPubKey, from_agent: AgentPubKey, receipt: SerializedBytes) -> ();
+
+        /// Get a snapshot of the transport activity of this node.
+        fn get_transport_metrics() -> kitsune_p2p::metrics::TransportMetrics;
+
+        /// Get the addresses other nodes can reach this node on.
+        fn list_transport_bindings() -> Vec<kitsune_p2p::dependencies::url2::Url2>;
+
+        /// Check whether the bootstrap service responds.
+        /// Returns `None` if there is no bootstrap service configured.
+        fn check_bootstrap() -> Option<bool>;
     }
 }
 
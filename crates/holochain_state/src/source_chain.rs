@@ -55,13 +55,24 @@ impl SourceChain {
         header_builder: B,
         maybe_entry: Option<Entry>,
     ) -> SourceChainResult<HeaderHash> {
+        let prev_header = self.chain_head()?.to_owned();
         let common = HeaderBuilderCommon {
             author: self.agent_pubkey()?,
             timestamp: timestamp::now(),
             header_seq: self.len() as u32,
-            prev_header: self.chain_head()?.to_owned(),
+            prev_header: prev_header.clone(),
         };
         let header = header_builder.build(common).into();
+        let head = self
+            .get_header(&prev_header)?
+            .ok_or(SourceChainError::MissingHead)?;
+        match (head.header(), &header) {
+            (Header::CloseChain(_), _) => return Err(SourceChainError::ChainClosed),
+            (head, Header::OpenChain(_)) if !head.can_precede_open_chain() => {
+                return Err(SourceChainError::OpenChainNotAfterGenesis)
+            }
+            _ => (),
+        }
         self.put_raw(header, maybe_entry).await
     }
 
@@ -236,6 +247,45 @@ impl SourceChain {
         Ok(committed_valid_grant)
     }
 
+    /// Every cap grant committed to this chain in chain order,
+    /// with the header that committed it and whether a later
+    /// update or delete of that header has revoked it.
+    /// Takes the scratch space into account.
+    pub fn cap_grants(&self) -> SourceChainResult<Vec<(HeaderHash, ZomeCallCapGrant, bool)>> {
+        // Walking backwards means every update or delete
+        // is seen before the grant it references.
+        let mut references = HashSet::new();
+        let mut grants = Vec::new();
+        let mut iter = self.iter_back();
+        while let Some(shh) = iter.next()? {
+            let entry_hash = match shh.header() {
+                Header::Create(create) if create.entry_type == EntryType::CapGrant => {
+                    &create.entry_hash
+                }
+                Header::Update(update) => {
+                    references.insert(update.original_header_address.clone());
+                    if update.entry_type != EntryType::CapGrant {
+                        continue;
+                    }
+                    &update.entry_hash
+                }
+                Header::Delete(delete) => {
+                    references.insert(delete.deletes_address.clone());
+                    continue;
+                }
+                _ => continue,
+            };
+            if let Some(Entry::CapGrant(grant)) =
+                self.0.get_entry(entry_hash)?.map(|e| e.into_content())
+            {
+                let revoked = references.contains(shh.header_address());
+                grants.push((shh.header_address().clone(), grant, revoked));
+            }
+        }
+        grants.reverse();
+        Ok(grants)
+    }
+
     // @todo bring all this back when we want to administer cap claims better
     //         /// Fetch a CapClaim from the private entries.
     //         ///
@@ -322,8 +372,10 @@ pub mod tests {
     use hdk::prelude::*;
     use holochain_lmdb::test_utils::test_cell_env;
     use holochain_types::test_utils::fake_dna_hash;
+    use holochain_types::test_utils::fake_entry_hash;
     use holochain_zome_types::capability::CapAccess;
     use holochain_zome_types::capability::ZomeCallCapGrant;
+    use matches::assert_matches;
     use std::collections::BTreeSet;
 
     #[tokio::test(flavor = "multi_thread")]
@@ -394,6 +446,10 @@ pub mod tests {
                 chain.valid_cap_grant(&function, &bob, secret.as_ref())?,
                 Some(grant.clone().into())
             );
+            assert_eq!(
+                chain.cap_grants()?,
+                vec![(original_header_address.clone(), grant.clone(), false)]
+            );
         }
 
         // let's roll the secret and assign the grant to bob specifically
@@ -403,6 +459,7 @@ pub mod tests {
         let updated_access = CapAccess::from((updated_secret.clone().unwrap(), assignees));
         let updated_grant = ZomeCallCapGrant::new("tag".into(), updated_access.clone(), functions);
 
+        let original_header_hash = original_header_address.clone();
         let (updated_header_hash, updated_entry_hash) = {
             let mut chain = SourceChain::new(env.clone().into())?;
             let (entry, entry_hash) =
@@ -441,14 +498,22 @@ pub mod tests {
             );
             assert_eq!(
                 chain.valid_cap_grant(&function, &bob, updated_secret.as_ref())?,
-                Some(updated_grant.into())
+                Some(updated_grant.clone().into())
+            );
+            // the update revokes the original grant
+            assert_eq!(
+                chain.cap_grants()?,
+                vec![
+                    (original_header_hash.clone(), grant.clone(), true),
+                    (updated_header_hash.clone(), updated_grant.clone(), false),
+                ]
             );
         }
 
         {
             let mut chain = SourceChain::new(env.clone().into())?;
             let header_builder = builder::Delete {
-                deletes_address: updated_header_hash,
+                deletes_address: updated_header_hash.clone(),
                 deletes_entry_address: updated_entry_hash,
             };
             chain.put(header_builder, None).await?;
@@ -478,11 +543,55 @@ pub mod tests {
                 chain.valid_cap_grant(&function, &bob, updated_secret.as_ref())?,
                 None
             );
+            assert_eq!(
+                chain.cap_grants()?,
+                vec![
+                    (original_header_hash, grant, true),
+                    (updated_header_hash, updated_grant, true),
+                ]
+            );
         }
 
         Ok(())
     }
 
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_migration_header_placement() -> SourceChainResult<()> {
+        let test_env = test_cell_env();
+        let env = test_env.env();
+        let alice = AgentPubKeyFixturator::new(Predictable).next().unwrap();
+        let mut chain = SourceChain::new(env.clone().into())?;
+        chain.genesis(fake_dna_hash(1), alice, None).await?;
+        let open_chain = builder::OpenChain {
+            prev_dna_hash: fake_dna_hash(2),
+        };
+        let create_link = builder::CreateLink {
+            base_address: fake_entry_hash(1),
+            target_address: fake_entry_hash(2),
+            zome_id: 0.into(),
+            tag: LinkTag::new("tag"),
+        };
+
+        // an OpenChain can directly follow genesis but nothing else
+        chain.put(open_chain.clone(), None).await?;
+        chain.put(create_link.clone(), None).await?;
+        assert_matches!(
+            chain.put(open_chain, None).await,
+            Err(SourceChainError::OpenChainNotAfterGenesis)
+        );
+
+        // nothing can follow a CloseChain
+        let close_chain = builder::CloseChain {
+            new_dna_hash: fake_dna_hash(3),
+        };
+        chain.put(close_chain, None).await?;
+        assert_matches!(
+            chain.put(create_link, None).await,
+            Err(SourceChainError::ChainClosed)
+        );
+        Ok(())
+    }
+
     // @todo bring all this back when we want to administer cap claims better
     // #[tokio::test(flavor = "multi_thread")]
     // async fn test_get_cap_claim() -> SourceChainResult<()> {
@@ -65,6 +65,14 @@ pub enum SourceChainError {
 
     #[error(transparent)]
     ElementGroupError(#[from] ElementGroupError),
+
+    #[error("The source chain has been closed by a CloseChain header so nothing more can be committed to it")]
+    ChainClosed,
+
+    #[error(
+        "An OpenChain header can only be committed straight after genesis or zome initialization"
+    )]
+    OpenChainNotAfterGenesis,
 }
 
 // serde_json::Error does not implement PartialEq - why is that a requirement??
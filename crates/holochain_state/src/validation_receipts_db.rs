@@ -76,6 +76,10 @@ pub struct SignedValidationReceipt {
     pub validator_signature: Signature,
 }
 
+/// Validation receipts for the same author sent in one message.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize, SerializedBytes)]
+pub struct ValidationReceiptBundle(pub Vec<SignedValidationReceipt>);
+
 /// The database/buffer for aggregating validation_receipts sent by remote
 /// nodes in charge of storage thereof.
 pub struct ValidationReceiptsBuf(KvvBufUsed<DhtOpHash, SignedValidationReceipt>);
@@ -88,6 +88,17 @@ impl CreateCloneCellPayload {
     }
 }
 
+/// The instructions on how to remove a cloned Cell from an App
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct DeleteCloneCellPayload {
+    /// The App the clone belongs to
+    pub installed_app_id: InstalledAppId,
+    /// The SlotId the clone was created under
+    pub slot_id: SlotId,
+    /// The CellId of the clone to delete
+    pub cell_id: CellId,
+}
+
 /// A collection of [DnaHash]es paired with an [AgentPubKey] and an app id
 #[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
 pub struct InstallAppPayload {
@@ -336,6 +347,13 @@ impl InstalledApp {
         self.slots.iter().map(|(_, slot)| &slot.clones).flatten()
     }
 
+    /// Iterator of the cloned cells along with the slot they were cloned from
+    pub fn cloned_cells_by_slot(&self) -> impl Iterator<Item = (&SlotId, &CellId)> {
+        self.slots
+            .iter()
+            .flat_map(|(nick, slot)| slot.clones.iter().map(move |c| (nick, c)))
+    }
+
     /// Iterator of all cells, both provisioned and cloned
     pub fn all_cells(&self) -> impl Iterator<Item = &CellId> {
         self.provisioned_cells()
@@ -493,6 +511,10 @@ mod tests {
             app.cloned_cells().collect::<HashSet<_>>(),
             maplit::hashset! { &clones[0], &clones[2] }
         );
+        assert_eq!(
+            app.cloned_cells_by_slot().collect::<HashSet<_>>(),
+            maplit::hashset! { (&slot_id, &clones[0]), (&slot_id, &clones[2]) }
+        );
 
         // Adding the same clone twice should probably be a panic, but if this
         // line is still here, I never got around to making it panic...
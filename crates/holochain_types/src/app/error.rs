@@ -11,6 +11,9 @@ pub enum AppError {
     #[error("Tried to access missing slot id: '{0}'")]
     SlotIdMissing(SlotId),
 
+    #[error("Cell {1:?} is not a clone in slot '{0}'")]
+    CloneCellMissing(SlotId, CellId),
+
     #[error("Tried to install app '{0}' which contains duplicate slot ids. The following slot ids have duplicates: {1:?}")]
     DuplicateSlotIds(InstalledAppId, Vec<SlotId>),
 }
@@ -63,6 +63,11 @@ impl InlineZome {
         self
     }
 
+    /// Whether this zome defines a function or callback with the given name.
+    pub fn has_callback(&self, name: &FunctionName) -> bool {
+        self.callbacks.contains_key(name)
+    }
+
     /// Make a call to an inline zome callback.
     /// If the callback doesn't exist, return None.
     pub fn maybe_call(
@@ -68,6 +68,29 @@ impl GetOptions {
             strategy: GetStrategy::Content,
         }
     }
+    /// Always asks the network for the content
+    /// and latest metadata, even if you are an
+    /// authority for this hash or authored it.
+    ///
+    /// Use this when the local copy might be stale.
+    /// Anything found is still combined with what
+    /// is available locally.
+    pub fn network() -> Self {
+        Self {
+            strategy: GetStrategy::Network,
+        }
+    }
+    /// Only answers with what the network returns,
+    /// ignoring anything stored locally.
+    ///
+    /// `get` returns nothing if no authority responds,
+    /// even if you have a copy of the data.
+    /// `get_details` treats this like [`GetOptions::network`].
+    pub fn network_only() -> Self {
+        Self {
+            strategy: GetStrategy::NetworkOnly,
+        }
+    }
 }
 
 impl Default for GetOptions {
@@ -76,7 +99,7 @@ impl Default for GetOptions {
     }
 }
 
-#[derive(PartialEq, Debug, Clone, Copy, Serialize, Deserialize)]
+#[derive(PartialEq, Eq, Hash, Debug, Clone, Copy, Serialize, Deserialize)]
 /// Describes the get call and what information
 /// the caller is concerned about.
 /// This helps the subconscious avoid unnecessary network calls.
@@ -89,6 +112,20 @@ pub enum GetStrategy {
     /// to the network if it is not found.
     /// Does not go to the network if you are an authority for the data.
     Content,
+    /// Will always go to the network for the latest metadata,
+    /// even if you are an authority for the data.
+    Network,
+    /// Will only use the data returned by the network and never
+    /// looks in the cache or any other local store.
+    NetworkOnly,
+}
+
+impl GetStrategy {
+    /// Does this strategy go to the network even
+    /// when the data is available locally.
+    pub fn always_asks_network(&self) -> bool {
+        matches!(self, GetStrategy::Network | GetStrategy::NetworkOnly)
+    }
 }
 
 /// Structure holding the entry portion of a chain element.
@@ -190,6 +190,20 @@ impl Header {
         }
     }
 
+    /// returns true if an OpenChain header can directly follow this header,
+    /// i.e. this is the agent key that ends genesis or the end of zome initialization
+    pub fn can_precede_open_chain(&self) -> bool {
+        match self {
+            Self::InitZomesComplete(_) => true,
+            Self::Create(Create {
+                entry_type: EntryType::AgentPubKey,
+                header_seq,
+                ..
+            }) => *header_seq == 2,
+            _ => false,
+        }
+    }
+
     /// returns the previous header except for the DNA header which doesn't have a previous
     pub fn prev_header(&self) -> Option<&HeaderHash> {
         Some(match self {
@@ -304,7 +318,8 @@ pub struct DeleteLink {
 }
 
 /// When migrating to a new version of a DNA, this header is committed to the
-/// new chain to declare the migration path taken. **Currently unused**
+/// new chain to declare the migration path taken.
+/// Committed by the `open_chain` host function.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, SerializedBytes)]
 pub struct OpenChain {
     pub author: AgentPubKey,
@@ -316,7 +331,8 @@ pub struct OpenChain {
 }
 
 /// When migrating to a new version of a DNA, this header is committed to the
-/// old chain to declare the migration path taken. **Currently unused**
+/// old chain to declare the migration path taken.
+/// Committed by the `close_chain` host function.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, SerializedBytes)]
 pub struct CloseChain {
     pub author: AgentPubKey,
@@ -125,6 +125,12 @@ wasm_io_types! {
     // Delete an element.
     fn delete (holo_hash::HeaderHash) -> holo_hash::HeaderHash;
 
+    // Commit a CloseChain header pointing at the new DNA.
+    fn close_chain (holo_hash::DnaHash) -> holo_hash::HeaderHash;
+
+    // Commit an OpenChain header pointing at the previous DNA.
+    fn open_chain (holo_hash::DnaHash) -> holo_hash::HeaderHash;
+
     // Header hash of the newly committed element.
     // Emit a Signal::App to subscribers on the interface
     fn emit_signal (zt::signal::AppSignal) -> ();
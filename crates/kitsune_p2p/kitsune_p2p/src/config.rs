@@ -11,6 +11,12 @@ pub const BOOTSTRAP_SERVICE_DEFAULT: &str = "https://bootstrap-staging.holo.host
 /// The default development bootstrap service url.
 pub const BOOTSTRAP_SERVICE_DEV: &str = "https://bootstrap-dev.holohost.workers.dev";
 
+/// The url scheme of a bootstrap service shared by all the nodes in this
+/// process that use the same url, e.g. `kitsune-mem-bootstrap://my-network`.
+/// Nodes using the mem transport can only reach nodes in the same process
+/// so this lets them find each other without a real bootstrap service.
+pub const MEM_BOOTSTRAP_SCHEME: &str = "kitsune-mem-bootstrap";
+
 pub(crate) enum KitsuneP2pTx2Backend {
     Mem,
     Quic { bind_to: TxUrl },
@@ -37,6 +43,11 @@ pub struct KitsuneP2pConfig {
     pub tuning_params: KitsuneP2pTuningParams,
     /// The network used for connecting to other peers
     pub network_type: NetworkType,
+    /// Periodically POST the transport metrics of this node
+    /// as JSON to this url.
+    /// See the `metrics_publish_interval_ms` tuning param.
+    #[serde(default)]
+    pub metrics_endpoint: Option<Url2>,
 }
 
 impl Default for KitsuneP2pConfig {
@@ -46,6 +57,7 @@ impl Default for KitsuneP2pConfig {
             bootstrap_service: None,
             tuning_params: KitsuneP2pTuningParams::default(),
             network_type: NetworkType::QuicBootstrap,
+            metrics_endpoint: None,
         }
     }
 }
@@ -5,6 +5,8 @@ use crate::actor::*;
 use crate::event::*;
 use crate::gossip::*;
 use crate::metrics::KitsuneMetrics;
+use crate::metrics::TransportMetrics;
+use crate::metrics::TransportMetricsTracker;
 use crate::*;
 use futures::future::FutureExt;
 use futures::stream::StreamExt;
@@ -19,6 +21,7 @@ use kitsune_p2p_types::*;
 use std::collections::hash_map::Entry;
 use std::collections::HashMap;
 use std::sync::Arc;
+use std::sync::Weak;
 
 /// The bootstrap service is much more thoroughly documented in the default service implementation.
 /// See https://github.com/holochain/bootstrap
@@ -29,6 +32,9 @@ mod space;
 use ghost_actor::dependencies::tracing;
 use space::*;
 
+/// How long to wait for the bootstrap service when checking it is reachable.
+const CHECK_BOOTSTRAP_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(5);
+
 ghost_actor::ghost_chan! {
     pub(crate) chan Internal<crate::KitsuneP2pError> {
         /// Register space event handler
@@ -44,6 +50,9 @@ pub(crate) struct KitsuneP2pActor {
     ep_hnd: Tx2EpHnd<wire::Wire>,
     spaces: HashMap<Arc<KitsuneSpace>, AsyncLazy<ghost_actor::GhostSender<KitsuneP2p>>>,
     config: Arc<KitsuneP2pConfig>,
+    transport_metrics: Arc<TransportMetricsTracker>,
+    /// Dropped with the actor to stop publishing transport metrics
+    _stop_metrics_publish: Option<tokio::sync::oneshot::Sender<()>>,
 }
 
 impl KitsuneP2pActor {
@@ -92,7 +101,11 @@ impl KitsuneP2pActor {
         conf.tuning_params = Some(config.tuning_params.clone());
         let f = tx2_proxy(f, conf)?;
 
-        let metrics = Tx2ApiMetrics::default().set_write_len(|d, l| {
+        let transport_metrics = Arc::new(TransportMetricsTracker::default());
+
+        let write_metrics = transport_metrics.clone();
+        let metrics = Tx2ApiMetrics::default().set_write_len(move |d, l| {
+            write_metrics.sent(l);
             let t = match d {
                 "Wire::Failure" => KitsuneMetrics::Fail,
                 "Wire::Call" => KitsuneMetrics::Call,
@@ -109,8 +122,21 @@ impl KitsuneP2pActor {
                 "Wire::GossipResp" => KitsuneMetrics::GossipResp,
                 _ => return,
             };
+            if let KitsuneMetrics::FetchOpHashes
+            | KitsuneMetrics::FetchOpHashesResp
+            | KitsuneMetrics::FetchOpData
+            | KitsuneMetrics::FetchOpDataResp
+            | KitsuneMetrics::Gossip
+            | KitsuneMetrics::GossipResp = t
+            {
+                write_metrics.gossip_sent(l);
+            }
             KitsuneMetrics::count(t, l);
         });
+        let read_metrics = transport_metrics.clone();
+        let metrics = metrics.set_read_len(move |l| read_metrics.received(l));
+        let rtt_metrics = transport_metrics.clone();
+        let metrics = metrics.set_request_rtt(move |_, rtt| rtt_metrics.request_answered(rtt));
 
         // wrap in api
         let f = tx2_api(f, metrics);
@@ -166,17 +192,35 @@ impl KitsuneP2pActor {
 
         tracing::info!("this_addr: {}", this_addr);
 
+        let stop_metrics_publish = config.metrics_endpoint.clone().map(|endpoint| {
+            let (stop, stopped) = tokio::sync::oneshot::channel();
+            tokio::task::spawn(publish_transport_metrics(
+                endpoint,
+                config.tuning_params.clone(),
+                Arc::downgrade(&transport_metrics),
+                stopped,
+            ));
+            stop
+        });
+
         tokio::task::spawn({
             let evt_sender = evt_sender.clone();
             let tuning_params = config.tuning_params.clone();
+            let transport_metrics = transport_metrics.clone();
             ep.for_each_concurrent(tuning_params.concurrent_limit_per_thread, move |event| {
                 let evt_sender = evt_sender.clone();
                 let tuning_params = tuning_params.clone();
+                let transport_metrics = transport_metrics.clone();
                 async move {
                     let evt_sender = &evt_sender;
                     use tx2_api::Tx2EpEvent::*;
-                    #[allow(clippy::single_match)]
                     match event {
+                        OutgoingConnection(_) | IncomingConnection(_) => {
+                            transport_metrics.connection_opened();
+                        }
+                        ConnectionClosed(_) => {
+                            transport_metrics.connection_closed();
+                        }
                         IncomingRequest(Tx2EpIncomingRequest { data, respond, .. }) => {
                             match data {
                                 wire::Wire::Call(wire::Call {
@@ -382,10 +426,59 @@ impl KitsuneP2pActor {
             ep_hnd,
             spaces: HashMap::new(),
             config: Arc::new(config),
+            transport_metrics,
+            _stop_metrics_publish: stop_metrics_publish,
         })
     }
 }
 
+/// POST the transport metrics as JSON to the metrics endpoint
+/// every `metrics_publish_interval_ms` until `stopped` resolves,
+/// i.e. when the actor holding its sender is dropped,
+/// or the metrics are no longer being tracked.
+async fn publish_transport_metrics(
+    endpoint: url2::Url2,
+    tuning_params: kitsune_p2p_types::config::KitsuneP2pTuningParams,
+    transport_metrics: Weak<TransportMetricsTracker>,
+    mut stopped: tokio::sync::oneshot::Receiver<()>,
+) {
+    use kitsune_p2p_types::dependencies::serde_json;
+    let client = reqwest::Client::new();
+    let interval =
+        std::time::Duration::from_millis(tuning_params.metrics_publish_interval_ms as u64);
+    loop {
+        tokio::select! {
+            _ = tokio::time::sleep(interval) => (),
+            _ = &mut stopped => break,
+        }
+        let snapshot = match transport_metrics.upgrade() {
+            Some(transport_metrics) => transport_metrics.snapshot(),
+            None => break,
+        };
+        let body = match serde_json::to_vec(&snapshot) {
+            Ok(body) => body,
+            Err(e) => {
+                tracing::warn!(msg = "failed to serialize transport metrics", ?e);
+                continue;
+            }
+        };
+        // A missing metrics service shouldn't affect the network
+        // so failures are only logged.
+        if let Err(e) = client
+            .post(endpoint.as_str())
+            .header(reqwest::header::CONTENT_TYPE, "application/json")
+            .body(body)
+            // Don't let a slow endpoint back up the publishing
+            .timeout(interval)
+            .send()
+            .await
+        {
+            tracing::warn!(msg = "failed to publish transport metrics", ?e);
+        }
+    }
+    tracing::debug!("stopped publishing transport metrics");
+}
+
 async fn agent_info_query(
     q: wire::AgentInfoQuery,
     evt_sender: futures::channel::mpsc::Sender<KitsuneP2pEvent>,
@@ -527,6 +620,28 @@ impl KitsuneP2pHandler for KitsuneP2pActor {
         Ok(async move { Ok(this_addr) }.boxed().into())
     }
 
+    fn handle_get_transport_metrics(&mut self) -> KitsuneP2pHandlerResult<TransportMetrics> {
+        let metrics = self.transport_metrics.snapshot();
+        Ok(async move { Ok(metrics) }.boxed().into())
+    }
+
+    fn handle_check_bootstrap(&mut self) -> KitsuneP2pHandlerResult<Option<bool>> {
+        let bootstrap_service = match self.config.bootstrap_service.clone() {
+            Some(url) => url,
+            None => return Ok(async move { Ok(None) }.boxed().into()),
+        };
+        Ok(async move {
+            let reachable = tokio::time::timeout(
+                CHECK_BOOTSTRAP_TIMEOUT,
+                bootstrap::now(Some(bootstrap_service)),
+            )
+            .await;
+            Ok(Some(matches!(reachable, Ok(Ok(_)))))
+        }
+        .boxed()
+        .into())
+    }
+
     fn handle_join(
         &mut self,
         space: Arc<KitsuneSpace>,
@@ -537,12 +652,14 @@ impl KitsuneP2pHandler for KitsuneP2pActor {
         let this_addr = self.this_addr.clone();
         let ep_hnd = self.ep_hnd.clone();
         let config = Arc::clone(&self.config);
+        let transport_metrics = self.transport_metrics.clone();
         let space_sender = match self.spaces.entry(space.clone()) {
             Entry::Occupied(entry) => entry.into_mut(),
             Entry::Vacant(entry) => entry.insert(AsyncLazy::new(async move {
-                let (send, evt_recv) = spawn_space(space2, this_addr, ep_hnd, config)
-                    .await
-                    .expect("cannot fail to create space");
+                let (send, evt_recv) =
+                    spawn_space(space2, this_addr, ep_hnd, config, transport_metrics)
+                        .await
+                        .expect("cannot fail to create space");
                 internal_sender
                     .register_space_event_handler(evt_recv)
                     .await
@@ -573,6 +690,18 @@ impl KitsuneP2pHandler for KitsuneP2pActor {
         .into())
     }
 
+    fn handle_trigger_gossip(&mut self, space: Arc<KitsuneSpace>) -> KitsuneP2pHandlerResult<()> {
+        let space_sender = match self.spaces.get_mut(&space) {
+            None => return Err(KitsuneP2pError::RoutingSpaceError(space)),
+            Some(space) => space.get(),
+        };
+        Ok(
+            async move { space_sender.await.trigger_gossip(space).await }
+                .boxed()
+                .into(),
+        )
+    }
+
     fn handle_rpc_single(
         &mut self,
         space: Arc<KitsuneSpace>,
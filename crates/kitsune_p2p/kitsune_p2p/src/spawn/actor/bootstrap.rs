@@ -1,11 +1,16 @@
+use crate::config::MEM_BOOTSTRAP_SCHEME;
+use crate::types::agent_store::AgentInfo;
 use crate::types::agent_store::AgentInfoSigned;
+use crate::types::KitsuneAgent;
 use crate::types::KitsuneBinType;
 use crate::types::KitsuneSpace;
 use once_cell::sync::Lazy;
 use once_cell::sync::OnceCell;
+use std::collections::HashMap;
 use std::convert::TryFrom;
 use std::convert::TryInto;
 use std::sync::Arc;
+use std::sync::Mutex;
 use url2::Url2;
 
 /// Reuse a single reqwest Client for efficiency as we likely need several connections.
@@ -24,6 +29,23 @@ const RANDOM_LIMIT_DEFAULT: u32 = 16;
 /// relative to the expiry times.
 pub static NOW_OFFSET_MILLIS: OnceCell<i64> = OnceCell::new();
 
+/// The agent infos put to each in-process bootstrap network.
+/// Keyed by the network name, then the space and agent of the info.
+static MEM_NETWORKS: Lazy<Mutex<HashMap<String, MemNetwork>>> = Lazy::new(Default::default);
+
+/// The latest info of each agent along with the time it expires at in milliseconds.
+type MemNetwork = HashMap<(KitsuneSpace, KitsuneAgent), (u64, AgentInfoSigned)>;
+
+/// The name of the in-process network if the url is a mem bootstrap url.
+fn mem_network_name(url: &Option<Url2>) -> Option<String> {
+    match url {
+        Some(url) if url.scheme() == MEM_BOOTSTRAP_SCHEME => {
+            Some(url.host_str().unwrap_or_default().to_string())
+        }
+        _ => None,
+    }
+}
+
 /// The HTTP header name for setting the op on POST requests.
 const OP_HEADER: &str = "X-Op";
 /// The header op to tell the service to put a signed agent info.
@@ -79,6 +101,18 @@ pub async fn put(
     url: Option<Url2>,
     agent_info_signed: crate::types::agent_store::AgentInfoSigned,
 ) -> crate::types::actor::KitsuneP2pResult<()> {
+    if let Some(name) = mem_network_name(&url) {
+        let info = AgentInfo::try_from(&agent_info_signed)?;
+        let key = (info.as_space_ref().clone(), info.as_agent_ref().clone());
+        let expires_at_ms = info.signed_at_ms() + info.expires_after_ms();
+        MEM_NETWORKS
+            .lock()
+            .expect("mem bootstrap lock poisoned")
+            .entry(name)
+            .or_default()
+            .insert(key, (expires_at_ms, agent_info_signed));
+        return Ok(());
+    }
     match do_api(url, OP_PUT, agent_info_signed).await {
         Ok(Some(())) => Ok(()),
         Ok(None) => Ok(()),
@@ -99,6 +133,10 @@ fn local_now() -> crate::types::actor::KitsuneP2pResult<u64> {
 /// There is no input to the `now` endpoint, just `()` to be encoded as nil in messagepack.
 #[allow(dead_code)]
 pub async fn now(url: Option<Url2>) -> crate::types::actor::KitsuneP2pResult<u64> {
+    // An in-process network shares our clock.
+    if mem_network_name(&url).is_some() {
+        return local_now();
+    }
     match do_api(url, OP_NOW, ()).await {
         // If the server gives us something useful we use it.
         Ok(Some(v)) => Ok(v),
@@ -186,6 +224,22 @@ pub async fn random(
     url: Option<Url2>,
     query: RandomQuery,
 ) -> crate::types::actor::KitsuneP2pResult<Vec<AgentInfoSigned>> {
+    if let Some(name) = mem_network_name(&url) {
+        let now = local_now()?;
+        let mut networks = MEM_NETWORKS.lock().expect("mem bootstrap lock poisoned");
+        let network = networks.entry(name).or_default();
+        // Agents that stopped refreshing their info are gone.
+        network.retain(|_, (expires_at_ms, _)| *expires_at_ms > now);
+        let mut list: Vec<AgentInfoSigned> = network
+            .iter()
+            .filter(|((space, _), _)| *space == *query.space)
+            .map(|(_, (_, info))| info.clone())
+            .collect();
+        drop(networks);
+        rand::seq::SliceRandom::shuffle(&mut list[..], &mut rand::thread_rng());
+        list.truncate(query.limit.0 as usize);
+        return Ok(list);
+    }
     let outer_vec: Vec<serde_bytes::ByteBuf> = match do_api(url, OP_RANDOM, query).await {
         Ok(Some(v)) => v,
         Ok(None) => Vec::new(),
@@ -368,4 +422,65 @@ mod tests {
         assert!(random_single.len() == 1);
         assert!(expected[0] == random_single[0] || expected[1] == random_single[0]);
     }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_mem_bootstrap() {
+        let space = fixt!(KitsuneSpace, Unpredictable);
+        let other_space = fixt!(KitsuneSpace, Unpredictable);
+        let now = super::local_now().unwrap();
+        let info_signed_at = |space: &KitsuneSpace, signed_at_ms: u64| {
+            let mut data = Vec::new();
+            kitsune_p2p_types::codec::rmp_encode(
+                &mut data,
+                &AgentInfo::new(
+                    space.clone(),
+                    fixt!(KitsuneAgent),
+                    fixt!(Urls),
+                    signed_at_ms,
+                    60_000,
+                ),
+            )
+            .unwrap();
+            AgentInfoSigned::try_new(fixt!(KitsuneAgent), fixt!(KitsuneSignature), data).unwrap()
+        };
+        let info = |space: &KitsuneSpace| info_signed_at(space, now);
+        let url = Some(url2::url2!(
+            "{}://test-mem-bootstrap",
+            crate::config::MEM_BOOTSTRAP_SCHEME
+        ));
+        let other_url = Some(url2::url2!(
+            "{}://test-mem-bootstrap-other",
+            crate::config::MEM_BOOTSTRAP_SCHEME
+        ));
+
+        let mut expected = vec![info(&space), info(&space)];
+        for agent_info_signed in expected.iter() {
+            super::put(url.clone(), agent_info_signed.clone())
+                .await
+                .unwrap();
+        }
+        // Infos in other spaces or networks are not returned.
+        super::put(url.clone(), info(&other_space)).await.unwrap();
+        super::put(other_url.clone(), info(&space)).await.unwrap();
+        // Neither are expired infos.
+        super::put(url.clone(), info_signed_at(&space, now - 120_000))
+            .await
+            .unwrap();
+
+        let query = |limit: u32| super::RandomQuery {
+            space: Arc::new(space.clone()),
+            limit: limit.into(),
+        };
+        let mut random = super::random(url.clone(), query(8)).await.unwrap();
+        expected.sort();
+        random.sort();
+        assert_eq!(random, expected);
+
+        let random_single = super::random(url.clone(), query(1)).await.unwrap();
+        assert_eq!(random_single.len(), 1);
+        assert!(expected.contains(&random_single[0]));
+
+        // The network shares our clock.
+        assert!(super::now(url).await.is_ok());
+    }
 }
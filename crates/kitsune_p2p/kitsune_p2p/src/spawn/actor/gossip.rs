@@ -3,6 +3,7 @@
 
 use crate::types::actor::KitsuneP2pResult;
 use crate::types::gossip::*;
+use crate::types::metrics::TransportMetricsTracker;
 use crate::*;
 use ghost_actor::dependencies::tracing;
 use ghost_actor::GhostError;
@@ -38,22 +39,31 @@ ghost_actor::ghost_chan! {
 pub type GossipEventReceiver = futures::channel::mpsc::Receiver<GossipEvent>;
 
 /// spawn a gossip module to control gossip for a space
-pub fn spawn_gossip_module(config: Arc<KitsuneP2pConfig>) -> GossipEventReceiver {
+/// notifying `trigger` runs a round of gossip with every known agent straight away
+pub(crate) fn spawn_gossip_module(
+    config: Arc<KitsuneP2pConfig>,
+    transport_metrics: Arc<TransportMetricsTracker>,
+    trigger: Arc<tokio::sync::Notify>,
+) -> GossipEventReceiver {
     let (evt_send, evt_recv) = futures::channel::mpsc::channel(10);
 
-    tokio::task::spawn(gossip_loop(config, evt_send));
+    tokio::task::spawn(gossip_loop(config, evt_send, transport_metrics, trigger));
 
     evt_recv
 }
 
-#[tracing::instrument(skip(config, evt_send))]
+#[tracing::instrument(skip(config, evt_send, transport_metrics, trigger))]
 /// the gossip module is not an actor because we want to pause while
 /// awaiting requests - not process requests in parallel.
 async fn gossip_loop(
     config: Arc<KitsuneP2pConfig>,
     evt_send: futures::channel::mpsc::Sender<GossipEvent>,
+    transport_metrics: Arc<TransportMetricsTracker>,
+    trigger: Arc<tokio::sync::Notify>,
 ) -> KitsuneP2pResult<()> {
-    let mut gossip_data = GossipData::new(evt_send);
+    let mut gossip_data = GossipData::new(evt_send, transport_metrics);
+    // a triggered round runs through all its pairs without the delay
+    let mut triggered = false;
     loop {
         match gossip_data.take_action().await {
             Err(KitsuneP2pError::GhostError(GhostError::Disconnected)) => {
@@ -66,10 +76,21 @@ async fn gossip_loop(
             Ok(_) => (),
         }
 
-        tokio::time::sleep(std::time::Duration::from_millis(
-            config.tuning_params.gossip_loop_iteration_delay_ms as u64,
-        ))
-        .await;
+        if triggered && !gossip_data.pending_gossip_list.is_empty() {
+            continue;
+        }
+        triggered = false;
+
+        tokio::select! {
+            _ = tokio::time::sleep(std::time::Duration::from_millis(
+                config.tuning_params.gossip_loop_iteration_delay_ms as u64,
+            )) => (),
+            _ = trigger.notified() => {
+                // start the round with a fresh list of agents
+                gossip_data.pending_gossip_list.clear();
+                triggered = true;
+            }
+        }
     }
 }
 
@@ -77,14 +98,19 @@ struct GossipData {
     evt_send: futures::channel::mpsc::Sender<GossipEvent>,
     pending_gossip_list: Vec<(Arc<KitsuneAgent>, Arc<KitsuneAgent>)>,
     last_counts: HashMap<Arc<KitsuneAgent>, (u64, u64)>,
+    transport_metrics: Arc<TransportMetricsTracker>,
 }
 
 impl GossipData {
-    pub fn new(evt_send: futures::channel::mpsc::Sender<GossipEvent>) -> Self {
+    pub fn new(
+        evt_send: futures::channel::mpsc::Sender<GossipEvent>,
+        transport_metrics: Arc<TransportMetricsTracker>,
+    ) -> Self {
         Self {
             evt_send,
             pending_gossip_list: Vec::new(),
             last_counts: HashMap::new(),
+            transport_metrics,
         }
     }
 
@@ -93,6 +119,7 @@ impl GossipData {
             self.fetch_pending_gossip_list().await?;
         } else {
             self.process_next_gossip().await?;
+            self.transport_metrics.gossip_round();
         }
         Ok(())
     }
@@ -29,6 +29,7 @@ pub(crate) async fn spawn_space(
     this_addr: url2::Url2,
     ep_hnd: Tx2EpHnd<wire::Wire>,
     config: Arc<KitsuneP2pConfig>,
+    transport_metrics: Arc<TransportMetricsTracker>,
 ) -> KitsuneP2pResult<(
     ghost_actor::GhostSender<KitsuneP2p>,
     KitsuneP2pEventReceiver,
@@ -38,7 +39,9 @@ pub(crate) async fn spawn_space(
     let builder = ghost_actor::actor_builder::GhostActorBuilder::new();
 
     // initialize gossip module
-    let gossip_recv = gossip::spawn_gossip_module(config.clone());
+    let gossip_trigger = Arc::new(tokio::sync::Notify::new());
+    let gossip_recv =
+        gossip::spawn_gossip_module(config.clone(), transport_metrics, gossip_trigger.clone());
     builder
         .channel_factory()
         .attach_receiver(gossip_recv)
@@ -54,7 +57,15 @@ pub(crate) async fn spawn_space(
         .create_channel::<KitsuneP2p>()
         .await?;
 
-    tokio::task::spawn(builder.spawn(Space::new(space, this_addr, i_s, evt_send, ep_hnd, config)));
+    tokio::task::spawn(builder.spawn(Space::new(
+        space,
+        this_addr,
+        i_s,
+        evt_send,
+        ep_hnd,
+        config,
+        gossip_trigger,
+    )));
 
     Ok((sender, evt_recv))
 }
@@ -525,6 +536,18 @@ impl KitsuneP2pHandler for Space {
         )
     }
 
+    fn handle_get_transport_metrics(&mut self) -> KitsuneP2pHandlerResult<TransportMetrics> {
+        unreachable!(
+            "These requests are handled at the to actor level and are never propagated down to the space."
+        )
+    }
+
+    fn handle_check_bootstrap(&mut self) -> KitsuneP2pHandlerResult<Option<bool>> {
+        unreachable!(
+            "These requests are handled at the to actor level and are never propagated down to the space."
+        )
+    }
+
     fn handle_join(
         &mut self,
         space: Arc<KitsuneSpace>,
@@ -626,6 +649,11 @@ impl KitsuneP2pHandler for Space {
         Ok(async move { Ok(()) }.boxed().into())
     }
 
+    fn handle_trigger_gossip(&mut self, _space: Arc<KitsuneSpace>) -> KitsuneP2pHandlerResult<()> {
+        self.gossip_trigger.notify_one();
+        Ok(async move { Ok(()) }.boxed().into())
+    }
+
     fn handle_rpc_single(
         &mut self,
         space: Arc<KitsuneSpace>,
@@ -763,6 +791,8 @@ pub(crate) struct Space {
     pub(crate) ep_hnd: Tx2EpHnd<wire::Wire>,
     pub(crate) local_joined_agents: HashSet<Arc<KitsuneAgent>>,
     pub(crate) config: Arc<KitsuneP2pConfig>,
+    /// Wakes the gossip loop for a round of gossip.
+    gossip_trigger: Arc<tokio::sync::Notify>,
     mdns_handles: HashMap<Vec<u8>, Arc<AtomicBool>>,
     mdns_listened_spaces: HashSet<String>,
 }
@@ -776,6 +806,7 @@ impl Space {
         evt_sender: futures::channel::mpsc::Sender<KitsuneP2pEvent>,
         ep_hnd: Tx2EpHnd<wire::Wire>,
         config: Arc<KitsuneP2pConfig>,
+        gossip_trigger: Arc<tokio::sync::Notify>,
     ) -> Self {
         let i_s_c = i_s.clone();
         tokio::task::spawn(async move {
@@ -795,6 +826,7 @@ impl Space {
             ep_hnd,
             local_joined_agents: HashSet::new(),
             config,
+            gossip_trigger,
             mdns_handles: HashMap::new(),
             mdns_listened_spaces: HashSet::new(),
         }
@@ -69,12 +69,23 @@ ghost_actor::ghost_chan! {
         /// Get the calculated transport bindings.
         fn list_transport_bindings() -> Vec<Url2>;
 
+        /// Get a snapshot of the transport activity of this node.
+        fn get_transport_metrics() -> super::metrics::TransportMetrics;
+
+        /// Check whether the bootstrap service responds.
+        /// Returns `None` if there is no bootstrap service configured.
+        fn check_bootstrap() -> Option<bool>;
+
         /// Announce a space/agent pair on this network.
         fn join(space: Arc<super::KitsuneSpace>, agent: Arc<super::KitsuneAgent>) -> ();
 
         /// Withdraw this space/agent pair from this network.
         fn leave(space: Arc<super::KitsuneSpace>, agent: Arc<super::KitsuneAgent>) -> ();
 
+        /// Gossip with every known agent in this space now
+        /// instead of waiting for the gossip loop's next iteration.
+        fn trigger_gossip(space: Arc<super::KitsuneSpace>) -> ();
+
         /// Make a request of a single remote agent, expecting a response.
         /// The remote side will receive a "Call" event.
         fn rpc_single(space: Arc<super::KitsuneSpace>, to_agent: Arc<super::KitsuneAgent>, from_agent: Arc<super::KitsuneAgent>, payload: Vec<u8>, timeout_ms: Option<u64>) -> Vec<u8>;
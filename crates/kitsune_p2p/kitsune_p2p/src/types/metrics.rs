@@ -1,4 +1,6 @@
 use ghost_actor::dependencies::tracing;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, Instant};
 
 observability::metrics!(
     KitsuneMetrics,
@@ -92,3 +94,227 @@ pub fn init() {
         }
     }
 }
+
+/// A snapshot of the transport activity of a kitsune endpoint.
+#[derive(Clone, Debug, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct TransportMetrics {
+    /// Connections that are currently open, incoming and outgoing.
+    pub open_connections: u64,
+    /// Total bytes written to remote connections.
+    pub bytes_sent: u64,
+    /// Total bytes read from remote connections.
+    pub bytes_received: u64,
+    /// Gossip rounds completed across all spaces.
+    pub gossip_rounds: u64,
+    /// Gossip rounds completed in the last [`RECENT_GOSSIP_WINDOW`].
+    pub recent_gossip_rounds: u64,
+    /// Bytes of gossip written in the last [`RECENT_GOSSIP_WINDOW`].
+    pub recent_gossip_bytes_sent: u64,
+    /// Requests to remotes that were answered in the last [`RECENT_GOSSIP_WINDOW`].
+    pub recent_requests: u64,
+    /// The mean round trip time in microseconds of the requests
+    /// answered in the last [`RECENT_GOSSIP_WINDOW`],
+    /// or `None` if there were none.
+    pub recent_request_rtt_us: Option<u64>,
+}
+
+/// How far back the recent gossip counts in [`TransportMetrics`] go.
+pub const RECENT_GOSSIP_WINDOW: Duration = Duration::from_secs(60);
+
+/// A sum of the values added within the [`RECENT_GOSSIP_WINDOW`].
+///
+/// Values are counted in one bucket per second of the window so the memory
+/// used is fixed and adding never takes a lock. A value added while its
+/// bucket is being reused for a new second can be lost, which is fine for
+/// a metric.
+#[derive(Debug)]
+struct RecentSum {
+    start: Instant,
+    buckets: Box<[RecentBucket]>,
+}
+
+/// The seconds in the [`RECENT_GOSSIP_WINDOW`], one bucket each.
+const RECENT_BUCKETS: usize = 60;
+
+#[derive(Debug, Default)]
+struct RecentBucket {
+    /// The second since `start` this bucket is counting.
+    second: AtomicU64,
+    sum: AtomicU64,
+}
+
+impl Default for RecentSum {
+    fn default() -> Self {
+        Self {
+            start: Instant::now(),
+            buckets: (0..RECENT_BUCKETS)
+                .map(|_| RecentBucket::default())
+                .collect(),
+        }
+    }
+}
+
+impl RecentSum {
+    fn add(&self, v: u64) {
+        self.add_at(self.now(), v)
+    }
+
+    fn sum(&self) -> u64 {
+        self.sum_at(self.now())
+    }
+
+    fn now(&self) -> u64 {
+        self.start.elapsed().as_secs()
+    }
+
+    fn add_at(&self, second: u64, v: u64) {
+        let bucket = &self.buckets[second as usize % RECENT_BUCKETS];
+        let old = bucket.second.load(Ordering::Relaxed);
+        if old != second
+            && bucket
+                .second
+                .compare_exchange(old, second, Ordering::Relaxed, Ordering::Relaxed)
+                .is_ok()
+        {
+            // This bucket last counted a second that has left the window.
+            bucket.sum.store(0, Ordering::Relaxed);
+        }
+        bucket.sum.fetch_add(v, Ordering::Relaxed);
+    }
+
+    fn sum_at(&self, now: u64) -> u64 {
+        self.buckets
+            .iter()
+            .filter(|bucket| {
+                now.saturating_sub(bucket.second.load(Ordering::Relaxed))
+                    < RECENT_GOSSIP_WINDOW.as_secs()
+            })
+            .map(|bucket| bucket.sum.load(Ordering::Relaxed))
+            .sum()
+    }
+}
+
+/// Tracks the counters behind [`TransportMetrics`] for a single kitsune actor.
+/// Unlike [`KitsuneMetrics`] these are always on and not shared between actors.
+#[derive(Debug, Default)]
+pub(crate) struct TransportMetricsTracker {
+    open_connections: AtomicU64,
+    bytes_sent: AtomicU64,
+    bytes_received: AtomicU64,
+    gossip_rounds: AtomicU64,
+    recent_gossip_rounds: RecentSum,
+    recent_gossip_bytes_sent: RecentSum,
+    recent_requests: RecentSum,
+    recent_request_rtt_us: RecentSum,
+}
+
+impl TransportMetricsTracker {
+    pub(crate) fn connection_opened(&self) {
+        self.open_connections.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub(crate) fn connection_closed(&self) {
+        // Never underflow if a close is seen for a connection
+        // that was opened before we started counting.
+        let _ = self
+            .open_connections
+            .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |n| {
+                Some(n.saturating_sub(1))
+            });
+    }
+
+    pub(crate) fn sent(&self, len: usize) {
+        self.bytes_sent.fetch_add(len as u64, Ordering::Relaxed);
+    }
+
+    pub(crate) fn received(&self, len: usize) {
+        self.bytes_received.fetch_add(len as u64, Ordering::Relaxed);
+    }
+
+    pub(crate) fn gossip_round(&self) {
+        self.gossip_rounds.fetch_add(1, Ordering::Relaxed);
+        self.recent_gossip_rounds.add(1);
+    }
+
+    pub(crate) fn gossip_sent(&self, len: usize) {
+        self.recent_gossip_bytes_sent.add(len as u64);
+    }
+
+    pub(crate) fn request_answered(&self, rtt: Duration) {
+        self.recent_requests.add(1);
+        self.recent_request_rtt_us.add(rtt.as_micros() as u64);
+    }
+
+    pub(crate) fn snapshot(&self) -> TransportMetrics {
+        let recent_requests = self.recent_requests.sum();
+        // The sums are read separately so a request answered in between
+        // can skew the mean a little, which is fine for a metric.
+        let recent_request_rtt_us = match recent_requests {
+            0 => None,
+            n => Some(self.recent_request_rtt_us.sum() / n),
+        };
+        TransportMetrics {
+            open_connections: self.open_connections.load(Ordering::Relaxed),
+            bytes_sent: self.bytes_sent.load(Ordering::Relaxed),
+            bytes_received: self.bytes_received.load(Ordering::Relaxed),
+            gossip_rounds: self.gossip_rounds.load(Ordering::Relaxed),
+            recent_gossip_rounds: self.recent_gossip_rounds.sum(),
+            recent_gossip_bytes_sent: self.recent_gossip_bytes_sent.sum(),
+            recent_requests,
+            recent_request_rtt_us,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn transport_metrics_are_tracked() {
+        let tracker = TransportMetricsTracker::default();
+        // A close without an open doesn't wrap around.
+        tracker.connection_closed();
+        tracker.connection_opened();
+        tracker.connection_opened();
+        tracker.connection_closed();
+        tracker.sent(10);
+        tracker.received(20);
+        tracker.gossip_round();
+        tracker.gossip_sent(5);
+        tracker.gossip_sent(6);
+        assert_eq!(tracker.snapshot().recent_request_rtt_us, None);
+        tracker.request_answered(Duration::from_micros(100));
+        tracker.request_answered(Duration::from_micros(300));
+        assert_eq!(
+            tracker.snapshot(),
+            TransportMetrics {
+                open_connections: 1,
+                bytes_sent: 10,
+                bytes_received: 20,
+                gossip_rounds: 1,
+                recent_gossip_rounds: 1,
+                recent_gossip_bytes_sent: 11,
+                recent_requests: 2,
+                recent_request_rtt_us: Some(200),
+            }
+        );
+    }
+
+    #[test]
+    fn recent_sum_only_counts_the_window() {
+        let recent = RecentSum::default();
+        recent.add_at(0, 1);
+        recent.add_at(0, 2);
+        recent.add_at(30, 4);
+        assert_eq!(recent.sum_at(30), 7);
+        assert_eq!(recent.sum_at(59), 7);
+        // The first second has left the window.
+        assert_eq!(recent.sum_at(60), 4);
+        // Its bucket is reused without the old values.
+        recent.add_at(60, 8);
+        assert_eq!(recent.sum_at(60), 12);
+        assert_eq!(recent.sum_at(90), 8);
+        assert_eq!(recent.sum_at(200), 0);
+    }
+}
@@ -137,6 +137,11 @@ pub mod tuning_params_struct {
         /// multiplied by 2x on every loop)
         /// [Default: 200 ms]
         tx2_initial_connect_retry_delay_ms: usize = 200,
+
+        /// How often transport metrics are published
+        /// when a metrics endpoint is configured.
+        /// [Default: 1 minute]
+        metrics_publish_interval_ms: u32 = 1000 * 60,
     }
 
     impl KitsuneP2pTuningParams {
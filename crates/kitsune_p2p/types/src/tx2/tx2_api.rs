@@ -311,13 +311,18 @@ impl<C: Codec + 'static + Send + Unpin> Tx2ConHnd<C> {
                 peer_cert,
             )?;
 
+            let start = std::time::Instant::now();
             this.con
                 .write(MsgId::new(msg_id).as_req(), data, timeout)
                 .await?;
 
             this.metrics.write_len(dbg_name, len);
 
-            timeout.mix(r_res.map_err(KitsuneError::other)).await?
+            let res = timeout.mix(r_res.map_err(KitsuneError::other)).await?;
+            if res.is_ok() {
+                this.metrics.request_rtt(dbg_name, start.elapsed());
+            }
+            res
         }
     }
 
@@ -457,6 +462,7 @@ pub struct Tx2Respond<C: Codec + 'static + Send + Unpin> {
     req_byte_count: usize,
     con: ConHnd,
     msg_id: u64,
+    metrics: Arc<Tx2ApiMetrics>,
     _p: std::marker::PhantomData<C>,
 }
 
@@ -474,6 +480,7 @@ impl<C: Codec + 'static + Send + Unpin> Tx2Respond<C> {
         req_byte_count: usize,
         con: ConHnd,
         msg_id: u64,
+        metrics: Arc<Tx2ApiMetrics>,
     ) -> Self {
         let time = std::time::Instant::now();
         Self {
@@ -484,6 +491,7 @@ impl<C: Codec + 'static + Send + Unpin> Tx2Respond<C> {
             req_byte_count,
             con,
             msg_id,
+            metrics,
             _p: std::marker::PhantomData,
         }
     }
@@ -502,6 +510,7 @@ impl<C: Codec + 'static + Send + Unpin> Tx2Respond<C> {
             req_byte_count,
             con,
             msg_id,
+            metrics,
             ..
         } = self;
         async move {
@@ -522,7 +531,11 @@ impl<C: Codec + 'static + Send + Unpin> Tx2Respond<C> {
                 "(api) res",
             );
 
-            con.write(MsgId::new(msg_id).as_res(), buf, timeout).await
+            con.write(MsgId::new(msg_id).as_res(), buf, timeout).await?;
+
+            metrics.write_len(resp_dbg_name, resp_byte_count);
+
+            Ok(())
         }
     }
 }
@@ -635,6 +648,7 @@ impl<C: Codec + 'static + Send + Unpin> Stream for Tx2Ep<C> {
                     }) => {
                         let peer_cert = con.peer_cert();
                         let len = data.len();
+                        self.2.read_len(len);
                         let (_, c) = match C::decode_ref(&data) {
                             Err(e) => {
                                 // TODO - close connection?
@@ -664,6 +678,7 @@ impl<C: Codec + 'static + Send + Unpin> Stream for Tx2Ep<C> {
                                     len,
                                     con,
                                     msg_id.as_id(),
+                                    self.2.clone(),
                                 ),
                             }),
                             MsgIdType::Res => {
@@ -718,10 +733,14 @@ impl<C: Codec + 'static + Send + Unpin> Tx2Ep<C> {
 }
 
 type WriteLenCb = Box<dyn Fn(&'static str, usize) + 'static + Send + Sync>;
+type ReadLenCb = Box<dyn Fn(usize) + 'static + Send + Sync>;
+type RequestRttCb = Box<dyn Fn(&'static str, std::time::Duration) + 'static + Send + Sync>;
 
 /// Metrics callback manager to be injected into the endpoint
 pub struct Tx2ApiMetrics {
     write_len: Option<WriteLenCb>,
+    read_len: Option<ReadLenCb>,
+    request_rtt: Option<RequestRttCb>,
 }
 
 impl Default for Tx2ApiMetrics {
@@ -733,7 +752,11 @@ impl Default for Tx2ApiMetrics {
 impl Tx2ApiMetrics {
     /// Construct a new default Tx2ApiMetrics with no set callbacks
     pub fn new() -> Self {
-        Self { write_len: None }
+        Self {
+            write_len: None,
+            read_len: None,
+            request_rtt: None,
+        }
     }
 
     /// This callback will be invoked when we successfully write data
@@ -747,11 +770,45 @@ impl Tx2ApiMetrics {
         self
     }
 
+    /// This callback will be invoked when we read data
+    /// from a transport connection.
+    pub fn set_read_len<F>(mut self, f: F) -> Self
+    where
+        F: Fn(usize) + 'static + Send + Sync,
+    {
+        let f: ReadLenCb = Box::new(f);
+        self.read_len = Some(f);
+        self
+    }
+
+    /// This callback will be invoked when a response is received
+    /// for a request, with how long it took from writing the request.
+    pub fn set_request_rtt<F>(mut self, f: F) -> Self
+    where
+        F: Fn(&'static str, std::time::Duration) + 'static + Send + Sync,
+    {
+        let f: RequestRttCb = Box::new(f);
+        self.request_rtt = Some(f);
+        self
+    }
+
     fn write_len(&self, d: &'static str, l: usize) {
         if let Some(cb) = &self.write_len {
             cb(d, l)
         }
     }
+
+    fn read_len(&self, l: usize) {
+        if let Some(cb) = &self.read_len {
+            cb(l)
+        }
+    }
+
+    fn request_rtt(&self, d: &'static str, rtt: std::time::Duration) {
+        if let Some(cb) = &self.request_rtt {
+            cb(d, rtt)
+        }
+    }
 }
 
 /// Construct a new Tx2EpFactory instance from a pool EpFactory
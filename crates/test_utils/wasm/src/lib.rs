@@ -20,12 +20,15 @@ pub enum TestWasm {
     HashPath,
     HdkExtern,
     InitFail,
+    InitMarker,
     InitPass,
     Link,
     MigrateAgentFail,
+    MigrateAgentFailReason,
     MigrateAgentPass,
     MultipleCalls,
     PostCommitFail,
+    PostCommitRecord,
     PostCommitSuccess,
     Query,
     RandomBytes,
@@ -42,6 +45,7 @@ pub enum TestWasm {
     ValidateCreateLinkValid,
     ValidationPackageFail,
     ValidationPackageSuccess,
+    ValidationPackageUnresolved,
     WhoAmI,
     ZomeInfo,
 }
@@ -64,12 +68,15 @@ impl From<TestWasm> for ZomeName {
             TestWasm::HashPath => "hash_path",
             TestWasm::HdkExtern => "hdk_extern",
             TestWasm::InitFail => "init_fail",
+            TestWasm::InitMarker => "init_marker",
             TestWasm::InitPass => "init_pass",
             TestWasm::Link => "link",
             TestWasm::MigrateAgentFail => "migrate_agent_fail",
+            TestWasm::MigrateAgentFailReason => "migrate_agent_fail_reason",
             TestWasm::MigrateAgentPass => "migrate_agent_pass",
             TestWasm::MultipleCalls => "multiple_calls",
             TestWasm::PostCommitFail => "post_commit_fail",
+            TestWasm::PostCommitRecord => "post_commit_record",
             TestWasm::PostCommitSuccess => "post_commit_success",
             TestWasm::Query => "query",
             TestWasm::RandomBytes => "random_bytes",
@@ -86,6 +93,7 @@ impl From<TestWasm> for ZomeName {
             TestWasm::ValidateCreateLinkValid => "validate_link_add_valid",
             TestWasm::ValidationPackageFail => "validation_package_fail",
             TestWasm::ValidationPackageSuccess => "validation_package_success",
+            TestWasm::ValidationPackageUnresolved => "validation_package_unresolved",
             TestWasm::WhoAmI => "whoami",
             TestWasm::ZomeInfo => "zome_info",
         })
@@ -128,6 +136,9 @@ impl From<TestWasm> for DnaWasm {
             TestWasm::InitFail => {
                 get_code("wasm32-unknown-unknown/release/test_wasm_init_fail.wasm")
             }
+            TestWasm::InitMarker => {
+                get_code("wasm32-unknown-unknown/release/test_wasm_init_marker.wasm")
+            }
             TestWasm::InitPass => {
                 get_code("wasm32-unknown-unknown/release/test_wasm_init_pass.wasm")
             }
@@ -135,6 +146,9 @@ impl From<TestWasm> for DnaWasm {
             TestWasm::MigrateAgentFail => {
                 get_code("wasm32-unknown-unknown/release/test_wasm_migrate_agent_fail.wasm")
             }
+            TestWasm::MigrateAgentFailReason => {
+                get_code("wasm32-unknown-unknown/release/test_wasm_migrate_agent_fail_reason.wasm")
+            }
             TestWasm::MigrateAgentPass => {
                 get_code("wasm32-unknown-unknown/release/test_wasm_migrate_agent_pass.wasm")
             }
@@ -144,6 +158,9 @@ impl From<TestWasm> for DnaWasm {
             TestWasm::PostCommitFail => {
                 get_code("wasm32-unknown-unknown/release/test_wasm_post_commit_fail.wasm")
             }
+            TestWasm::PostCommitRecord => {
+                get_code("wasm32-unknown-unknown/release/test_wasm_post_commit_record.wasm")
+            }
             TestWasm::PostCommitSuccess => {
                 get_code("wasm32-unknown-unknown/release/test_wasm_post_commit_success.wasm")
             }
@@ -186,6 +203,9 @@ impl From<TestWasm> for DnaWasm {
             TestWasm::ValidationPackageSuccess => {
                 get_code("wasm32-unknown-unknown/release/test_wasm_validation_package_success.wasm")
             }
+            TestWasm::ValidationPackageUnresolved => get_code(
+                "wasm32-unknown-unknown/release/test_wasm_validation_package_unresolved.wasm",
+            ),
             TestWasm::WhoAmI => get_code("wasm32-unknown-unknown/release/test_wasm_whoami.wasm"),
             TestWasm::ZomeInfo => {
                 get_code("wasm32-unknown-unknown/release/test_wasm_zome_info.wasm")
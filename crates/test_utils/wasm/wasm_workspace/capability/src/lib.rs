@@ -51,6 +51,11 @@ pub fn delete_cap_grant(header_hash: HeaderHash) -> ExternResult<HeaderHash> {
     hdk::prelude::delete_cap_grant(header_hash)
 }
 
+#[hdk_extern]
+fn active_cap_grants(_: ()) -> ExternResult<Vec<(HeaderHash, CapGrantEntry)>> {
+    query_cap_grants()
+}
+
 #[hdk_extern]
 fn get_entry(header_hash: HeaderHash) -> ExternResult<Option<Element>> {
     get(header_hash, GetOptions::latest())
@@ -28,6 +28,10 @@ fn post() -> Post {
     Post("foo".into())
 }
 
+fn updated_post() -> Post {
+    Post("bar".into())
+}
+
 fn msg() -> Msg {
     Msg("hello".into())
 }
@@ -135,6 +139,33 @@ fn call_create_entry(_: ()) -> ExternResult<HeaderHash> {
     }
 }
 
+#[hdk_extern]
+fn update_post(original: HeaderHash) -> ExternResult<HeaderHash> {
+    hdk::prelude::update_entry(original, &updated_post())
+}
+
+/// Get the updated post, update the original
+/// post through a call then get it again
+#[hdk_extern]
+fn call_update_post(original: HeaderHash) -> ExternResult<(Option<Element>, Option<Element>)> {
+    let updated = hash_entry(&updated_post())?;
+    let before = get(updated.clone(), GetOptions::content())?;
+    let zome_call_response: ZomeCallResponse = call(
+        None,
+        "create_entry".to_string().into(),
+        "update_post".to_string().into(),
+        None,
+        &original,
+    )?;
+    match zome_call_response {
+        ZomeCallResponse::Ok(_) => (),
+        // Should handle this in real code.
+        _ => unreachable!(),
+    }
+    let after = get(updated, GetOptions::content())?;
+    Ok((before, after))
+}
+
 #[hdk_extern]
 fn call_create_entry_remotely(agent: AgentPubKey) -> ExternResult<HeaderHash> {
     let zome_call_response: ZomeCallResponse = call_remote(
@@ -14,19 +14,122 @@ impl std::ops::Add for CounTree {
 }
 
 impl CounTree {
+    /// the header could not be fetched, possibly only for now
+    pub const NOT_FOUND: &'static str = "countree header not found";
+    /// the countree was deleted so incrementing it would be a reset
+    pub const DELETED: &'static str = "countree was deleted";
+    /// the header does not point at a countree entry
+    pub const NOT_A_COUNTREE: &'static str = "header is not for a countree";
+    /// the link is not a live link from the anchor
+    pub const NOT_LISTED: &'static str = "countree is not listed";
+
     #[allow(clippy::new_ret_no_self)]
     /// ensures that a default countree exists and returns the header
     pub fn new() -> ExternResult<HeaderHash> {
         Self::ensure(Self::default())
     }
 
+    /// the fixed anchor every created countree is linked from
+    fn anchor() -> ExternResult<EntryHash> {
+        let path = Path::from("countrees");
+        path.ensure()?;
+        path.hash()
+    }
+
     /// commits if not exists else returns found header
     /// produces redundant headers in a partition
     pub fn ensure(countree: CounTree) -> ExternResult<HeaderHash> {
         match get(hash_entry(&countree)?, GetOptions::latest())? {
             Some(element) => Ok(element.header_address().to_owned()),
-            None => create_entry(&countree),
+            None => {
+                let header_hash = create_entry(&countree)?;
+                create_link(Self::anchor()?, hash_entry(&countree)?, ())?;
+                Ok(header_hash)
+            }
+        }
+    }
+
+    /// the headers of every countree linked from the anchor
+    pub fn list_all() -> ExternResult<Vec<HeaderHash>> {
+        let mut header_hashes = Vec::new();
+        for link in get_links(Self::anchor()?, None)?.into_inner() {
+            if let Some(element) = get(link.target, GetOptions::latest())? {
+                header_hashes.push(element.header_address().to_owned());
+            }
+        }
+        Ok(header_hashes)
+    }
+
+    /// links the countree of the given header from the anchor
+    /// so it shows up in list_all again after being unlisted
+    pub fn list(header_hash: HeaderHash) -> ExternResult<HeaderHash> {
+        let element = match get(header_hash, GetOptions::latest())? {
+            Some(element) => element,
+            None => return Err(WasmError::Guest(Self::NOT_FOUND.to_string())),
+        };
+        let entry_hash = match element.header().entry_hash() {
+            Some(entry_hash) => entry_hash.clone(),
+            None => return Err(WasmError::Guest(Self::NOT_A_COUNTREE.to_string())),
+        };
+        create_link(Self::anchor()?, entry_hash, ())
+    }
+
+    /// removes the link with the given create header from the anchor
+    /// errors with NOT_LISTED rather than committing a delete for a link
+    /// that isn't on the anchor or has already been removed
+    pub fn unlist(link_header_hash: HeaderHash) -> ExternResult<HeaderHash> {
+        let listed = get_links(Self::anchor()?, None)?
+            .into_inner()
+            .into_iter()
+            .any(|link| link.create_link_hash == link_header_hash);
+        if !listed {
+            return Err(WasmError::Guest(Self::NOT_LISTED.to_string()));
+        }
+        delete_link(link_header_hash)
+    }
+
+    /// every link create from the anchor with the deletes on it
+    /// as header hashes, including creates that have been deleted
+    pub fn link_history() -> ExternResult<Vec<(HeaderHash, Vec<HeaderHash>)>> {
+        Ok(get_link_details(Self::anchor()?, None)?
+            .into_inner()
+            .into_iter()
+            .map(|(create, deletes)| {
+                (
+                    create.header_address().to_owned(),
+                    deletes
+                        .into_iter()
+                        .map(|delete| delete.header_address().to_owned())
+                        .collect(),
+                )
+            })
+            .collect())
+    }
+
+    /// the headers of every countree the agent has authored in chain order
+    pub fn history(agent: AgentPubKey) -> ExternResult<Vec<HeaderHash>> {
+        let filter = QueryFilter::new().entry_type(entry_type!(CounTree)?);
+        let activity = get_agent_activity(agent, filter, ActivityRequest::Full)?;
+        Ok(activity
+            .valid_activity
+            .into_iter()
+            .map(|(_, header_hash)| header_hash)
+            .collect())
+    }
+
+    /// every countree this agent has committed, latest first
+    /// includes countrees committed earlier in this same call
+    pub fn mine() -> ExternResult<Vec<CounTree>> {
+        let filter = QueryFilter::new()
+            .entry_type(entry_type!(CounTree)?)
+            .include_entries(true);
+        let mut countrees = Vec::new();
+        for element in query(filter)? {
+            if let Some(countree) = element.entry().to_app_option()? {
+                countrees.push(countree);
+            }
         }
+        Ok(countrees)
     }
 
     pub fn header_details(header_hash: HeaderHash) -> ExternResult<Option<Details>> {
@@ -44,6 +147,32 @@ impl CounTree {
         )
     }
 
+    /// the element details for the header or NOT_FOUND
+    fn element_details(header_hash: HeaderHash) -> ExternResult<ElementDetails> {
+        match get_details(header_hash, GetOptions::latest())? {
+            Some(Details::Element(details)) => Ok(details),
+            _ => Err(WasmError::Guest(Self::NOT_FOUND.to_string())),
+        }
+    }
+
+    /// the hashes of every delete header on the given header
+    pub fn deletions(header_hash: HeaderHash) -> ExternResult<Vec<HeaderHash>> {
+        Ok(Self::element_details(header_hash)?
+            .deletes
+            .into_iter()
+            .map(|delete| delete.header_address().to_owned())
+            .collect())
+    }
+
+    /// the hashes of every update header on the given header
+    pub fn updates(header_hash: HeaderHash) -> ExternResult<Vec<HeaderHash>> {
+        Ok(Self::element_details(header_hash)?
+            .updates
+            .into_iter()
+            .map(|update| update.header_address().to_owned())
+            .collect())
+    }
+
     /// increments the given header hash by 1 or creates it if not found
     /// this is silly as being offline resets the counter >.<
     pub fn incsert(header_hash: HeaderHash) -> ExternResult<HeaderHash> {
@@ -58,6 +187,25 @@ impl CounTree {
         update_entry(header_hash, &(current + CounTree(1)))
     }
 
+    /// increments the given header hash by 1 without ever resetting the counter
+    /// errors with NOT_FOUND if the header can't be fetched right now so the caller can retry
+    /// errors with DELETED if the countree has really gone
+    pub fn incsert_checked(header_hash: HeaderHash) -> ExternResult<HeaderHash> {
+        let details = match get_details(header_hash.clone(), GetOptions::latest())? {
+            Some(Details::Element(details)) => details,
+            _ => return Err(WasmError::Guest(Self::NOT_FOUND.to_string())),
+        };
+        if !details.deletes.is_empty() {
+            return Err(WasmError::Guest(Self::DELETED.to_string()));
+        }
+        let current: CounTree = match details.element.entry().to_app_option()? {
+            Some(v) => v,
+            None => return Err(WasmError::Guest(Self::NOT_A_COUNTREE.to_string())),
+        };
+
+        update_entry(header_hash, &(current + CounTree(1)))
+    }
+
     pub fn dec(header_hash: HeaderHash) -> ExternResult<HeaderHash> {
         delete_entry(header_hash)
     }
@@ -1,7 +1,7 @@
 use hdk::prelude::*;
 mod countree;
 
-entry_defs![countree::CounTree::entry_def()];
+entry_defs![countree::CounTree::entry_def(), Path::entry_def()];
 
 #[hdk_extern]
 fn new(_: ()) -> ExternResult<HeaderHash> {
@@ -28,7 +28,52 @@ fn inc(header_hash: HeaderHash) -> ExternResult<HeaderHash> {
     countree::CounTree::incsert(header_hash)
 }
 
+#[hdk_extern]
+fn inc_checked(header_hash: HeaderHash) -> ExternResult<HeaderHash> {
+    countree::CounTree::incsert_checked(header_hash)
+}
+
+#[hdk_extern]
+fn list_all(_: ()) -> ExternResult<Vec<HeaderHash>> {
+    countree::CounTree::list_all()
+}
+
+#[hdk_extern]
+fn list(header_hash: HeaderHash) -> ExternResult<HeaderHash> {
+    countree::CounTree::list(header_hash)
+}
+
+#[hdk_extern]
+fn unlist(link_header_hash: HeaderHash) -> ExternResult<HeaderHash> {
+    countree::CounTree::unlist(link_header_hash)
+}
+
+#[hdk_extern]
+fn link_history(_: ()) -> ExternResult<Vec<(HeaderHash, Vec<HeaderHash>)>> {
+    countree::CounTree::link_history()
+}
+
 #[hdk_extern]
 fn dec(header_hash: HeaderHash) -> ExternResult<HeaderHash> {
     countree::CounTree::dec(header_hash)
 }
+
+#[hdk_extern]
+fn deletions(header_hash: HeaderHash) -> ExternResult<Vec<HeaderHash>> {
+    countree::CounTree::deletions(header_hash)
+}
+
+#[hdk_extern]
+fn updates(header_hash: HeaderHash) -> ExternResult<Vec<HeaderHash>> {
+    countree::CounTree::updates(header_hash)
+}
+
+#[hdk_extern]
+fn history(agent: AgentPubKey) -> ExternResult<Vec<HeaderHash>> {
+    countree::CounTree::history(agent)
+}
+
+#[hdk_extern]
+fn mine(_: ()) -> ExternResult<Vec<countree::CounTree>> {
+    countree::CounTree::mine()
+}
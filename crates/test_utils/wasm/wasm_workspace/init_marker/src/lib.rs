@@ -0,0 +1,20 @@
+use hdk::prelude::*;
+
+#[hdk_entry(id = "marker")]
+/// committed by init so tests can see how many times it ran
+struct Marker;
+
+entry_defs![Marker::entry_def()];
+
+#[hdk_extern]
+fn init(_: ()) -> ExternResult<InitCallbackResult> {
+    create_entry(&Marker)?;
+    Ok(InitCallbackResult::Pass)
+}
+
+/// the number of markers on the chain, i.e. the number of times init ran
+#[hdk_extern]
+fn markers(_: ()) -> ExternResult<u32> {
+    let filter = QueryFilter::new().entry_type(entry_type!(Marker)?);
+    Ok(query(filter)?.len() as u32)
+}
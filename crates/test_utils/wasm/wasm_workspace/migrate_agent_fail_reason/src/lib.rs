@@ -0,0 +1,9 @@
+use hdk::prelude::*;
+
+#[hdk_extern]
+fn migrate_agent(migrate_agent: MigrateAgent) -> ExternResult<MigrateAgentCallbackResult> {
+    Ok(MigrateAgentCallbackResult::Fail(format!(
+        "no migrate {:?}",
+        migrate_agent
+    )))
+}
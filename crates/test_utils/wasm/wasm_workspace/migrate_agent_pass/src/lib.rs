@@ -1,6 +1,69 @@
 use hdk::prelude::*;
 
+#[hdk_entry(id = "note")]
+struct Note(String);
+
+entry_defs![Note::entry_def()];
+
 #[hdk_extern]
 fn migrate_agent(_: MigrateAgent) -> ExternResult<MigrateAgentCallbackResult> {
     Ok(MigrateAgentCallbackResult::Pass)
 }
+
+#[hdk_extern]
+fn create_note(note: String) -> ExternResult<HeaderHash> {
+    create_entry(&Note(note))
+}
+
+/// every note on this chain, in chain order
+#[hdk_extern]
+fn notes(_: ()) -> ExternResult<Vec<String>> {
+    let filter = QueryFilter::new()
+        .entry_type(entry_type!(Note)?)
+        .include_entries(true);
+    let mut notes = Vec::new();
+    for element in query(filter)? {
+        if let Some(Note(note)) = element.entry().to_app_option()? {
+            notes.push(note);
+        }
+    }
+    Ok(notes)
+}
+
+#[hdk_extern]
+fn close_chain(new_dna_hash: DnaHash) -> ExternResult<HeaderHash> {
+    hdk::prelude::close_chain(new_dna_hash)
+}
+
+#[hdk_extern]
+fn open_chain(prev_dna_hash: DnaHash) -> ExternResult<HeaderHash> {
+    hdk::prelude::open_chain(prev_dna_hash)
+}
+
+/// copy the notes from the given cell of this agent to this chain
+#[hdk_extern]
+fn migrate_notes(prev_cell_id: CellId) -> ExternResult<Vec<HeaderHash>> {
+    let zome_call_response: ZomeCallResponse = call(
+        Some(prev_cell_id),
+        zome_info()?.zome_name,
+        "notes".to_string().into(),
+        None,
+        &(),
+    )?;
+    let notes: Vec<String> = match zome_call_response {
+        ZomeCallResponse::Ok(v) => v.decode()?,
+        // This should be handled in real code.
+        _ => unreachable!(),
+    };
+    notes.into_iter().map(create_note).collect()
+}
+
+/// the CloseChain and OpenChain headers on this chain, in chain order
+#[hdk_extern]
+fn migration_headers(_: ()) -> ExternResult<Vec<Header>> {
+    Ok(query(QueryFilter::new())?
+        .into_iter()
+        .map(|element| element.header().clone())
+        .filter(|header| matches!(header, Header::CloseChain(_) | Header::OpenChain(_)))
+        .collect())
+}
@@ -0,0 +1,43 @@
+use hdk::prelude::*;
+
+#[hdk_entry(id = "post")]
+struct Post(String);
+
+#[hdk_entry(id = "post_commit_record")]
+/// the headers post_commit was called with that it could get
+struct PostCommitRecord(Vec<HeaderHash>);
+
+entry_defs![Post::entry_def(), PostCommitRecord::entry_def()];
+
+#[hdk_extern]
+fn create_post(post: Post) -> ExternResult<HeaderHash> {
+    create_entry(&post)
+}
+
+/// records every committed header that is already visible to post_commit
+#[hdk_extern]
+fn post_commit(headers: HeaderHashes) -> ExternResult<PostCommitCallbackResult> {
+    let mut seen = Vec::new();
+    for header_hash in headers.0 {
+        if get(header_hash.clone(), GetOptions::latest())?.is_some() {
+            seen.push(header_hash);
+        }
+    }
+    create_entry(&PostCommitRecord(seen))?;
+    Ok(PostCommitCallbackResult::Success)
+}
+
+/// every record post_commit made, in chain order
+#[hdk_extern]
+fn post_commit_records(_: ()) -> ExternResult<Vec<Vec<HeaderHash>>> {
+    let filter = QueryFilter::new()
+        .entry_type(entry_type!(PostCommitRecord)?)
+        .include_entries(true);
+    let mut records = Vec::new();
+    for element in query(filter)? {
+        if let Some(PostCommitRecord(headers)) = element.entry().to_app_option()? {
+            records.push(headers);
+        }
+    }
+    Ok(records)
+}
@@ -8,7 +8,10 @@ struct Song;
 #[hdk_entry(id = "artist", required_validation_type = "custom")]
 struct Artist;
 
-entry_defs![Song::entry_def(), Artist::entry_def()];
+#[hdk_entry(id = "album", required_validation_type = "custom")]
+struct Album;
+
+entry_defs![Song::entry_def(), Artist::entry_def(), Album::entry_def()];
 
 #[hdk_extern]
 fn validation_package(
@@ -37,6 +40,14 @@ fn validation_package(
                 ))
             }
         }
+        // Album
+        // Albums are validated against the entire source chain
+        2 => {
+            let query = QueryFilter::new().include_entries(true);
+            Ok(ValidationPackageCallbackResult::Success(
+                ValidationPackage::new(hdk::prelude::query(query)?),
+            ))
+        }
         _ => Ok(ValidationPackageCallbackResult::Success(
             ValidationPackage::new(vec![]),
         )),
@@ -48,6 +59,11 @@ fn commit_artist(_: ()) -> ExternResult<HeaderHash> {
     create_entry(&Artist)
 }
 
+#[hdk_extern]
+fn commit_album(_: ()) -> ExternResult<HeaderHash> {
+    create_entry(&Album)
+}
+
 #[hdk_extern]
 fn commit_songs(_: ()) -> ExternResult<()> {
     for _ in 0..NUM_SONGS {
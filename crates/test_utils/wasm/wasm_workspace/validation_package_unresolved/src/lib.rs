@@ -0,0 +1,13 @@
+use hdk::prelude::*;
+
+/// The dependency that can never be found.
+fn missing() -> AnyDhtHash {
+    EntryHash::from_raw_36(vec![0; 36]).into()
+}
+
+#[hdk_extern]
+fn validation_package(_: AppEntryType) -> ExternResult<ValidationPackageCallbackResult> {
+    Ok(ValidationPackageCallbackResult::UnresolvedDependencies(
+        vec![missing()],
+    ))
+}